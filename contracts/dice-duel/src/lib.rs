@@ -3,37 +3,28 @@
 //! # Dice Duel
 //!
 //! A two-player dice game where each player rolls two dice.
-//! The player with the highest total wins (ties go to player 1).
+//! The player with the highest total wins. Equal totals are reported to the
+//! Game Hub as a draw.
+//!
+//! The dice are seeded by both players: each `roll`s a commitment
+//! `keccak256(secret || player_address)` to a secret of their choosing, and
+//! once both have rolled each reveals the secret with `reveal_secret`. Neither
+//! player can predict or steer the dice without the other's secret.
 //!
 //! **Game Hub Integration:**
 //! This game is Game Hub-aware and enforces all games to be played through the
 //! Game Hub contract. Games cannot be started or completed without points involvement.
 
+use sgs_game_common::{
+    hub_client, require_not_paused, write_game, GameCallbacks, GameContract, GameInfo,
+    SessionStatus, StakeSemantics,
+};
 use soroban_sdk::{
-    Address, Bytes, BytesN, Env, IntoVal, contract, contractclient, contracterror, contractimpl,
+    Address, Bytes, BytesN, Env, IntoVal, String, contract, contracterror, contractimpl,
     contracttype, vec
 };
 
-// Import GameHub contract interface
-// This allows us to call into the GameHub contract
-#[contractclient(name = "GameHubClient")]
-pub trait GameHub {
-    fn start_game(
-        env: Env,
-        game_id: Address,
-        session_id: u32,
-        player1: Address,
-        player2: Address,
-        player1_points: i128,
-        player2_points: i128,
-    );
-
-    fn end_game(
-        env: Env,
-        session_id: u32,
-        player1_won: bool
-    );
-}
+pub use sgs_game_common::GameOutcome;
 
 // ============================================================================
 // Errors
@@ -48,12 +39,25 @@ pub enum Error {
     AlreadyRolled = 3,
     BothPlayersNotRolled = 4,
     GameAlreadyEnded = 5,
+    PlayerNotStalling = 6,
+    ContractPaused = 7,
+    SessionAlreadyExists = 8,
+    AlreadyRevealed = 9,
+    CommitmentMismatch = 10,
+    BothPlayersNotRevealed = 11,
 }
 
 // ============================================================================
 // Data Types
 // ============================================================================
 
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum GameStatus {
+    Active,
+    Ended(GameOutcome),
+}
+
 #[contracttype]
 #[derive(Clone, Debug, Eq, PartialEq)]
 pub struct Game {
@@ -61,37 +65,37 @@ pub struct Game {
     pub player2: Address,
     pub player1_points: i128,
     pub player2_points: i128,
-    pub player1_rolled: bool,
-    pub player2_rolled: bool,
+    /// Commitments to each player's secret, set by `roll`
+    pub player1_commitment: Option<BytesN<32>>,
+    pub player2_commitment: Option<BytesN<32>>,
+    /// Revealed secrets, set by `reveal_secret`
+    pub player1_secret: Option<BytesN<32>>,
+    pub player2_secret: Option<BytesN<32>>,
     pub player1_die1: Option<u32>,
     pub player1_die2: Option<u32>,
     pub player2_die1: Option<u32>,
     pub player2_die2: Option<u32>,
     pub winner: Option<Address>,
+    pub status: GameStatus,
 }
 
 #[contracttype]
 #[derive(Clone)]
 pub enum DataKey {
     Game(u32),
-    GameHubAddress,
-    Admin,
 }
 
-// ============================================================================
-// Storage TTL Management
-// ============================================================================
-// TTL (Time To Live) ensures game data doesn't expire unexpectedly
-// Games are stored in temporary storage with a minimum 30-day retention
-
-/// TTL for game storage (30 days in ledgers, ~5 seconds per ledger)
-/// 30 days = 30 * 24 * 60 * 60 / 5 = 518,400 ledgers
-const GAME_TTL_LEDGERS: u32 = 518_400;
-
 // ============================================================================
 // Helper Functions
 // ============================================================================
 
+/// Compute the commitment hash: keccak256(secret || player_address)
+fn compute_commitment(env: &Env, secret: &BytesN<32>, player: &Address) -> BytesN<32> {
+    let mut data = Bytes::from(secret.clone());
+    data.append(&player.to_string().to_bytes());
+    env.crypto().keccak256(&data).into()
+}
+
 /// Roll a single die (1-6) using deterministic PRNG
 fn roll_die(env: &Env, seed: BytesN<32>) -> u32 {
     env.prng().seed(seed.into());
@@ -114,10 +118,7 @@ impl DiceDuelContract {
     /// * `game_hub` - Address of the GameHub contract
     pub fn __constructor(env: Env, admin: Address, game_hub: Address) {
         // Store admin and GameHub address
-        env.storage().instance().set(&DataKey::Admin, &admin);
-        env.storage()
-            .instance()
-            .set(&DataKey::GameHubAddress, &game_hub);
+        sgs_game_common::init(&env, &admin, &game_hub);
     }
 
     /// Start a new game between two players with points.
@@ -127,7 +128,7 @@ impl DiceDuelContract {
     /// The Game Hub will call `game_id.require_auth()` which checks this contract's address.
     ///
     /// # Arguments
-    /// * `session_id` - Unique session identifier (u32)
+    /// * `session_id` - Session ID reserved on the Game Hub (`reserve_session`)
     /// * `player1` - Address of first player
     /// * `player2` - Address of second player
     /// * `player1_points` - Points amount committed by player 1
@@ -140,6 +141,8 @@ impl DiceDuelContract {
         player1_points: i128,
         player2_points: i128,
     ) -> Result<(), Error> {
+        require_not_paused(&env, Error::ContractPaused)?;
+
         // Prevent self-play: Player 1 and Player 2 must be different
         if player1 == player2 {
             panic!("Cannot play against yourself: Player 1 and Player 2 must be different addresses");
@@ -149,15 +152,12 @@ impl DiceDuelContract {
         player1.require_auth_for_args(vec![&env, session_id.into_val(&env), player1_points.into_val(&env)]);
         player2.require_auth_for_args(vec![&env, session_id.into_val(&env), player2_points.into_val(&env)]);
 
-        // Get GameHub address
-        let game_hub_addr: Address = env
-            .storage()
-            .instance()
-            .get(&DataKey::GameHubAddress)
-            .expect("GameHub address not set");
+        if env.storage().temporary().has(&DataKey::Game(session_id)) {
+            return Err(Error::SessionAlreadyExists);
+        }
 
         // Create GameHub client
-        let game_hub = GameHubClient::new(&env, &game_hub_addr);
+        let game_hub = hub_client(&env);
 
         // Call the Game Hub to start the session and lock points
         // This requires THIS contract's authorization (env.current_contract_address())
@@ -176,23 +176,20 @@ impl DiceDuelContract {
             player2: player2.clone(),
             player1_points,
             player2_points,
-            player1_rolled: false,
-            player2_rolled: false,
+            player1_commitment: None,
+            player2_commitment: None,
+            player1_secret: None,
+            player2_secret: None,
             player1_die1: None,
             player1_die2: None,
             player2_die1: None,
             player2_die2: None,
             winner: None,
+            status: GameStatus::Active,
         };
 
-        // Store game in temporary storage with 30-day TTL
-        let game_key = DataKey::Game(session_id);
-        env.storage().temporary().set(&game_key, &game);
-
-        // Set TTL to ensure game is retained for at least 30 days
-        env.storage()
-            .temporary()
-            .extend_ttl(&game_key, GAME_TTL_LEDGERS, GAME_TTL_LEDGERS);
+        // Store game in temporary storage, retained for at least 30 days
+        write_game(&env, &DataKey::Game(session_id), &game);
 
         // Event emitted by GameHub contract (GameStarted)
 
@@ -200,12 +197,22 @@ impl DiceDuelContract {
     }
 
     /// Commit a roll for the current game.
-    /// Both players must roll before the winner can be revealed.
+    /// Both players must roll, then reveal their secrets, before the winner
+    /// can be revealed.
     ///
     /// # Arguments
     /// * `session_id` - The session ID of the game
     /// * `player` - Address of the player rolling the dice
-    pub fn roll(env: Env, session_id: u32, player: Address) -> Result<(), Error> {
+    /// * `commitment` - `keccak256(secret || player_address)` for a 32-byte
+    ///   secret chosen by the player
+    pub fn roll(
+        env: Env,
+        session_id: u32,
+        player: Address,
+        commitment: BytesN<32>,
+    ) -> Result<(), Error> {
+        require_not_paused(&env, Error::ContractPaused)?;
+
         player.require_auth();
 
         // Get game from temporary storage
@@ -216,22 +223,22 @@ impl DiceDuelContract {
             .get(&key)
             .ok_or(Error::GameNotFound)?;
 
-        // Check game is still active (no winner yet)
-        if game.winner.is_some() {
+        // Check game is still active
+        if game.status != GameStatus::Active {
             return Err(Error::GameAlreadyEnded);
         }
 
         // Update roll commitment for the appropriate player
         if player == game.player1 {
-            if game.player1_rolled {
+            if game.player1_commitment.is_some() {
                 return Err(Error::AlreadyRolled);
             }
-            game.player1_rolled = true;
+            game.player1_commitment = Some(commitment);
         } else if player == game.player2 {
-            if game.player2_rolled {
+            if game.player2_commitment.is_some() {
                 return Err(Error::AlreadyRolled);
             }
-            game.player2_rolled = true;
+            game.player2_commitment = Some(commitment);
         } else {
             return Err(Error::NotPlayer);
         }
@@ -242,16 +249,82 @@ impl DiceDuelContract {
         Ok(())
     }
 
-    /// Reveal the winner of the game and submit outcome to GameHub.
+    /// Reveal the secret committed in `roll`.
     /// Can only be called after both players have rolled.
+    ///
+    /// # Arguments
+    /// * `session_id` - The session ID of the game
+    /// * `player` - Address of the player revealing
+    /// * `secret` - The secret behind the player's commitment
+    pub fn reveal_secret(
+        env: Env,
+        session_id: u32,
+        player: Address,
+        secret: BytesN<32>,
+    ) -> Result<(), Error> {
+        require_not_paused(&env, Error::ContractPaused)?;
+
+        player.require_auth();
+
+        // Get game from temporary storage
+        let key = DataKey::Game(session_id);
+        let mut game: Game = env
+            .storage()
+            .temporary()
+            .get(&key)
+            .ok_or(Error::GameNotFound)?;
+
+        // Check game is still active
+        if game.status != GameStatus::Active {
+            return Err(Error::GameAlreadyEnded);
+        }
+
+        // Nobody reveals until both secrets are committed
+        if game.player1_commitment.is_none() || game.player2_commitment.is_none() {
+            return Err(Error::BothPlayersNotRolled);
+        }
+
+        let expected = compute_commitment(&env, &secret, &player);
+
+        // Check the secret against the appropriate player's commitment
+        if player == game.player1 {
+            if game.player1_secret.is_some() {
+                return Err(Error::AlreadyRevealed);
+            }
+            if game.player1_commitment != Some(expected) {
+                return Err(Error::CommitmentMismatch);
+            }
+            game.player1_secret = Some(secret);
+        } else if player == game.player2 {
+            if game.player2_secret.is_some() {
+                return Err(Error::AlreadyRevealed);
+            }
+            if game.player2_commitment != Some(expected) {
+                return Err(Error::CommitmentMismatch);
+            }
+            game.player2_secret = Some(secret);
+        } else {
+            return Err(Error::NotPlayer);
+        }
+
+        // Store updated game in temporary storage
+        env.storage().temporary().set(&key, &game);
+
+        Ok(())
+    }
+
+    /// Reveal the winner of the game and submit outcome to GameHub.
+    /// Can only be called after both players have revealed their secrets.
     /// This generates dice rolls for both players, determines the winner, and ends the session.
     ///
     /// # Arguments
     /// * `session_id` - The session ID of the game
     ///
     /// # Returns
-    /// * `Address` - Address of the winning player
-    pub fn reveal_winner(env: Env, session_id: u32) -> Result<Address, Error> {
+    /// * `Option<Address>` - Address of the winning player, or `None` for a draw
+    pub fn reveal_winner(env: Env, session_id: u32) -> Result<Option<Address>, Error> {
+        require_not_paused(&env, Error::ContractPaused)?;
+
         // Get game from temporary storage
         let key = DataKey::Game(session_id);
         let mut game: Game = env
@@ -260,25 +333,37 @@ impl DiceDuelContract {
             .get(&key)
             .ok_or(Error::GameNotFound)?;
 
-        // Check if game already ended (has a winner)
-        if let Some(winner) = &game.winner {
-            return Ok(winner.clone());
+        // Check if game already ended
+        if game.status != GameStatus::Active {
+            return Ok(game.winner);
         }
 
-        // Check both players have rolled
-        if !game.player1_rolled || !game.player2_rolled {
+        // Check both players have rolled and revealed
+        if game.player1_commitment.is_none() || game.player2_commitment.is_none() {
             return Err(Error::BothPlayersNotRolled);
         }
+        let secret1 = game
+            .player1_secret
+            .clone()
+            .ok_or(Error::BothPlayersNotRevealed)?;
+        let secret2 = game
+            .player2_secret
+            .clone()
+            .ok_or(Error::BothPlayersNotRevealed)?;
 
         // Generate deterministic dice rolls (1-6)
         // Seed components (all deterministic and identical between sim/submit):
         // 1. Session ID - unique per game
-        // 2. Player addresses - both players contribute
+        // 2. Both revealed secrets - each was committed before either was
+        //    revealed, so neither player controls or can predict the seed alone
+        // 3. Player addresses
         //
         // Note: We do NOT include ledger sequence or timestamp because those differ
         // between simulation and submission, which would cause different winners.
         let mut seed_bytes = Bytes::new(&env);
         seed_bytes.append(&Bytes::from_array(&env, &session_id.to_be_bytes()));
+        seed_bytes.append(&secret1.into());
+        seed_bytes.append(&secret2.into());
         seed_bytes.append(&game.player1.to_string().to_bytes());
         seed_bytes.append(&game.player2.to_string().to_bytes());
         let base_seed = env.crypto().keccak256(&seed_bytes);
@@ -309,36 +394,32 @@ impl DiceDuelContract {
         game.player2_die1 = Some(player2_die1);
         game.player2_die2 = Some(player2_die2);
 
-        // Determine winner (if tie, player1 wins)
+        // Determine outcome (equal totals are a draw)
         let player1_total = player1_die1 + player1_die2;
         let player2_total = player2_die1 + player2_die2;
-        let winner = if player1_total >= player2_total {
-            game.player1.clone()
+        let outcome = if player1_total > player2_total {
+            game.winner = Some(game.player1.clone());
+            GameOutcome::Player1Won
+        } else if player2_total > player1_total {
+            game.winner = Some(game.player2.clone());
+            GameOutcome::Player2Won
         } else {
-            game.player2.clone()
+            GameOutcome::Draw
         };
 
-        // Update game with winner (this marks the game as ended)
-        game.winner = Some(winner.clone());
+        // Update game with outcome (this marks the game as ended)
+        game.status = GameStatus::Ended(outcome.clone());
         env.storage().temporary().set(&key, &game);
 
-        // Get GameHub address
-        let game_hub_addr: Address = env
-            .storage()
-            .instance()
-            .get(&DataKey::GameHubAddress)
-            .expect("GameHub address not set");
-
         // Create GameHub client
-        let game_hub = GameHubClient::new(&env, &game_hub_addr);
+        let game_hub = hub_client(&env);
 
         // Call GameHub to end the session
         // This unlocks points and updates standings
         // Event emitted by the Game Hub contract (GameEnded)
-        let player1_won = winner == game.player1; // true if player1 won, false if player2 won
-        game_hub.end_game(&session_id, &player1_won);
+        game_hub.end_game_with_outcome(&session_id, &outcome);
 
-        Ok(winner)
+        Ok(game.winner)
     }
 
     /// Get game information.
@@ -355,81 +436,168 @@ impl DiceDuelContract {
             .get(&key)
             .ok_or(Error::GameNotFound)
     }
+}
 
-    // ========================================================================
-    // Admin Functions
-    // ========================================================================
+// ============================================================================
+// Game Interface
+// ============================================================================
 
-    /// Get the current admin address
-    ///
-    /// # Returns
-    /// * `Address` - The admin address
-    pub fn get_admin(env: Env) -> Address {
-        env.storage()
-            .instance()
-            .get(&DataKey::Admin)
-            .expect("Admin not set")
+#[contractimpl]
+impl GameContract for DiceDuelContract {
+    /// Describe the game: two players, winner takes both stakes
+    fn game_info(env: Env) -> GameInfo {
+        GameInfo {
+            name: String::from_str(&env, "Dice Duel"),
+            rules_version: 2,
+            min_players: 2,
+            max_players: 2,
+            stakes: StakeSemantics::WinnerTakesAll,
+        }
     }
 
-    /// Set a new admin address
+    /// Get the status of a session, or `None` if it does not exist (or expired)
+    fn get_session_status(env: Env, session_id: u32) -> Option<SessionStatus> {
+        let game: Game = env.storage().temporary().get(&DataKey::Game(session_id))?;
+        Some(match game.status {
+            GameStatus::Active => SessionStatus::Active,
+            GameStatus::Ended(outcome) => SessionStatus::Ended(outcome),
+        })
+    }
+
+    /// Concede the game. The opponent wins and the Game Hub settles the points.
     ///
     /// # Arguments
-    /// * `new_admin` - The new admin address
-    pub fn set_admin(env: Env, new_admin: Address) {
-        let admin: Address = env
+    /// * `session_id` - The session ID of the game
+    /// * `player` - Address of the player conceding
+    fn forfeit(env: Env, session_id: u32, player: Address) -> Result<(), soroban_sdk::Error> {
+        require_not_paused(&env, Error::ContractPaused)?;
+
+        player.require_auth();
+
+        let key = DataKey::Game(session_id);
+        let mut game: Game = env
             .storage()
-            .instance()
-            .get(&DataKey::Admin)
-            .expect("Admin not set");
-        admin.require_auth();
+            .temporary()
+            .get(&key)
+            .ok_or(Error::GameNotFound)?;
 
-        env.storage().instance().set(&DataKey::Admin, &new_admin);
-    }
+        if game.status != GameStatus::Active {
+            return Err(Error::GameAlreadyEnded.into());
+        }
 
-    /// Get the current GameHub contract address
-    ///
-    /// # Returns
-    /// * `Address` - The GameHub contract address
-    pub fn get_hub(env: Env) -> Address {
-        env.storage()
-            .instance()
-            .get(&DataKey::GameHubAddress)
-            .expect("GameHub address not set")
+        let opponent = if player == game.player1 {
+            game.player2.clone()
+        } else if player == game.player2 {
+            game.player1.clone()
+        } else {
+            return Err(Error::NotPlayer.into());
+        };
+
+        let outcome = GameOutcome::Forfeit(player);
+        game.winner = Some(opponent);
+        game.status = GameStatus::Ended(outcome.clone());
+        env.storage().temporary().set(&key, &game);
+
+        // Event emitted by the Game Hub contract (GameEnded)
+        hub_client(&env).end_game_with_outcome(&session_id, &outcome);
+
+        Ok(())
     }
+}
+
+// ============================================================================
+// Game Hub Callbacks
+// ============================================================================
 
-    /// Set a new GameHub contract address
+#[contractimpl]
+impl GameCallbacks for DiceDuelContract {
+    /// Called by the Game Hub when a timeout is claimed against `player`.
+    /// Only accepted if `player` has not rolled while their opponent has, or
+    /// has not revealed their secret while their opponent has; the game then
+    /// ends as a forfeit by `player`. The hub settles the points.
     ///
     /// # Arguments
-    /// * `new_hub` - The new GameHub contract address
-    pub fn set_hub(env: Env, new_hub: Address) {
-        let admin: Address = env
+    /// * `session_id` - The session ID of the game
+    /// * `player` - Address of the player being forfeited
+    fn on_session_forfeited(
+        env: Env,
+        session_id: u32,
+        player: Address,
+    ) -> Result<(), soroban_sdk::Error> {
+        Self::get_hub(env.clone()).require_auth();
+
+        let key = DataKey::Game(session_id);
+        let mut game: Game = env
             .storage()
-            .instance()
-            .get(&DataKey::Admin)
-            .expect("Admin not set");
-        admin.require_auth();
+            .temporary()
+            .get(&key)
+            .ok_or(Error::GameNotFound)?;
 
-        env.storage()
-            .instance()
-            .set(&DataKey::GameHubAddress, &new_hub);
+        if game.status != GameStatus::Active {
+            return Err(Error::GameAlreadyEnded.into());
+        }
+
+        let rolling = game.player1_commitment.is_none() || game.player2_commitment.is_none();
+        let (stalling, opponent) = if player == game.player1 {
+            let stalling = if rolling {
+                game.player1_commitment.is_none() && game.player2_commitment.is_some()
+            } else {
+                game.player1_secret.is_none() && game.player2_secret.is_some()
+            };
+            (stalling, game.player2.clone())
+        } else if player == game.player2 {
+            let stalling = if rolling {
+                game.player2_commitment.is_none() && game.player1_commitment.is_some()
+            } else {
+                game.player2_secret.is_none() && game.player1_secret.is_some()
+            };
+            (stalling, game.player1.clone())
+        } else {
+            return Err(Error::NotPlayer.into());
+        };
+        if !stalling {
+            return Err(Error::PlayerNotStalling.into());
+        }
+
+        game.winner = Some(opponent);
+        game.status = GameStatus::Ended(GameOutcome::Forfeit(player));
+        env.storage().temporary().set(&key, &game);
+
+        Ok(())
     }
 
-    /// Update the contract WASM hash (upgrade contract)
+    /// Called by the Game Hub when it cancels the session (timeout or pause).
+    /// The game ends without a winner; the hub refunds both stakes.
     ///
     /// # Arguments
-    /// * `new_wasm_hash` - The hash of the new WASM binary
-    pub fn upgrade(env: Env, new_wasm_hash: BytesN<32>) {
-        let admin: Address = env
+    /// * `session_id` - The session ID of the game
+    fn on_session_cancelled(env: Env, session_id: u32) -> Result<(), soroban_sdk::Error> {
+        Self::get_hub(env.clone()).require_auth();
+
+        let key = DataKey::Game(session_id);
+        let mut game: Game = env
             .storage()
-            .instance()
-            .get(&DataKey::Admin)
-            .expect("Admin not set");
-        admin.require_auth();
+            .temporary()
+            .get(&key)
+            .ok_or(Error::GameNotFound)?;
 
-        env.deployer().update_current_contract_wasm(new_wasm_hash);
+        if game.status != GameStatus::Active {
+            return Err(Error::GameAlreadyEnded.into());
+        }
+
+        game.status = GameStatus::Ended(GameOutcome::Cancelled);
+        env.storage().temporary().set(&key, &game);
+
+        Ok(())
     }
 }
 
+// ============================================================================
+// Admin Functions
+// ============================================================================
+
+sgs_game_common::game_admin_entrypoints!(DiceDuelContract);
+
 // ============================================================================
 // Tests
 // ============================================================================
@@ -3,9 +3,10 @@
 // Unit tests for the dice-duel contract using a simple mock GameHub.
 // These tests verify game logic independently of the full GameHub system.
 
-use crate::{DiceDuelContract, DiceDuelContractClient, Error};
+use crate::{DiceDuelContract, DiceDuelContractClient, Error, GameOutcome, GameStatus};
+use sgs_game_common::{SessionStatus, StakeSemantics};
 use soroban_sdk::testutils::{Address as _, Ledger as _};
-use soroban_sdk::{contract, contractimpl, Address, BytesN, Env};
+use soroban_sdk::{contract, contractimpl, Address, Bytes, BytesN, Env, String};
 
 // ============================================================================
 // Mock GameHub for Unit Testing
@@ -28,6 +29,10 @@ impl MockGameHub {
         // Mock implementation - does nothing
     }
 
+    pub fn end_game_with_outcome(_env: Env, _session_id: u32, _outcome: GameOutcome) {
+        // Mock implementation - does nothing
+    }
+
     pub fn end_game(_env: Env, _session_id: u32, _player1_won: bool) {
         // Mock implementation - does nothing
     }
@@ -117,13 +122,47 @@ fn assert_dice_duel_error<T, E>(
     }
 }
 
+/// Secret a test player rolls with (distinct per player)
+fn test_secret(env: &Env, player: &Address) -> BytesN<32> {
+    env.crypto()
+        .keccak256(&player.to_string().to_bytes())
+        .into()
+}
+
+/// Commitment hash: keccak256(secret || player_address)
+fn make_commitment(env: &Env, secret: &BytesN<32>, player: &Address) -> BytesN<32> {
+    let mut data = Bytes::from(secret.clone());
+    data.append(&player.to_string().to_bytes());
+    env.crypto().keccak256(&data).into()
+}
+
+/// Roll for `player`, committing to their test secret
+fn roll(env: &Env, client: &DiceDuelContractClient, session_id: u32, player: &Address) {
+    let commitment = make_commitment(env, &test_secret(env, player), player);
+    client.roll(&session_id, player, &commitment);
+}
+
+/// Both players roll, then both reveal their secrets
+fn roll_and_reveal(
+    env: &Env,
+    client: &DiceDuelContractClient,
+    session_id: u32,
+    player1: &Address,
+    player2: &Address,
+) {
+    roll(env, client, session_id, player1);
+    roll(env, client, session_id, player2);
+    client.reveal_secret(&session_id, player1, &test_secret(env, player1));
+    client.reveal_secret(&session_id, player2, &test_secret(env, player2));
+}
+
 // ============================================================================
 // Basic Game Flow Tests
 // ============================================================================
 
 #[test]
 fn test_complete_game() {
-    let (_env, client, _hub, player1, player2) = setup_test();
+    let (env, client, _hub, player1, player2) = setup_test();
 
     let session_id = 1u32;
     let points = 100_0000000;
@@ -134,29 +173,27 @@ fn test_complete_game() {
     // Verify initial state
     let game = client.get_game(&session_id);
     assert!(game.winner.is_none());
+    assert_eq!(game.status, GameStatus::Active);
     assert_eq!(game.player1, player1);
     assert_eq!(game.player2, player2);
     assert_eq!(game.player1_points, points);
     assert_eq!(game.player2_points, points);
-    assert_eq!(game.player1_rolled, false);
-    assert_eq!(game.player2_rolled, false);
+    assert!(game.player1_commitment.is_none());
+    assert!(game.player2_commitment.is_none());
     assert!(game.player1_die1.is_none());
     assert!(game.player1_die2.is_none());
     assert!(game.player2_die1.is_none());
     assert!(game.player2_die2.is_none());
 
-    // Players roll
-    client.roll(&session_id, &player1);
-    client.roll(&session_id, &player2);
+    // Players roll and reveal their secrets
+    roll_and_reveal(&env, &client, session_id, &player1, &player2);
 
     // Reveal winner
     let winner = client.reveal_winner(&session_id);
-    assert!(winner == player1 || winner == player2);
 
     // Verify dice values and winner stored
     let final_game = client.get_game(&session_id);
-    assert!(final_game.winner.is_some());
-    assert_eq!(final_game.winner.unwrap(), winner);
+    assert_eq!(final_game.winner, winner);
 
     let p1d1 = final_game.player1_die1.unwrap();
     let p1d2 = final_game.player1_die2.unwrap();
@@ -172,63 +209,137 @@ fn test_complete_game() {
     let total2 = p2d1 + p2d2;
     assert!((2..=12).contains(&total1));
     assert!((2..=12).contains(&total2));
+
+    // Outcome follows the totals; equal totals are a draw
+    let (expected_outcome, expected_winner) = if total1 > total2 {
+        (GameOutcome::Player1Won, Some(player1))
+    } else if total2 > total1 {
+        (GameOutcome::Player2Won, Some(player2))
+    } else {
+        (GameOutcome::Draw, None)
+    };
+    assert_eq!(final_game.status, GameStatus::Ended(expected_outcome));
+    assert_eq!(winner, expected_winner);
 }
 
 #[test]
 fn test_cannot_roll_twice() {
-    let (_env, client, _hub, player1, player2) = setup_test();
+    let (env, client, _hub, player1, player2) = setup_test();
 
     let session_id = 2u32;
     let points = 100_0000000;
 
     client.start_game(&session_id, &player1, &player2, &points, &points);
 
-    client.roll(&session_id, &player1);
-    let result = client.try_roll(&session_id, &player1);
+    roll(&env, &client, session_id, &player1);
+    let commitment = make_commitment(&env, &test_secret(&env, &player1), &player1);
+    let result = client.try_roll(&session_id, &player1, &commitment);
     assert_dice_duel_error(&result, Error::AlreadyRolled);
 }
 
 #[test]
 fn test_cannot_reveal_before_both_roll() {
-    let (_env, client, _hub, player1, player2) = setup_test();
+    let (env, client, _hub, player1, player2) = setup_test();
 
     let session_id = 3u32;
     let points = 100_0000000;
 
     client.start_game(&session_id, &player1, &player2, &points, &points);
-    client.roll(&session_id, &player1);
+    roll(&env, &client, session_id, &player1);
 
     let result = client.try_reveal_winner(&session_id);
     assert_dice_duel_error(&result, Error::BothPlayersNotRolled);
+    let secret = test_secret(&env, &player1);
+    let result = client.try_reveal_secret(&session_id, &player1, &secret);
+    assert_dice_duel_error(&result, Error::BothPlayersNotRolled);
+
+    // Both rolled, only player1 revealed
+    roll(&env, &client, session_id, &player2);
+    client.reveal_secret(&session_id, &player1, &secret);
+    let result = client.try_reveal_winner(&session_id);
+    assert_dice_duel_error(&result, Error::BothPlayersNotRevealed);
+}
+
+#[test]
+fn test_reveal_must_match_commitment() {
+    let (env, client, _hub, player1, player2) = setup_test();
+
+    let session_id = 6u32;
+    client.start_game(&session_id, &player1, &player2, &100, &100);
+    roll(&env, &client, session_id, &player1);
+    roll(&env, &client, session_id, &player2);
+
+    // Wrong secret, or another player's secret
+    let wrong = BytesN::from_array(&env, &[0x11; 32]);
+    let result = client.try_reveal_secret(&session_id, &player1, &wrong);
+    assert_dice_duel_error(&result, Error::CommitmentMismatch);
+    let result = client.try_reveal_secret(&session_id, &player1, &test_secret(&env, &player2));
+    assert_dice_duel_error(&result, Error::CommitmentMismatch);
+
+    let secret = test_secret(&env, &player1);
+    client.reveal_secret(&session_id, &player1, &secret);
+    let result = client.try_reveal_secret(&session_id, &player1, &secret);
+    assert_dice_duel_error(&result, Error::AlreadyRevealed);
+}
+
+#[test]
+fn test_dice_depend_on_both_secrets() {
+    let (env, _client, hub, player1, player2) = setup_test();
+    let admin = Address::generate(&env);
+    let session_id = 7u32;
+
+    // Same session ID, same players, same player1 secret: only player2's
+    // secret changes. Each game runs on a fresh contract instance.
+    let player1_dice = |player2_secret: [u8; 32]| {
+        let contract_id = env.register(DiceDuelContract, (&admin, &hub.address));
+        let client = DiceDuelContractClient::new(&env, &contract_id);
+        client.start_game(&session_id, &player1, &player2, &100, &100);
+
+        let secret2 = BytesN::from_array(&env, &player2_secret);
+        roll(&env, &client, session_id, &player1);
+        let commitment = make_commitment(&env, &secret2, &player2);
+        client.roll(&session_id, &player2, &commitment);
+        client.reveal_secret(&session_id, &player1, &test_secret(&env, &player1));
+        client.reveal_secret(&session_id, &player2, &secret2);
+        client.reveal_winner(&session_id);
+
+        let game = client.get_game(&session_id);
+        (game.player1_die1.unwrap(), game.player1_die2.unwrap())
+    };
+
+    // Neither the session ID nor player1's own secret fixes player1's dice
+    let first = player1_dice([1; 32]);
+    assert!((2..=8u8).any(|byte| player1_dice([byte; 32]) != first));
 }
 
 #[test]
 fn test_non_player_cannot_roll() {
-    let (_env, client, _hub, player1, player2) = setup_test();
+    let (env, client, _hub, player1, player2) = setup_test();
 
     let session_id = 4u32;
     let points = 100_0000000;
 
     client.start_game(&session_id, &player1, &player2, &points, &points);
 
-    let non_player = Address::generate(&_env);
-    let result = client.try_roll(&session_id, &non_player);
+    let non_player = Address::generate(&env);
+    let commitment = make_commitment(&env, &test_secret(&env, &non_player), &non_player);
+    let result = client.try_roll(&session_id, &non_player, &commitment);
     assert_dice_duel_error(&result, Error::NotPlayer);
 }
 
 #[test]
 fn test_cannot_roll_after_game_ended() {
-    let (_env, client, _hub, player1, player2) = setup_test();
+    let (env, client, _hub, player1, player2) = setup_test();
 
     let session_id = 5u32;
     let points = 100_0000000;
 
     client.start_game(&session_id, &player1, &player2, &points, &points);
-    client.roll(&session_id, &player1);
-    client.roll(&session_id, &player2);
+    roll_and_reveal(&env, &client, session_id, &player1, &player2);
     client.reveal_winner(&session_id);
 
-    let result = client.try_roll(&session_id, &player1);
+    let commitment = make_commitment(&env, &test_secret(&env, &player1), &player1);
+    let result = client.try_roll(&session_id, &player1, &commitment);
     assert_dice_duel_error(&result, Error::GameAlreadyEnded);
 }
 
@@ -243,3 +354,191 @@ fn test_upgrade_function_exists() {
     // Should fail (WASM doesn't exist) but confirms function signature is correct
     assert!(result.is_err());
 }
+
+#[test]
+fn test_cannot_start_over_live_session() {
+    let (env, client, _hub, player1, player2) = setup_test();
+
+    let session_id = 30u32;
+    client.start_game(&session_id, &player1, &player2, &100, &100);
+
+    let player3 = Address::generate(&env);
+    let result = client.try_start_game(&session_id, &player3, &player2, &100, &100);
+    assert_dice_duel_error(&result, Error::SessionAlreadyExists);
+    assert_eq!(client.get_game(&session_id).player1, player1);
+}
+
+// ============================================================================
+// Game Hub Callback Tests
+// ============================================================================
+
+#[test]
+fn test_hub_forfeits_stalling_player() {
+    let (env, client, hub, player1, player2) = setup_test();
+
+    let session_id = 40u32;
+    client.start_game(&session_id, &player1, &player2, &100, &100);
+    roll(&env, &client, session_id, &player1);
+
+    client.on_session_forfeited(&session_id, &player2);
+    assert_eq!(env.auths()[0].0, hub.address);
+
+    let game = client.get_game(&session_id);
+    assert_eq!(game.winner, Some(player1));
+    assert_eq!(
+        game.status,
+        GameStatus::Ended(GameOutcome::Forfeit(player2.clone()))
+    );
+
+    let commitment = make_commitment(&env, &test_secret(&env, &player2), &player2);
+    let result = client.try_roll(&session_id, &player2, &commitment);
+    assert_dice_duel_error(&result, Error::GameAlreadyEnded);
+}
+
+#[test]
+fn test_hub_forfeits_player_who_has_not_revealed() {
+    let (env, client, _hub, player1, player2) = setup_test();
+
+    let session_id = 43u32;
+    client.start_game(&session_id, &player1, &player2, &100, &100);
+    roll(&env, &client, session_id, &player1);
+    roll(&env, &client, session_id, &player2);
+
+    // Both rolled: nobody is stalling until someone reveals
+    let result = client.try_on_session_forfeited(&session_id, &player2);
+    assert_eq!(result, Err(Ok(Error::PlayerNotStalling.into())));
+
+    // player2 can now compute the dice and declines to reveal a losing roll
+    client.reveal_secret(&session_id, &player1, &test_secret(&env, &player1));
+    client.on_session_forfeited(&session_id, &player2);
+
+    let game = client.get_game(&session_id);
+    assert_eq!(game.winner, Some(player1));
+    assert_eq!(
+        game.status,
+        GameStatus::Ended(GameOutcome::Forfeit(player2.clone()))
+    );
+}
+
+#[test]
+fn test_hub_forfeit_rejected_for_waiting_player() {
+    let (env, client, _hub, player1, player2) = setup_test();
+
+    let session_id = 41u32;
+    client.start_game(&session_id, &player1, &player2, &100, &100);
+
+    // Nobody has moved yet, so neither player is holding up the game
+    let result = client.try_on_session_forfeited(&session_id, &player2);
+    assert_eq!(result, Err(Ok(Error::PlayerNotStalling.into())));
+
+    // player1 has moved and is waiting on player2
+    roll(&env, &client, session_id, &player1);
+    let result = client.try_on_session_forfeited(&session_id, &player1);
+    assert_eq!(result, Err(Ok(Error::PlayerNotStalling.into())));
+    assert_eq!(client.get_game(&session_id).status, GameStatus::Active);
+}
+
+#[test]
+fn test_hub_cancels_session() {
+    let (_env, client, _hub, player1, player2) = setup_test();
+
+    let session_id = 42u32;
+    client.start_game(&session_id, &player1, &player2, &100, &100);
+
+    client.on_session_cancelled(&session_id);
+
+    let game = client.get_game(&session_id);
+    assert!(game.winner.is_none());
+    assert_eq!(game.status, GameStatus::Ended(GameOutcome::Cancelled));
+
+    let result = client.try_on_session_cancelled(&session_id);
+    assert_eq!(result, Err(Ok(Error::GameAlreadyEnded.into())));
+}
+
+// ============================================================================
+// Game Interface Tests
+// ============================================================================
+
+#[test]
+fn test_game_info() {
+    let (env, client, _hub, _player1, _player2) = setup_test();
+
+    let info = client.game_info();
+    assert_eq!(info.name, String::from_str(&env, "Dice Duel"));
+    assert_eq!((info.min_players, info.max_players), (2, 2));
+    assert_eq!(info.stakes, StakeSemantics::WinnerTakesAll);
+}
+
+#[test]
+fn test_forfeit_ends_game_for_opponent() {
+    let (env, client, _hub, player1, player2) = setup_test();
+
+    let session_id = 60u32;
+    assert_eq!(client.get_session_status(&session_id), None);
+    client.start_game(&session_id, &player1, &player2, &100, &100);
+    assert_eq!(
+        client.get_session_status(&session_id),
+        Some(SessionStatus::Active)
+    );
+
+    let outsider = Address::generate(&env);
+    let result = client.try_forfeit(&session_id, &outsider);
+    assert_eq!(result, Err(Ok(Error::NotPlayer.into())));
+
+    client.forfeit(&session_id, &player1);
+    assert_eq!(env.auths()[0].0, player1);
+    assert_eq!(client.get_game(&session_id).winner, Some(player2));
+    assert_eq!(
+        client.get_session_status(&session_id),
+        Some(SessionStatus::Ended(GameOutcome::Forfeit(player1.clone())))
+    );
+
+    let commitment = make_commitment(&env, &test_secret(&env, &player1), &player1);
+    let result = client.try_roll(&session_id, &player1, &commitment);
+    assert_dice_duel_error(&result, Error::GameAlreadyEnded);
+}
+
+// ============================================================================
+// Pause Tests
+// ============================================================================
+
+#[test]
+fn test_pause_blocks_gameplay() {
+    let (env, client, _hub, player1, player2) = setup_test();
+
+    let session_id = 50u32;
+    client.start_game(&session_id, &player1, &player2, &100, &100);
+
+    client.pause();
+    assert!(client.is_paused());
+
+    let result = client.try_start_game(&51, &player1, &player2, &100, &100);
+    assert_dice_duel_error(&result, Error::ContractPaused);
+    let commitment = make_commitment(&env, &test_secret(&env, &player1), &player1);
+    let result = client.try_roll(&session_id, &player1, &commitment);
+    assert_dice_duel_error(&result, Error::ContractPaused);
+    let result = client.try_reveal_secret(&session_id, &player1, &test_secret(&env, &player1));
+    assert_dice_duel_error(&result, Error::ContractPaused);
+    let result = client.try_reveal_winner(&session_id);
+    assert_dice_duel_error(&result, Error::ContractPaused);
+    let result = client.try_forfeit(&session_id, &player1);
+    assert_eq!(result, Err(Ok(Error::ContractPaused.into())));
+
+    client.unpause();
+    roll(&env, &client, session_id, &player1);
+}
+
+#[test]
+fn test_hub_can_cancel_while_paused() {
+    let (_env, client, _hub, player1, player2) = setup_test();
+
+    let session_id = 52u32;
+    client.start_game(&session_id, &player1, &player2, &100, &100);
+
+    client.pause();
+    client.on_session_cancelled(&session_id);
+    assert_eq!(
+        client.get_game(&session_id).status,
+        GameStatus::Ended(GameOutcome::Cancelled)
+    );
+}
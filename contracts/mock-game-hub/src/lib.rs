@@ -1,14 +1,384 @@
 #![no_std]
 
-use soroban_sdk::{contract, contractevent, contractimpl, Address, Env};
+//! # Game Hub
+//!
+//! Points escrow for Stellar Game Studio games.
+//!
+//! Every player holds a points balance in the hub. When a game contract calls
+//! `start_game`, both stakes move from the players' balances into escrow for
+//! that session. When the game calls `end_game`, the whole pot is paid out to
+//! the winner's balance. Games that need to report a draw, a cancellation or a
+//! forfeit call `end_game_with_outcome` instead.
+//!
+//! The hub keeps a `Session` record for every `session_id` it has seen, so it
+//! is the single source of truth for which sessions exist and whether they
+//! have already been settled.
+//!
+//! Session IDs are issued by the hub: `reserve_session` hands out the next ID
+//! from a single counter shared by all games, and `start_game` only accepts an
+//! ID that was reserved for the calling game by one of the session's players.
+//! Unused reservations expire.
+//!
+//! Only game contracts registered by the admin (`add_game`) can open sessions,
+//! and both `start_game` and `end_game` require the game contract's own auth.
+//! Each registered game has a `GameConfig` (`set_game_config`) that bounds the
+//! stakes its sessions accept and can disable new sessions altogether.
+//!
+//! The external interface (`start_game` / `end_game`) is the one every game
+//! contract in this repo already calls through its `GameHub` client trait.
+//!
+//! Games with more than two players open a session with
+//! `start_multiplayer_game` and settle it with `end_game_with_placements`.
+//! The hub splits the pot by finishing position (see `PAYOUT_SPLITS_BPS`).
+//!
+//! Every settled session also updates each player's Elo rating for that game
+//! (`get_rating`). Ratings use integer math only: expected scores come from a
+//! basis-point lookup table, and multiplayer sessions are rated as every pair
+//! of players meeting once.
+//!
+//! A game can also be bound to a token contract (`set_game_token`). Its
+//! sessions then escrow real tokens: each stake is `transfer`red from the
+//! player to the hub on start, and the payouts are transferred back on
+//! settlement. Points balances are not touched in that mode. Players authorize
+//! the transfer as part of the game's `start_game` call.
+//!
+//! Each game can carry a house fee (`set_game_fee`), taken in basis points
+//! from winning payouts in either mode; refunds are fee-free. Fees accrue in
+//! the hub per asset and the admin withdraws them to the treasury address
+//! (`set_treasury`, `withdraw_fees`).
+//!
+//! Sessions that stop progressing can be settled with `claim_timeout` once
+//! their deadline has passed: a player can have their stalling opponent
+//! forfeit, and after a grace period anyone can cancel the session for a
+//! refund. Game contracts take part through the `GameCallbacks` interface
+//! from `sgs-game-common`.
+//!
+//! The admin can pause the hub, either globally or for one game (`pause`).
+//! While paused, affected games cannot open sessions, and their in-flight
+//! sessions can be cancelled for a full refund by a player or the admin
+//! (`cancel_paused_session`).
+//!
+//! Every ended session also gets a compact `MatchRecord` in persistent storage,
+//! indexed per player and per game, so match history can be paged through
+//! (`list_sessions_by_player`, `list_sessions_by_game`) without an indexer.
+//!
+//! Every player also has a cross-game `PlayerProfile`: lifetime sessions,
+//! wins and XP are updated on every settlement, while the cosmetic display
+//! name and avatar hashes are set by the player (`set_profile`).
+//!
+//! The admin defines achievements (`define_achievement`) as a threshold on a
+//! profile counter, a per-game win count or a game-reported stat. Games feed
+//! their own metrics through `report_stat`. When a player crosses a threshold
+//! the hub records a soulbound `Badge` for them; badges cannot be transferred.
+//!
+//! The admin can define sequential, non-overlapping seasons (`create_season`).
+//! Sessions settled while a season is open add to per-player season stats and
+//! to a bounded top-N leaderboard for each game; `close_season` freezes them.
 
-/// Mock Game Hub contract for game studio development
-///
-/// This contract provides the same external interface that games expect
-/// (start_game, end_game) but does nothing internally. It exists purely
-/// for game contracts to compile and integrate during development.
-#[contract]
-pub struct MockGameHub;
+use sgs_game_common::GameCallbacksClient;
+use soroban_sdk::{
+    contract, contracterror, contractevent, contractimpl, contracttype, token, Address, BytesN,
+    Env, IntoVal, Symbol, TryFromVal, Val, Vec,
+};
+
+// ============================================================================
+// Errors
+// ============================================================================
+
+#[contracterror]
+#[derive(Copy, Clone, Debug, Eq, PartialEq, PartialOrd, Ord)]
+#[repr(u32)]
+pub enum Error {
+    /// Points amount is negative (or zero where a positive amount is required)
+    InvalidAmount = 1,
+    /// Player does not have enough available points to cover the stake
+    InsufficientBalance = 2,
+    /// No session exists with this ID
+    SessionNotFound = 3,
+    /// A session already exists with this ID
+    SessionAlreadyExists = 4,
+    /// Session has already been settled
+    SessionAlreadyEnded = 5,
+    /// Game contract is not on the hub's allowlist
+    GameNotRegistered = 6,
+    /// Address is not a player in this session
+    NotPlayer = 7,
+    /// Participant list is too short, too long or contains a player twice
+    InvalidParticipants = 8,
+    /// Placements are not an ordering of exactly the session's participants
+    InvalidPlacements = 9,
+    /// Outcome only applies to two-player sessions
+    InvalidOutcome = 10,
+    /// No season exists with this ID
+    SeasonNotFound = 11,
+    /// Season window is empty or overlaps the previous season
+    InvalidSeason = 12,
+    /// Season has already been closed
+    SeasonClosed = 13,
+    /// Fee is above 10_000 basis points
+    InvalidFee = 14,
+    /// Session deadline has not passed yet
+    SessionNotExpired = 15,
+    /// Withdrawal is larger than the fees accrued in that asset
+    InsufficientFees = 16,
+    /// Game is registered but its config has new sessions switched off
+    GameDisabled = 17,
+    /// A stake is below the game's `min_stake`
+    StakeTooLow = 18,
+    /// A stake is above the game's `max_stake`
+    StakeTooHigh = 19,
+    /// Game requires every player to stake the same amount
+    UnequalStakes = 20,
+    /// A stake is negative and the game does not allow it
+    NegativeStake = 21,
+    /// Config has `min_stake` above `max_stake`, or a negative `min_stake`
+    /// without `allow_negative`
+    InvalidConfig = 22,
+    /// The hub, or this game on the hub, is paused
+    SessionsPaused = 23,
+    /// Session's game is not paused, so it cannot be cancelled this way
+    NotPaused = 24,
+    /// Session ID was not reserved for this game by one of its players, or the
+    /// reservation expired
+    SessionNotReserved = 25,
+    /// No achievement exists with this ID
+    AchievementNotFound = 26,
+    /// Achievement threshold is not positive, or its metric does not fit its
+    /// game scope
+    InvalidAchievement = 27,
+    /// The maximum number of active achievements is already defined
+    TooManyAchievements = 28,
+    /// No admin transfer has been proposed
+    NoPendingAdmin = 29,
+}
+
+// ============================================================================
+// Data Types
+// ============================================================================
+
+/// How a session ended. Determines how the escrowed pot is settled.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum GameOutcome {
+    /// Player 1 takes the pot
+    Player1Won,
+    /// Player 2 takes the pot
+    Player2Won,
+    /// Played to a tie: each player gets their own stake back
+    Draw,
+    /// Called off without a result: each player gets their own stake back
+    Cancelled,
+    /// The given player forfeited: their opponent takes the pot
+    Forfeit(Address),
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum SessionStatus {
+    Active,
+    Ended(GameOutcome),
+    /// Settled by finishing position, first place first
+    Placed(Vec<Address>),
+}
+
+/// Per-game rules `start_game` and `start_multiplayer_game` enforce on stakes.
+/// Games without a config use `GameConfig::default()`, which accepts any
+/// non-negative stake.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct GameConfig {
+    pub min_stake: i128,
+    pub max_stake: i128,
+    /// Every player must stake the same amount
+    pub equal_stakes: bool,
+    /// Negative stakes are accepted as a free entry: nothing is escrowed
+    pub allow_negative: bool,
+    /// New sessions can be opened; running sessions always settle
+    pub enabled: bool,
+}
+
+impl Default for GameConfig {
+    fn default() -> Self {
+        GameConfig {
+            min_stake: 0,
+            max_stake: i128::MAX,
+            equal_stakes: false,
+            allow_negative: false,
+            enabled: true,
+        }
+    }
+}
+
+/// A player's Elo rating in one game
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Rating {
+    pub rating: i32,
+    /// Rated sessions played; players with few games move faster
+    pub games_played: u32,
+}
+
+/// An admin-defined competition window. Sessions settled between
+/// `start_ledger` and `end_ledger` (inclusive) count towards it.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Season {
+    pub start_ledger: u32,
+    pub end_ledger: u32,
+    pub closed: bool,
+    /// Sessions settled during the season, across all games
+    pub sessions: u32,
+    /// Total points staked in those sessions
+    pub points_wagered: i128,
+}
+
+/// A player's results in one game during one season
+#[contracttype]
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct SeasonStats {
+    pub wins: u32,
+    pub losses: u32,
+    /// Points won minus points staked
+    pub net_points: i128,
+    pub sessions: u32,
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct LeaderboardEntry {
+    pub player: Address,
+    pub net_points: i128,
+}
+
+/// A player's identity and lifetime record across every game on the hub
+#[contracttype]
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct PlayerProfile {
+    /// Hash of the display name; the name itself lives off-chain
+    pub display_name_hash: Option<BytesN<32>>,
+    /// Content hash of the avatar image
+    pub avatar_hash: Option<BytesN<32>>,
+    /// Settled sessions played, across all games (cancellations excluded)
+    pub sessions: u32,
+    /// Sessions won outright, across all games
+    pub wins: u32,
+    pub xp: u64,
+}
+
+/// Counter an achievement is measured on
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum AchievementMetric {
+    /// Sessions won: in the achievement's game, or across all games
+    Wins,
+    /// Profile sessions across all games
+    Sessions,
+    /// Profile XP
+    Xp,
+    /// Running total of a stat the achievement's game reports with `report_stat`
+    Stat(Symbol),
+}
+
+/// Admin-defined milestone. A player earns it once `metric` reaches
+/// `threshold`. `game_id` scopes `Wins` to one game and is required for
+/// `Stat`; `Sessions` and `Xp` are always cross-game.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Achievement {
+    pub game_id: Option<Address>,
+    pub metric: AchievementMetric,
+    pub threshold: i128,
+}
+
+/// A soulbound record that a player earned an achievement
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Badge {
+    pub achievement_id: u32,
+    pub earned_ledger: u32,
+}
+
+/// Permanent summary of an ended session, kept for match history
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct MatchRecord {
+    pub session_id: u32,
+    pub game_id: Address,
+    /// Every player with the points they staked, in seat order
+    pub participants: Vec<(Address, i128)>,
+    /// What each player was paid out, in seat order (after any fee)
+    pub payouts: Vec<i128>,
+    pub result: SessionStatus,
+    pub end_ledger: u32,
+}
+
+/// Hub-side record of a game session. Stakes stay locked while `Active`.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Session {
+    pub game_id: Address,
+    /// Every player with the points they staked, in seat order
+    pub participants: Vec<(Address, i128)>,
+    /// Token escrowed for this session, or `None` for hub points
+    pub token: Option<Address>,
+    /// House fee taken from winning payouts, fixed when the session starts
+    pub fee_bps: u32,
+    pub status: SessionStatus,
+    pub start_ledger: u32,
+    /// Last ledger before `claim_timeout` can be used
+    pub deadline_ledger: u32,
+    pub end_ledger: u32,
+}
+
+/// A session ID handed out by `reserve_session` but not yet started
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Reservation {
+    pub game_id: Address,
+    /// Player who reserved the ID; they must be seated in the session
+    pub reserver: Address,
+}
+
+#[contracttype]
+#[derive(Clone)]
+pub enum DataKey {
+    Admin,
+    PendingAdmin,
+    Balance(Address),
+    Locked(Address),
+    Session(u32),
+    NextSessionId,
+    Reservation(u32), // session_id -> Reservation
+    RegisteredGame(Address),
+    GameConfig(Address),
+    Paused,
+    GamePaused(Address),
+    Rating(Address, Address), // (game_id, player)
+    GameToken(Address),
+    GameFee(Address),
+    Treasury,
+    AccruedFees(Option<Address>), // token, or None for hub points
+    SeasonCount,
+    Season(u32),
+    SeasonStats(u32, Address, Address), // (season_id, game_id, player)
+    Leaderboard(u32, Address),          // (season_id, game_id)
+    Profile(Address),
+    GameWins(Address, Address), // (game_id, player)
+    AchievementCount,
+    Achievement(u32),
+    ActiveAchievements,
+    Badges(Address),
+    PlayerStat(Address, Address, Symbol), // (game_id, player, key)
+    MatchRecord(u32),
+    PlayerMatchCount(Address),
+    PlayerMatch(Address, u32), // (player, index) -> session_id
+    GameMatchCount(Address),
+    GameMatch(Address, u32), // (game_id, index) -> session_id
+}
+
+// ============================================================================
+// Events
+// ============================================================================
 
 #[contractevent]
 pub struct GameStarted {
@@ -20,23 +390,465 @@ pub struct GameStarted {
     pub player2_points: i128,
 }
 
+#[contractevent]
+pub struct SessionReserved {
+    pub session_id: u32,
+    pub game_id: Address,
+    pub reserver: Address,
+}
+
+#[contractevent]
+pub struct MultiplayerGameStarted {
+    pub session_id: u32,
+    pub game_id: Address,
+    pub participants: Vec<(Address, i128)>,
+}
+
 #[contractevent]
 pub struct GameEnded {
     pub session_id: u32,
-    pub player1_won: bool,
+    pub outcome: GameOutcome,
+}
+
+#[contractevent]
+pub struct GamePlaced {
+    pub session_id: u32,
+    pub placements: Vec<Address>,
+}
+
+#[contractevent]
+pub struct FeeCollected {
+    pub session_id: u32,
+    /// Token the fee was taken in, or `None` for hub points
+    pub token: Option<Address>,
+    pub amount: i128,
+}
+
+#[contractevent]
+pub struct FeesWithdrawn {
+    pub token: Option<Address>,
+    pub treasury: Address,
+    pub amount: i128,
+}
+
+#[contractevent]
+pub struct RatingChanged {
+    pub game_id: Address,
+    pub player: Address,
+    pub old_rating: i32,
+    pub new_rating: i32,
+}
+
+#[contractevent]
+pub struct SeasonCreated {
+    pub season_id: u32,
+    pub start_ledger: u32,
+    pub end_ledger: u32,
+}
+
+#[contractevent]
+pub struct ProfileUpdated {
+    pub player: Address,
+    pub display_name_hash: Option<BytesN<32>>,
+    pub avatar_hash: Option<BytesN<32>>,
+}
+
+#[contractevent]
+pub struct AchievementDefined {
+    pub achievement_id: u32,
+    pub achievement: Achievement,
+}
+
+#[contractevent]
+pub struct AchievementUnlocked {
+    pub player: Address,
+    pub achievement_id: u32,
+}
+
+#[contractevent]
+pub struct SeasonEnded {
+    pub season_id: u32,
+    pub sessions: u32,
+    pub points_wagered: i128,
+}
+
+#[contractevent]
+pub struct GameAdded {
+    pub game_id: Address,
+}
+
+#[contractevent]
+pub struct GameRemoved {
+    pub game_id: Address,
+}
+
+/// `game_id` is `None` when the whole hub was paused
+#[contractevent]
+pub struct HubPaused {
+    pub game_id: Option<Address>,
+}
+
+#[contractevent]
+pub struct HubUnpaused {
+    pub game_id: Option<Address>,
+}
+
+#[contractevent]
+pub struct PointsAdded {
+    pub player: Address,
+    pub amount: i128,
+}
+
+// ============================================================================
+// Storage TTL Management
+// ============================================================================
+// Balances and sessions live in persistent storage: locked points must never
+// expire mid-game. Entries are bumped back to 30 days whenever they drop
+// below one day of remaining TTL.
+
+/// ~1 day in ledgers (~5 seconds per ledger)
+const TTL_THRESHOLD_LEDGERS: u32 = 17_280;
+
+/// 30 days in ledgers (~5 seconds per ledger)
+const TTL_EXTEND_LEDGERS: u32 = 518_400;
+
+// ============================================================================
+// Session Timeouts
+// ============================================================================
+
+/// Ledgers a reserved session ID stays valid before it must be started (~1 day)
+const RESERVATION_TTL_LEDGERS: u32 = 17_280;
+
+/// Ledgers a session can stay open before a timeout can be claimed (~1 day)
+const SESSION_TIMEOUT_LEDGERS: u32 = 17_280;
+
+/// Ledgers after the deadline during which only a player can claim, as a
+/// forfeit by their opponent (~1 day). Afterwards anyone can cancel.
+const TIMEOUT_GRACE_LEDGERS: u32 = 17_280;
+
+// ============================================================================
+// Multiplayer Payouts
+// ============================================================================
+
+/// Most players a single session can hold
+const MAX_PARTICIPANTS: u32 = 16;
+
+/// Share of the pot (in basis points) paid to each finishing position,
+/// indexed by participant count. Positions past the end of a row get nothing;
+/// rounding dust goes to first place.
+const PAYOUT_SPLITS_BPS: [&[i128]; 3] = [
+    &[10_000],              // 2 players: winner takes all
+    &[7_000, 3_000],        // 3-4 players
+    &[5_000, 3_000, 2_000], // 5+ players
+];
+
+fn payout_splits(participant_count: u32) -> &'static [i128] {
+    match participant_count {
+        0..=2 => PAYOUT_SPLITS_BPS[0],
+        3..=4 => PAYOUT_SPLITS_BPS[1],
+        _ => PAYOUT_SPLITS_BPS[2],
+    }
+}
+
+// ============================================================================
+// Elo Ratings
+// ============================================================================
+
+/// Rating of a player who has never finished a rated session in a game
+const INITIAL_RATING: i32 = 1500;
+
+/// Sessions a player needs before the established K-factor applies
+const PROVISIONAL_GAMES: u32 = 30;
+
+const PROVISIONAL_K_FACTOR: i128 = 40;
+const ESTABLISHED_K_FACTOR: i128 = 20;
+
+/// Rating differences above this are treated as this large
+const MAX_RATING_DIFF: i128 = 800;
+
+/// Expected score (basis points) for a rating advantage of 0, 25, 50, ... 800.
+/// Values in between are interpolated linearly.
+const EXPECTED_SCORE_BPS: [i128; 33] = [
+    5000, 5359, 5715, 6063, 6401, 6725, 7034, 7325, 7597, 7850, 8083, 8296, 8490, 8666, 8823, 8965,
+    9091, 9203, 9302, 9390, 9468, 9536, 9595, 9648, 9693, 9733, 9768, 9799, 9825, 9848, 9868, 9886,
+    9901,
+];
+
+/// Expected score of `rating` against `opponent`, in basis points.
+fn expected_score_bps(rating: i32, opponent: i32) -> i128 {
+    let diff = rating as i128 - opponent as i128;
+    let abs_diff = diff.abs().min(MAX_RATING_DIFF);
+
+    let step = (abs_diff / 25) as usize;
+    let low = EXPECTED_SCORE_BPS[step];
+    let high = EXPECTED_SCORE_BPS[(step + 1).min(EXPECTED_SCORE_BPS.len() - 1)];
+    let expected = low + (high - low) * (abs_diff % 25) / 25;
+
+    if diff >= 0 {
+        expected
+    } else {
+        10_000 - expected
+    }
+}
+
+/// Integer division rounding half away from zero
+fn div_round(numerator: i128, denominator: i128) -> i128 {
+    let half = denominator / 2;
+    if numerator >= 0 {
+        (numerator + half) / denominator
+    } else {
+        (numerator - half) / denominator
+    }
+}
+
+fn read_rating(env: &Env, game_id: &Address, player: &Address) -> Rating {
+    env.storage()
+        .persistent()
+        .get(&DataKey::Rating(game_id.clone(), player.clone()))
+        .unwrap_or(Rating {
+            rating: INITIAL_RATING,
+            games_played: 0,
+        })
+}
+
+fn write_rating(env: &Env, game_id: &Address, player: &Address, rating: &Rating) {
+    let key = DataKey::Rating(game_id.clone(), player.clone());
+    env.storage().persistent().set(&key, rating);
+    env.storage()
+        .persistent()
+        .extend_ttl(&key, TTL_THRESHOLD_LEDGERS, TTL_EXTEND_LEDGERS);
+}
+
+// ============================================================================
+// Player Profiles
+// ============================================================================
+
+/// XP every player earns for a settled session
+const XP_PER_SESSION: u64 = 10;
+
+/// Extra XP for winning a session outright
+const XP_PER_WIN: u64 = 20;
+
+/// Most achievements that can be active at once. Every active achievement
+/// is checked for each player on settlement, so this bounds that work.
+const MAX_ACHIEVEMENTS: u32 = 20;
+
+// ============================================================================
+// Match History
+// ============================================================================
+
+/// Most records returned by one page of a match history query. Each record
+/// costs two ledger reads (index entry + record), so this keeps a full page
+/// inside the per-transaction footprint limit.
+const MAX_PAGE_SIZE: u32 = 25;
+
+/// Append `session_id` to a match history index
+fn push_match_index(
+    env: &Env,
+    count_key: DataKey,
+    entry_key: impl Fn(u32) -> DataKey,
+    session_id: u32,
+) {
+    let count: u32 = env.storage().persistent().get(&count_key).unwrap_or(0);
+    write_persistent(env, &entry_key(count), &session_id);
+    write_persistent(env, &count_key, &(count + 1));
+}
+
+/// Read up to `limit` records from a match history index, oldest first,
+/// starting at index `cursor`. Reading extends the TTL of every entry it
+/// touches; an entry that has already been archived is skipped.
+fn read_match_page(
+    env: &Env,
+    count_key: DataKey,
+    entry_key: impl Fn(u32) -> DataKey,
+    cursor: u32,
+    limit: u32,
+) -> Vec<MatchRecord> {
+    let count: u32 = read_persistent(env, &count_key).unwrap_or(0);
+    let end = cursor.saturating_add(limit.min(MAX_PAGE_SIZE)).min(count);
+
+    let mut records = Vec::new(env);
+    for index in cursor..end {
+        let record = read_persistent::<u32>(env, &entry_key(index)).and_then(|session_id| {
+            read_persistent::<MatchRecord>(env, &DataKey::MatchRecord(session_id))
+        });
+        if let Some(record) = record {
+            records.push_back(record);
+        }
+    }
+    records
+}
+
+// ============================================================================
+// Seasons
+// ============================================================================
+
+/// Entries kept on each season leaderboard
+const LEADERBOARD_SIZE: u32 = 10;
+
+fn write_persistent<V: IntoVal<Env, Val>>(env: &Env, key: &DataKey, value: &V) {
+    env.storage().persistent().set(key, value);
+    env.storage()
+        .persistent()
+        .extend_ttl(key, TTL_THRESHOLD_LEDGERS, TTL_EXTEND_LEDGERS);
+}
+
+fn read_persistent<V: TryFromVal<Env, Val>>(env: &Env, key: &DataKey) -> Option<V> {
+    let value = env.storage().persistent().get(key);
+    if value.is_some() {
+        env.storage()
+            .persistent()
+            .extend_ttl(key, TTL_THRESHOLD_LEDGERS, TTL_EXTEND_LEDGERS);
+    }
+    value
+}
+
+/// The season open at the current ledger, if any. Seasons never overlap,
+/// so only the latest season that has already started can be open.
+fn current_season(env: &Env) -> Option<(u32, Season)> {
+    let now = env.ledger().sequence();
+    let mut season_id: u32 = env
+        .storage()
+        .instance()
+        .get(&DataKey::SeasonCount)
+        .unwrap_or(0);
+
+    while season_id > 0 {
+        let season: Season = env
+            .storage()
+            .persistent()
+            .get(&DataKey::Season(season_id))
+            .expect("Season not stored");
+        if season.start_ledger <= now {
+            if now <= season.end_ledger && !season.closed {
+                return Some((season_id, season));
+            }
+            return None;
+        }
+        season_id -= 1;
+    }
+    None
+}
+
+/// Re-rank `player` on a season leaderboard. Players are only re-ranked when
+/// they finish a session, so a player who falls off the board stays off until
+/// they play again.
+fn update_leaderboard(
+    env: &Env,
+    season_id: u32,
+    game_id: &Address,
+    player: &Address,
+    net_points: i128,
+) {
+    let key = DataKey::Leaderboard(season_id, game_id.clone());
+    let mut board: Vec<LeaderboardEntry> = env
+        .storage()
+        .persistent()
+        .get(&key)
+        .unwrap_or(Vec::new(env));
+
+    if let Some(index) = board.iter().position(|entry| entry.player == *player) {
+        board.remove(index as u32);
+    }
+
+    let position = board
+        .iter()
+        .position(|entry| entry.net_points < net_points)
+        .map(|index| index as u32)
+        .unwrap_or(board.len());
+    if position < LEADERBOARD_SIZE {
+        board.insert(
+            position,
+            LeaderboardEntry {
+                player: player.clone(),
+                net_points,
+            },
+        );
+        if board.len() > LEADERBOARD_SIZE {
+            board.pop_back();
+        }
+    }
+
+    write_persistent(env, &key, &board);
+}
+
+// ============================================================================
+// Helper Functions
+// ============================================================================
+
+fn read_amount(env: &Env, key: &DataKey) -> i128 {
+    env.storage().persistent().get(key).unwrap_or(0)
+}
+
+fn write_amount(env: &Env, key: &DataKey, amount: i128) {
+    env.storage().persistent().set(key, &amount);
+    env.storage()
+        .persistent()
+        .extend_ttl(key, TTL_THRESHOLD_LEDGERS, TTL_EXTEND_LEDGERS);
+}
+
+/// Move `amount` from a player's available balance into their locked total.
+fn lock_points(env: &Env, player: &Address, amount: i128) -> Result<(), Error> {
+    let balance_key = DataKey::Balance(player.clone());
+    let balance = read_amount(env, &balance_key);
+    if balance < amount {
+        return Err(Error::InsufficientBalance);
+    }
+    write_amount(env, &balance_key, balance - amount);
+
+    let locked_key = DataKey::Locked(player.clone());
+    write_amount(env, &locked_key, read_amount(env, &locked_key) + amount);
+    Ok(())
+}
+
+fn release_locked(env: &Env, player: &Address, amount: i128) {
+    let locked_key = DataKey::Locked(player.clone());
+    write_amount(env, &locked_key, read_amount(env, &locked_key) - amount);
+}
+
+fn credit_balance(env: &Env, player: &Address, amount: i128) {
+    let balance_key = DataKey::Balance(player.clone());
+    write_amount(env, &balance_key, read_amount(env, &balance_key) + amount);
+}
+
+fn write_session(env: &Env, session_id: u32, session: &Session) {
+    let key = DataKey::Session(session_id);
+    env.storage().persistent().set(&key, session);
+    env.storage()
+        .persistent()
+        .extend_ttl(&key, TTL_THRESHOLD_LEDGERS, TTL_EXTEND_LEDGERS);
 }
 
+// ============================================================================
+// Contract Definition
+// ============================================================================
+
+#[contract]
+pub struct MockGameHub;
+
 #[contractimpl]
 impl MockGameHub {
-    /// Start a game session
+    /// Initialize the hub with its admin
+    ///
+    /// # Arguments
+    /// * `admin` - Admin address (can add points and upgrade the contract)
+    pub fn __constructor(env: Env, admin: Address) {
+        env.storage().instance().set(&DataKey::Admin, &admin);
+    }
+
+    /// Start a game session and lock both players' points in escrow.
+    /// Fails unless one of the players reserved `session_id` for this game with
+    /// `reserve_session` and no session with this ID was started yet.
+    ///
+    /// **Auth:** Requires authorization from `game_id`, which must be registered.
     ///
     /// # Arguments
     /// * `game_id` - Address of the game contract calling this method
-    /// * `session_id` - Unique identifier for this game session
+    /// * `session_id` - ID a player reserved for this game with `reserve_session`
     /// * `player1` - Address of first player
     /// * `player2` - Address of second player
-    /// * `player1_points` - Points amount for player 1 (ignored in mock)
-    /// * `player2_points` - Points amount for player 2 (ignored in mock)
+    /// * `player1_points` - Points locked from player 1's balance
+    /// * `player2_points` - Points locked from player 2's balance
     pub fn start_game(
         env: Env,
         game_id: Address,
@@ -45,8 +857,16 @@ impl MockGameHub {
         player2: Address,
         player1_points: i128,
         player2_points: i128,
-    ) {
-        // No auth required for mock
+    ) -> Result<(), Error> {
+        let participants = Vec::from_array(
+            &env,
+            [
+                (player1.clone(), player1_points),
+                (player2.clone(), player2_points),
+            ],
+        );
+        Self::open_session(&env, &game_id, session_id, participants)?;
+
         GameStarted {
             session_id,
             game_id,
@@ -56,42 +876,1641 @@ impl MockGameHub {
             player2_points,
         }
         .publish(&env);
-        // bump instance ttl if required
-        env.storage().instance().extend_ttl(17_280, 518_400);
+
+        Ok(())
+    }
+
+    /// Start a session with any number of players (2 to 16) sharing one pot.
+    /// Each participant's stake is locked from their balance.
+    ///
+    /// **Auth:** Requires authorization from `game_id`, which must be registered.
+    ///
+    /// # Arguments
+    /// * `game_id` - Address of the game contract calling this method
+    /// * `session_id` - ID a player reserved for this game with `reserve_session`
+    /// * `participants` - Each player with the points locked from their balance
+    pub fn start_multiplayer_game(
+        env: Env,
+        game_id: Address,
+        session_id: u32,
+        participants: Vec<(Address, i128)>,
+    ) -> Result<(), Error> {
+        Self::open_session(&env, &game_id, session_id, participants.clone())?;
+
+        MultiplayerGameStarted {
+            session_id,
+            game_id,
+            participants,
+        }
+        .publish(&env);
+
+        Ok(())
     }
 
-    /// End a game session and declare winner
+    /// End a game session and pay the pot to the winner.
+    /// Fails for unknown sessions and for sessions that were already ended.
+    ///
+    /// **Auth:** Requires authorization from the game contract that started the session.
     ///
     /// # Arguments
     /// * `session_id` - The game session being ended
     /// * `player1_won` - True if player1 won, false if player2 won
-    pub fn end_game(
+    pub fn end_game(env: Env, session_id: u32, player1_won: bool) -> Result<(), Error> {
+        let outcome = if player1_won {
+            GameOutcome::Player1Won
+        } else {
+            GameOutcome::Player2Won
+        };
+        Self::settle_session(&env, session_id, outcome)
+    }
+
+    /// End a game session with any outcome and settle the pot accordingly.
+    ///
+    /// Settlement rules:
+    /// - `Player1Won` / `Player2Won`: the winner takes the whole pot
+    /// - `Draw` / `Cancelled`: each player gets their own stake back
+    /// - `Forfeit(player)`: the opponent of `player` takes the whole pot
+    ///
+    /// **Auth:** Requires authorization from the game contract that started the session.
+    ///
+    /// # Arguments
+    /// * `session_id` - The game session being ended
+    /// * `outcome` - How the session ended
+    pub fn end_game_with_outcome(
         env: Env,
         session_id: u32,
-        player1_won: bool,
-    ) {
-        // No auth required for mock
-        GameEnded {
+        outcome: GameOutcome,
+    ) -> Result<(), Error> {
+        Self::settle_session(&env, session_id, outcome)
+    }
+
+    /// End a session by finishing position and split the pot by placement.
+    ///
+    /// `placements` lists every participant exactly once, first place first.
+    /// The pot is split using the hub's `PAYOUT_SPLITS_BPS` table:
+    /// - 2 players: winner takes all
+    /// - 3-4 players: 70% / 30%
+    /// - 5+ players: 50% / 30% / 20%
+    ///
+    /// **Auth:** Requires authorization from the game contract that started the session.
+    ///
+    /// # Arguments
+    /// * `session_id` - The game session being ended
+    /// * `placements` - Participants ordered from first to last place
+    pub fn end_game_with_placements(
+        env: Env,
+        session_id: u32,
+        placements: Vec<Address>,
+    ) -> Result<(), Error> {
+        let mut session = Self::load_active_session(&env, session_id)?;
+        session.game_id.require_auth();
+
+        let payouts = Self::placement_payouts(&env, &session, &placements)?;
+        let received = Self::close_session(
+            &env,
+            session_id,
+            &mut session,
+            &payouts,
+            SessionStatus::Placed(placements.clone()),
+        );
+
+        GamePlaced {
             session_id,
-            player1_won,
+            placements,
         }
         .publish(&env);
+
+        Self::update_ratings(&env, &session);
+        Self::record_season_results(&env, &session, &received);
+        Self::record_profiles(&env, &session);
+        Self::record_match(&env, session_id, &session, &received);
+
+        Ok(())
     }
-}
 
-#[cfg(test)]
-mod test {
-    use super::*;
-    use soroban_sdk::testutils::Address as _;
-    #[test]
-    fn test_start_and_end_game() {
-        let env = Env::default();
-        let contract_id = env.register_contract(None, MockGameHub);
-        let client = MockGameHubClient::new(&env, &contract_id);
-        let game_id = Address::generate(&env);
-        let player1 = Address::generate(&env);
-        let player2 = Address::generate(&env);
-        client.start_game(&game_id, &1, &player1, &player2, &1000, &1000);
-        client.end_game(&1, &true);
+    /// Settle a session whose game has stopped progressing.
+    ///
+    /// Once the session's `deadline_ledger` has passed, a player in a
+    /// two-player session can claim for `TIMEOUT_GRACE_LEDGERS`: their opponent
+    /// forfeits, provided the game contract accepts `on_session_forfeited` for
+    /// that opponent. Players in larger sessions have no single opponent to
+    /// blame and must wait out the grace period. After the grace period anyone
+    /// can claim, and the session is cancelled with every stake refunded; the
+    /// game is told through `on_session_cancelled` on a best-effort basis so it
+    /// cannot block refunds.
+    ///
+    /// **Auth:** Requires authorization from `claimant`.
+    ///
+    /// # Arguments
+    /// * `session_id` - The stalled game session
+    /// * `claimant` - Address claiming the timeout
+    ///
+    /// # Returns
+    /// * `GameOutcome` - `Forfeit(opponent)` or `Cancelled`
+    pub fn claim_timeout(
+        env: Env,
+        session_id: u32,
+        claimant: Address,
+    ) -> Result<GameOutcome, Error> {
+        claimant.require_auth();
+
+        let session = Self::load_active_session(&env, session_id)?;
+
+        let now = env.ledger().sequence();
+        if now <= session.deadline_ledger {
+            return Err(Error::SessionNotExpired);
+        }
+
+        let game = GameCallbacksClient::new(&env, &session.game_id);
+        let outcome = if now <= session.deadline_ledger + TIMEOUT_GRACE_LEDGERS {
+            let is_player = session
+                .participants
+                .iter()
+                .any(|(player, _)| player == claimant);
+            if !is_player {
+                return Err(Error::NotPlayer);
+            }
+            if session.participants.len() != 2 {
+                return Err(Error::InvalidOutcome);
+            }
+            let staller = session
+                .participants
+                .iter()
+                .map(|(player, _)| player)
+                .find(|player| *player != claimant)
+                .ok_or(Error::InvalidOutcome)?;
+
+            game.on_session_forfeited(&session_id, &staller);
+            GameOutcome::Forfeit(staller)
+        } else {
+            let _ = game.try_on_session_cancelled(&session_id);
+            GameOutcome::Cancelled
+        };
+
+        Self::apply_outcome(&env, session_id, session, outcome.clone())?;
+
+        Ok(outcome)
     }
-}
+
+    // ========================================================================
+    // Game Registry
+    // ========================================================================
+
+    /// Allow a game contract to open sessions. Admin only.
+    pub fn add_game(env: Env, game_id: Address) {
+        let admin: Address = env
+            .storage()
+            .instance()
+            .get(&DataKey::Admin)
+            .expect("Admin not set");
+        admin.require_auth();
+
+        let key = DataKey::RegisteredGame(game_id.clone());
+        env.storage().persistent().set(&key, &true);
+        env.storage()
+            .persistent()
+            .extend_ttl(&key, TTL_THRESHOLD_LEDGERS, TTL_EXTEND_LEDGERS);
+
+        // `remove_game` leaves these behind so the game's running sessions can
+        // still be cancelled while it is paused; a re-registered game starts clean
+        env.storage()
+            .persistent()
+            .remove(&DataKey::GameToken(game_id.clone()));
+        env.storage()
+            .persistent()
+            .remove(&DataKey::GameFee(game_id.clone()));
+        env.storage()
+            .persistent()
+            .remove(&DataKey::GamePaused(game_id.clone()));
+
+        GameAdded { game_id }.publish(&env);
+    }
+
+    /// Stop a game contract from opening new sessions. Admin only.
+    /// Sessions it already started can still be ended.
+    pub fn remove_game(env: Env, game_id: Address) {
+        let admin: Address = env
+            .storage()
+            .instance()
+            .get(&DataKey::Admin)
+            .expect("Admin not set");
+        admin.require_auth();
+
+        env.storage()
+            .persistent()
+            .remove(&DataKey::RegisteredGame(game_id.clone()));
+        env.storage()
+            .persistent()
+            .remove(&DataKey::GameConfig(game_id.clone()));
+
+        GameRemoved { game_id }.publish(&env);
+    }
+
+    /// Check whether a game contract is on the allowlist
+    pub fn is_game_registered(env: Env, game_id: Address) -> bool {
+        env.storage()
+            .persistent()
+            .has(&DataKey::RegisteredGame(game_id))
+    }
+
+    /// Set the stake rules for a registered game. Admin only.
+    /// Applies to sessions opened from now on.
+    ///
+    /// # Arguments
+    /// * `game_id` - Registered game contract
+    /// * `config` - Stake limits and enabled flag
+    pub fn set_game_config(env: Env, game_id: Address, config: GameConfig) -> Result<(), Error> {
+        let admin: Address = env
+            .storage()
+            .instance()
+            .get(&DataKey::Admin)
+            .expect("Admin not set");
+        admin.require_auth();
+
+        if !Self::is_game_registered(env.clone(), game_id.clone()) {
+            return Err(Error::GameNotRegistered);
+        }
+        if config.min_stake > config.max_stake || (config.min_stake < 0 && !config.allow_negative) {
+            return Err(Error::InvalidConfig);
+        }
+
+        write_persistent(&env, &DataKey::GameConfig(game_id), &config);
+
+        Ok(())
+    }
+
+    /// Get a game's stake rules (the default config if none was set)
+    pub fn get_game_config(env: Env, game_id: Address) -> GameConfig {
+        env.storage()
+            .persistent()
+            .get(&DataKey::GameConfig(game_id))
+            .unwrap_or_default()
+    }
+
+    // ========================================================================
+    // Emergency Pause
+    // ========================================================================
+
+    /// Stop new sessions from opening, for one game or for the whole hub.
+    /// Admin only. Running sessions can still be settled by their game or
+    /// cancelled with `cancel_paused_session`.
+    ///
+    /// # Arguments
+    /// * `game_id` - Game to pause, or `None` to pause every game
+    pub fn pause(env: Env, game_id: Option<Address>) {
+        Self::set_paused(&env, &game_id, true);
+        HubPaused { game_id }.publish(&env);
+    }
+
+    /// Lift a pause set with `pause`. Admin only. Unpausing the hub does not
+    /// lift per-game pauses.
+    ///
+    /// # Arguments
+    /// * `game_id` - Game to unpause, or `None` to lift the global pause
+    pub fn unpause(env: Env, game_id: Option<Address>) {
+        Self::set_paused(&env, &game_id, false);
+        HubUnpaused { game_id }.publish(&env);
+    }
+
+    /// Check whether sessions are paused, for one game (counting the global
+    /// pause) or, with `None`, for the whole hub
+    pub fn is_paused(env: Env, game_id: Option<Address>) -> bool {
+        let globally_paused = env
+            .storage()
+            .instance()
+            .get(&DataKey::Paused)
+            .unwrap_or(false);
+        globally_paused
+            || game_id.is_some_and(|game_id| {
+                env.storage()
+                    .persistent()
+                    .has(&DataKey::GamePaused(game_id))
+            })
+    }
+
+    /// Cancel a running session of a paused game, refunding every stake.
+    /// The game is told through `on_session_cancelled` on a best-effort basis.
+    ///
+    /// **Auth:** Requires authorization from `caller`, who must be a player in
+    /// the session or the admin.
+    ///
+    /// # Arguments
+    /// * `session_id` - The session to cancel
+    /// * `caller` - Player or admin requesting the refund
+    pub fn cancel_paused_session(env: Env, session_id: u32, caller: Address) -> Result<(), Error> {
+        caller.require_auth();
+
+        let session = Self::load_active_session(&env, session_id)?;
+        if !Self::is_paused(env.clone(), Some(session.game_id.clone())) {
+            return Err(Error::NotPaused);
+        }
+
+        let is_player = session
+            .participants
+            .iter()
+            .any(|(player, _)| player == caller);
+        if !is_player && caller != Self::get_admin(env.clone()) {
+            return Err(Error::NotPlayer);
+        }
+
+        let game = GameCallbacksClient::new(&env, &session.game_id);
+        let _ = game.try_on_session_cancelled(&session_id);
+
+        Self::apply_outcome(&env, session_id, session, GameOutcome::Cancelled)
+    }
+
+    // ========================================================================
+    // Token Stakes
+    // ========================================================================
+
+    /// Stake a game's future sessions in `token` instead of hub points. Admin only.
+    /// Sessions already running keep the mode they started with.
+    ///
+    /// # Arguments
+    /// * `game_id` - Game contract whose sessions use the token
+    /// * `token` - Token contract (e.g. a Stellar Asset Contract)
+    pub fn set_game_token(env: Env, game_id: Address, token: Address) -> Result<(), Error> {
+        let admin: Address = env
+            .storage()
+            .instance()
+            .get(&DataKey::Admin)
+            .expect("Admin not set");
+        admin.require_auth();
+
+        if !Self::is_game_registered(env.clone(), game_id.clone()) {
+            return Err(Error::GameNotRegistered);
+        }
+
+        write_persistent(&env, &DataKey::GameToken(game_id), &token);
+
+        Ok(())
+    }
+
+    /// Switch a game's future sessions back to hub points. Admin only.
+    pub fn remove_game_token(env: Env, game_id: Address) {
+        let admin: Address = env
+            .storage()
+            .instance()
+            .get(&DataKey::Admin)
+            .expect("Admin not set");
+        admin.require_auth();
+
+        env.storage()
+            .persistent()
+            .remove(&DataKey::GameToken(game_id));
+    }
+
+    /// Get the token a game's sessions are staked in, if any
+    pub fn get_game_token(env: Env, game_id: Address) -> Option<Address> {
+        env.storage().persistent().get(&DataKey::GameToken(game_id))
+    }
+
+    // ========================================================================
+    // Treasury
+    // ========================================================================
+
+    /// Set the house fee taken from a game's winning payouts. Admin only.
+    /// Sessions already running keep the fee they started with.
+    ///
+    /// # Arguments
+    /// * `game_id` - Game contract the fee applies to
+    /// * `fee_bps` - Share of each winning payout kept by the hub (max 10_000)
+    pub fn set_game_fee(env: Env, game_id: Address, fee_bps: u32) -> Result<(), Error> {
+        let admin: Address = env
+            .storage()
+            .instance()
+            .get(&DataKey::Admin)
+            .expect("Admin not set");
+        admin.require_auth();
+
+        if fee_bps > 10_000 {
+            return Err(Error::InvalidFee);
+        }
+
+        write_persistent(&env, &DataKey::GameFee(game_id), &fee_bps);
+
+        Ok(())
+    }
+
+    /// Get a game's house fee in basis points (0 if none was set)
+    pub fn get_game_fee(env: Env, game_id: Address) -> u32 {
+        env.storage()
+            .persistent()
+            .get(&DataKey::GameFee(game_id))
+            .unwrap_or(0)
+    }
+
+    /// Set the address fee withdrawals are paid to. Admin only.
+    pub fn set_treasury(env: Env, treasury: Address) {
+        let admin: Address = env
+            .storage()
+            .instance()
+            .get(&DataKey::Admin)
+            .expect("Admin not set");
+        admin.require_auth();
+
+        env.storage().instance().set(&DataKey::Treasury, &treasury);
+    }
+
+    /// Get the treasury address (the admin until one is set)
+    pub fn get_treasury(env: Env) -> Address {
+        env.storage()
+            .instance()
+            .get(&DataKey::Treasury)
+            .unwrap_or_else(|| Self::get_admin(env))
+    }
+
+    /// Get the fees accrued and not yet withdrawn in one asset
+    ///
+    /// # Arguments
+    /// * `token` - Token contract, or `None` for hub points
+    pub fn get_accrued_fees(env: Env, token: Option<Address>) -> i128 {
+        read_amount(&env, &DataKey::AccruedFees(token))
+    }
+
+    /// Pay accrued fees out to the treasury. Admin only. Token fees are
+    /// transferred; point fees are credited to the treasury's points balance.
+    ///
+    /// # Arguments
+    /// * `token` - Token contract, or `None` for hub points
+    /// * `amount` - Amount to withdraw (must be positive)
+    pub fn withdraw_fees(env: Env, token: Option<Address>, amount: i128) -> Result<(), Error> {
+        let admin: Address = env
+            .storage()
+            .instance()
+            .get(&DataKey::Admin)
+            .expect("Admin not set");
+        admin.require_auth();
+
+        if amount <= 0 {
+            return Err(Error::InvalidAmount);
+        }
+
+        let fees_key = DataKey::AccruedFees(token.clone());
+        let accrued = read_amount(&env, &fees_key);
+        if amount > accrued {
+            return Err(Error::InsufficientFees);
+        }
+        write_amount(&env, &fees_key, accrued - amount);
+
+        let treasury = Self::get_treasury(env.clone());
+        match &token {
+            Some(token_id) => token::Client::new(&env, token_id).transfer(
+                &env.current_contract_address(),
+                &treasury,
+                &amount,
+            ),
+            None => credit_balance(&env, &treasury, amount),
+        }
+
+        FeesWithdrawn {
+            token,
+            treasury,
+            amount,
+        }
+        .publish(&env);
+
+        Ok(())
+    }
+
+    // ========================================================================
+    // Points Balances
+    // ========================================================================
+
+    /// Credit points to a player's available balance. Admin only.
+    ///
+    /// # Arguments
+    /// * `player` - Player receiving the points
+    /// * `amount` - Points to add (must be positive)
+    pub fn add_points(env: Env, player: Address, amount: i128) -> Result<(), Error> {
+        let admin: Address = env
+            .storage()
+            .instance()
+            .get(&DataKey::Admin)
+            .expect("Admin not set");
+        admin.require_auth();
+
+        if amount <= 0 {
+            return Err(Error::InvalidAmount);
+        }
+
+        credit_balance(&env, &player, amount);
+
+        PointsAdded { player, amount }.publish(&env);
+
+        Ok(())
+    }
+
+    /// Get a player's available (unlocked) points balance
+    pub fn get_balance(env: Env, player: Address) -> i128 {
+        read_amount(&env, &DataKey::Balance(player))
+    }
+
+    /// Get the total points a player currently has locked in active sessions
+    pub fn get_locked(env: Env, player: Address) -> i128 {
+        read_amount(&env, &DataKey::Locked(player))
+    }
+
+    // ========================================================================
+    // Sessions
+    // ========================================================================
+
+    /// Reserve the next session ID for a game. The ID must be passed to the
+    /// game's `start_game` within `RESERVATION_TTL_LEDGERS`, and only a session
+    /// of that game with `reserver` among its players can start with it.
+    ///
+    /// **Auth:** Requires authorization from `reserver`.
+    ///
+    /// # Arguments
+    /// * `game_id` - Registered game contract the session will be played in
+    /// * `reserver` - Player who will take part in the session
+    ///
+    /// # Returns
+    /// * `u32` - The reserved session ID
+    pub fn reserve_session(env: Env, game_id: Address, reserver: Address) -> Result<u32, Error> {
+        reserver.require_auth();
+
+        if !Self::is_game_registered(env.clone(), game_id.clone()) {
+            return Err(Error::GameNotRegistered);
+        }
+
+        let session_id = Self::next_session_id(env.clone());
+        env.storage()
+            .instance()
+            .set(&DataKey::NextSessionId, &(session_id + 1));
+
+        let key = DataKey::Reservation(session_id);
+        let reservation = Reservation {
+            game_id: game_id.clone(),
+            reserver: reserver.clone(),
+        };
+        env.storage().temporary().set(&key, &reservation);
+        env.storage().temporary().extend_ttl(
+            &key,
+            RESERVATION_TTL_LEDGERS,
+            RESERVATION_TTL_LEDGERS,
+        );
+
+        SessionReserved {
+            session_id,
+            game_id,
+            reserver,
+        }
+        .publish(&env);
+
+        Ok(session_id)
+    }
+
+    /// Get the ID the next `reserve_session` call will hand out
+    pub fn next_session_id(env: Env) -> u32 {
+        env.storage()
+            .instance()
+            .get(&DataKey::NextSessionId)
+            .unwrap_or(1)
+    }
+
+    /// Get the reservation for a session ID, if it is still live
+    pub fn get_reservation(env: Env, session_id: u32) -> Option<Reservation> {
+        env.storage()
+            .temporary()
+            .get(&DataKey::Reservation(session_id))
+    }
+
+    /// Get the hub's record of a session (active or ended)
+    pub fn get_session(env: Env, session_id: u32) -> Result<Session, Error> {
+        env.storage()
+            .persistent()
+            .get(&DataKey::Session(session_id))
+            .ok_or(Error::SessionNotFound)
+    }
+
+    // ========================================================================
+    // Ratings
+    // ========================================================================
+
+    /// Get a player's Elo rating in a game (1500 if they have not played it)
+    pub fn get_rating(env: Env, game_id: Address, player: Address) -> Rating {
+        read_rating(&env, &game_id, &player)
+    }
+
+    // ========================================================================
+    // Seasons
+    // ========================================================================
+
+    /// Define the next season. Admin only.
+    /// Seasons are numbered from 1 and must start after the previous one ends.
+    ///
+    /// # Arguments
+    /// * `start_ledger` - First ledger of the season
+    /// * `end_ledger` - Last ledger of the season
+    ///
+    /// # Returns
+    /// * `u32` - The new season's ID
+    pub fn create_season(env: Env, start_ledger: u32, end_ledger: u32) -> Result<u32, Error> {
+        let admin: Address = env
+            .storage()
+            .instance()
+            .get(&DataKey::Admin)
+            .expect("Admin not set");
+        admin.require_auth();
+
+        if start_ledger > end_ledger {
+            return Err(Error::InvalidSeason);
+        }
+
+        let last_id: u32 = env
+            .storage()
+            .instance()
+            .get(&DataKey::SeasonCount)
+            .unwrap_or(0);
+        if last_id > 0 {
+            let last = Self::get_season(env.clone(), last_id)?;
+            if start_ledger <= last.end_ledger {
+                return Err(Error::InvalidSeason);
+            }
+        }
+
+        let season_id = last_id + 1;
+        let season = Season {
+            start_ledger,
+            end_ledger,
+            closed: false,
+            sessions: 0,
+            points_wagered: 0,
+        };
+        write_persistent(&env, &DataKey::Season(season_id), &season);
+        env.storage()
+            .instance()
+            .set(&DataKey::SeasonCount, &season_id);
+
+        SeasonCreated {
+            season_id,
+            start_ledger,
+            end_ledger,
+        }
+        .publish(&env);
+
+        Ok(season_id)
+    }
+
+    /// Freeze a season's stats and leaderboards and emit its summary. Admin only.
+    /// Sessions settled afterwards no longer count towards it, even if its
+    /// window has not ended yet.
+    pub fn close_season(env: Env, season_id: u32) -> Result<(), Error> {
+        let admin: Address = env
+            .storage()
+            .instance()
+            .get(&DataKey::Admin)
+            .expect("Admin not set");
+        admin.require_auth();
+
+        let mut season = Self::get_season(env.clone(), season_id)?;
+        if season.closed {
+            return Err(Error::SeasonClosed);
+        }
+
+        season.closed = true;
+        write_persistent(&env, &DataKey::Season(season_id), &season);
+
+        SeasonEnded {
+            season_id,
+            sessions: season.sessions,
+            points_wagered: season.points_wagered,
+        }
+        .publish(&env);
+
+        Ok(())
+    }
+
+    /// Get a season's definition and running totals
+    pub fn get_season(env: Env, season_id: u32) -> Result<Season, Error> {
+        env.storage()
+            .persistent()
+            .get(&DataKey::Season(season_id))
+            .ok_or(Error::SeasonNotFound)
+    }
+
+    /// Get the ID of the season open at the current ledger, if any
+    pub fn get_current_season(env: Env) -> Option<u32> {
+        current_season(&env).map(|(season_id, _)| season_id)
+    }
+
+    /// Get a player's results in one game during one season
+    pub fn get_season_stats(
+        env: Env,
+        season_id: u32,
+        game_id: Address,
+        player: Address,
+    ) -> SeasonStats {
+        env.storage()
+            .persistent()
+            .get(&DataKey::SeasonStats(season_id, game_id, player))
+            .unwrap_or_default()
+    }
+
+    /// Get the top players by net points in one game during one season
+    pub fn get_leaderboard(env: Env, season_id: u32, game_id: Address) -> Vec<LeaderboardEntry> {
+        env.storage()
+            .persistent()
+            .get(&DataKey::Leaderboard(season_id, game_id))
+            .unwrap_or(Vec::new(&env))
+    }
+
+    // ========================================================================
+    // Player Profiles
+    // ========================================================================
+
+    /// Set the cosmetic fields of the caller's profile. Lifetime stats and XP
+    /// are kept by the hub and cannot be changed here.
+    ///
+    /// **Auth:** Requires authorization from `player`.
+    ///
+    /// # Arguments
+    /// * `player` - Player whose profile is updated
+    /// * `display_name_hash` - Hash of the display name, or `None` to clear it
+    /// * `avatar_hash` - Content hash of the avatar, or `None` to clear it
+    pub fn set_profile(
+        env: Env,
+        player: Address,
+        display_name_hash: Option<BytesN<32>>,
+        avatar_hash: Option<BytesN<32>>,
+    ) {
+        player.require_auth();
+
+        let mut profile = Self::get_profile(env.clone(), player.clone());
+        profile.display_name_hash = display_name_hash.clone();
+        profile.avatar_hash = avatar_hash.clone();
+        write_persistent(&env, &DataKey::Profile(player.clone()), &profile);
+
+        ProfileUpdated {
+            player,
+            display_name_hash,
+            avatar_hash,
+        }
+        .publish(&env);
+    }
+
+    /// Get a player's profile (empty if they have never played or set one)
+    pub fn get_profile(env: Env, player: Address) -> PlayerProfile {
+        env.storage()
+            .persistent()
+            .get(&DataKey::Profile(player))
+            .unwrap_or_default()
+    }
+
+    /// Number of sessions a player has won outright in one game
+    pub fn get_game_wins(env: Env, game_id: Address, player: Address) -> u32 {
+        env.storage()
+            .persistent()
+            .get(&DataKey::GameWins(game_id, player))
+            .unwrap_or(0)
+    }
+
+    // ========================================================================
+    // Achievements
+    // ========================================================================
+
+    /// Define a new achievement. Admin only. Players are checked against it
+    /// the next time one of its counters changes.
+    ///
+    /// # Returns
+    /// * `u32` - ID of the new achievement (IDs start at 1)
+    pub fn define_achievement(env: Env, achievement: Achievement) -> Result<u32, Error> {
+        let admin: Address = env
+            .storage()
+            .instance()
+            .get(&DataKey::Admin)
+            .expect("Admin not set");
+        admin.require_auth();
+
+        let scope_fits = match achievement.metric {
+            AchievementMetric::Wins => true,
+            AchievementMetric::Sessions | AchievementMetric::Xp => achievement.game_id.is_none(),
+            AchievementMetric::Stat(_) => achievement.game_id.is_some(),
+        };
+        if !scope_fits || achievement.threshold <= 0 {
+            return Err(Error::InvalidAchievement);
+        }
+
+        let mut active = Self::list_achievements(env.clone());
+        if active.len() >= MAX_ACHIEVEMENTS {
+            return Err(Error::TooManyAchievements);
+        }
+
+        let achievement_id: u32 = env
+            .storage()
+            .instance()
+            .get(&DataKey::AchievementCount)
+            .unwrap_or(0)
+            + 1;
+        write_persistent(&env, &DataKey::Achievement(achievement_id), &achievement);
+        active.push_back(achievement_id);
+        env.storage()
+            .instance()
+            .set(&DataKey::AchievementCount, &achievement_id);
+        env.storage()
+            .instance()
+            .set(&DataKey::ActiveAchievements, &active);
+
+        AchievementDefined {
+            achievement_id,
+            achievement,
+        }
+        .publish(&env);
+
+        Ok(achievement_id)
+    }
+
+    /// Stop awarding an achievement. Admin only. Badges already earned stay.
+    pub fn retire_achievement(env: Env, achievement_id: u32) -> Result<(), Error> {
+        let admin: Address = env
+            .storage()
+            .instance()
+            .get(&DataKey::Admin)
+            .expect("Admin not set");
+        admin.require_auth();
+
+        let mut active = Self::list_achievements(env.clone());
+        let index = active
+            .first_index_of(achievement_id)
+            .ok_or(Error::AchievementNotFound)?;
+        active.remove(index);
+        env.storage()
+            .instance()
+            .set(&DataKey::ActiveAchievements, &active);
+
+        Ok(())
+    }
+
+    /// Get an achievement definition (active or retired)
+    pub fn get_achievement(env: Env, achievement_id: u32) -> Result<Achievement, Error> {
+        env.storage()
+            .persistent()
+            .get(&DataKey::Achievement(achievement_id))
+            .ok_or(Error::AchievementNotFound)
+    }
+
+    /// IDs of the achievements still being awarded
+    pub fn list_achievements(env: Env) -> Vec<u32> {
+        env.storage()
+            .instance()
+            .get(&DataKey::ActiveAchievements)
+            .unwrap_or(Vec::new(&env))
+    }
+
+    /// Add to a player's running total of a game-specific stat, and award any
+    /// achievement on that stat the new total reaches.
+    ///
+    /// **Auth:** Requires authorization from the game contract that started the session.
+    ///
+    /// # Arguments
+    /// * `session_id` - Session the stat was earned in (active or ended)
+    /// * `player` - Participant the stat belongs to
+    /// * `key` - Stat name, defined by the game
+    /// * `value` - Amount to add (must be positive)
+    pub fn report_stat(
+        env: Env,
+        session_id: u32,
+        player: Address,
+        key: Symbol,
+        value: i128,
+    ) -> Result<(), Error> {
+        let session = Self::get_session(env.clone(), session_id)?;
+        session.game_id.require_auth();
+
+        if value <= 0 {
+            return Err(Error::InvalidAmount);
+        }
+        if !session.participants.iter().any(|(p, _)| p == player) {
+            return Err(Error::NotPlayer);
+        }
+
+        let stat_key = DataKey::PlayerStat(session.game_id.clone(), player.clone(), key.clone());
+        let total = read_amount(&env, &stat_key) + value;
+        write_amount(&env, &stat_key, total);
+
+        Self::award_achievements(&env, &session.game_id, &player, |metric| match metric {
+            AchievementMetric::Stat(stat) if *stat == key => Some(total),
+            _ => None,
+        });
+
+        Ok(())
+    }
+
+    /// Get a player's running total of a game-reported stat
+    pub fn get_stat(env: Env, game_id: Address, player: Address, key: Symbol) -> i128 {
+        read_amount(&env, &DataKey::PlayerStat(game_id, player, key))
+    }
+
+    /// Get every badge a player has earned, oldest first
+    pub fn get_badges(env: Env, player: Address) -> Vec<Badge> {
+        env.storage()
+            .persistent()
+            .get(&DataKey::Badges(player))
+            .unwrap_or(Vec::new(&env))
+    }
+
+    /// Check whether a player has earned an achievement
+    pub fn has_badge(env: Env, player: Address, achievement_id: u32) -> bool {
+        Self::get_badges(env, player)
+            .iter()
+            .any(|badge| badge.achievement_id == achievement_id)
+    }
+
+    // ========================================================================
+    // Match History
+    // ========================================================================
+
+    /// Get the match record of an ended session
+    pub fn get_match(env: Env, session_id: u32) -> Result<MatchRecord, Error> {
+        read_persistent(&env, &DataKey::MatchRecord(session_id)).ok_or(Error::SessionNotFound)
+    }
+
+    /// Number of ended sessions a player took part in
+    pub fn count_sessions_by_player(env: Env, player: Address) -> u32 {
+        read_persistent(&env, &DataKey::PlayerMatchCount(player)).unwrap_or(0)
+    }
+
+    /// Page through a player's ended sessions, oldest first.
+    ///
+    /// # Arguments
+    /// * `player` - Player whose history to read
+    /// * `cursor` - Index of the first record to return (0 for the oldest)
+    /// * `limit` - Maximum records to return (capped at 25)
+    pub fn list_sessions_by_player(
+        env: Env,
+        player: Address,
+        cursor: u32,
+        limit: u32,
+    ) -> Vec<MatchRecord> {
+        read_match_page(
+            &env,
+            DataKey::PlayerMatchCount(player.clone()),
+            |index| DataKey::PlayerMatch(player.clone(), index),
+            cursor,
+            limit,
+        )
+    }
+
+    /// Number of ended sessions of a game
+    pub fn count_sessions_by_game(env: Env, game_id: Address) -> u32 {
+        read_persistent(&env, &DataKey::GameMatchCount(game_id)).unwrap_or(0)
+    }
+
+    /// Page through a game's ended sessions, oldest first.
+    ///
+    /// # Arguments
+    /// * `game_id` - Game whose history to read
+    /// * `cursor` - Index of the first record to return (0 for the oldest)
+    /// * `limit` - Maximum records to return (capped at 25)
+    pub fn list_sessions_by_game(
+        env: Env,
+        game_id: Address,
+        cursor: u32,
+        limit: u32,
+    ) -> Vec<MatchRecord> {
+        read_match_page(
+            &env,
+            DataKey::GameMatchCount(game_id.clone()),
+            |index| DataKey::GameMatch(game_id.clone(), index),
+            cursor,
+            limit,
+        )
+    }
+
+    // ========================================================================
+    // Admin Functions
+    // ========================================================================
+
+    /// Get the current admin address
+    pub fn get_admin(env: Env) -> Address {
+        env.storage()
+            .instance()
+            .get(&DataKey::Admin)
+            .expect("Admin not set")
+    }
+
+    /// Get the admin proposed by `propose_admin`, if any
+    pub fn get_pending_admin(env: Env) -> Option<Address> {
+        env.storage().instance().get(&DataKey::PendingAdmin)
+    }
+
+    /// Propose a new admin. Control only moves once they call
+    /// `accept_admin`; a later proposal replaces this one. Current admin only.
+    pub fn propose_admin(env: Env, new_admin: Address) {
+        let admin: Address = env
+            .storage()
+            .instance()
+            .get(&DataKey::Admin)
+            .expect("Admin not set");
+        admin.require_auth();
+
+        env.storage()
+            .instance()
+            .set(&DataKey::PendingAdmin, &new_admin);
+    }
+
+    /// Accept a transfer started by `propose_admin`. Pending admin only.
+    pub fn accept_admin(env: Env) -> Result<(), Error> {
+        let pending: Address = env
+            .storage()
+            .instance()
+            .get(&DataKey::PendingAdmin)
+            .ok_or(Error::NoPendingAdmin)?;
+        pending.require_auth();
+
+        env.storage().instance().set(&DataKey::Admin, &pending);
+        env.storage().instance().remove(&DataKey::PendingAdmin);
+
+        Ok(())
+    }
+
+    /// Update the contract WASM hash (upgrade contract). Admin only.
+    pub fn upgrade(env: Env, new_wasm_hash: BytesN<32>) {
+        let admin: Address = env
+            .storage()
+            .instance()
+            .get(&DataKey::Admin)
+            .expect("Admin not set");
+        admin.require_auth();
+
+        env.deployer().update_current_contract_wasm(new_wasm_hash);
+    }
+
+    // ========================================================================
+    // Internal Helpers
+    // ========================================================================
+
+    /// Validate and lock every stake, then record a new `Active` session.
+    fn open_session(
+        env: &Env,
+        game_id: &Address,
+        session_id: u32,
+        participants: Vec<(Address, i128)>,
+    ) -> Result<(), Error> {
+        game_id.require_auth();
+
+        if !Self::is_game_registered(env.clone(), game_id.clone()) {
+            return Err(Error::GameNotRegistered);
+        }
+        if Self::is_paused(env.clone(), Some(game_id.clone())) {
+            return Err(Error::SessionsPaused);
+        }
+        let config = Self::get_game_config(env.clone(), game_id.clone());
+        if !config.enabled {
+            return Err(Error::GameDisabled);
+        }
+
+        if participants.len() < 2 || participants.len() > MAX_PARTICIPANTS {
+            return Err(Error::InvalidParticipants);
+        }
+        Self::check_stakes(&config, &participants)?;
+
+        // Negative stakes that passed the config check are free entries
+        let mut escrowed = Vec::new(env);
+        for (i, (player, points)) in participants.iter().enumerate() {
+            let seen_before = participants
+                .iter()
+                .take(i)
+                .any(|(other, _)| other == player);
+            if seen_before {
+                return Err(Error::InvalidParticipants);
+            }
+            escrowed.push_back((player, points.max(0)));
+        }
+        let participants = escrowed;
+
+        if env
+            .storage()
+            .persistent()
+            .has(&DataKey::Session(session_id))
+        {
+            return Err(Error::SessionAlreadyExists);
+        }
+        let reserved = match Self::get_reservation(env.clone(), session_id) {
+            Some(reservation) => {
+                reservation.game_id == *game_id
+                    && participants
+                        .iter()
+                        .any(|(player, _)| player == reservation.reserver)
+            }
+            None => false,
+        };
+        if !reserved {
+            return Err(Error::SessionNotReserved);
+        }
+        env.storage()
+            .temporary()
+            .remove(&DataKey::Reservation(session_id));
+
+        let stake_token: Option<Address> = env
+            .storage()
+            .persistent()
+            .get(&DataKey::GameToken(game_id.clone()));
+        match &stake_token {
+            Some(token_id) => {
+                let token = token::Client::new(env, token_id);
+                let hub = env.current_contract_address();
+                for (player, points) in participants.iter() {
+                    if points > 0 {
+                        token.transfer(&player, &hub, &points);
+                    }
+                }
+            }
+            None => {
+                for (player, points) in participants.iter() {
+                    lock_points(env, &player, points)?;
+                }
+            }
+        }
+
+        let session = Session {
+            game_id: game_id.clone(),
+            participants,
+            token: stake_token,
+            fee_bps: Self::get_game_fee(env.clone(), game_id.clone()),
+            status: SessionStatus::Active,
+            start_ledger: env.ledger().sequence(),
+            deadline_ledger: env.ledger().sequence() + SESSION_TIMEOUT_LEDGERS,
+            end_ledger: 0,
+        };
+        write_session(env, session_id, &session);
+
+        // bump instance ttl if required
+        env.storage()
+            .instance()
+            .extend_ttl(TTL_THRESHOLD_LEDGERS, TTL_EXTEND_LEDGERS);
+
+        Ok(())
+    }
+
+    /// Set or clear the global or per-game pause flag. Admin only.
+    fn set_paused(env: &Env, game_id: &Option<Address>, paused: bool) {
+        let admin: Address = env
+            .storage()
+            .instance()
+            .get(&DataKey::Admin)
+            .expect("Admin not set");
+        admin.require_auth();
+
+        match game_id {
+            None => env.storage().instance().set(&DataKey::Paused, &paused),
+            Some(game_id) => {
+                let key = DataKey::GamePaused(game_id.clone());
+                if paused {
+                    write_persistent(env, &key, &true);
+                } else {
+                    env.storage().persistent().remove(&key);
+                }
+            }
+        }
+    }
+
+    /// Check every stake against a game's config
+    fn check_stakes(config: &GameConfig, participants: &Vec<(Address, i128)>) -> Result<(), Error> {
+        let first_stake = participants.get_unchecked(0).1;
+        for (_, points) in participants.iter() {
+            if points < 0 && !config.allow_negative {
+                return Err(Error::NegativeStake);
+            }
+            if points < config.min_stake {
+                return Err(Error::StakeTooLow);
+            }
+            if points > config.max_stake {
+                return Err(Error::StakeTooHigh);
+            }
+            if config.equal_stakes && points != first_stake {
+                return Err(Error::UnequalStakes);
+            }
+        }
+        Ok(())
+    }
+
+    /// Load a session that is still open
+    fn load_active_session(env: &Env, session_id: u32) -> Result<Session, Error> {
+        let session: Session = env
+            .storage()
+            .persistent()
+            .get(&DataKey::Session(session_id))
+            .ok_or(Error::SessionNotFound)?;
+
+        if session.status != SessionStatus::Active {
+            return Err(Error::SessionAlreadyEnded);
+        }
+
+        Ok(session)
+    }
+
+    /// Release every stake, pay out `payouts` (in seat order) and mark the
+    /// session ended. The game's house fee is kept from winning payouts and
+    /// accrues to the treasury; refunds are fee-free. Returns what each player
+    /// actually received.
+    fn close_session(
+        env: &Env,
+        session_id: u32,
+        session: &mut Session,
+        payouts: &Vec<i128>,
+        status: SessionStatus,
+    ) -> Vec<i128> {
+        let is_refund = matches!(
+            status,
+            SessionStatus::Ended(GameOutcome::Draw | GameOutcome::Cancelled)
+        );
+        let fee_bps = if is_refund { 0 } else { session.fee_bps };
+
+        let hub = env.current_contract_address();
+        let mut received = Vec::new(env);
+        let mut fees = 0;
+        for ((player, points), payout) in session.participants.iter().zip(payouts.iter()) {
+            let fee = payout * fee_bps as i128 / 10_000;
+            match &session.token {
+                Some(token_id) => {
+                    if payout - fee > 0 {
+                        token::Client::new(env, token_id).transfer(&hub, &player, &(payout - fee));
+                    }
+                }
+                None => {
+                    release_locked(env, &player, points);
+                    credit_balance(env, &player, payout - fee);
+                }
+            }
+            fees += fee;
+            received.push_back(payout - fee);
+        }
+
+        if fees > 0 {
+            let fees_key = DataKey::AccruedFees(session.token.clone());
+            write_amount(env, &fees_key, read_amount(env, &fees_key) + fees);
+            FeeCollected {
+                session_id,
+                token: session.token.clone(),
+                amount: fees,
+            }
+            .publish(env);
+        }
+
+        session.status = status;
+        session.end_ledger = env.ledger().sequence();
+        write_session(env, session_id, session);
+
+        received
+    }
+
+    /// Release all stakes and pay them out according to `outcome`.
+    fn settle_session(env: &Env, session_id: u32, outcome: GameOutcome) -> Result<(), Error> {
+        let session = Self::load_active_session(env, session_id)?;
+        session.game_id.require_auth();
+
+        Self::apply_outcome(env, session_id, session, outcome)
+    }
+
+    /// Pay out an open session according to `outcome` and record the result.
+    fn apply_outcome(
+        env: &Env,
+        session_id: u32,
+        mut session: Session,
+        outcome: GameOutcome,
+    ) -> Result<(), Error> {
+        let payouts = Self::payouts(env, &session, &outcome)?;
+        let received = Self::close_session(
+            env,
+            session_id,
+            &mut session,
+            &payouts,
+            SessionStatus::Ended(outcome.clone()),
+        );
+
+        GameEnded {
+            session_id,
+            outcome,
+        }
+        .publish(env);
+
+        Self::update_ratings(env, &session);
+        Self::record_season_results(env, &session, &received);
+        Self::record_profiles(env, &session);
+        Self::record_match(env, session_id, &session, &received);
+
+        Ok(())
+    }
+
+    /// Split the pot between the participants (in seat order) for an outcome.
+    /// Draws and cancellations refund every stake; the other outcomes only
+    /// make sense for two-player sessions.
+    fn payouts(env: &Env, session: &Session, outcome: &GameOutcome) -> Result<Vec<i128>, Error> {
+        let participants = &session.participants;
+        let (player1_takes_pot, player2_takes_pot) = match outcome {
+            GameOutcome::Draw | GameOutcome::Cancelled => {
+                let mut stakes = Vec::new(env);
+                for (_, points) in participants.iter() {
+                    stakes.push_back(points);
+                }
+                return Ok(stakes);
+            }
+            _ if participants.len() != 2 => return Err(Error::InvalidOutcome),
+            GameOutcome::Player1Won => (true, false),
+            GameOutcome::Player2Won => (false, true),
+            GameOutcome::Forfeit(player) if *player == participants.get_unchecked(0).0 => {
+                (false, true)
+            }
+            GameOutcome::Forfeit(player) if *player == participants.get_unchecked(1).0 => {
+                (true, false)
+            }
+            GameOutcome::Forfeit(_) => return Err(Error::NotPlayer),
+        };
+
+        let pot = participants.get_unchecked(0).1 + participants.get_unchecked(1).1;
+        let payout = |takes_pot: bool| if takes_pot { pot } else { 0 };
+        Ok(Vec::from_array(
+            env,
+            [payout(player1_takes_pot), payout(player2_takes_pot)],
+        ))
+    }
+
+    /// Store the match record of an ended session and index it by player and game.
+    fn record_match(env: &Env, session_id: u32, session: &Session, payouts: &Vec<i128>) {
+        let record = MatchRecord {
+            session_id,
+            game_id: session.game_id.clone(),
+            participants: session.participants.clone(),
+            payouts: payouts.clone(),
+            result: session.status.clone(),
+            end_ledger: session.end_ledger,
+        };
+        write_persistent(env, &DataKey::MatchRecord(session_id), &record);
+
+        for (player, _) in session.participants.iter() {
+            push_match_index(
+                env,
+                DataKey::PlayerMatchCount(player.clone()),
+                |index| DataKey::PlayerMatch(player.clone(), index),
+                session_id,
+            );
+        }
+        push_match_index(
+            env,
+            DataKey::GameMatchCount(session.game_id.clone()),
+            |index| DataKey::GameMatch(session.game_id.clone(), index),
+            session_id,
+        );
+    }
+
+    /// Add a settled session to the open season's stats, if there is one.
+    /// Rank-0 players are credited a win and everyone else a loss, unless
+    /// every participant shares rank 0 (a draw). Cancelled sessions are skipped.
+    fn record_season_results(env: &Env, session: &Session, payouts: &Vec<i128>) {
+        let Some((season_id, mut season)) = current_season(env) else {
+            return;
+        };
+        let Some(ranks) = Self::session_ranks(env, session) else {
+            return;
+        };
+        let is_draw = ranks.iter().all(|rank| rank == 0);
+
+        let game_id = &session.game_id;
+        let mut pot = 0;
+        for (i, (player, points)) in session.participants.iter().enumerate() {
+            let i = i as u32;
+            pot += points;
+
+            let key = DataKey::SeasonStats(season_id, game_id.clone(), player.clone());
+            let mut stats: SeasonStats = env.storage().persistent().get(&key).unwrap_or_default();
+            stats.sessions += 1;
+            stats.net_points += payouts.get_unchecked(i) - points;
+            if !is_draw {
+                if ranks.get_unchecked(i) == 0 {
+                    stats.wins += 1;
+                } else {
+                    stats.losses += 1;
+                }
+            }
+            write_persistent(env, &key, &stats);
+
+            update_leaderboard(env, season_id, game_id, &player, stats.net_points);
+        }
+
+        season.sessions += 1;
+        season.points_wagered += pot;
+        write_persistent(env, &DataKey::Season(season_id), &season);
+    }
+
+    /// Add a settled session to every participant's profile. Rank-0 players
+    /// are credited a win unless everyone shares rank 0 (a draw). Cancelled
+    /// sessions are skipped.
+    fn record_profiles(env: &Env, session: &Session) {
+        let Some(ranks) = Self::session_ranks(env, session) else {
+            return;
+        };
+        let is_draw = ranks.iter().all(|rank| rank == 0);
+
+        for (i, (player, _)) in session.participants.iter().enumerate() {
+            let mut profile = Self::get_profile(env.clone(), player.clone());
+            profile.sessions += 1;
+            profile.xp += XP_PER_SESSION;
+
+            let wins_key = DataKey::GameWins(session.game_id.clone(), player.clone());
+            let mut game_wins: u32 = env.storage().persistent().get(&wins_key).unwrap_or(0);
+            if !is_draw && ranks.get_unchecked(i as u32) == 0 {
+                profile.wins += 1;
+                profile.xp += XP_PER_WIN;
+                game_wins += 1;
+                write_persistent(env, &wins_key, &game_wins);
+            }
+            write_persistent(env, &DataKey::Profile(player.clone()), &profile);
+
+            Self::award_achievements(env, &session.game_id, &player, |metric| match metric {
+                AchievementMetric::Wins => Some(game_wins as i128),
+                AchievementMetric::Sessions => Some(profile.sessions as i128),
+                AchievementMetric::Xp => Some(profile.xp as i128),
+                AchievementMetric::Stat(_) => None,
+            });
+        }
+    }
+
+    /// Award `player` every active achievement they have not earned yet whose
+    /// counter, as returned by `measure`, has reached its threshold. `measure`
+    /// returns `None` for metrics that did not change. Cross-game `Wins`
+    /// achievements are measured on the profile's total wins instead.
+    fn award_achievements(
+        env: &Env,
+        game_id: &Address,
+        player: &Address,
+        measure: impl Fn(&AchievementMetric) -> Option<i128>,
+    ) {
+        let active = Self::list_achievements(env.clone());
+        if active.is_empty() {
+            return;
+        }
+
+        let mut badges = Self::get_badges(env.clone(), player.clone());
+        let mut earned_any = false;
+        for achievement_id in active.iter() {
+            if badges
+                .iter()
+                .any(|badge| badge.achievement_id == achievement_id)
+            {
+                continue;
+            }
+            let achievement = Self::get_achievement(env.clone(), achievement_id)
+                .expect("Active achievement missing");
+            let value = match (&achievement.game_id, &achievement.metric) {
+                (Some(scope), _) if scope != game_id => None,
+                (None, AchievementMetric::Wins) => measure(&AchievementMetric::Wins)
+                    .map(|_| Self::get_profile(env.clone(), player.clone()).wins as i128),
+                (_, metric) => measure(metric),
+            };
+            if value.is_some_and(|value| value >= achievement.threshold) {
+                badges.push_back(Badge {
+                    achievement_id,
+                    earned_ledger: env.ledger().sequence(),
+                });
+                earned_any = true;
+                AchievementUnlocked {
+                    player: player.clone(),
+                    achievement_id,
+                }
+                .publish(env);
+            }
+        }
+
+        if earned_any {
+            write_persistent(env, &DataKey::Badges(player.clone()), &badges);
+        }
+    }
+
+    /// Finishing rank of each participant (in seat order) for a settled
+    /// session; lower is better and equal ranks are a draw between them.
+    /// Returns `None` for sessions that should not be rated.
+    fn session_ranks(env: &Env, session: &Session) -> Option<Vec<u32>> {
+        let participants = &session.participants;
+        match &session.status {
+            SessionStatus::Active => None,
+            SessionStatus::Ended(outcome) => match outcome {
+                GameOutcome::Cancelled => None,
+                GameOutcome::Draw => {
+                    let mut ranks = Vec::new(env);
+                    for _ in participants.iter() {
+                        ranks.push_back(0);
+                    }
+                    Some(ranks)
+                }
+                GameOutcome::Player1Won => Some(Vec::from_array(env, [0, 1])),
+                GameOutcome::Player2Won => Some(Vec::from_array(env, [1, 0])),
+                GameOutcome::Forfeit(player) if *player == participants.get_unchecked(0).0 => {
+                    Some(Vec::from_array(env, [1, 0]))
+                }
+                GameOutcome::Forfeit(_) => Some(Vec::from_array(env, [0, 1])),
+            },
+            SessionStatus::Placed(placements) => {
+                let mut ranks = Vec::new(env);
+                for (player, _) in participants.iter() {
+                    ranks.push_back(placements.first_index_of(&player)?);
+                }
+                Some(ranks)
+            }
+        }
+    }
+
+    /// Apply the Elo update for a settled session. Every pair of participants
+    /// counts as one game; the summed change is averaged over the opponents.
+    fn update_ratings(env: &Env, session: &Session) {
+        let Some(ranks) = Self::session_ranks(env, session) else {
+            return;
+        };
+
+        let game_id = &session.game_id;
+        let mut ratings = Vec::new(env);
+        for (player, _) in session.participants.iter() {
+            ratings.push_back(read_rating(env, game_id, &player));
+        }
+
+        let opponents = session.participants.len() as i128 - 1;
+        for (i, (player, _)) in session.participants.iter().enumerate() {
+            let i = i as u32;
+            let old = ratings.get_unchecked(i);
+
+            let mut score_minus_expected_bps = 0;
+            for (j, other) in ratings.iter().enumerate() {
+                let j = j as u32;
+                if j == i {
+                    continue;
+                }
+                let score_bps = match ranks.get_unchecked(i).cmp(&ranks.get_unchecked(j)) {
+                    core::cmp::Ordering::Less => 10_000,
+                    core::cmp::Ordering::Equal => 5_000,
+                    core::cmp::Ordering::Greater => 0,
+                };
+                score_minus_expected_bps +=
+                    score_bps - expected_score_bps(old.rating, other.rating);
+            }
+
+            let k_factor = if old.games_played < PROVISIONAL_GAMES {
+                PROVISIONAL_K_FACTOR
+            } else {
+                ESTABLISHED_K_FACTOR
+            };
+            let delta = div_round(k_factor * score_minus_expected_bps, 10_000 * opponents);
+
+            let new = Rating {
+                rating: old.rating + delta as i32,
+                games_played: old.games_played + 1,
+            };
+            write_rating(env, game_id, &player, &new);
+
+            RatingChanged {
+                game_id: game_id.clone(),
+                player,
+                old_rating: old.rating,
+                new_rating: new.rating,
+            }
+            .publish(env);
+        }
+    }
+
+    /// Split the pot by finishing position. Returns payouts in seat order.
+    fn placement_payouts(
+        env: &Env,
+        session: &Session,
+        placements: &Vec<Address>,
+    ) -> Result<Vec<i128>, Error> {
+        let count = session.participants.len();
+        if placements.len() != count {
+            return Err(Error::InvalidPlacements);
+        }
+
+        let pot: i128 = session.participants.iter().map(|(_, p)| p).sum();
+        let splits = payout_splits(count);
+
+        let mut payouts = Vec::new(env);
+        let mut paid = 0;
+        for (player, _) in session.participants.iter() {
+            let place = placements
+                .first_index_of(&player)
+                .ok_or(Error::InvalidPlacements)?;
+            let share = match splits.get(place as usize) {
+                Some(bps) => pot * bps / 10_000,
+                None => 0,
+            };
+            paid += share;
+            payouts.push_back(share);
+        }
+
+        // Rounding dust goes to first place
+        let first = placements.get_unchecked(0);
+        let first_seat = session
+            .participants
+            .iter()
+            .position(|(player, _)| player == first)
+            .ok_or(Error::InvalidPlacements)? as u32;
+        payouts.set(first_seat, payouts.get_unchecked(first_seat) + pot - paid);
+
+        Ok(payouts)
+    }
+}
+
+// ============================================================================
+// Tests
+// ============================================================================
+
+#[cfg(test)]
+mod test;
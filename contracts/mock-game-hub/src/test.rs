@@ -0,0 +1,1891 @@
+#![cfg(test)]
+
+// Unit tests for the Game Hub points escrow.
+// A plain generated address stands in for the calling game contract;
+// `mock_all_auths` covers its `require_auth` calls.
+
+use crate::{
+    Achievement, AchievementMetric, DataKey, Error, GameConfig, GameOutcome, MockGameHub,
+    MockGameHubClient, PlayerProfile, Reservation, SessionStatus, TTL_EXTEND_LEDGERS,
+};
+use sgs_game_common::GameCallbacks;
+use soroban_sdk::testutils::{storage::Persistent as _, Address as _, Ledger as _};
+use soroban_sdk::token::{StellarAssetClient, TokenClient};
+use soroban_sdk::{contract, contractimpl, contracttype, symbol_short, vec, Address, BytesN, Env};
+
+// ============================================================================
+// Test Helpers
+// ============================================================================
+
+const RESERVED_SESSIONS: u32 = 10;
+
+fn setup_test() -> (
+    Env,
+    MockGameHubClient<'static>,
+    Address, // game_id
+    Address, // player1
+    Address, // player2
+) {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let admin = Address::generate(&env);
+    let contract_id = env.register(MockGameHub, (&admin,));
+    let client = MockGameHubClient::new(&env, &contract_id);
+
+    let game_id = Address::generate(&env);
+    let player1 = Address::generate(&env);
+    let player2 = Address::generate(&env);
+
+    client.add_game(&game_id);
+    client.add_points(&player1, &1000);
+    client.add_points(&player2, &1000);
+
+    // Session IDs 1 to RESERVED_SESSIONS belong to `game_id`, so tests can
+    // start sessions with fixed IDs
+    for _ in 0..RESERVED_SESSIONS {
+        client.reserve_session(&game_id, &player1);
+    }
+
+    (env, client, game_id, player1, player2)
+}
+
+fn assert_hub_error<T, E>(
+    result: &Result<Result<T, E>, Result<Error, soroban_sdk::InvokeError>>,
+    expected_error: Error,
+) {
+    match result {
+        Err(Ok(actual_error)) => {
+            assert_eq!(
+                *actual_error, expected_error,
+                "Expected {:?} (code {}), got {:?} (code {})",
+                expected_error, expected_error as u32, actual_error, *actual_error as u32
+            );
+        }
+        Err(Err(_)) => panic!(
+            "Expected {:?} (code {}), got invocation error",
+            expected_error, expected_error as u32
+        ),
+        Ok(Err(_)) => panic!(
+            "Expected {:?} (code {}), got conversion error",
+            expected_error, expected_error as u32
+        ),
+        Ok(Ok(_)) => panic!(
+            "Expected {:?} (code {}), but operation succeeded",
+            expected_error, expected_error as u32
+        ),
+    }
+}
+
+// ============================================================================
+// Escrow Tests
+// ============================================================================
+
+#[test]
+fn test_start_game_locks_points() {
+    let (_env, client, game_id, player1, player2) = setup_test();
+
+    client.start_game(&game_id, &1, &player1, &player2, &100, &250);
+
+    assert_eq!(client.get_balance(&player1), 900);
+    assert_eq!(client.get_balance(&player2), 750);
+    assert_eq!(client.get_locked(&player1), 100);
+    assert_eq!(client.get_locked(&player2), 250);
+}
+
+#[test]
+fn test_end_game_pays_winner() {
+    let (_env, client, game_id, player1, player2) = setup_test();
+
+    client.start_game(&game_id, &1, &player1, &player2, &100, &250);
+    client.end_game(&1, &false);
+
+    assert_eq!(client.get_balance(&player1), 900);
+    assert_eq!(client.get_balance(&player2), 1100);
+    assert_eq!(client.get_locked(&player1), 0);
+    assert_eq!(client.get_locked(&player2), 0);
+}
+
+#[test]
+fn test_concurrent_sessions_track_locked_totals() {
+    let (env, client, game_id, player1, player2) = setup_test();
+    let player3 = Address::generate(&env);
+    client.add_points(&player3, &500);
+
+    client.start_game(&game_id, &1, &player1, &player2, &100, &100);
+    client.start_game(&game_id, &2, &player1, &player3, &200, &200);
+    assert_eq!(client.get_locked(&player1), 300);
+    assert_eq!(client.get_balance(&player1), 700);
+
+    client.end_game(&1, &true);
+    assert_eq!(client.get_locked(&player1), 200);
+    assert_eq!(client.get_balance(&player1), 900);
+
+    client.end_game(&2, &false);
+    assert_eq!(client.get_locked(&player1), 0);
+    assert_eq!(client.get_balance(&player1), 900);
+    assert_eq!(client.get_balance(&player3), 700);
+}
+
+#[test]
+fn test_insufficient_balance_rejected() {
+    let (_env, client, game_id, player1, player2) = setup_test();
+
+    let result = client.try_start_game(&game_id, &1, &player1, &player2, &100, &1001);
+    assert_hub_error(&result, Error::InsufficientBalance);
+
+    // Nothing was locked for either player
+    assert_eq!(client.get_locked(&player1), 0);
+    assert_eq!(client.get_balance(&player1), 1000);
+}
+
+#[test]
+fn test_negative_stake_rejected() {
+    let (_env, client, game_id, player1, player2) = setup_test();
+
+    let result = client.try_start_game(&game_id, &1, &player1, &player2, &-5, &10);
+    assert_hub_error(&result, Error::NegativeStake);
+}
+
+#[test]
+fn test_duplicate_session_rejected() {
+    let (_env, client, game_id, player1, player2) = setup_test();
+
+    client.start_game(&game_id, &1, &player1, &player2, &100, &100);
+    let result = client.try_start_game(&game_id, &1, &player1, &player2, &100, &100);
+    assert_hub_error(&result, Error::SessionAlreadyExists);
+}
+
+#[test]
+fn test_end_unknown_session_rejected() {
+    let (_env, client, _game_id, _player1, _player2) = setup_test();
+
+    assert_hub_error(&client.try_end_game(&42, &true), Error::SessionNotFound);
+}
+
+// ============================================================================
+// Session Registry Tests
+// ============================================================================
+
+#[test]
+fn test_session_record_lifecycle() {
+    let (env, client, game_id, player1, player2) = setup_test();
+
+    env.ledger().set_sequence_number(100);
+    client.start_game(&game_id, &7, &player1, &player2, &100, &250);
+
+    let session = client.get_session(&7);
+    assert_eq!(session.game_id, game_id);
+    assert_eq!(
+        session.participants,
+        vec![&env, (player1.clone(), 100), (player2.clone(), 250)]
+    );
+    assert_eq!(session.status, SessionStatus::Active);
+    assert_eq!(session.start_ledger, 100);
+    assert_eq!(session.end_ledger, 0);
+
+    env.ledger().set_sequence_number(150);
+    client.end_game(&7, &true);
+
+    let session = client.get_session(&7);
+    assert_eq!(
+        session.status,
+        SessionStatus::Ended(GameOutcome::Player1Won)
+    );
+    assert_eq!(session.start_ledger, 100);
+    assert_eq!(session.end_ledger, 150);
+}
+
+#[test]
+fn test_end_game_twice_rejected() {
+    let (_env, client, game_id, player1, player2) = setup_test();
+
+    client.start_game(&game_id, &1, &player1, &player2, &100, &100);
+    client.end_game(&1, &true);
+
+    assert_hub_error(&client.try_end_game(&1, &false), Error::SessionAlreadyEnded);
+    // The pot was only paid once
+    assert_eq!(client.get_balance(&player1), 1100);
+    assert_eq!(client.get_balance(&player2), 900);
+}
+
+#[test]
+fn test_ended_session_id_cannot_be_reused() {
+    let (_env, client, game_id, player1, player2) = setup_test();
+
+    client.start_game(&game_id, &1, &player1, &player2, &100, &100);
+    client.end_game(&1, &true);
+
+    let result = client.try_start_game(&game_id, &1, &player1, &player2, &100, &100);
+    assert_hub_error(&result, Error::SessionAlreadyExists);
+}
+
+#[test]
+fn test_get_unknown_session() {
+    let (_env, client, _game_id, _player1, _player2) = setup_test();
+
+    assert_hub_error(&client.try_get_session(&99), Error::SessionNotFound);
+}
+
+#[test]
+fn test_reserve_session_issues_increasing_ids() {
+    let (env, client, game_id, player1, _player2) = setup_test();
+    let other_game = Address::generate(&env);
+    client.add_game(&other_game);
+
+    assert_eq!(client.next_session_id(), RESERVED_SESSIONS + 1);
+    let first = client.reserve_session(&other_game, &player1);
+    let second = client.reserve_session(&game_id, &player1);
+    assert_eq!(first, RESERVED_SESSIONS + 1);
+    assert_eq!(second, first + 1);
+    assert_eq!(
+        client.get_reservation(&first),
+        Some(Reservation {
+            game_id: other_game,
+            reserver: player1.clone(),
+        })
+    );
+    assert_eq!(client.get_reservation(&second).unwrap().game_id, game_id);
+
+    let unregistered = Address::generate(&env);
+    let result = client.try_reserve_session(&unregistered, &player1);
+    assert_hub_error(&result, Error::GameNotRegistered);
+}
+
+#[test]
+fn test_start_requires_reservation_for_game() {
+    let (env, client, game_id, player1, player2) = setup_test();
+    let other_game = Address::generate(&env);
+    client.add_game(&other_game);
+
+    // Never issued
+    let result = client.try_start_game(&game_id, &999, &player1, &player2, &10, &10);
+    assert_hub_error(&result, Error::SessionNotReserved);
+
+    // Issued to a different game
+    let session_id = client.reserve_session(&other_game, &player1);
+    let result = client.try_start_game(&game_id, &session_id, &player1, &player2, &10, &10);
+    assert_hub_error(&result, Error::SessionNotReserved);
+
+    client.start_game(&other_game, &session_id, &player1, &player2, &10, &10);
+    assert_eq!(client.get_reservation(&session_id), None);
+}
+
+#[test]
+fn test_reservation_bound_to_reserver() {
+    let (env, client, game_id, player1, player2) = setup_test();
+    let squatter = funded_player(&env, &client);
+    let accomplice = funded_player(&env, &client);
+
+    // The reserver has to authorize the reservation
+    let session_id = client.reserve_session(&game_id, &player1);
+    assert_eq!(env.auths()[0].0, player1);
+
+    // A session without the reserver cannot take over the ID
+    let result = client.try_start_game(&game_id, &session_id, &squatter, &accomplice, &10, &10);
+    assert_hub_error(&result, Error::SessionNotReserved);
+
+    // The reserver can seat themselves in either position
+    client.start_game(&game_id, &session_id, &player2, &player1, &10, &10);
+}
+
+const RESERVATION_TTL: u32 = 17_280;
+
+#[test]
+fn test_reservation_expires() {
+    let (env, client, game_id, player1, player2) = setup_test();
+
+    let session_id = client.reserve_session(&game_id, &player1);
+    env.ledger().set_sequence_number(RESERVATION_TTL + 1);
+
+    let result = client.try_start_game(&game_id, &session_id, &player1, &player2, &10, &10);
+    assert_hub_error(&result, Error::SessionNotReserved);
+}
+
+// ============================================================================
+// Outcome Settlement Tests
+// ============================================================================
+
+#[test]
+fn test_draw_refunds_both_stakes() {
+    let (_env, client, game_id, player1, player2) = setup_test();
+
+    client.start_game(&game_id, &1, &player1, &player2, &100, &250);
+    client.end_game_with_outcome(&1, &GameOutcome::Draw);
+
+    assert_eq!(client.get_balance(&player1), 1000);
+    assert_eq!(client.get_balance(&player2), 1000);
+    assert_eq!(client.get_locked(&player1), 0);
+    assert_eq!(client.get_locked(&player2), 0);
+    assert_eq!(
+        client.get_session(&1).status,
+        SessionStatus::Ended(GameOutcome::Draw)
+    );
+}
+
+#[test]
+fn test_cancelled_refunds_both_stakes() {
+    let (_env, client, game_id, player1, player2) = setup_test();
+
+    client.start_game(&game_id, &1, &player1, &player2, &100, &250);
+    client.end_game_with_outcome(&1, &GameOutcome::Cancelled);
+
+    assert_eq!(client.get_balance(&player1), 1000);
+    assert_eq!(client.get_balance(&player2), 1000);
+    assert_eq!(
+        client.get_session(&1).status,
+        SessionStatus::Ended(GameOutcome::Cancelled)
+    );
+}
+
+#[test]
+fn test_forfeit_pays_opponent() {
+    let (_env, client, game_id, player1, player2) = setup_test();
+
+    client.start_game(&game_id, &1, &player1, &player2, &100, &250);
+    client.end_game_with_outcome(&1, &GameOutcome::Forfeit(player2.clone()));
+
+    assert_eq!(client.get_balance(&player1), 1250);
+    assert_eq!(client.get_balance(&player2), 750);
+    assert_eq!(client.get_locked(&player2), 0);
+}
+
+#[test]
+fn test_forfeit_by_outsider_rejected() {
+    let (env, client, game_id, player1, player2) = setup_test();
+    let outsider = Address::generate(&env);
+
+    client.start_game(&game_id, &1, &player1, &player2, &100, &100);
+    let result = client.try_end_game_with_outcome(&1, &GameOutcome::Forfeit(outsider));
+    assert_hub_error(&result, Error::NotPlayer);
+
+    // Session is still open and can be settled normally
+    assert_eq!(client.get_session(&1).status, SessionStatus::Active);
+    client.end_game_with_outcome(&1, &GameOutcome::Player2Won);
+    assert_eq!(client.get_balance(&player2), 1100);
+}
+
+#[test]
+fn test_outcome_cannot_settle_ended_session() {
+    let (_env, client, game_id, player1, player2) = setup_test();
+
+    client.start_game(&game_id, &1, &player1, &player2, &100, &100);
+    client.end_game_with_outcome(&1, &GameOutcome::Draw);
+
+    let result = client.try_end_game_with_outcome(&1, &GameOutcome::Player1Won);
+    assert_hub_error(&result, Error::SessionAlreadyEnded);
+}
+
+// ============================================================================
+// Multiplayer Session Tests
+// ============================================================================
+
+fn funded_player(env: &Env, client: &MockGameHubClient) -> Address {
+    let player = Address::generate(env);
+    client.add_points(&player, &1000);
+    player
+}
+
+#[test]
+fn test_multiplayer_session_locks_every_stake() {
+    let (env, client, game_id, player1, player2) = setup_test();
+    let player3 = funded_player(&env, &client);
+
+    let participants = vec![
+        &env,
+        (player1.clone(), 100),
+        (player2.clone(), 200),
+        (player3.clone(), 300),
+    ];
+    client.start_multiplayer_game(&game_id, &1, &participants);
+
+    assert_eq!(client.get_locked(&player1), 100);
+    assert_eq!(client.get_locked(&player2), 200);
+    assert_eq!(client.get_locked(&player3), 300);
+    assert_eq!(client.get_session(&1).participants, participants);
+}
+
+#[test]
+fn test_placements_split_pot() {
+    let (env, client, game_id, player1, player2) = setup_test();
+    let player3 = funded_player(&env, &client);
+
+    let participants = vec![
+        &env,
+        (player1.clone(), 100),
+        (player2.clone(), 100),
+        (player3.clone(), 101),
+    ];
+    client.start_multiplayer_game(&game_id, &1, &participants);
+
+    let placements = vec![&env, player3.clone(), player1.clone(), player2.clone()];
+    client.end_game_with_placements(&1, &placements);
+
+    // Pot of 301: 70% = 210 (+1 rounding dust) to first, 30% = 90 to second
+    assert_eq!(client.get_balance(&player3), 1000 - 101 + 211);
+    assert_eq!(client.get_balance(&player1), 1000 - 100 + 90);
+    assert_eq!(client.get_balance(&player2), 1000 - 100);
+    assert_eq!(client.get_locked(&player3), 0);
+    assert_eq!(
+        client.get_session(&1).status,
+        SessionStatus::Placed(placements)
+    );
+}
+
+#[test]
+fn test_five_player_split_pays_top_three() {
+    let (env, client, game_id, _player1, _player2) = setup_test();
+    let players = [
+        funded_player(&env, &client),
+        funded_player(&env, &client),
+        funded_player(&env, &client),
+        funded_player(&env, &client),
+        funded_player(&env, &client),
+    ];
+
+    let mut participants = vec![&env];
+    let mut placements = vec![&env];
+    for player in players.iter() {
+        participants.push_back((player.clone(), 200));
+        placements.push_front(player.clone());
+    }
+    let session_id = client.reserve_session(&game_id, &players[0]);
+    client.start_multiplayer_game(&game_id, &session_id, &participants);
+    client.end_game_with_placements(&session_id, &placements);
+
+    // Pot of 1000 split 50/30/20, last seat finished first
+    assert_eq!(client.get_balance(&players[4]), 1300);
+    assert_eq!(client.get_balance(&players[3]), 1100);
+    assert_eq!(client.get_balance(&players[2]), 1000);
+    assert_eq!(client.get_balance(&players[1]), 800);
+    assert_eq!(client.get_balance(&players[0]), 800);
+}
+
+#[test]
+fn test_two_player_session_accepts_placements() {
+    let (env, client, game_id, player1, player2) = setup_test();
+
+    client.start_game(&game_id, &1, &player1, &player2, &100, &250);
+    client.end_game_with_placements(&1, &vec![&env, player2.clone(), player1.clone()]);
+
+    assert_eq!(client.get_balance(&player1), 900);
+    assert_eq!(client.get_balance(&player2), 1100);
+}
+
+#[test]
+fn test_invalid_participants_rejected() {
+    let (env, client, game_id, player1, player2) = setup_test();
+
+    let solo = vec![&env, (player1.clone(), 100)];
+    let result = client.try_start_multiplayer_game(&game_id, &1, &solo);
+    assert_hub_error(&result, Error::InvalidParticipants);
+
+    let duplicate = vec![
+        &env,
+        (player1.clone(), 100),
+        (player2.clone(), 100),
+        (player1.clone(), 100),
+    ];
+    let result = client.try_start_multiplayer_game(&game_id, &1, &duplicate);
+    assert_hub_error(&result, Error::InvalidParticipants);
+
+    let negative = vec![&env, (player1.clone(), 100), (player2.clone(), -1)];
+    let result = client.try_start_multiplayer_game(&game_id, &1, &negative);
+    assert_hub_error(&result, Error::NegativeStake);
+}
+
+#[test]
+fn test_invalid_placements_rejected() {
+    let (env, client, game_id, player1, player2) = setup_test();
+    let player3 = funded_player(&env, &client);
+    let outsider = Address::generate(&env);
+
+    let participants = vec![
+        &env,
+        (player1.clone(), 100),
+        (player2.clone(), 100),
+        (player3.clone(), 100),
+    ];
+    client.start_multiplayer_game(&game_id, &1, &participants);
+
+    let missing = vec![&env, player1.clone(), player2.clone()];
+    let result = client.try_end_game_with_placements(&1, &missing);
+    assert_hub_error(&result, Error::InvalidPlacements);
+
+    let repeated = vec![&env, player1.clone(), player2.clone(), player1.clone()];
+    let result = client.try_end_game_with_placements(&1, &repeated);
+    assert_hub_error(&result, Error::InvalidPlacements);
+
+    let with_outsider = vec![&env, player1.clone(), player2.clone(), outsider];
+    let result = client.try_end_game_with_placements(&1, &with_outsider);
+    assert_hub_error(&result, Error::InvalidPlacements);
+
+    assert_eq!(client.get_session(&1).status, SessionStatus::Active);
+}
+
+#[test]
+fn test_multiplayer_outcomes() {
+    let (env, client, game_id, player1, player2) = setup_test();
+    let player3 = funded_player(&env, &client);
+
+    let participants = vec![
+        &env,
+        (player1.clone(), 100),
+        (player2.clone(), 200),
+        (player3.clone(), 300),
+    ];
+    client.start_multiplayer_game(&game_id, &1, &participants);
+
+    // Two-player outcomes have no meaning here
+    let result = client.try_end_game(&1, &true);
+    assert_hub_error(&result, Error::InvalidOutcome);
+    let result = client.try_end_game_with_outcome(&1, &GameOutcome::Forfeit(player1.clone()));
+    assert_hub_error(&result, Error::InvalidOutcome);
+
+    // Cancelling refunds every stake
+    client.end_game_with_outcome(&1, &GameOutcome::Cancelled);
+    assert_eq!(client.get_balance(&player1), 1000);
+    assert_eq!(client.get_balance(&player2), 1000);
+    assert_eq!(client.get_balance(&player3), 1000);
+    assert_eq!(client.get_locked(&player3), 0);
+}
+
+// ============================================================================
+// Pause Tests
+// ============================================================================
+
+#[test]
+fn test_global_pause_blocks_new_sessions() {
+    let (_env, client, game_id, player1, player2) = setup_test();
+
+    client.pause(&None);
+    assert!(client.is_paused(&None));
+    assert!(client.is_paused(&Some(game_id.clone())));
+
+    let result = client.try_start_game(&game_id, &1, &player1, &player2, &10, &10);
+    assert_hub_error(&result, Error::SessionsPaused);
+
+    client.unpause(&None);
+    client.start_game(&game_id, &1, &player1, &player2, &10, &10);
+}
+
+#[test]
+fn test_game_pause_only_affects_that_game() {
+    let (env, client, game_id, player1, player2) = setup_test();
+    let other_game = Address::generate(&env);
+    client.add_game(&other_game);
+
+    client.pause(&Some(game_id.clone()));
+    assert!(!client.is_paused(&None));
+    assert!(!client.is_paused(&Some(other_game.clone())));
+
+    let result = client.try_start_game(&game_id, &1, &player1, &player2, &10, &10);
+    assert_hub_error(&result, Error::SessionsPaused);
+    let session_id = client.reserve_session(&other_game, &player1);
+    client.start_game(&other_game, &session_id, &player1, &player2, &10, &10);
+
+    // Lifting the global pause leaves the game pause in place
+    client.unpause(&None);
+    assert!(client.is_paused(&Some(game_id.clone())));
+    client.unpause(&Some(game_id.clone()));
+    assert!(!client.is_paused(&Some(game_id)));
+}
+
+#[test]
+fn test_cancel_paused_session_refunds() {
+    let (env, client, game_id, player1, player2) = setup_test();
+    client.start_game(&game_id, &1, &player1, &player2, &100, &250);
+
+    let result = client.try_cancel_paused_session(&1, &player1);
+    assert_hub_error(&result, Error::NotPaused);
+
+    client.pause(&Some(game_id.clone()));
+    let outsider = Address::generate(&env);
+    let result = client.try_cancel_paused_session(&1, &outsider);
+    assert_hub_error(&result, Error::NotPlayer);
+
+    client.cancel_paused_session(&1, &player2);
+
+    assert_eq!(client.get_balance(&player1), 1000);
+    assert_eq!(client.get_balance(&player2), 1000);
+    assert_eq!(client.get_locked(&player2), 0);
+    assert_eq!(
+        client.get_session(&1).status,
+        SessionStatus::Ended(GameOutcome::Cancelled)
+    );
+}
+
+#[test]
+fn test_admin_cancels_paused_session_and_notifies_game() {
+    let (env, client, _game_id, player1, player2) = setup_test();
+    let game = setup_timeout_game(&env, &client);
+    let session_id = client.reserve_session(&game.address, &player1);
+    client.start_game(&game.address, &session_id, &player1, &player2, &100, &100);
+
+    client.pause(&None);
+    client.cancel_paused_session(&session_id, &client.get_admin());
+
+    assert!(game.was_cancelled(&session_id));
+    assert_eq!(client.get_balance(&player1), 1000);
+}
+
+// ============================================================================
+// Token Stake Tests
+// ============================================================================
+
+fn setup_token(
+    env: &Env,
+    client: &MockGameHubClient,
+    game_id: &Address,
+    players: &[&Address],
+    fee_bps: u32,
+) -> TokenClient<'static> {
+    // The stake transfer is authorized by the player below the game's
+    // root invocation, as it would be in a real transaction
+    env.mock_all_auths_allowing_non_root_auth();
+
+    let issuer = Address::generate(env);
+    let token_id = env.register_stellar_asset_contract_v2(issuer).address();
+    let asset = StellarAssetClient::new(env, &token_id);
+    for player in players {
+        asset.mint(player, &1000);
+    }
+    client.set_game_token(game_id, &token_id);
+    client.set_game_fee(game_id, &fee_bps);
+    TokenClient::new(env, &token_id)
+}
+
+#[test]
+fn test_token_session_escrows_and_pays_out() {
+    let (env, client, game_id, player1, player2) = setup_test();
+    let token = setup_token(&env, &client, &game_id, &[&player1, &player2], 0);
+
+    client.start_game(&game_id, &1, &player1, &player2, &100, &250);
+
+    assert_eq!(token.balance(&player1), 900);
+    assert_eq!(token.balance(&player2), 750);
+    assert_eq!(token.balance(&client.address), 350);
+    // Points balances are untouched in token mode
+    assert_eq!(client.get_balance(&player1), 1000);
+    assert_eq!(client.get_locked(&player1), 0);
+
+    let session = client.get_session(&1);
+    assert_eq!(session.token, Some(token.address.clone()));
+
+    client.end_game(&1, &true);
+
+    assert_eq!(token.balance(&player1), 1250);
+    assert_eq!(token.balance(&player2), 750);
+    assert_eq!(token.balance(&client.address), 0);
+}
+
+#[test]
+fn test_token_fee_taken_from_winnings() {
+    let (env, client, game_id, player1, player2) = setup_test();
+    let token = setup_token(&env, &client, &game_id, &[&player1, &player2], 500);
+    let treasury = Address::generate(&env);
+    client.set_treasury(&treasury);
+
+    client.start_game(&game_id, &1, &player1, &player2, &100, &100);
+    client.end_game(&1, &false);
+
+    // 5% of the 200 pot stays in the hub until withdrawn
+    assert_eq!(token.balance(&player2), 900 + 190);
+    assert_eq!(token.balance(&client.address), 10);
+    assert_eq!(client.get_accrued_fees(&Some(token.address.clone())), 10);
+
+    client.withdraw_fees(&Some(token.address.clone()), &10);
+    assert_eq!(token.balance(&treasury), 10);
+    assert_eq!(token.balance(&client.address), 0);
+    assert_eq!(client.get_accrued_fees(&Some(token.address.clone())), 0);
+}
+
+#[test]
+fn test_token_refund_is_fee_free() {
+    let (env, client, game_id, player1, player2) = setup_test();
+    let token = setup_token(&env, &client, &game_id, &[&player1, &player2], 500);
+
+    client.start_game(&game_id, &1, &player1, &player2, &100, &250);
+    client.end_game_with_outcome(&1, &GameOutcome::Cancelled);
+
+    assert_eq!(token.balance(&player1), 1000);
+    assert_eq!(token.balance(&player2), 1000);
+    assert_eq!(client.get_accrued_fees(&Some(token.address.clone())), 0);
+}
+
+#[test]
+fn test_token_mode_fixed_at_session_start() {
+    let (env, client, game_id, player1, player2) = setup_test();
+    let token = setup_token(&env, &client, &game_id, &[&player1, &player2], 0);
+
+    client.start_game(&game_id, &1, &player1, &player2, &100, &100);
+    client.remove_game_token(&game_id);
+    assert_eq!(client.get_game_token(&game_id), None);
+
+    // New sessions use points again
+    client.start_game(&game_id, &2, &player1, &player2, &100, &100);
+    assert_eq!(client.get_locked(&player1), 100);
+
+    // The running token session still settles in tokens
+    client.end_game(&1, &true);
+    assert_eq!(token.balance(&player1), 1100);
+    assert_eq!(client.get_balance(&player1), 900);
+}
+
+#[test]
+fn test_token_requires_registered_game() {
+    let (env, client, _game_id, _player1, _player2) = setup_test();
+    let rogue_game = Address::generate(&env);
+    let token_id = env
+        .register_stellar_asset_contract_v2(Address::generate(&env))
+        .address();
+
+    let result = client.try_set_game_token(&rogue_game, &token_id);
+    assert_hub_error(&result, Error::GameNotRegistered);
+    assert_eq!(client.get_game_token(&rogue_game), None);
+}
+
+#[test]
+fn test_token_placements_payout() {
+    let (env, client, game_id, player1, player2) = setup_test();
+    let player3 = Address::generate(&env);
+    let token = setup_token(
+        &env,
+        &client,
+        &game_id,
+        &[&player1, &player2, &player3],
+        1000,
+    );
+
+    let participants = vec![
+        &env,
+        (player1.clone(), 100),
+        (player2.clone(), 100),
+        (player3.clone(), 100),
+    ];
+    client.start_multiplayer_game(&game_id, &1, &participants);
+    client.end_game_with_placements(
+        &1,
+        &vec![&env, player1.clone(), player2.clone(), player3.clone()],
+    );
+
+    // 210 / 90 split, less 10%
+    assert_eq!(token.balance(&player1), 900 + 189);
+    assert_eq!(token.balance(&player2), 900 + 81);
+    assert_eq!(token.balance(&player3), 900);
+    assert_eq!(client.get_accrued_fees(&Some(token.address.clone())), 30);
+}
+
+// ============================================================================
+// Treasury Tests
+// ============================================================================
+
+#[test]
+fn test_points_fee_accrues_and_withdraws_to_treasury() {
+    let (env, client, game_id, player1, player2) = setup_test();
+    let treasury = Address::generate(&env);
+    client.set_treasury(&treasury);
+    client.set_game_fee(&game_id, &250);
+
+    client.start_game(&game_id, &1, &player1, &player2, &200, &200);
+    client.end_game(&1, &true);
+
+    // 2.5% of the 400 pot
+    assert_eq!(client.get_balance(&player1), 800 + 390);
+    assert_eq!(client.get_locked(&player1), 0);
+    assert_eq!(client.get_accrued_fees(&None), 10);
+    assert_eq!(client.get_match(&1).payouts, vec![&env, 390, 0]);
+
+    client.withdraw_fees(&None, &4);
+    assert_eq!(client.get_balance(&treasury), 4);
+    assert_eq!(client.get_accrued_fees(&None), 6);
+}
+
+#[test]
+fn test_points_draw_is_fee_free() {
+    let (_env, client, game_id, player1, player2) = setup_test();
+    client.set_game_fee(&game_id, &1000);
+
+    client.start_game(&game_id, &1, &player1, &player2, &100, &100);
+    client.end_game_with_outcome(&1, &GameOutcome::Draw);
+
+    assert_eq!(client.get_balance(&player1), 1000);
+    assert_eq!(client.get_balance(&player2), 1000);
+    assert_eq!(client.get_accrued_fees(&None), 0);
+}
+
+#[test]
+fn test_fee_fixed_at_session_start() {
+    let (_env, client, game_id, player1, player2) = setup_test();
+
+    client.start_game(&game_id, &1, &player1, &player2, &100, &100);
+    client.set_game_fee(&game_id, &1000);
+    assert_eq!(client.get_game_fee(&game_id), 1000);
+
+    client.end_game(&1, &true);
+    assert_eq!(client.get_balance(&player1), 1100);
+    assert_eq!(client.get_accrued_fees(&None), 0);
+}
+
+#[test]
+fn test_treasury_defaults_to_admin() {
+    let (_env, client, game_id, player1, player2) = setup_test();
+    let admin = client.get_admin();
+    assert_eq!(client.get_treasury(), admin);
+
+    client.set_game_fee(&game_id, &1000);
+    client.start_game(&game_id, &1, &player1, &player2, &100, &100);
+    client.end_game(&1, &false);
+    client.withdraw_fees(&None, &20);
+
+    assert_eq!(client.get_balance(&admin), 20);
+}
+
+#[test]
+fn test_withdraw_more_than_accrued_rejected() {
+    let (_env, client, game_id, player1, player2) = setup_test();
+    client.set_game_fee(&game_id, &1000);
+    client.start_game(&game_id, &1, &player1, &player2, &100, &100);
+    client.end_game(&1, &true);
+
+    let result = client.try_withdraw_fees(&None, &21);
+    assert_hub_error(&result, Error::InsufficientFees);
+
+    let result = client.try_withdraw_fees(&None, &0);
+    assert_hub_error(&result, Error::InvalidAmount);
+}
+
+#[test]
+fn test_fee_above_100_percent_rejected() {
+    let (_env, client, game_id, _player1, _player2) = setup_test();
+
+    let result = client.try_set_game_fee(&game_id, &10_001);
+    assert_hub_error(&result, Error::InvalidFee);
+}
+
+// ============================================================================
+// Timeout Tests
+// ============================================================================
+
+// Stands in for a game contract implementing the timeout callbacks. It only
+// accepts a forfeit for the player it was told is stalling.
+#[contract]
+pub struct TimeoutGame;
+
+#[contracttype]
+enum TimeoutGameKey {
+    Staller,
+    Cancelled(u32),
+}
+
+#[contractimpl]
+impl TimeoutGame {
+    pub fn set_staller(env: Env, player: Address) {
+        env.storage()
+            .instance()
+            .set(&TimeoutGameKey::Staller, &player);
+    }
+
+    pub fn was_cancelled(env: Env, session_id: u32) -> bool {
+        env.storage()
+            .instance()
+            .has(&TimeoutGameKey::Cancelled(session_id))
+    }
+}
+
+#[contractimpl]
+impl GameCallbacks for TimeoutGame {
+    fn on_session_forfeited(
+        env: Env,
+        _session_id: u32,
+        player: Address,
+    ) -> Result<(), soroban_sdk::Error> {
+        let staller: Address = env
+            .storage()
+            .instance()
+            .get(&TimeoutGameKey::Staller)
+            .unwrap();
+        assert_eq!(player, staller, "Player is not stalling");
+        Ok(())
+    }
+
+    fn on_session_cancelled(env: Env, session_id: u32) -> Result<(), soroban_sdk::Error> {
+        env.storage()
+            .instance()
+            .set(&TimeoutGameKey::Cancelled(session_id), &true);
+        Ok(())
+    }
+}
+
+const TIMEOUT: u32 = 17_280;
+const GRACE: u32 = 17_280;
+
+fn setup_timeout_game(env: &Env, client: &MockGameHubClient) -> TimeoutGameClient<'static> {
+    let game_id = env.register(TimeoutGame, ());
+    client.add_game(&game_id);
+    TimeoutGameClient::new(env, &game_id)
+}
+
+#[test]
+fn test_claim_timeout_before_deadline_rejected() {
+    let (env, client, _game_id, player1, player2) = setup_test();
+    let game = setup_timeout_game(&env, &client);
+
+    env.ledger().set_sequence_number(100);
+    let session_id = client.reserve_session(&game.address, &player1);
+    client.start_game(&game.address, &session_id, &player1, &player2, &100, &100);
+    assert_eq!(
+        client.get_session(&session_id).deadline_ledger,
+        100 + TIMEOUT
+    );
+
+    env.ledger().set_sequence_number(100 + TIMEOUT);
+    let result = client.try_claim_timeout(&session_id, &player1);
+    assert_hub_error(&result, Error::SessionNotExpired);
+}
+
+#[test]
+fn test_claim_timeout_forfeits_stalling_opponent() {
+    let (env, client, _game_id, player1, player2) = setup_test();
+    let game = setup_timeout_game(&env, &client);
+    game.set_staller(&player2);
+
+    env.ledger().set_sequence_number(100);
+    let session_id = client.reserve_session(&game.address, &player1);
+    client.start_game(&game.address, &session_id, &player1, &player2, &100, &100);
+
+    env.ledger().set_sequence_number(101 + TIMEOUT);
+    let outcome = client.claim_timeout(&session_id, &player1);
+    assert_eq!(outcome, GameOutcome::Forfeit(player2.clone()));
+
+    assert_eq!(client.get_balance(&player1), 1100);
+    assert_eq!(client.get_balance(&player2), 900);
+    assert_eq!(
+        client.get_session(&session_id).status,
+        SessionStatus::Ended(GameOutcome::Forfeit(player2))
+    );
+}
+
+#[test]
+fn test_claim_timeout_rejected_by_game() {
+    let (env, client, _game_id, player1, player2) = setup_test();
+    let game = setup_timeout_game(&env, &client);
+    game.set_staller(&player1);
+
+    env.ledger().set_sequence_number(100);
+    let session_id = client.reserve_session(&game.address, &player1);
+    client.start_game(&game.address, &session_id, &player1, &player2, &100, &100);
+
+    // player1 is the one stalling, so they cannot claim against player2
+    env.ledger().set_sequence_number(101 + TIMEOUT);
+    assert!(client.try_claim_timeout(&session_id, &player1).is_err());
+    assert_eq!(
+        client.get_session(&session_id).status,
+        SessionStatus::Active
+    );
+
+    // Outsiders cannot claim during the grace period
+    let outsider = Address::generate(&env);
+    let result = client.try_claim_timeout(&session_id, &outsider);
+    assert_hub_error(&result, Error::NotPlayer);
+}
+
+#[test]
+fn test_claim_timeout_after_grace_cancels() {
+    let (env, client, _game_id, player1, player2) = setup_test();
+    let game = setup_timeout_game(&env, &client);
+
+    env.ledger().set_sequence_number(100);
+    let session_id = client.reserve_session(&game.address, &player1);
+    client.start_game(&game.address, &session_id, &player1, &player2, &100, &250);
+
+    env.ledger().set_sequence_number(101 + TIMEOUT + GRACE);
+    let outsider = Address::generate(&env);
+    let outcome = client.claim_timeout(&session_id, &outsider);
+    assert_eq!(outcome, GameOutcome::Cancelled);
+
+    assert_eq!(client.get_balance(&player1), 1000);
+    assert_eq!(client.get_balance(&player2), 1000);
+    assert!(game.was_cancelled(&session_id));
+
+    let result = client.try_claim_timeout(&session_id, &outsider);
+    assert_hub_error(&result, Error::SessionAlreadyEnded);
+}
+
+#[test]
+fn test_cancel_refunds_even_without_callbacks() {
+    // The default test game_id is not a contract, so the callback fails
+    let (env, client, game_id, player1, player2) = setup_test();
+
+    env.ledger().set_sequence_number(100);
+    client.start_game(&game_id, &1, &player1, &player2, &100, &100);
+
+    env.ledger().set_sequence_number(101 + TIMEOUT + GRACE);
+    client.claim_timeout(&1, &player1);
+
+    assert_eq!(client.get_balance(&player1), 1000);
+    assert_eq!(client.get_locked(&player1), 0);
+}
+
+#[test]
+fn test_multiplayer_timeout_waits_for_grace() {
+    let (env, client, _game_id, player1, player2) = setup_test();
+    let game = setup_timeout_game(&env, &client);
+    let player3 = funded_player(&env, &client);
+
+    env.ledger().set_sequence_number(100);
+    let participants = vec![
+        &env,
+        (player1.clone(), 100),
+        (player2.clone(), 100),
+        (player3.clone(), 100),
+    ];
+    let session_id = client.reserve_session(&game.address, &player1);
+    client.start_multiplayer_game(&game.address, &session_id, &participants);
+
+    env.ledger().set_sequence_number(101 + TIMEOUT);
+    let result = client.try_claim_timeout(&session_id, &player1);
+    assert_hub_error(&result, Error::InvalidOutcome);
+    let result = client.try_claim_timeout(&session_id, &player3);
+    assert_hub_error(&result, Error::InvalidOutcome);
+
+    // Outsiders are rejected as non-players, not as a bad outcome
+    let outsider = Address::generate(&env);
+    let result = client.try_claim_timeout(&session_id, &outsider);
+    assert_hub_error(&result, Error::NotPlayer);
+
+    env.ledger().set_sequence_number(101 + TIMEOUT + GRACE);
+    client.claim_timeout(&session_id, &player1);
+    assert_eq!(client.get_balance(&player3), 1000);
+}
+
+// ============================================================================
+// Rating Tests
+// ============================================================================
+
+#[test]
+fn test_unrated_player_starts_at_1500() {
+    let (_env, client, game_id, player1, _player2) = setup_test();
+
+    let rating = client.get_rating(&game_id, &player1);
+    assert_eq!(rating.rating, 1500);
+    assert_eq!(rating.games_played, 0);
+}
+
+#[test]
+fn test_win_moves_ratings() {
+    let (_env, client, game_id, player1, player2) = setup_test();
+
+    client.start_game(&game_id, &1, &player1, &player2, &100, &100);
+    client.end_game(&1, &true);
+
+    // Provisional K of 40, expected score 0.5
+    assert_eq!(client.get_rating(&game_id, &player1).rating, 1520);
+    assert_eq!(client.get_rating(&game_id, &player2).rating, 1480);
+    assert_eq!(client.get_rating(&game_id, &player1).games_played, 1);
+
+    // The underdog gains more for beating the favourite
+    client.start_game(&game_id, &2, &player1, &player2, &100, &100);
+    client.end_game(&2, &false);
+
+    assert_eq!(client.get_rating(&game_id, &player1).rating, 1498);
+    assert_eq!(client.get_rating(&game_id, &player2).rating, 1502);
+}
+
+#[test]
+fn test_draw_and_cancel_ratings() {
+    let (_env, client, game_id, player1, player2) = setup_test();
+
+    client.start_game(&game_id, &1, &player1, &player2, &100, &100);
+    client.end_game_with_outcome(&1, &GameOutcome::Draw);
+
+    let rating = client.get_rating(&game_id, &player1);
+    assert_eq!(rating.rating, 1500);
+    assert_eq!(rating.games_played, 1);
+
+    // Cancelled sessions are not rated at all
+    client.start_game(&game_id, &2, &player1, &player2, &100, &100);
+    client.end_game_with_outcome(&2, &GameOutcome::Cancelled);
+
+    assert_eq!(client.get_rating(&game_id, &player1).games_played, 1);
+}
+
+#[test]
+fn test_forfeit_counts_as_loss() {
+    let (_env, client, game_id, player1, player2) = setup_test();
+
+    client.start_game(&game_id, &1, &player1, &player2, &100, &100);
+    client.end_game_with_outcome(&1, &GameOutcome::Forfeit(player1.clone()));
+
+    assert_eq!(client.get_rating(&game_id, &player1).rating, 1480);
+    assert_eq!(client.get_rating(&game_id, &player2).rating, 1520);
+}
+
+#[test]
+fn test_ratings_are_per_game() {
+    let (env, client, game_id, player1, player2) = setup_test();
+    let other_game = Address::generate(&env);
+    client.add_game(&other_game);
+
+    client.start_game(&game_id, &1, &player1, &player2, &100, &100);
+    client.end_game(&1, &true);
+
+    assert_eq!(client.get_rating(&game_id, &player1).rating, 1520);
+    assert_eq!(client.get_rating(&other_game, &player1).rating, 1500);
+}
+
+#[test]
+fn test_placements_update_ratings_pairwise() {
+    let (env, client, game_id, player1, player2) = setup_test();
+    let player3 = funded_player(&env, &client);
+
+    let participants = vec![
+        &env,
+        (player1.clone(), 100),
+        (player2.clone(), 100),
+        (player3.clone(), 100),
+    ];
+    client.start_multiplayer_game(&game_id, &1, &participants);
+    client.end_game_with_placements(
+        &1,
+        &vec![&env, player2.clone(), player3.clone(), player1.clone()],
+    );
+
+    assert_eq!(client.get_rating(&game_id, &player2).rating, 1520);
+    assert_eq!(client.get_rating(&game_id, &player3).rating, 1500);
+    assert_eq!(client.get_rating(&game_id, &player1).rating, 1480);
+    assert_eq!(client.get_rating(&game_id, &player3).games_played, 1);
+}
+
+// ============================================================================
+// Season Tests
+// ============================================================================
+
+#[test]
+fn test_create_season_validates_window() {
+    let (env, client, _game_id, _player1, _player2) = setup_test();
+    env.ledger().set_sequence_number(100);
+
+    assert_hub_error(&client.try_create_season(&200, &199), Error::InvalidSeason);
+
+    assert_eq!(client.create_season(&100, &199), 1);
+    assert_hub_error(&client.try_create_season(&199, &300), Error::InvalidSeason);
+    assert_eq!(client.create_season(&200, &300), 2);
+
+    assert_eq!(client.get_current_season(), Some(1));
+    env.ledger().set_sequence_number(250);
+    assert_eq!(client.get_current_season(), Some(2));
+    env.ledger().set_sequence_number(301);
+    assert_eq!(client.get_current_season(), None);
+
+    assert_hub_error(&client.try_get_season(&3), Error::SeasonNotFound);
+}
+
+#[test]
+fn test_season_stats_aggregate_results() {
+    let (env, client, game_id, player1, player2) = setup_test();
+    env.ledger().set_sequence_number(100);
+    let season_id = client.create_season(&100, &200);
+
+    client.start_game(&game_id, &1, &player1, &player2, &100, &50);
+    client.end_game(&1, &true);
+    client.start_game(&game_id, &2, &player1, &player2, &100, &100);
+    client.end_game(&2, &false);
+    client.start_game(&game_id, &3, &player1, &player2, &10, &10);
+    client.end_game_with_outcome(&3, &GameOutcome::Draw);
+    client.start_game(&game_id, &4, &player1, &player2, &10, &10);
+    client.end_game_with_outcome(&4, &GameOutcome::Cancelled);
+
+    let stats = client.get_season_stats(&season_id, &game_id, &player1);
+    assert_eq!(stats.wins, 1);
+    assert_eq!(stats.losses, 1);
+    assert_eq!(stats.sessions, 3);
+    assert_eq!(stats.net_points, 50 - 100);
+
+    let stats = client.get_season_stats(&season_id, &game_id, &player2);
+    assert_eq!(stats.net_points, 100 - 50);
+
+    let season = client.get_season(&season_id);
+    assert_eq!(season.sessions, 3);
+    assert_eq!(season.points_wagered, 150 + 200 + 20);
+}
+
+#[test]
+fn test_sessions_outside_season_not_counted() {
+    let (env, client, game_id, player1, player2) = setup_test();
+    env.ledger().set_sequence_number(100);
+    let season_id = client.create_season(&150, &200);
+
+    client.start_game(&game_id, &1, &player1, &player2, &100, &100);
+    client.end_game(&1, &true);
+
+    // Settlement ledger decides the season, not the start ledger
+    client.start_game(&game_id, &2, &player1, &player2, &100, &100);
+    env.ledger().set_sequence_number(150);
+    client.end_game(&2, &true);
+
+    env.ledger().set_sequence_number(201);
+    client.start_game(&game_id, &3, &player1, &player2, &100, &100);
+    client.end_game(&3, &true);
+
+    let stats = client.get_season_stats(&season_id, &game_id, &player1);
+    assert_eq!(stats.sessions, 1);
+    assert_eq!(stats.wins, 1);
+}
+
+#[test]
+fn test_leaderboard_ranks_by_net_points() {
+    let (env, client, game_id, player1, player2) = setup_test();
+    env.ledger().set_sequence_number(100);
+    let season_id = client.create_season(&100, &200);
+    let player3 = funded_player(&env, &client);
+
+    client.start_game(&game_id, &1, &player1, &player2, &100, &100);
+    client.end_game(&1, &true);
+    let session_id = client.reserve_session(&game_id, &player3);
+    client.start_game(&game_id, &session_id, &player3, &player2, &300, &300);
+    client.end_game(&session_id, &true);
+
+    let board = client.get_leaderboard(&season_id, &game_id);
+    assert_eq!(board.len(), 3);
+    assert_eq!(board.get_unchecked(0).player, player3);
+    assert_eq!(board.get_unchecked(0).net_points, 300);
+    assert_eq!(board.get_unchecked(1).player, player1);
+    assert_eq!(board.get_unchecked(2).player, player2);
+    assert_eq!(board.get_unchecked(2).net_points, -400);
+}
+
+#[test]
+fn test_leaderboard_is_bounded() {
+    let (env, client, game_id, player1, _player2) = setup_test();
+    env.ledger().set_sequence_number(100);
+    let season_id = client.create_season(&100, &200);
+
+    // player1 loses to twelve different players
+    for _ in 0..12 {
+        let session_id = client.reserve_session(&game_id, &player1);
+        let opponent = funded_player(&env, &client);
+        client.start_game(&game_id, &session_id, &player1, &opponent, &10, &10);
+        client.end_game(&session_id, &false);
+    }
+
+    let board = client.get_leaderboard(&season_id, &game_id);
+    assert_eq!(board.len(), 10);
+    assert!(board.iter().all(|entry| entry.player != player1));
+    assert!(board.iter().all(|entry| entry.net_points == 10));
+}
+
+#[test]
+fn test_close_season_freezes_results() {
+    let (env, client, game_id, player1, player2) = setup_test();
+    env.ledger().set_sequence_number(100);
+    let season_id = client.create_season(&100, &200);
+
+    client.start_game(&game_id, &1, &player1, &player2, &100, &100);
+    client.end_game(&1, &true);
+
+    client.close_season(&season_id);
+    assert!(client.get_season(&season_id).closed);
+    assert_eq!(client.get_current_season(), None);
+    assert_hub_error(&client.try_close_season(&season_id), Error::SeasonClosed);
+
+    client.start_game(&game_id, &2, &player1, &player2, &100, &100);
+    client.end_game(&2, &true);
+
+    let stats = client.get_season_stats(&season_id, &game_id, &player1);
+    assert_eq!(stats.sessions, 1);
+    assert_eq!(client.get_season(&season_id).sessions, 1);
+}
+
+// ============================================================================
+// Profile Tests
+// ============================================================================
+
+#[test]
+fn test_profile_tracks_sessions_wins_and_xp() {
+    let (env, client, game_id, player1, player2) = setup_test();
+    let other_game = Address::generate(&env);
+    client.add_game(&other_game);
+
+    client.start_game(&game_id, &1, &player1, &player2, &10, &10);
+    client.end_game(&1, &true);
+    client.start_game(&game_id, &2, &player1, &player2, &10, &10);
+    client.end_game_with_outcome(&2, &GameOutcome::Draw);
+    let other_session = client.reserve_session(&other_game, &player1);
+    client.start_game(&other_game, &other_session, &player1, &player2, &10, &10);
+    client.end_game(&other_session, &true);
+
+    let profile = client.get_profile(&player1);
+    assert_eq!(profile.sessions, 3);
+    assert_eq!(profile.wins, 2);
+    assert_eq!(profile.xp, 3 * 10 + 2 * 20);
+
+    let profile = client.get_profile(&player2);
+    assert_eq!(profile.sessions, 3);
+    assert_eq!(profile.wins, 0);
+    assert_eq!(profile.xp, 30);
+
+    assert_eq!(client.get_game_wins(&game_id, &player1), 1);
+    assert_eq!(client.get_game_wins(&other_game, &player1), 1);
+    assert_eq!(client.get_game_wins(&game_id, &player2), 0);
+}
+
+#[test]
+fn test_cancelled_session_not_on_profile() {
+    let (_env, client, game_id, player1, player2) = setup_test();
+
+    client.start_game(&game_id, &1, &player1, &player2, &10, &10);
+    client.end_game_with_outcome(&1, &GameOutcome::Cancelled);
+
+    assert_eq!(client.get_profile(&player1), PlayerProfile::default());
+}
+
+#[test]
+fn test_placements_credit_first_place_win() {
+    let (env, client, game_id, player1, player2) = setup_test();
+    let player3 = funded_player(&env, &client);
+
+    let participants = vec![
+        &env,
+        (player1.clone(), 10),
+        (player2.clone(), 10),
+        (player3.clone(), 10),
+    ];
+    client.start_multiplayer_game(&game_id, &1, &participants);
+    client.end_game_with_placements(&1, &vec![&env, player3.clone(), player1.clone(), player2]);
+
+    assert_eq!(client.get_profile(&player3).wins, 1);
+    assert_eq!(client.get_profile(&player1).wins, 0);
+    assert_eq!(client.get_profile(&player1).sessions, 1);
+}
+
+#[test]
+fn test_set_profile_keeps_stats() {
+    let (env, client, game_id, player1, player2) = setup_test();
+    client.start_game(&game_id, &1, &player1, &player2, &10, &10);
+    client.end_game(&1, &true);
+
+    let name = BytesN::from_array(&env, &[1u8; 32]);
+    let avatar = BytesN::from_array(&env, &[2u8; 32]);
+    client.set_profile(&player1, &Some(name.clone()), &Some(avatar));
+    assert_eq!(env.auths()[0].0, player1);
+
+    let profile = client.get_profile(&player1);
+    assert_eq!(profile.display_name_hash, Some(name.clone()));
+    assert_eq!(profile.wins, 1);
+
+    client.set_profile(&player1, &Some(name), &None);
+    let profile = client.get_profile(&player1);
+    assert_eq!(profile.avatar_hash, None);
+    assert_eq!(profile.xp, 30);
+}
+
+// ============================================================================
+// Achievement Tests
+// ============================================================================
+
+fn achievement(
+    game_id: Option<Address>,
+    metric: AchievementMetric,
+    threshold: i128,
+) -> Achievement {
+    Achievement {
+        game_id,
+        metric,
+        threshold,
+    }
+}
+
+#[test]
+fn test_win_achievement_awarded_once_at_threshold() {
+    let (env, client, game_id, player1, player2) = setup_test();
+    let two_wins = client.define_achievement(&achievement(
+        Some(game_id.clone()),
+        AchievementMetric::Wins,
+        2,
+    ));
+    assert_eq!(two_wins, 1);
+
+    client.start_game(&game_id, &1, &player1, &player2, &10, &10);
+    client.end_game(&1, &true);
+    assert!(!client.has_badge(&player1, &two_wins));
+
+    env.ledger().with_mut(|li| li.sequence_number += 5);
+    client.start_game(&game_id, &2, &player1, &player2, &10, &10);
+    client.end_game(&2, &true);
+    client.start_game(&game_id, &3, &player1, &player2, &10, &10);
+    client.end_game(&3, &true);
+
+    let badges = client.get_badges(&player1);
+    assert_eq!(badges.len(), 1);
+    assert_eq!(badges.get(0).unwrap().achievement_id, two_wins);
+    assert_eq!(
+        badges.get(0).unwrap().earned_ledger,
+        env.ledger().sequence()
+    );
+    assert!(!client.has_badge(&player2, &two_wins));
+}
+
+#[test]
+fn test_game_scoped_wins_ignore_other_games() {
+    let (env, client, game_id, player1, player2) = setup_test();
+    let other_game = Address::generate(&env);
+    client.add_game(&other_game);
+    let scoped = client.define_achievement(&achievement(
+        Some(game_id.clone()),
+        AchievementMetric::Wins,
+        1,
+    ));
+    let any_game = client.define_achievement(&achievement(None, AchievementMetric::Wins, 1));
+
+    let other_session = client.reserve_session(&other_game, &player1);
+    client.start_game(&other_game, &other_session, &player1, &player2, &10, &10);
+    client.end_game(&other_session, &true);
+
+    assert!(client.has_badge(&player1, &any_game));
+    assert!(!client.has_badge(&player1, &scoped));
+}
+
+#[test]
+fn test_session_achievement_counts_draws() {
+    let (_env, client, game_id, player1, player2) = setup_test();
+    let first_session =
+        client.define_achievement(&achievement(None, AchievementMetric::Sessions, 1));
+
+    client.start_game(&game_id, &1, &player1, &player2, &10, &10);
+    client.end_game_with_outcome(&1, &GameOutcome::Draw);
+
+    assert!(client.has_badge(&player1, &first_session));
+    assert!(client.has_badge(&player2, &first_session));
+}
+
+#[test]
+fn test_report_stat_awards_stat_achievement() {
+    let (env, client, game_id, player1, player2) = setup_test();
+    let solved = symbol_short!("solved");
+    let detective = client.define_achievement(&achievement(
+        Some(game_id.clone()),
+        AchievementMetric::Stat(solved.clone()),
+        3,
+    ));
+
+    client.start_game(&game_id, &1, &player1, &player2, &10, &10);
+    client.report_stat(&1, &player1, &solved, &2);
+    assert_eq!(env.auths()[0].0, game_id);
+    assert!(!client.has_badge(&player1, &detective));
+
+    // Stats can still be reported once the session has ended
+    client.end_game(&1, &true);
+    client.report_stat(&1, &player1, &solved, &1);
+
+    assert_eq!(client.get_stat(&game_id, &player1, &solved), 3);
+    assert!(client.has_badge(&player1, &detective));
+    assert!(!client.has_badge(&player2, &detective));
+}
+
+#[test]
+fn test_report_stat_rejects_bad_reports() {
+    let (env, client, game_id, player1, player2) = setup_test();
+    let outsider = Address::generate(&env);
+    let key = symbol_short!("solved");
+
+    let result = client.try_report_stat(&1, &player1, &key, &1);
+    assert_hub_error(&result, Error::SessionNotFound);
+
+    client.start_game(&game_id, &1, &player1, &player2, &10, &10);
+    let result = client.try_report_stat(&1, &outsider, &key, &1);
+    assert_hub_error(&result, Error::NotPlayer);
+    let result = client.try_report_stat(&1, &player1, &key, &0);
+    assert_hub_error(&result, Error::InvalidAmount);
+}
+
+#[test]
+fn test_define_achievement_validates_scope() {
+    let (_env, client, game_id, _player1, _player2) = setup_test();
+
+    let result = client.try_define_achievement(&achievement(
+        Some(game_id.clone()),
+        AchievementMetric::Xp,
+        100,
+    ));
+    assert_hub_error(&result, Error::InvalidAchievement);
+
+    let result = client.try_define_achievement(&achievement(
+        None,
+        AchievementMetric::Stat(symbol_short!("solved")),
+        1,
+    ));
+    assert_hub_error(&result, Error::InvalidAchievement);
+
+    let result = client.try_define_achievement(&achievement(None, AchievementMetric::Wins, 0));
+    assert_hub_error(&result, Error::InvalidAchievement);
+
+    for _ in 0..20 {
+        client.define_achievement(&achievement(None, AchievementMetric::Wins, 1));
+    }
+    let result = client.try_define_achievement(&achievement(None, AchievementMetric::Wins, 1));
+    assert_hub_error(&result, Error::TooManyAchievements);
+}
+
+#[test]
+fn test_retired_achievement_no_longer_awarded() {
+    let (_env, client, game_id, player1, player2) = setup_test();
+    let first_win = client.define_achievement(&achievement(None, AchievementMetric::Wins, 1));
+
+    client.start_game(&game_id, &1, &player1, &player2, &10, &10);
+    client.end_game(&1, &true);
+    client.retire_achievement(&first_win);
+    client.start_game(&game_id, &2, &player1, &player2, &10, &10);
+    client.end_game(&2, &false);
+
+    assert!(client.has_badge(&player1, &first_win));
+    assert!(!client.has_badge(&player2, &first_win));
+    assert_eq!(client.list_achievements().len(), 0);
+    assert_eq!(client.get_achievement(&first_win).threshold, 1);
+
+    let result = client.try_retire_achievement(&first_win);
+    assert_hub_error(&result, Error::AchievementNotFound);
+}
+
+// ============================================================================
+// Match History Tests
+// ============================================================================
+
+#[test]
+fn test_match_record_written_on_end() {
+    let (env, client, game_id, player1, player2) = setup_test();
+
+    client.start_game(&game_id, &1, &player1, &player2, &100, &250);
+    assert_hub_error(&client.try_get_match(&1), Error::SessionNotFound);
+
+    env.ledger().set_sequence_number(42);
+    client.end_game(&1, &false);
+
+    let record = client.get_match(&1);
+    assert_eq!(record.session_id, 1);
+    assert_eq!(record.game_id, game_id);
+    assert_eq!(
+        record.participants,
+        vec![&env, (player1.clone(), 100), (player2.clone(), 250)]
+    );
+    assert_eq!(record.payouts, vec![&env, 0, 350]);
+    assert_eq!(record.result, SessionStatus::Ended(GameOutcome::Player2Won));
+    assert_eq!(record.end_ledger, 42);
+}
+
+#[test]
+fn test_list_sessions_by_player_paginates() {
+    let (env, client, game_id, player1, player2) = setup_test();
+    let player3 = funded_player(&env, &client);
+
+    for session_id in 1..=5u32 {
+        client.start_game(&game_id, &session_id, &player1, &player2, &10, &10);
+        client.end_game(&session_id, &true);
+    }
+    client.start_game(&game_id, &6, &player1, &player3, &10, &10);
+    client.end_game(&6, &true);
+    // Sessions still running are not part of the history
+    client.start_game(&game_id, &7, &player1, &player3, &10, &10);
+
+    assert_eq!(client.count_sessions_by_player(&player1), 6);
+    assert_eq!(client.count_sessions_by_player(&player3), 1);
+
+    let page = client.list_sessions_by_player(&player1, &0, &4);
+    assert_eq!(page.len(), 4);
+    assert_eq!(page.get_unchecked(0).session_id, 1);
+    assert_eq!(page.get_unchecked(3).session_id, 4);
+
+    let page = client.list_sessions_by_player(&player1, &4, &4);
+    assert_eq!(page.len(), 2);
+    assert_eq!(page.get_unchecked(1).session_id, 6);
+
+    assert_eq!(client.list_sessions_by_player(&player1, &6, &4).len(), 0);
+    assert_eq!(client.list_sessions_by_player(&player3, &0, &10).len(), 1);
+}
+
+#[test]
+fn test_list_sessions_by_game() {
+    let (env, client, game_id, player1, player2) = setup_test();
+    let other_game = Address::generate(&env);
+    client.add_game(&other_game);
+
+    let other_session = client.reserve_session(&other_game, &player1);
+    client.start_game(&game_id, &1, &player1, &player2, &10, &10);
+    client.start_game(&other_game, &other_session, &player1, &player2, &10, &10);
+    client.end_game_with_outcome(&other_session, &GameOutcome::Cancelled);
+    client.end_game(&1, &true);
+
+    let page = client.list_sessions_by_game(&game_id, &0, &10);
+    assert_eq!(page.len(), 1);
+    assert_eq!(page.get_unchecked(0).session_id, 1);
+
+    let page = client.list_sessions_by_game(&other_game, &0, &10);
+    assert_eq!(page.len(), 1);
+    assert_eq!(
+        page.get_unchecked(0).result,
+        SessionStatus::Ended(GameOutcome::Cancelled)
+    );
+    assert_eq!(client.count_sessions_by_game(&game_id), 1);
+}
+
+#[test]
+fn test_match_history_reads_extend_ttl() {
+    let (env, client, game_id, player1, player2) = setup_test();
+    client.start_game(&game_id, &1, &player1, &player2, &10, &10);
+    client.end_game(&1, &true);
+
+    let record_key = DataKey::MatchRecord(1);
+    let index_key = DataKey::GameMatch(game_id.clone(), 0);
+    let ttl = |key: &DataKey| {
+        env.as_contract(&client.address, || env.storage().persistent().get_ttl(key))
+    };
+
+    // Nothing writes to a match record once it exists; reading the history keeps it alive
+    let remaining = 100;
+    env.ledger()
+        .set_sequence_number(env.ledger().sequence() + ttl(&record_key) - remaining);
+    assert_eq!(ttl(&record_key), remaining);
+    assert_eq!(client.list_sessions_by_game(&game_id, &0, &10).len(), 1);
+    assert_eq!(ttl(&record_key), TTL_EXTEND_LEDGERS);
+    assert_eq!(ttl(&index_key), TTL_EXTEND_LEDGERS);
+}
+
+#[test]
+fn test_match_page_size_is_capped() {
+    let (_env, client, game_id, player1, player2) = setup_test();
+
+    for _ in 0..30 {
+        let session_id = client.reserve_session(&game_id, &player1);
+        client.start_game(&game_id, &session_id, &player1, &player2, &1, &1);
+        client.end_game(&session_id, &true);
+    }
+
+    let page = client.list_sessions_by_game(&game_id, &0, &u32::MAX);
+    assert_eq!(page.len(), 25);
+    let page = client.list_sessions_by_game(&game_id, &u32::MAX, &10);
+    assert_eq!(page.len(), 0);
+}
+
+// ============================================================================
+// Game Registry Tests
+// ============================================================================
+
+#[test]
+fn test_unregistered_game_rejected() {
+    let (env, client, _game_id, player1, player2) = setup_test();
+    let rogue_game = Address::generate(&env);
+
+    assert!(!client.is_game_registered(&rogue_game));
+    let result = client.try_start_game(&rogue_game, &1, &player1, &player2, &100, &100);
+    assert_hub_error(&result, Error::GameNotRegistered);
+}
+
+#[test]
+fn test_re_registered_game_starts_clean() {
+    let (env, client, game_id, player1, player2) = setup_test();
+    client.start_game(&game_id, &1, &player1, &player2, &100, &100);
+    client.set_game_token(&game_id, &Address::generate(&env));
+    client.set_game_fee(&game_id, &500);
+    client.pause(&Some(game_id.clone()));
+
+    // Removing the game keeps its pause, so the running session can still be refunded
+    client.remove_game(&game_id);
+    assert!(!client.is_game_registered(&game_id));
+    assert!(client.is_paused(&Some(game_id.clone())));
+    client.cancel_paused_session(&1, &player1);
+    assert_eq!(client.get_balance(&player1), 1000);
+
+    client.add_game(&game_id);
+    assert!(client.is_game_registered(&game_id));
+    assert_eq!(client.get_game_token(&game_id), None);
+    assert_eq!(client.get_game_fee(&game_id), 0);
+    assert!(!client.is_paused(&Some(game_id)));
+}
+
+#[test]
+fn test_removed_game_can_still_end_sessions() {
+    let (_env, client, game_id, player1, player2) = setup_test();
+
+    client.start_game(&game_id, &1, &player1, &player2, &100, &100);
+    client.remove_game(&game_id);
+    assert!(!client.is_game_registered(&game_id));
+
+    let result = client.try_start_game(&game_id, &2, &player1, &player2, &100, &100);
+    assert_hub_error(&result, Error::GameNotRegistered);
+
+    client.end_game(&1, &true);
+    assert_eq!(client.get_balance(&player1), 1100);
+}
+
+#[test]
+fn test_start_and_end_require_game_auth() {
+    let (env, client, game_id, player1, player2) = setup_test();
+
+    client.start_game(&game_id, &1, &player1, &player2, &100, &100);
+    assert_eq!(env.auths().len(), 1);
+    assert_eq!(env.auths()[0].0, game_id);
+
+    client.end_game(&1, &true);
+    assert_eq!(env.auths().len(), 1);
+    assert_eq!(env.auths()[0].0, game_id);
+}
+
+#[test]
+fn test_start_game_without_game_auth_fails() {
+    let (env, client, game_id, player1, player2) = setup_test();
+
+    env.set_auths(&[]);
+    let result = client.try_start_game(&game_id, &1, &player1, &player2, &100, &100);
+    assert!(result.is_err());
+    assert_eq!(client.get_locked(&player1), 0);
+}
+
+// ============================================================================
+// Game Config Tests
+// ============================================================================
+
+fn stake_config(min_stake: i128, max_stake: i128) -> GameConfig {
+    GameConfig {
+        min_stake,
+        max_stake,
+        ..GameConfig::default()
+    }
+}
+
+#[test]
+fn test_default_config_accepts_any_non_negative_stake() {
+    let (_env, client, game_id, player1, player2) = setup_test();
+    assert_eq!(client.get_game_config(&game_id), GameConfig::default());
+
+    client.start_game(&game_id, &1, &player1, &player2, &0, &1000);
+    assert_eq!(client.get_locked(&player2), 1000);
+}
+
+#[test]
+fn test_stake_limits_enforced() {
+    let (_env, client, game_id, player1, player2) = setup_test();
+    client.set_game_config(&game_id, &stake_config(10, 100));
+
+    let result = client.try_start_game(&game_id, &1, &player1, &player2, &5, &50);
+    assert_hub_error(&result, Error::StakeTooLow);
+
+    let result = client.try_start_game(&game_id, &1, &player1, &player2, &50, &101);
+    assert_hub_error(&result, Error::StakeTooHigh);
+
+    client.start_game(&game_id, &1, &player1, &player2, &10, &100);
+}
+
+#[test]
+fn test_equal_stakes_enforced() {
+    let (env, client, game_id, player1, player2) = setup_test();
+    let config = GameConfig {
+        equal_stakes: true,
+        ..GameConfig::default()
+    };
+    client.set_game_config(&game_id, &config);
+
+    let result = client.try_start_game(&game_id, &1, &player1, &player2, &50, &60);
+    assert_hub_error(&result, Error::UnequalStakes);
+
+    let player3 = funded_player(&env, &client);
+    let participants = vec![
+        &env,
+        (player1.clone(), 50),
+        (player2.clone(), 50),
+        (player3, 40),
+    ];
+    let result = client.try_start_multiplayer_game(&game_id, &1, &participants);
+    assert_hub_error(&result, Error::UnequalStakes);
+
+    client.start_game(&game_id, &1, &player1, &player2, &50, &50);
+}
+
+#[test]
+fn test_allowed_negative_stake_escrows_nothing() {
+    let (_env, client, game_id, player1, player2) = setup_test();
+    let config = GameConfig {
+        allow_negative: true,
+        ..stake_config(-10, 100)
+    };
+    client.set_game_config(&game_id, &config);
+
+    let result = client.try_start_game(&game_id, &1, &player1, &player2, &-11, &10);
+    assert_hub_error(&result, Error::StakeTooLow);
+
+    client.start_game(&game_id, &1, &player1, &player2, &-5, &10);
+    assert_eq!(client.get_locked(&player1), 0);
+    assert_eq!(client.get_balance(&player1), 1000);
+    assert_eq!(client.get_session(&1).participants.get_unchecked(0).1, 0);
+
+    client.end_game(&1, &true);
+    assert_eq!(client.get_balance(&player1), 1010);
+}
+
+#[test]
+fn test_disabled_game_cannot_open_sessions() {
+    let (_env, client, game_id, player1, player2) = setup_test();
+    client.start_game(&game_id, &1, &player1, &player2, &10, &10);
+
+    let config = GameConfig {
+        enabled: false,
+        ..GameConfig::default()
+    };
+    client.set_game_config(&game_id, &config);
+
+    let result = client.try_start_game(&game_id, &2, &player1, &player2, &10, &10);
+    assert_hub_error(&result, Error::GameDisabled);
+
+    // Running sessions still settle
+    client.end_game(&1, &true);
+}
+
+#[test]
+fn test_invalid_config_rejected() {
+    let (env, client, game_id, _player1, _player2) = setup_test();
+
+    let result = client.try_set_game_config(&game_id, &stake_config(100, 10));
+    assert_hub_error(&result, Error::InvalidConfig);
+
+    let result = client.try_set_game_config(&game_id, &stake_config(-1, 10));
+    assert_hub_error(&result, Error::InvalidConfig);
+
+    let unregistered = Address::generate(&env);
+    let result = client.try_set_game_config(&unregistered, &GameConfig::default());
+    assert_hub_error(&result, Error::GameNotRegistered);
+}
+
+// ============================================================================
+// Points Balance Tests
+// ============================================================================
+
+#[test]
+fn test_add_points_requires_positive_amount() {
+    let (env, client, _game_id, _player1, _player2) = setup_test();
+    let player = Address::generate(&env);
+
+    assert_hub_error(&client.try_add_points(&player, &0), Error::InvalidAmount);
+    assert_eq!(client.get_balance(&player), 0);
+}
+
+// ============================================================================
+// Admin Tests
+// ============================================================================
+
+#[test]
+fn test_admin_transfer_requires_accept() {
+    let (env, client, _game_id, _player1, _player2) = setup_test();
+    let admin = client.get_admin();
+    let new_admin = Address::generate(&env);
+
+    assert_hub_error(&client.try_accept_admin(), Error::NoPendingAdmin);
+
+    client.propose_admin(&new_admin);
+    assert_eq!(env.auths()[0].0, admin);
+    assert_eq!(client.get_admin(), admin);
+    assert_eq!(client.get_pending_admin(), Some(new_admin.clone()));
+
+    client.accept_admin();
+    assert_eq!(env.auths()[0].0, new_admin);
+    assert_eq!(client.get_admin(), new_admin);
+    assert_eq!(client.get_pending_admin(), None);
+}
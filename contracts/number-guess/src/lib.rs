@@ -4,35 +4,28 @@
 //!
 //! A simple two-player guessing game where players guess a number between 1 and 10.
 //! The player whose guess is closest to the randomly generated number wins.
+//! Equally close guesses are reported to the Game Hub as a draw.
+//!
+//! Guesses are sealed: each player first commits `keccak256(guess || salt ||
+//! player_address)` with `commit_guess`, and only once both have committed do
+//! they reveal with `reveal_guess`. The winning number is drawn after both
+//! reveals, so neither player can pick a guess knowing how it will score. The
+//! draw is seeded with both revealed salts, so neither player can steer it by
+//! choosing their own salt either.
 //!
 //! **Game Hub Integration:**
 //! This game is Game Hub-aware and enforces all games to be played through the
 //! Game Hub contract. Games cannot be started or completed without points involvement.
 
+use sgs_game_common::{
+    hub_client, require_not_paused, write_game, GameCallbacks, GameContract, GameInfo,
+    SessionStatus, StakeSemantics,
+};
 use soroban_sdk::{
-    Address, Bytes, BytesN, Env, IntoVal, contract, contractclient, contracterror, contractimpl, contracttype, vec
+    Address, Bytes, BytesN, Env, IntoVal, String, contract, contracterror, contractimpl, contracttype, vec
 };
 
-// Import GameHub contract interface
-// This allows us to call into the GameHub contract
-#[contractclient(name = "GameHubClient")]
-pub trait GameHub {
-    fn start_game(
-        env: Env,
-        game_id: Address,
-        session_id: u32,
-        player1: Address,
-        player2: Address,
-        player1_points: i128,
-        player2_points: i128,
-    );
-
-    fn end_game(
-        env: Env,
-        session_id: u32,
-        player1_won: bool
-    );
-}
+pub use sgs_game_common::GameOutcome;
 
 // ============================================================================
 // Errors
@@ -47,12 +40,25 @@ pub enum Error {
     AlreadyGuessed = 3,
     BothPlayersNotGuessed = 4,
     GameAlreadyEnded = 5,
+    PlayerNotStalling = 6,
+    ContractPaused = 7,
+    SessionAlreadyExists = 8,
+    BothPlayersNotCommitted = 9,
+    AlreadyRevealed = 10,
+    CommitmentMismatch = 11,
 }
 
 // ============================================================================
 // Data Types
 // ============================================================================
 
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum GameStatus {
+    Active,
+    Ended(GameOutcome),
+}
+
 #[contracttype]
 #[derive(Clone, Debug, Eq, PartialEq)]
 pub struct Game {
@@ -60,29 +66,37 @@ pub struct Game {
     pub player2: Address,
     pub player1_points: i128,
     pub player2_points: i128,
+    pub player1_commitment: Option<BytesN<32>>,
+    pub player2_commitment: Option<BytesN<32>>,
+    /// Revealed guesses, set by `reveal_guess`
     pub player1_guess: Option<u32>,
     pub player2_guess: Option<u32>,
+    /// Revealed salts, set by `reveal_guess` and mixed into the winning
+    /// number's seed
+    pub player1_salt: Option<BytesN<32>>,
+    pub player2_salt: Option<BytesN<32>>,
     pub winning_number: Option<u32>,
     pub winner: Option<Address>,
+    pub status: GameStatus,
 }
 
 #[contracttype]
 #[derive(Clone)]
 pub enum DataKey {
     Game(u32),
-    GameHubAddress,
-    Admin,
 }
 
 // ============================================================================
-// Storage TTL Management
+// Commitments
 // ============================================================================
-// TTL (Time To Live) ensures game data doesn't expire unexpectedly
-// Games are stored in temporary storage with a minimum 30-day retention
 
-/// TTL for game storage (30 days in ledgers, ~5 seconds per ledger)
-/// 30 days = 30 * 24 * 60 * 60 / 5 = 518,400 ledgers
-const GAME_TTL_LEDGERS: u32 = 518_400;
+/// Compute the commitment hash: keccak256(guess || salt || player_address)
+fn compute_commitment(env: &Env, guess: u32, salt: &BytesN<32>, player: &Address) -> BytesN<32> {
+    let mut data = Bytes::from_array(env, &guess.to_be_bytes());
+    data.append(&Bytes::from_slice(env, salt.to_array().as_slice()));
+    data.append(&player.to_string().to_bytes());
+    env.crypto().keccak256(&data).into()
+}
 
 // ============================================================================
 // Contract Definition
@@ -100,10 +114,7 @@ impl NumberGuessContract {
     /// * `game_hub` - Address of the GameHub contract
     pub fn __constructor(env: Env, admin: Address, game_hub: Address) {
         // Store admin and GameHub address
-        env.storage().instance().set(&DataKey::Admin, &admin);
-        env.storage()
-            .instance()
-            .set(&DataKey::GameHubAddress, &game_hub);
+        sgs_game_common::init(&env, &admin, &game_hub);
     }
 
     /// Start a new game between two players with points.
@@ -113,7 +124,7 @@ impl NumberGuessContract {
     /// The Game Hub will call `game_id.require_auth()` which checks this contract's address.
     ///
     /// # Arguments
-    /// * `session_id` - Unique session identifier (u32)
+    /// * `session_id` - Session ID reserved on the Game Hub (`reserve_session`)
     /// * `player1` - Address of first player
     /// * `player2` - Address of second player
     /// * `player1_points` - Points amount committed by player 1
@@ -126,6 +137,8 @@ impl NumberGuessContract {
         player1_points: i128,
         player2_points: i128,
     ) -> Result<(), Error> {
+        require_not_paused(&env, Error::ContractPaused)?;
+
         // Prevent self-play: Player 1 and Player 2 must be different
         if player1 == player2 {
             panic!("Cannot play against yourself: Player 1 and Player 2 must be different addresses");
@@ -135,15 +148,12 @@ impl NumberGuessContract {
         player1.require_auth_for_args(vec![&env, session_id.into_val(&env), player1_points.into_val(&env)]);
         player2.require_auth_for_args(vec![&env, session_id.into_val(&env), player2_points.into_val(&env)]);
 
-        // Get GameHub address
-        let game_hub_addr: Address = env
-            .storage()
-            .instance()
-            .get(&DataKey::GameHubAddress)
-            .expect("GameHub address not set");
+        if env.storage().temporary().has(&DataKey::Game(session_id)) {
+            return Err(Error::SessionAlreadyExists);
+        }
 
         // Create GameHub client
-        let game_hub = GameHubClient::new(&env, &game_hub_addr);
+        let game_hub = hub_client(&env);
 
         // Call Game Hub to start the session and lock points
         // This requires THIS contract's authorization (env.current_contract_address())
@@ -162,38 +172,98 @@ impl NumberGuessContract {
             player2: player2.clone(),
             player1_points,
             player2_points,
+            player1_commitment: None,
+            player2_commitment: None,
             player1_guess: None,
             player2_guess: None,
+            player1_salt: None,
+            player2_salt: None,
             winning_number: None,
             winner: None,
+            status: GameStatus::Active,
         };
 
-        // Store game in temporary storage with 30-day TTL
-        let game_key = DataKey::Game(session_id);
-        env.storage().temporary().set(&game_key, &game);
+        // Store game in temporary storage, retained for at least 30 days
+        write_game(&env, &DataKey::Game(session_id), &game);
 
-        // Set TTL to ensure game is retained for at least 30 days
-        env.storage()
+        // Event emitted by the Game Hub contract (GameStarted)
+
+        Ok(())
+    }
+
+    /// Commit a sealed guess for the current game.
+    /// The guess stays hidden until both players have committed.
+    ///
+    /// # Arguments
+    /// * `session_id` - The session ID of the game
+    /// * `player` - Address of the player committing
+    /// * `commitment` - `keccak256(guess || salt || player_address)`, with the
+    ///   guess as 4 big-endian bytes and a 32-byte secret salt
+    pub fn commit_guess(
+        env: Env,
+        session_id: u32,
+        player: Address,
+        commitment: BytesN<32>,
+    ) -> Result<(), Error> {
+        require_not_paused(&env, Error::ContractPaused)?;
+
+        player.require_auth();
+
+        // Get game from temporary storage
+        let key = DataKey::Game(session_id);
+        let mut game: Game = env
+            .storage()
             .temporary()
-            .extend_ttl(&game_key, GAME_TTL_LEDGERS, GAME_TTL_LEDGERS);
+            .get(&key)
+            .ok_or(Error::GameNotFound)?;
 
-        // Event emitted by the Game Hub contract (GameStarted)
+        // Check game is still active
+        if game.status != GameStatus::Active {
+            return Err(Error::GameAlreadyEnded);
+        }
+
+        // Store the commitment for the appropriate player
+        if player == game.player1 {
+            if game.player1_commitment.is_some() {
+                return Err(Error::AlreadyGuessed);
+            }
+            game.player1_commitment = Some(commitment);
+        } else if player == game.player2 {
+            if game.player2_commitment.is_some() {
+                return Err(Error::AlreadyGuessed);
+            }
+            game.player2_commitment = Some(commitment);
+        } else {
+            return Err(Error::NotPlayer);
+        }
+
+        // Store updated game in temporary storage
+        env.storage().temporary().set(&key, &game);
 
         Ok(())
     }
 
-    /// Make a guess for the current game.
-    /// Players can guess a number between 1 and 10.
+    /// Reveal a committed guess. Can only be called after both players have
+    /// committed. Players can guess a number between 1 and 10.
     ///
     /// # Arguments
     /// * `session_id` - The session ID of the game
-    /// * `player` - Address of the player making the guess
+    /// * `player` - Address of the player revealing
     /// * `guess` - The guessed number (1-10)
-    pub fn make_guess(env: Env, session_id: u32, player: Address, guess: u32) -> Result<(), Error> {
+    /// * `salt` - The salt used in the commitment
+    pub fn reveal_guess(
+        env: Env,
+        session_id: u32,
+        player: Address,
+        guess: u32,
+        salt: BytesN<32>,
+    ) -> Result<(), Error> {
+        require_not_paused(&env, Error::ContractPaused)?;
+
         player.require_auth();
 
         // Validate guess is in range
-        if guess < 1 || guess > 10 {
+        if !(1..=10).contains(&guess) {
             panic!("Guess must be between 1 and 10");
         }
 
@@ -205,22 +275,37 @@ impl NumberGuessContract {
             .get(&key)
             .ok_or(Error::GameNotFound)?;
 
-        // Check game is still active (no winner yet)
-        if game.winner.is_some() {
+        // Check game is still active
+        if game.status != GameStatus::Active {
             return Err(Error::GameAlreadyEnded);
         }
 
-        // Update guess for the appropriate player
+        // Nobody reveals until both guesses are sealed
+        if game.player1_commitment.is_none() || game.player2_commitment.is_none() {
+            return Err(Error::BothPlayersNotCommitted);
+        }
+
+        let expected = compute_commitment(&env, guess, &salt, &player);
+
+        // Check the reveal against the appropriate player's commitment
         if player == game.player1 {
             if game.player1_guess.is_some() {
-                return Err(Error::AlreadyGuessed);
+                return Err(Error::AlreadyRevealed);
+            }
+            if game.player1_commitment != Some(expected) {
+                return Err(Error::CommitmentMismatch);
             }
             game.player1_guess = Some(guess);
+            game.player1_salt = Some(salt);
         } else if player == game.player2 {
             if game.player2_guess.is_some() {
-                return Err(Error::AlreadyGuessed);
+                return Err(Error::AlreadyRevealed);
+            }
+            if game.player2_commitment != Some(expected) {
+                return Err(Error::CommitmentMismatch);
             }
             game.player2_guess = Some(guess);
+            game.player2_salt = Some(salt);
         } else {
             return Err(Error::NotPlayer);
         }
@@ -234,15 +319,17 @@ impl NumberGuessContract {
     }
 
     /// Reveal the winner of the game and submit outcome to GameHub.
-    /// Can only be called after both players have made their guesses.
+    /// Can only be called after both players have revealed their guesses.
     /// This generates the winning number, determines the winner, and ends the session.
     ///
     /// # Arguments
     /// * `session_id` - The session ID of the game
     ///
     /// # Returns
-    /// * `Address` - Address of the winning player
-    pub fn reveal_winner(env: Env, session_id: u32) -> Result<Address, Error> {
+    /// * `Option<Address>` - Address of the winning player, or `None` for a draw
+    pub fn reveal_winner(env: Env, session_id: u32) -> Result<Option<Address>, Error> {
+        require_not_paused(&env, Error::ContractPaused)?;
+
         // Get game from temporary storage
         let key = DataKey::Game(session_id);
         let mut game: Game = env
@@ -251,22 +338,34 @@ impl NumberGuessContract {
             .get(&key)
             .ok_or(Error::GameNotFound)?;
 
-        // Check if game already ended (has a winner)
-        if let Some(winner) = &game.winner {
-            return Ok(winner.clone());
+        // Check if game already ended
+        if game.status != GameStatus::Active {
+            return Ok(game.winner);
         }
 
-        // Check both players have guessed
+        // Check both players have revealed
         let guess1 = game.player1_guess.ok_or(Error::BothPlayersNotGuessed)?;
         let guess2 = game.player2_guess.ok_or(Error::BothPlayersNotGuessed)?;
+        let salt1 = game
+            .player1_salt
+            .clone()
+            .ok_or(Error::BothPlayersNotGuessed)?;
+        let salt2 = game
+            .player2_salt
+            .clone()
+            .ok_or(Error::BothPlayersNotGuessed)?;
 
         // Generate random winning number between 1 and 10 using seeded PRNG
-        // This is done AFTER both players have committed their guesses
+        // This is done AFTER both players have revealed their guesses
         //
         // Seed components (all deterministic and identical between sim/submit):
         // 1. Session ID - unique per game, same between simulation and submission
         // 2. Player addresses - both players contribute, same between sim/submit
-        // 3. Guesses - committed before reveal, same between sim/submit
+        // 3. Guesses - sealed by commitments before either was revealed, so the
+        //    second mover cannot choose a guess by simulating the outcome
+        // 4. Salts - both secret until revealed and fixed by the commitments, so
+        //    the second committer cannot grind its salt for a good draw without
+        //    knowing the first committer's
         //
         // Note: We do NOT include ledger sequence or timestamp because those differ
         // between simulation and submission, which would cause different winners.
@@ -286,6 +385,8 @@ impl NumberGuessContract {
         let mut seed_bytes = Bytes::from_array(&env, &fixed_data);
         seed_bytes.append(&game.player1.to_string().to_bytes());
         seed_bytes.append(&game.player2.to_string().to_bytes());
+        seed_bytes.append(&salt1.into());
+        seed_bytes.append(&salt2.into());
 
         let seed = env.crypto().keccak256(&seed_bytes);
         env.prng().seed(seed.into());
@@ -293,46 +394,33 @@ impl NumberGuessContract {
         game.winning_number = Some(winning_number);
 
         // Calculate distances
-        let distance1 = if guess1 > winning_number {
-            guess1 - winning_number
+        let distance1 = guess1.abs_diff(winning_number);
+        let distance2 = guess2.abs_diff(winning_number);
+
+        // Determine outcome (equal distance is a draw)
+        let outcome = if distance1 < distance2 {
+            game.winner = Some(game.player1.clone());
+            GameOutcome::Player1Won
+        } else if distance2 < distance1 {
+            game.winner = Some(game.player2.clone());
+            GameOutcome::Player2Won
         } else {
-            winning_number - guess1
-        };
-
-        let distance2 = if guess2 > winning_number {
-            guess2 - winning_number
-        } else {
-            winning_number - guess2
-        };
-
-        // Determine winner (if equal distance, player1 wins)
-        let winner = if distance1 <= distance2 {
-            game.player1.clone()
-        } else {
-            game.player2.clone()
+            GameOutcome::Draw
         };
 
-        // Update game with winner (this marks the game as ended)
-        game.winner = Some(winner.clone());
+        // Update game with outcome (this marks the game as ended)
+        game.status = GameStatus::Ended(outcome.clone());
         env.storage().temporary().set(&key, &game);
 
-        // Get GameHub address
-        let game_hub_addr: Address = env
-            .storage()
-            .instance()
-            .get(&DataKey::GameHubAddress)
-            .expect("GameHub address not set");
-
         // Create GameHub client
-        let game_hub = GameHubClient::new(&env, &game_hub_addr);
+        let game_hub = hub_client(&env);
 
         // Call GameHub to end the session
         // This unlocks points and updates standings
         // Event emitted by the Game Hub contract (GameEnded)
-        let player1_won = winner == game.player1; // true if player1 won, false if player2 won
-        game_hub.end_game(&session_id, &player1_won);
+        game_hub.end_game_with_outcome(&session_id, &outcome);
 
-        Ok(winner)
+        Ok(game.winner)
     }
 
     /// Get game information.
@@ -349,81 +437,168 @@ impl NumberGuessContract {
             .get(&key)
             .ok_or(Error::GameNotFound)
     }
+}
 
-    // ========================================================================
-    // Admin Functions
-    // ========================================================================
+// ============================================================================
+// Game Interface
+// ============================================================================
 
-    /// Get the current admin address
-    ///
-    /// # Returns
-    /// * `Address` - The admin address
-    pub fn get_admin(env: Env) -> Address {
-        env.storage()
-            .instance()
-            .get(&DataKey::Admin)
-            .expect("Admin not set")
+#[contractimpl]
+impl GameContract for NumberGuessContract {
+    /// Describe the game: two players, winner takes both stakes
+    fn game_info(env: Env) -> GameInfo {
+        GameInfo {
+            name: String::from_str(&env, "Number Guess"),
+            rules_version: 3,
+            min_players: 2,
+            max_players: 2,
+            stakes: StakeSemantics::WinnerTakesAll,
+        }
     }
 
-    /// Set a new admin address
+    /// Get the status of a session, or `None` if it does not exist (or expired)
+    fn get_session_status(env: Env, session_id: u32) -> Option<SessionStatus> {
+        let game: Game = env.storage().temporary().get(&DataKey::Game(session_id))?;
+        Some(match game.status {
+            GameStatus::Active => SessionStatus::Active,
+            GameStatus::Ended(outcome) => SessionStatus::Ended(outcome),
+        })
+    }
+
+    /// Concede the game. The opponent wins and the Game Hub settles the points.
     ///
     /// # Arguments
-    /// * `new_admin` - The new admin address
-    pub fn set_admin(env: Env, new_admin: Address) {
-        let admin: Address = env
+    /// * `session_id` - The session ID of the game
+    /// * `player` - Address of the player conceding
+    fn forfeit(env: Env, session_id: u32, player: Address) -> Result<(), soroban_sdk::Error> {
+        require_not_paused(&env, Error::ContractPaused)?;
+
+        player.require_auth();
+
+        let key = DataKey::Game(session_id);
+        let mut game: Game = env
             .storage()
-            .instance()
-            .get(&DataKey::Admin)
-            .expect("Admin not set");
-        admin.require_auth();
+            .temporary()
+            .get(&key)
+            .ok_or(Error::GameNotFound)?;
 
-        env.storage().instance().set(&DataKey::Admin, &new_admin);
-    }
+        if game.status != GameStatus::Active {
+            return Err(Error::GameAlreadyEnded.into());
+        }
 
-    /// Get the current GameHub contract address
-    ///
-    /// # Returns
-    /// * `Address` - The GameHub contract address
-    pub fn get_hub(env: Env) -> Address {
-        env.storage()
-            .instance()
-            .get(&DataKey::GameHubAddress)
-            .expect("GameHub address not set")
+        let opponent = if player == game.player1 {
+            game.player2.clone()
+        } else if player == game.player2 {
+            game.player1.clone()
+        } else {
+            return Err(Error::NotPlayer.into());
+        };
+
+        let outcome = GameOutcome::Forfeit(player);
+        game.winner = Some(opponent);
+        game.status = GameStatus::Ended(outcome.clone());
+        env.storage().temporary().set(&key, &game);
+
+        // Event emitted by the Game Hub contract (GameEnded)
+        hub_client(&env).end_game_with_outcome(&session_id, &outcome);
+
+        Ok(())
     }
+}
+
+// ============================================================================
+// Game Hub Callbacks
+// ============================================================================
 
-    /// Set a new GameHub contract address
+#[contractimpl]
+impl GameCallbacks for NumberGuessContract {
+    /// Called by the Game Hub when a timeout is claimed against `player`.
+    /// Only accepted if `player` has not committed while their opponent has,
+    /// or has not revealed while their opponent has; the game then ends as a
+    /// forfeit by `player`. The hub settles the points.
     ///
     /// # Arguments
-    /// * `new_hub` - The new GameHub contract address
-    pub fn set_hub(env: Env, new_hub: Address) {
-        let admin: Address = env
+    /// * `session_id` - The session ID of the game
+    /// * `player` - Address of the player being forfeited
+    fn on_session_forfeited(
+        env: Env,
+        session_id: u32,
+        player: Address,
+    ) -> Result<(), soroban_sdk::Error> {
+        Self::get_hub(env.clone()).require_auth();
+
+        let key = DataKey::Game(session_id);
+        let mut game: Game = env
             .storage()
-            .instance()
-            .get(&DataKey::Admin)
-            .expect("Admin not set");
-        admin.require_auth();
+            .temporary()
+            .get(&key)
+            .ok_or(Error::GameNotFound)?;
 
-        env.storage()
-            .instance()
-            .set(&DataKey::GameHubAddress, &new_hub);
+        if game.status != GameStatus::Active {
+            return Err(Error::GameAlreadyEnded.into());
+        }
+
+        let committing = game.player1_commitment.is_none() || game.player2_commitment.is_none();
+        let (stalling, opponent) = if player == game.player1 {
+            let stalling = if committing {
+                game.player1_commitment.is_none() && game.player2_commitment.is_some()
+            } else {
+                game.player1_guess.is_none() && game.player2_guess.is_some()
+            };
+            (stalling, game.player2.clone())
+        } else if player == game.player2 {
+            let stalling = if committing {
+                game.player2_commitment.is_none() && game.player1_commitment.is_some()
+            } else {
+                game.player2_guess.is_none() && game.player1_guess.is_some()
+            };
+            (stalling, game.player1.clone())
+        } else {
+            return Err(Error::NotPlayer.into());
+        };
+        if !stalling {
+            return Err(Error::PlayerNotStalling.into());
+        }
+
+        game.winner = Some(opponent);
+        game.status = GameStatus::Ended(GameOutcome::Forfeit(player));
+        env.storage().temporary().set(&key, &game);
+
+        Ok(())
     }
 
-    /// Update the contract WASM hash (upgrade contract)
+    /// Called by the Game Hub when it cancels the session (timeout or pause).
+    /// The game ends without a winner; the hub refunds both stakes.
     ///
     /// # Arguments
-    /// * `new_wasm_hash` - The hash of the new WASM binary
-    pub fn upgrade(env: Env, new_wasm_hash: BytesN<32>) {
-        let admin: Address = env
+    /// * `session_id` - The session ID of the game
+    fn on_session_cancelled(env: Env, session_id: u32) -> Result<(), soroban_sdk::Error> {
+        Self::get_hub(env.clone()).require_auth();
+
+        let key = DataKey::Game(session_id);
+        let mut game: Game = env
             .storage()
-            .instance()
-            .get(&DataKey::Admin)
-            .expect("Admin not set");
-        admin.require_auth();
+            .temporary()
+            .get(&key)
+            .ok_or(Error::GameNotFound)?;
 
-        env.deployer().update_current_contract_wasm(new_wasm_hash);
+        if game.status != GameStatus::Active {
+            return Err(Error::GameAlreadyEnded.into());
+        }
+
+        game.status = GameStatus::Ended(GameOutcome::Cancelled);
+        env.storage().temporary().set(&key, &game);
+
+        Ok(())
     }
 }
 
+// ============================================================================
+// Admin Functions
+// ============================================================================
+
+sgs_game_common::game_admin_entrypoints!(NumberGuessContract);
+
 // ============================================================================
 // Tests
 // ============================================================================
@@ -6,9 +6,10 @@
 // Note: These tests use a minimal mock for isolation and speed.
 // For full integration tests with the real Game Hub contract, see the platform repo.
 
-use crate::{Error, NumberGuessContract, NumberGuessContractClient};
+use crate::{Error, GameOutcome, GameStatus, NumberGuessContract, NumberGuessContractClient};
+use sgs_game_common::{GameContractClient, SessionStatus, StakeSemantics};
 use soroban_sdk::testutils::{Address as _, Ledger as _};
-use soroban_sdk::{contract, contractimpl, Address, BytesN, Env};
+use soroban_sdk::{contract, contractimpl, Address, Bytes, BytesN, Env, String};
 
 // ============================================================================
 // Mock GameHub for Unit Testing
@@ -35,6 +36,10 @@ impl MockGameHub {
         // Mock implementation - does nothing
     }
 
+    pub fn end_game_with_outcome(_env: Env, _session_id: u32, _outcome: GameOutcome) {
+        // Mock implementation - does nothing
+    }
+
     pub fn add_game(_env: Env, _game_address: Address) {
         // Mock implementation - does nothing
     }
@@ -96,7 +101,7 @@ fn setup_test() -> (
 ///
 /// # Example
 /// ```
-/// let result = client.try_make_guess(&session_id, &player, &7);
+/// let result = client.try_commit_guess(&session_id, &player, &commitment);
 /// assert_number_guess_error(&result, Error::AlreadyGuessed);
 /// ```
 ///
@@ -139,13 +144,87 @@ fn assert_number_guess_error<T, E>(
     }
 }
 
+/// Salt every test player uses; commitments also bind the player's address
+fn test_salt(env: &Env) -> BytesN<32> {
+    BytesN::from_array(env, &[0xAB; 32])
+}
+
+/// Commitment hash: keccak256(guess || salt || player_address)
+fn make_commitment(env: &Env, guess: u32, player: &Address) -> BytesN<32> {
+    make_salted_commitment(env, guess, &test_salt(env), player)
+}
+
+/// Commitment hash with a chosen salt
+fn make_salted_commitment(
+    env: &Env,
+    guess: u32,
+    salt: &BytesN<32>,
+    player: &Address,
+) -> BytesN<32> {
+    let mut data = Bytes::from_array(env, &guess.to_be_bytes());
+    data.append(&salt.clone().into());
+    data.append(&player.to_string().to_bytes());
+    env.crypto().keccak256(&data).into()
+}
+
+/// Winning number `reveal_winner` draws for these guesses and salts
+fn draw_winning_number(
+    env: &Env,
+    client: &NumberGuessContractClient,
+    session_id: u32,
+    player1: &Address,
+    player2: &Address,
+    (guess1, salt1): (u32, &BytesN<32>),
+    (guess2, salt2): (u32, &BytesN<32>),
+) -> u32 {
+    let mut seed_bytes = Bytes::from_array(env, &session_id.to_be_bytes());
+    seed_bytes.append(&Bytes::from_array(env, &guess1.to_be_bytes()));
+    seed_bytes.append(&Bytes::from_array(env, &guess2.to_be_bytes()));
+    seed_bytes.append(&player1.to_string().to_bytes());
+    seed_bytes.append(&player2.to_string().to_bytes());
+    seed_bytes.append(&salt1.clone().into());
+    seed_bytes.append(&salt2.clone().into());
+    let seed = env.crypto().keccak256(&seed_bytes);
+    env.as_contract(&client.address, || {
+        env.prng().seed(seed.into());
+        env.prng().gen_range::<u64>(1..=10) as u32
+    })
+}
+
+/// Commit a sealed guess for `player`
+fn commit_guess(
+    env: &Env,
+    client: &NumberGuessContractClient,
+    session_id: u32,
+    player: &Address,
+    guess: u32,
+) {
+    client.commit_guess(&session_id, player, &make_commitment(env, guess, player));
+}
+
+/// Commit both guesses, then reveal both
+fn play_guesses(
+    env: &Env,
+    client: &NumberGuessContractClient,
+    session_id: u32,
+    player1: &Address,
+    guess1: u32,
+    player2: &Address,
+    guess2: u32,
+) {
+    commit_guess(env, client, session_id, player1, guess1);
+    commit_guess(env, client, session_id, player2, guess2);
+    client.reveal_guess(&session_id, player1, &guess1, &test_salt(env));
+    client.reveal_guess(&session_id, player2, &guess2, &test_salt(env));
+}
+
 // ============================================================================
 // Basic Game Flow Tests
 // ============================================================================
 
 #[test]
 fn test_complete_game() {
-    let (_env, client, _hub, player1, player2) = setup_test();
+    let (env, client, _hub, player1, player2) = setup_test();
 
     let session_id = 1u32;
     let points = 100_0000000;
@@ -156,39 +235,37 @@ fn test_complete_game() {
     // Get game to verify state
     let game = client.get_game(&session_id);
     assert!(game.winning_number.is_none()); // Winning number not set yet
-    assert!(game.winner.is_none()); // Game is still active
+    assert!(game.winner.is_none());
+    assert_eq!(game.status, GameStatus::Active);
     assert_eq!(game.player1, player1);
     assert_eq!(game.player2, player2);
     assert_eq!(game.player1_points, points);
     assert_eq!(game.player2_points, points);
 
     // Make guesses
-    client.make_guess(&session_id, &player1, &5);
-    client.make_guess(&session_id, &player2, &7);
+    play_guesses(&env, &client, session_id, &player1, 5, &player2, 7);
 
-    // Reveal winner
+    // Reveal winner (None if both guesses were equally close)
     let winner = client.reveal_winner(&session_id);
-    assert!(winner == player1 || winner == player2);
 
     // Verify game is ended and winning number is now set
     let final_game = client.get_game(&session_id);
-    assert!(final_game.winner.is_some()); // Game has ended
-    assert_eq!(final_game.winner.unwrap(), winner);
+    assert_ne!(final_game.status, GameStatus::Active); // Game has ended
+    assert_eq!(final_game.winner, winner);
     assert!(final_game.winning_number.is_some());
     let winning_number = final_game.winning_number.unwrap();
-    assert!(winning_number >= 1 && winning_number <= 10);
+    assert!((1..=10).contains(&winning_number));
 }
 
 #[test]
 fn test_winning_number_in_range() {
-    let (_env, client, _hub, player1, player2) = setup_test();
+    let (env, client, _hub, player1, player2) = setup_test();
 
     let session_id = 2u32;
     client.start_game(&session_id, &player1, &player2, &100_0000000, &100_0000000);
 
     // Make guesses and reveal winner to generate winning number
-    client.make_guess(&session_id, &player1, &5);
-    client.make_guess(&session_id, &player2, &7);
+    play_guesses(&env, &client, session_id, &player1, 5, &player2, 7);
     client.reveal_winner(&session_id);
 
     let game = client.get_game(&session_id);
@@ -196,7 +273,7 @@ fn test_winning_number_in_range() {
         .winning_number
         .expect("Winning number should be set after reveal");
     assert!(
-        winning_number >= 1 && winning_number <= 10,
+        (1..=10).contains(&winning_number),
         "Winning number should be between 1 and 10"
     );
 }
@@ -227,15 +304,14 @@ fn test_multiple_sessions() {
 
 #[test]
 fn test_closest_guess_wins() {
-    let (_env, client, _hub, player1, player2) = setup_test();
+    let (env, client, _hub, player1, player2) = setup_test();
 
     let session_id = 5u32;
     client.start_game(&session_id, &player1, &player2, &100_0000000, &100_0000000);
 
     // Player1 guesses closer (1 away from any number between 1-10)
     // Player2 guesses further (at least 2 away)
-    client.make_guess(&session_id, &player1, &5);
-    client.make_guess(&session_id, &player2, &10);
+    play_guesses(&env, &client, session_id, &player1, 5, &player2, 10);
 
     let winner = client.reveal_winner(&session_id);
 
@@ -244,76 +320,62 @@ fn test_closest_guess_wins() {
     let winning_number = game.winning_number.unwrap();
 
     // Calculate which player should have won based on distances
-    let distance1 = if 5 > winning_number {
-        5 - winning_number
-    } else {
-        winning_number - 5
-    };
-    let distance2 = if 10 > winning_number {
-        10 - winning_number
-    } else {
-        winning_number - 10
-    };
+    let distance1 = 5u32.abs_diff(winning_number);
+    let distance2 = 10u32.abs_diff(winning_number);
 
-    let expected_winner = if distance1 <= distance2 {
+    let expected_winner = if distance1 < distance2 {
         player1.clone()
     } else {
         player2.clone()
     };
     assert_eq!(
-        winner, expected_winner,
+        winner,
+        Some(expected_winner),
         "Player with closer guess should win"
     );
 }
 
 #[test]
-fn test_tie_game_player1_wins() {
-    let (_env, client, _hub, player1, player2) = setup_test();
+fn test_tie_game_is_draw() {
+    let (env, client, _hub, player1, player2) = setup_test();
 
     let session_id = 6u32;
     client.start_game(&session_id, &player1, &player2, &100_0000000, &100_0000000);
 
     // Both players guess the same number (guaranteed tie)
-    client.make_guess(&session_id, &player1, &5);
-    client.make_guess(&session_id, &player2, &5);
+    play_guesses(&env, &client, session_id, &player1, 5, &player2, 5);
 
     let winner = client.reveal_winner(&session_id);
-    assert_eq!(winner, player1, "Player1 should win in a tie");
+    assert_eq!(winner, None, "A tie should have no winner");
+
+    let game = client.get_game(&session_id);
+    assert_eq!(game.status, GameStatus::Ended(GameOutcome::Draw));
 }
 
 #[test]
 fn test_exact_guess_wins() {
-    let (_env, client, _hub, player1, player2) = setup_test();
+    let (env, client, _hub, player1, player2) = setup_test();
 
     let session_id = 7u32;
     client.start_game(&session_id, &player1, &player2, &100_0000000, &100_0000000);
 
     // Player1 guesses 5 (middle), player2 guesses 10 (edge)
     // Player1 is more likely to be closer to the winning number
-    client.make_guess(&session_id, &player1, &5);
-    client.make_guess(&session_id, &player2, &10);
+    play_guesses(&env, &client, session_id, &player1, 5, &player2, 10);
 
     let winner = client.reveal_winner(&session_id);
     let game = client.get_game(&session_id);
     let winning_number = game.winning_number.unwrap();
 
     // Verify the winner matches the distance calculation
-    let distance1 = if 5 > winning_number {
-        5 - winning_number
-    } else {
-        winning_number - 5
-    };
-    let distance2 = if 10 > winning_number {
-        10 - winning_number
-    } else {
-        winning_number - 10
-    };
-    let expected_winner = if distance1 <= distance2 {
+    let distance1 = 5u32.abs_diff(winning_number);
+    let distance2 = 10u32.abs_diff(winning_number);
+    let expected_winner = if distance1 < distance2 {
         player1.clone()
     } else {
         player2.clone()
     };
-    assert_eq!(winner, expected_winner);
+    assert_eq!(winner, Some(expected_winner));
 }
 
 // ============================================================================
@@ -322,68 +384,94 @@ fn test_exact_guess_wins() {
 
 #[test]
 fn test_cannot_guess_twice() {
-    let (_env, client, _hub, player1, player2) = setup_test();
+    let (env, client, _hub, player1, player2) = setup_test();
 
     let session_id = 8u32;
     client.start_game(&session_id, &player1, &player2, &100_0000000, &100_0000000);
 
-    // Make first guess
-    client.make_guess(&session_id, &player1, &5);
+    // Commit first guess
+    commit_guess(&env, &client, session_id, &player1, 5);
 
-    // Try to guess again - should fail
-    let result = client.try_make_guess(&session_id, &player1, &6);
+    // Try to commit again - should fail
+    let commitment = make_commitment(&env, 6, &player1);
+    let result = client.try_commit_guess(&session_id, &player1, &commitment);
     assert_number_guess_error(&result, Error::AlreadyGuessed);
 }
 
 #[test]
 fn test_cannot_reveal_before_both_guesses() {
-    let (_env, client, _hub, player1, player2) = setup_test();
+    let (env, client, _hub, player1, player2) = setup_test();
 
     let session_id = 9u32;
     client.start_game(&session_id, &player1, &player2, &100_0000000, &100_0000000);
 
-    // Only player1 guesses
-    client.make_guess(&session_id, &player1, &5);
+    // Only player1 commits; nobody can reveal yet
+    commit_guess(&env, &client, session_id, &player1, 5);
+    let result = client.try_reveal_guess(&session_id, &player1, &5, &test_salt(&env));
+    assert_number_guess_error(&result, Error::BothPlayersNotCommitted);
+
+    // Both commit, only player1 reveals
+    commit_guess(&env, &client, session_id, &player2, 7);
+    client.reveal_guess(&session_id, &player1, &5, &test_salt(&env));
 
     // Try to reveal winner - should fail
     let result = client.try_reveal_winner(&session_id);
     assert_number_guess_error(&result, Error::BothPlayersNotGuessed);
 }
 
+#[test]
+fn test_reveal_must_match_commitment() {
+    let (env, client, _hub, player1, player2) = setup_test();
+
+    let session_id = 13u32;
+    client.start_game(&session_id, &player1, &player2, &100_0000000, &100_0000000);
+    commit_guess(&env, &client, session_id, &player1, 5);
+    commit_guess(&env, &client, session_id, &player2, 7);
+
+    // Wrong guess
+    let result = client.try_reveal_guess(&session_id, &player1, &6, &test_salt(&env));
+    assert_number_guess_error(&result, Error::CommitmentMismatch);
+
+    // Wrong salt
+    let other_salt = BytesN::from_array(&env, &[0xCD; 32]);
+    let result = client.try_reveal_guess(&session_id, &player1, &5, &other_salt);
+    assert_number_guess_error(&result, Error::CommitmentMismatch);
+
+    // A commitment is bound to its player: player2 cannot reveal player1's guess
+    let result = client.try_reveal_guess(&session_id, &player2, &5, &test_salt(&env));
+    assert_number_guess_error(&result, Error::CommitmentMismatch);
+
+    client.reveal_guess(&session_id, &player1, &5, &test_salt(&env));
+    let result = client.try_reveal_guess(&session_id, &player1, &5, &test_salt(&env));
+    assert_number_guess_error(&result, Error::AlreadyRevealed);
+}
+
 #[test]
 #[should_panic(expected = "Guess must be between 1 and 10")]
 fn test_cannot_guess_below_range() {
-    let (env, client, _hub, player1, _player2) = setup_test();
+    let (env, client, _hub, player1, player2) = setup_test();
 
     let session_id = 10u32;
-    client.start_game(
-        &session_id,
-        &player1,
-        &Address::generate(&env),
-        &100_0000000,
-        &100_0000000,
-    );
+    client.start_game(&session_id, &player1, &player2, &100_0000000, &100_0000000);
 
-    // Try to guess 0 (below range) - should panic
-    client.make_guess(&session_id, &player1, &0);
+    // A guess of 0 (below range) can be committed but never revealed
+    commit_guess(&env, &client, session_id, &player1, 0);
+    commit_guess(&env, &client, session_id, &player2, 5);
+    client.reveal_guess(&session_id, &player1, &0, &test_salt(&env));
 }
 
 #[test]
 #[should_panic(expected = "Guess must be between 1 and 10")]
 fn test_cannot_guess_above_range() {
-    let (env, client, _hub, player1, _player2) = setup_test();
+    let (env, client, _hub, player1, player2) = setup_test();
 
     let session_id = 11u32;
-    client.start_game(
-        &session_id,
-        &player1,
-        &Address::generate(&env),
-        &100_0000000,
-        &100_0000000,
-    );
+    client.start_game(&session_id, &player1, &player2, &100_0000000, &100_0000000);
 
-    // Try to guess 11 (above range) - should panic
-    client.make_guess(&session_id, &player1, &11);
+    // A guess of 11 (above range) can be committed but never revealed
+    commit_guess(&env, &client, session_id, &player1, 11);
+    commit_guess(&env, &client, session_id, &player2, 5);
+    client.reveal_guess(&session_id, &player1, &11, &test_salt(&env));
 }
 
 #[test]
@@ -395,7 +483,8 @@ fn test_non_player_cannot_guess() {
     client.start_game(&session_id, &player1, &player2, &100_0000000, &100_0000000);
 
     // Non-player tries to guess
-    let result = client.try_make_guess(&session_id, &non_player, &5);
+    let commitment = make_commitment(&env, 5, &non_player);
+    let result = client.try_commit_guess(&session_id, &non_player, &commitment);
     assert_number_guess_error(&result, Error::NotPlayer);
 }
 
@@ -409,42 +498,140 @@ fn test_cannot_reveal_nonexistent_game() {
 
 #[test]
 fn test_cannot_guess_after_game_ended() {
-    let (_env, client, _hub, player1, player2) = setup_test();
+    let (env, client, _hub, player1, player2) = setup_test();
 
     let session_id = 12u32;
     client.start_game(&session_id, &player1, &player2, &100_0000000, &100_0000000);
 
     // Both players make guesses
-    client.make_guess(&session_id, &player1, &5);
-    client.make_guess(&session_id, &player2, &7);
+    play_guesses(&env, &client, session_id, &player1, 5, &player2, 7);
 
     // Reveal winner - game ends
     let _winner = client.reveal_winner(&session_id);
 
     // Try to make another guess after game has ended - should fail
-    let result = client.try_make_guess(&session_id, &player1, &3);
+    let commitment = make_commitment(&env, 3, &player1);
+    let result = client.try_commit_guess(&session_id, &player1, &commitment);
     assert_number_guess_error(&result, Error::GameAlreadyEnded);
 }
 
 #[test]
 fn test_cannot_reveal_twice() {
-    let (_env, client, _hub, player1, player2) = setup_test();
+    let (env, client, _hub, player1, player2) = setup_test();
 
     let session_id = 14u32;
     client.start_game(&session_id, &player1, &player2, &100_0000000, &100_0000000);
 
-    client.make_guess(&session_id, &player1, &5);
-    client.make_guess(&session_id, &player2, &7);
+    play_guesses(&env, &client, session_id, &player1, 5, &player2, 7);
 
     // First reveal succeeds
     let winner = client.reveal_winner(&session_id);
-    assert!(winner == player1 || winner == player2);
 
     // Second reveal should return same winner (idempotent)
     let winner2 = client.reveal_winner(&session_id);
     assert_eq!(winner, winner2);
 }
 
+// ============================================================================
+// Sealed Guess Tests
+// ============================================================================
+
+#[test]
+fn test_committed_guess_stays_hidden() {
+    let (env, client, _hub, player1, player2) = setup_test();
+
+    let session_id = 16u32;
+    client.start_game(&session_id, &player1, &player2, &100_0000000, &100_0000000);
+    commit_guess(&env, &client, session_id, &player1, 5);
+
+    // player2 moves second but only sees a hash of player1's guess
+    let game = client.get_game(&session_id);
+    assert_eq!(
+        game.player1_commitment,
+        Some(make_commitment(&env, 5, &player1))
+    );
+    assert_eq!(game.player1_guess, None);
+
+    // ...and cannot simulate the outcome against candidate guesses
+    let result = client.try_reveal_winner(&session_id);
+    assert_number_guess_error(&result, Error::BothPlayersNotGuessed);
+    let result = client.try_reveal_guess(&session_id, &player1, &5, &test_salt(&env));
+    assert_number_guess_error(&result, Error::BothPlayersNotCommitted);
+}
+
+#[test]
+fn test_second_mover_cannot_grind_outcome() {
+    let (env, client, _hub, player1, player2) = setup_test();
+
+    let session_id = 17u32;
+    client.start_game(&session_id, &player1, &player2, &100_0000000, &100_0000000);
+    let salt1 = BytesN::from_array(&env, &[0x5A; 32]);
+    client.commit_guess(
+        &session_id,
+        &player1,
+        &make_salted_commitment(&env, 5, &salt1, &player1),
+    );
+
+    // player2 commits second and grinds its salt offline for a draw of exactly
+    // its guess of 1. Even granting it player1's guess, it can only try the
+    // salts player1 might have used; here it assumes an all-zero salt.
+    let assumed_salt1 = BytesN::from_array(&env, &[0; 32]);
+    let ground_salt2 = (0..=u8::MAX)
+        .map(|i| BytesN::from_array(&env, &[i; 32]))
+        .find(|salt2| {
+            draw_winning_number(
+                &env,
+                &client,
+                session_id,
+                &player1,
+                &player2,
+                (5, &assumed_salt1),
+                (1, salt2),
+            ) == 1
+        })
+        .expect("some salt draws a 1 under the assumed salt");
+
+    // The ground salt does not fix the draw: it still depends on which salt
+    // player1 really committed to
+    assert!((1..=16u8).any(|i| {
+        draw_winning_number(
+            &env,
+            &client,
+            session_id,
+            &player1,
+            &player2,
+            (5, &BytesN::from_array(&env, &[i; 32])),
+            (1, &ground_salt2),
+        ) != 1
+    }));
+
+    // Played out, the draw comes from player1's real salt, not the assumed one
+    client.commit_guess(
+        &session_id,
+        &player2,
+        &make_salted_commitment(&env, 1, &ground_salt2, &player2),
+    );
+    client.reveal_guess(&session_id, &player1, &5, &salt1);
+    client.reveal_guess(&session_id, &player2, &1, &ground_salt2);
+    client.reveal_winner(&session_id);
+
+    let game = client.get_game(&session_id);
+    assert_eq!(game.player1_salt, Some(salt1.clone()));
+    assert_eq!(game.player2_salt, Some(ground_salt2.clone()));
+    assert_eq!(
+        game.winning_number,
+        Some(draw_winning_number(
+            &env,
+            &client,
+            session_id,
+            &player1,
+            &player2,
+            (5, &salt1),
+            (1, &ground_salt2),
+        ))
+    );
+}
+
 // ============================================================================
 // Multiple Games Tests
 // ============================================================================
@@ -463,24 +650,28 @@ fn test_multiple_games_independent() {
     client.start_game(&session2, &player3, &player4, &50_0000000, &50_0000000);
 
     // Play both games independently
-    client.make_guess(&session1, &player1, &3);
-    client.make_guess(&session2, &player3, &8);
-    client.make_guess(&session1, &player2, &7);
-    client.make_guess(&session2, &player4, &2);
+    commit_guess(&env, &client, session1, &player1, 3);
+    commit_guess(&env, &client, session2, &player3, 8);
+    commit_guess(&env, &client, session1, &player2, 7);
+    commit_guess(&env, &client, session2, &player4, 2);
+    client.reveal_guess(&session2, &player4, &2, &test_salt(&env));
+    client.reveal_guess(&session1, &player1, &3, &test_salt(&env));
+    client.reveal_guess(&session2, &player3, &8, &test_salt(&env));
+    client.reveal_guess(&session1, &player2, &7, &test_salt(&env));
 
     // Reveal both winners
     let winner1 = client.reveal_winner(&session1);
     let winner2 = client.reveal_winner(&session2);
 
-    assert!(winner1 == player1 || winner1 == player2);
-    assert!(winner2 == player3 || winner2 == player4);
+    assert!(winner1.is_none() || winner1 == Some(player1) || winner1 == Some(player2));
+    assert!(winner2.is_none() || winner2 == Some(player3) || winner2 == Some(player4));
 
     // Verify both games are independent
     let final_game1 = client.get_game(&session1);
     let final_game2 = client.get_game(&session2);
 
-    assert!(final_game1.winner.is_some()); // Game 1 has ended
-    assert!(final_game2.winner.is_some()); // Game 2 has ended
+    assert_ne!(final_game1.status, GameStatus::Active); // Game 1 has ended
+    assert_ne!(final_game2.status, GameStatus::Active); // Game 2 has ended
 
     // Note: winning numbers could be the same by chance, so we just verify they're both set
     assert!(final_game1.winning_number.is_some());
@@ -489,7 +680,7 @@ fn test_multiple_games_independent() {
 
 #[test]
 fn test_asymmetric_points() {
-    let (_env, client, _hub, player1, player2) = setup_test();
+    let (env, client, _hub, player1, player2) = setup_test();
 
     let session_id = 15u32;
     let points1 = 200_0000000;
@@ -501,13 +692,229 @@ fn test_asymmetric_points() {
     assert_eq!(game.player1_points, points1);
     assert_eq!(game.player2_points, points2);
 
-    client.make_guess(&session_id, &player1, &5);
-    client.make_guess(&session_id, &player2, &5);
+    play_guesses(&env, &client, session_id, &player1, 5, &player2, 5);
     client.reveal_winner(&session_id);
 
     // Game completes successfully with asymmetric points
     let final_game = client.get_game(&session_id);
-    assert!(final_game.winner.is_some()); // Game has ended
+    assert_eq!(final_game.status, GameStatus::Ended(GameOutcome::Draw));
+}
+
+#[test]
+fn test_cannot_start_over_live_session() {
+    let (env, client, _hub, player1, player2) = setup_test();
+
+    let session_id = 30u32;
+    client.start_game(&session_id, &player1, &player2, &100, &100);
+
+    let player3 = Address::generate(&env);
+    let result = client.try_start_game(&session_id, &player3, &player2, &100, &100);
+    assert_number_guess_error(&result, Error::SessionAlreadyExists);
+    assert_eq!(client.get_game(&session_id).player1, player1);
+}
+
+// ============================================================================
+// Game Hub Callback Tests
+// ============================================================================
+
+#[test]
+fn test_hub_forfeits_stalling_player() {
+    let (env, client, hub, player1, player2) = setup_test();
+
+    let session_id = 40u32;
+    client.start_game(&session_id, &player1, &player2, &100, &100);
+    commit_guess(&env, &client, session_id, &player1, 5);
+
+    client.on_session_forfeited(&session_id, &player2);
+    assert_eq!(env.auths()[0].0, hub.address);
+
+    let game = client.get_game(&session_id);
+    assert_eq!(game.winner, Some(player1));
+    assert_eq!(
+        game.status,
+        GameStatus::Ended(GameOutcome::Forfeit(player2.clone()))
+    );
+
+    let commitment = make_commitment(&env, 5, &player2);
+    let result = client.try_commit_guess(&session_id, &player2, &commitment);
+    assert_number_guess_error(&result, Error::GameAlreadyEnded);
+}
+
+#[test]
+fn test_hub_forfeits_player_who_has_not_revealed() {
+    let (env, client, _hub, player1, player2) = setup_test();
+
+    let session_id = 43u32;
+    client.start_game(&session_id, &player1, &player2, &100, &100);
+    commit_guess(&env, &client, session_id, &player1, 5);
+    commit_guess(&env, &client, session_id, &player2, 7);
+
+    // Both committed: nobody is stalling until someone reveals
+    let result = client.try_on_session_forfeited(&session_id, &player2);
+    assert_eq!(result, Err(Ok(Error::PlayerNotStalling.into())));
+
+    // player2 sees player1's guess and declines to reveal a losing one
+    client.reveal_guess(&session_id, &player1, &5, &test_salt(&env));
+    client.on_session_forfeited(&session_id, &player2);
+
+    let game = client.get_game(&session_id);
+    assert_eq!(game.winner, Some(player1));
+    assert_eq!(
+        game.status,
+        GameStatus::Ended(GameOutcome::Forfeit(player2.clone()))
+    );
+}
+
+#[test]
+fn test_hub_forfeit_rejected_for_waiting_player() {
+    let (env, client, _hub, player1, player2) = setup_test();
+
+    let session_id = 41u32;
+    client.start_game(&session_id, &player1, &player2, &100, &100);
+
+    // Nobody has moved yet, so neither player is holding up the game
+    let result = client.try_on_session_forfeited(&session_id, &player2);
+    assert_eq!(result, Err(Ok(Error::PlayerNotStalling.into())));
+
+    // player1 has moved and is waiting on player2
+    commit_guess(&env, &client, session_id, &player1, 5);
+    let result = client.try_on_session_forfeited(&session_id, &player1);
+    assert_eq!(result, Err(Ok(Error::PlayerNotStalling.into())));
+    assert_eq!(client.get_game(&session_id).status, GameStatus::Active);
+}
+
+#[test]
+fn test_hub_cancels_session() {
+    let (_env, client, _hub, player1, player2) = setup_test();
+
+    let session_id = 42u32;
+    client.start_game(&session_id, &player1, &player2, &100, &100);
+
+    client.on_session_cancelled(&session_id);
+
+    let game = client.get_game(&session_id);
+    assert!(game.winner.is_none());
+    assert_eq!(game.status, GameStatus::Ended(GameOutcome::Cancelled));
+
+    let result = client.try_on_session_cancelled(&session_id);
+    assert_eq!(result, Err(Ok(Error::GameAlreadyEnded.into())));
+}
+
+// ============================================================================
+// Game Interface Tests
+// ============================================================================
+
+#[test]
+fn test_game_info() {
+    let (env, client, _hub, _player1, _player2) = setup_test();
+
+    // Any game can be described through the shared interface client
+    let info = GameContractClient::new(&env, &client.address).game_info();
+    assert_eq!(info.name, String::from_str(&env, "Number Guess"));
+    assert_eq!((info.min_players, info.max_players), (2, 2));
+    assert_eq!(info.stakes, StakeSemantics::WinnerTakesAll);
+}
+
+#[test]
+fn test_session_status() {
+    let (env, client, _hub, player1, player2) = setup_test();
+
+    let session_id = 60u32;
+    assert_eq!(client.get_session_status(&session_id), None);
+
+    client.start_game(&session_id, &player1, &player2, &100, &100);
+    assert_eq!(
+        client.get_session_status(&session_id),
+        Some(SessionStatus::Active)
+    );
+
+    play_guesses(&env, &client, session_id, &player1, 5, &player2, 5);
+    client.reveal_winner(&session_id);
+    assert_eq!(
+        client.get_session_status(&session_id),
+        Some(SessionStatus::Ended(GameOutcome::Draw))
+    );
+}
+
+#[test]
+fn test_forfeit_ends_game_for_opponent() {
+    let (env, client, _hub, player1, player2) = setup_test();
+
+    let session_id = 61u32;
+    client.start_game(&session_id, &player1, &player2, &100, &100);
+    commit_guess(&env, &client, session_id, &player1, 5);
+
+    client.forfeit(&session_id, &player2);
+    assert_eq!(env.auths()[0].0, player2);
+
+    let game = client.get_game(&session_id);
+    assert_eq!(game.winner, Some(player1.clone()));
+    assert_eq!(
+        game.status,
+        GameStatus::Ended(GameOutcome::Forfeit(player2.clone()))
+    );
+
+    let result = client.try_forfeit(&session_id, &player1);
+    assert_eq!(result, Err(Ok(Error::GameAlreadyEnded.into())));
+}
+
+#[test]
+fn test_forfeit_rejects_non_player() {
+    let (env, client, _hub, player1, player2) = setup_test();
+
+    let session_id = 62u32;
+    client.start_game(&session_id, &player1, &player2, &100, &100);
+
+    let outsider = Address::generate(&env);
+    let result = client.try_forfeit(&session_id, &outsider);
+    assert_eq!(result, Err(Ok(Error::NotPlayer.into())));
+    let result = client.try_forfeit(&99u32, &player1);
+    assert_eq!(result, Err(Ok(Error::GameNotFound.into())));
+}
+
+// ============================================================================
+// Pause Tests
+// ============================================================================
+
+#[test]
+fn test_pause_blocks_gameplay() {
+    let (env, client, _hub, player1, player2) = setup_test();
+
+    let session_id = 50u32;
+    client.start_game(&session_id, &player1, &player2, &100, &100);
+
+    client.pause();
+    assert!(client.is_paused());
+
+    let result = client.try_start_game(&51, &player1, &player2, &100, &100);
+    assert_number_guess_error(&result, Error::ContractPaused);
+    let commitment = make_commitment(&env, 5, &player1);
+    let result = client.try_commit_guess(&session_id, &player1, &commitment);
+    assert_number_guess_error(&result, Error::ContractPaused);
+    let result = client.try_reveal_guess(&session_id, &player1, &5, &test_salt(&env));
+    assert_number_guess_error(&result, Error::ContractPaused);
+    let result = client.try_reveal_winner(&session_id);
+    assert_number_guess_error(&result, Error::ContractPaused);
+    let result = client.try_forfeit(&session_id, &player1);
+    assert_eq!(result, Err(Ok(Error::ContractPaused.into())));
+
+    client.unpause();
+    commit_guess(&env, &client, session_id, &player1, 5);
+}
+
+#[test]
+fn test_hub_can_cancel_while_paused() {
+    let (_env, client, _hub, player1, player2) = setup_test();
+
+    let session_id = 52u32;
+    client.start_game(&session_id, &player1, &player2, &100, &100);
+
+    client.pause();
+    client.on_session_cancelled(&session_id);
+    assert_eq!(
+        client.get_game(&session_id).status,
+        GameStatus::Ended(GameOutcome::Cancelled)
+    );
 }
 
 // ============================================================================
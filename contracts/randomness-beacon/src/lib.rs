@@ -0,0 +1,568 @@
+#![no_std]
+
+//! # Randomness Beacon
+//!
+//! Publishes one shared random value per round, so games don't have to seed
+//! their PRNG from inputs that are all known ahead of time.
+//!
+//! Each round runs in two ledger windows:
+//!
+//! 1. **Commit:** participants post `keccak256(secret || participant_address)`
+//!    together with a bond in the beacon's bond token.
+//! 2. **Reveal:** once the commit window has closed, each participant reveals
+//!    their secret and gets their bond back.
+//!
+//! After the reveal window anyone can `finalize` the round. The random value
+//! is `keccak256(round_id || secret...)` over the revealed secrets in commit
+//! order. Bonds of participants who did not reveal are slashed; the admin
+//! withdraws them with `withdraw_penalties`. A round where nobody revealed
+//! fails and publishes no value.
+//!
+//! The value cannot be known before the last secret is revealed, but the last
+//! revealer can still choose not to reveal. The bond is what that costs them,
+//! so it should outweigh anything riding on a single round.
+//!
+//! Games read a round's value with `get_randomness(round_id)` through
+//! `sgs_game_common::RandomnessBeaconClient`.
+
+use sgs_game_common::{require_admin, require_role, CommonError, RandomnessBeacon, Role};
+use soroban_sdk::{
+    contract, contracterror, contractevent, contractimpl, contracttype, token, Address, Bytes,
+    BytesN, Env, IntoVal, TryFromVal, Val, Vec,
+};
+
+// ============================================================================
+// Errors
+// ============================================================================
+
+#[contracterror]
+#[derive(Copy, Clone, Debug, Eq, PartialEq, PartialOrd, Ord)]
+#[repr(u32)]
+pub enum Error {
+    RoundNotFound = 1,
+    CommitWindowClosed = 2,
+    AlreadyCommitted = 3,
+    RoundFull = 4,
+    RevealWindowNotOpen = 5,
+    RevealWindowClosed = 6,
+    NotCommitted = 7,
+    AlreadyRevealed = 8,
+    CommitmentMismatch = 9,
+    RoundNotOver = 10,
+    RoundAlreadyFinalized = 11,
+    RoundNotFinalized = 12,
+    RoundFailed = 13,
+    InvalidConfig = 14,
+    InvalidAmount = 15,
+    InsufficientPenalties = 16,
+}
+
+// ============================================================================
+// Data Types
+// ============================================================================
+
+/// Parameters for new rounds, set by the admin
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct RoundConfig {
+    /// Bond each participant posts on `commit` (in the bond token)
+    pub bond_amount: i128,
+    /// Length of the commit window in ledgers
+    pub commit_ledgers: u32,
+    /// Length of the reveal window in ledgers, starting after the commit window
+    pub reveal_ledgers: u32,
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum RoundStatus {
+    Open,
+    /// Finalized with this random value
+    Finalized(BytesN<32>),
+    /// Finalized without any reveals; no value was published
+    Failed,
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Round {
+    /// Last ledger on which `commit` is accepted
+    pub commit_deadline: u32,
+    /// Last ledger on which `reveal` is accepted
+    pub reveal_deadline: u32,
+    /// Bond posted by each participant, fixed when the round starts
+    pub bond_amount: i128,
+    /// Participants in commit order
+    pub participants: Vec<Address>,
+    pub reveals: u32,
+    pub status: RoundStatus,
+}
+
+/// One participant's entry in a round
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Entry {
+    pub commitment: BytesN<32>,
+    pub secret: Option<BytesN<32>>,
+}
+
+#[contracttype]
+#[derive(Clone)]
+pub enum DataKey {
+    BondToken,
+    Config,
+    NextRoundId,
+    Penalties,
+    Round(u32),
+    Entry(u32, Address),
+}
+
+// ============================================================================
+// Events
+// ============================================================================
+
+#[contractevent]
+pub struct RoundStarted {
+    pub round_id: u32,
+    pub commit_deadline: u32,
+    pub reveal_deadline: u32,
+}
+
+#[contractevent]
+pub struct RoundFinalized {
+    pub round_id: u32,
+    pub status: RoundStatus,
+}
+
+// ============================================================================
+// Limits
+// ============================================================================
+
+/// Most participants a round accepts, so `finalize` stays within budget
+pub const MAX_PARTICIPANTS: u32 = 32;
+
+// ============================================================================
+// Storage TTL Management
+// ============================================================================
+// Rounds and entries live in persistent storage: bonds must never expire
+// while held, and games read published values long after a round ends, so
+// reads extend an entry's TTL as well as writes.
+
+/// ~1 day in ledgers (~5 seconds per ledger)
+const TTL_THRESHOLD_LEDGERS: u32 = 17_280;
+
+/// 30 days in ledgers (~5 seconds per ledger)
+const TTL_EXTEND_LEDGERS: u32 = 518_400;
+
+fn write_persistent<K, V>(env: &Env, key: &K, value: &V)
+where
+    K: IntoVal<Env, Val>,
+    V: IntoVal<Env, Val>,
+{
+    env.storage().persistent().set(key, value);
+    env.storage()
+        .persistent()
+        .extend_ttl(key, TTL_THRESHOLD_LEDGERS, TTL_EXTEND_LEDGERS);
+}
+
+fn read_persistent<K, V>(env: &Env, key: &K) -> Option<V>
+where
+    K: IntoVal<Env, Val>,
+    V: TryFromVal<Env, Val>,
+{
+    let value = env.storage().persistent().get(key);
+    if value.is_some() {
+        env.storage()
+            .persistent()
+            .extend_ttl(key, TTL_THRESHOLD_LEDGERS, TTL_EXTEND_LEDGERS);
+    }
+    value
+}
+
+/// Keep the admin, config, bond token and round counter alive while rounds run
+fn extend_instance_ttl(env: &Env) {
+    env.storage()
+        .instance()
+        .extend_ttl(TTL_THRESHOLD_LEDGERS, TTL_EXTEND_LEDGERS);
+}
+
+fn read_round(env: &Env, round_id: u32) -> Result<Round, Error> {
+    read_persistent(env, &DataKey::Round(round_id)).ok_or(Error::RoundNotFound)
+}
+
+fn bond_token(env: &Env) -> token::Client<'_> {
+    let token_id: Address = env
+        .storage()
+        .instance()
+        .get(&DataKey::BondToken)
+        .expect("Bond token not set");
+    token::Client::new(env, &token_id)
+}
+
+fn validate_config(config: &RoundConfig) -> Result<(), Error> {
+    if config.bond_amount <= 0 || config.commit_ledgers == 0 || config.reveal_ledgers == 0 {
+        return Err(Error::InvalidConfig);
+    }
+    Ok(())
+}
+
+// ============================================================================
+// Commitments
+// ============================================================================
+
+/// Compute a commitment: keccak256(secret || participant_address)
+fn compute_commitment(env: &Env, secret: &BytesN<32>, participant: &Address) -> BytesN<32> {
+    let mut data = Bytes::from(secret.clone());
+    data.append(&participant.to_string().to_bytes());
+    env.crypto().keccak256(&data).into()
+}
+
+// ============================================================================
+// Contract Definition
+// ============================================================================
+
+#[contract]
+pub struct RandomnessBeaconContract;
+
+#[contractimpl]
+impl RandomnessBeaconContract {
+    /// Initialize the beacon with its admin, bond token and round parameters
+    ///
+    /// # Arguments
+    /// * `admin` - Admin address (can change the config and withdraw penalties)
+    /// * `bond_token` - Token contract participants post bonds in
+    /// * `config` - Parameters for new rounds
+    pub fn __constructor(env: Env, admin: Address, bond_token: Address, config: RoundConfig) {
+        validate_config(&config).expect("Invalid round config");
+        sgs_game_common::write_admin(&env, &admin);
+        env.storage()
+            .instance()
+            .set(&DataKey::BondToken, &bond_token);
+        env.storage().instance().set(&DataKey::Config, &config);
+    }
+
+    // ========================================================================
+    // Rounds
+    // ========================================================================
+
+    /// Open a new round. Its commit window starts now. Operator only.
+    ///
+    /// # Arguments
+    /// * `caller` - Address holding `Role::Operator` (or the admin)
+    ///
+    /// # Returns
+    /// * The new round's ID
+    pub fn start_round(env: Env, caller: Address) -> u32 {
+        require_role(&env, Role::Operator, &caller);
+        extend_instance_ttl(&env);
+
+        let config = Self::get_config(env.clone());
+        let round_id: u32 = env
+            .storage()
+            .instance()
+            .get(&DataKey::NextRoundId)
+            .unwrap_or(1);
+        env.storage()
+            .instance()
+            .set(&DataKey::NextRoundId, &(round_id + 1));
+
+        let commit_deadline = env.ledger().sequence() + config.commit_ledgers;
+        let round = Round {
+            commit_deadline,
+            reveal_deadline: commit_deadline + config.reveal_ledgers,
+            bond_amount: config.bond_amount,
+            participants: Vec::new(&env),
+            reveals: 0,
+            status: RoundStatus::Open,
+        };
+        write_persistent(&env, &DataKey::Round(round_id), &round);
+
+        RoundStarted {
+            round_id,
+            commit_deadline: round.commit_deadline,
+            reveal_deadline: round.reveal_deadline,
+        }
+        .publish(&env);
+
+        round_id
+    }
+
+    /// Commit to a secret for a round and post the round's bond.
+    ///
+    /// # Arguments
+    /// * `round_id` - Round to take part in
+    /// * `participant` - Address committing (authorizes the bond transfer)
+    /// * `commitment` - `keccak256(secret || participant_address)`
+    pub fn commit(
+        env: Env,
+        round_id: u32,
+        participant: Address,
+        commitment: BytesN<32>,
+    ) -> Result<(), Error> {
+        participant.require_auth();
+        extend_instance_ttl(&env);
+
+        let mut round = read_round(&env, round_id)?;
+        if env.ledger().sequence() > round.commit_deadline {
+            return Err(Error::CommitWindowClosed);
+        }
+
+        let entry_key = DataKey::Entry(round_id, participant.clone());
+        if env.storage().persistent().has(&entry_key) {
+            return Err(Error::AlreadyCommitted);
+        }
+        if round.participants.len() >= MAX_PARTICIPANTS {
+            return Err(Error::RoundFull);
+        }
+
+        bond_token(&env).transfer(
+            &participant,
+            env.current_contract_address(),
+            &round.bond_amount,
+        );
+
+        round.participants.push_back(participant);
+        write_persistent(&env, &DataKey::Round(round_id), &round);
+        write_persistent(
+            &env,
+            &entry_key,
+            &Entry {
+                commitment,
+                secret: None,
+            },
+        );
+
+        Ok(())
+    }
+
+    /// Reveal the secret behind a commitment and get the bond back.
+    /// Only accepted in the reveal window, after the commit window has closed.
+    ///
+    /// # Arguments
+    /// * `round_id` - Round the commitment was made in
+    /// * `participant` - Address that committed
+    /// * `secret` - The secret behind the commitment
+    pub fn reveal(
+        env: Env,
+        round_id: u32,
+        participant: Address,
+        secret: BytesN<32>,
+    ) -> Result<(), Error> {
+        participant.require_auth();
+        extend_instance_ttl(&env);
+
+        let mut round = read_round(&env, round_id)?;
+        let ledger = env.ledger().sequence();
+        if ledger <= round.commit_deadline {
+            return Err(Error::RevealWindowNotOpen);
+        }
+        if ledger > round.reveal_deadline {
+            return Err(Error::RevealWindowClosed);
+        }
+
+        let entry_key = DataKey::Entry(round_id, participant.clone());
+        let mut entry: Entry = read_persistent(&env, &entry_key).ok_or(Error::NotCommitted)?;
+        if entry.secret.is_some() {
+            return Err(Error::AlreadyRevealed);
+        }
+        if compute_commitment(&env, &secret, &participant) != entry.commitment {
+            return Err(Error::CommitmentMismatch);
+        }
+
+        entry.secret = Some(secret);
+        round.reveals += 1;
+        write_persistent(&env, &entry_key, &entry);
+        write_persistent(&env, &DataKey::Round(round_id), &round);
+
+        bond_token(&env).transfer(
+            &env.current_contract_address(),
+            &participant,
+            &round.bond_amount,
+        );
+
+        Ok(())
+    }
+
+    /// Close a round once its reveal window has passed. Anyone can call this.
+    /// Publishes the random value and slashes the bonds of participants who
+    /// did not reveal; fails the round if nobody revealed.
+    ///
+    /// # Returns
+    /// * The round's final status
+    pub fn finalize(env: Env, round_id: u32) -> Result<RoundStatus, Error> {
+        extend_instance_ttl(&env);
+        let mut round = read_round(&env, round_id)?;
+        if round.status != RoundStatus::Open {
+            return Err(Error::RoundAlreadyFinalized);
+        }
+        if env.ledger().sequence() <= round.reveal_deadline {
+            return Err(Error::RoundNotOver);
+        }
+
+        let mut seed_bytes = Bytes::from_array(&env, &round_id.to_be_bytes());
+        let mut slashed: i128 = 0;
+        for participant in round.participants.iter() {
+            let entry: Entry = read_persistent(&env, &DataKey::Entry(round_id, participant))
+                .expect("Entry not found");
+            match entry.secret {
+                Some(secret) => seed_bytes.append(&secret.into()),
+                None => slashed += round.bond_amount,
+            }
+        }
+
+        if slashed > 0 {
+            let penalties = Self::get_penalties(env.clone());
+            env.storage()
+                .instance()
+                .set(&DataKey::Penalties, &(penalties + slashed));
+        }
+
+        round.status = if round.reveals == 0 {
+            RoundStatus::Failed
+        } else {
+            RoundStatus::Finalized(env.crypto().keccak256(&seed_bytes).into())
+        };
+        write_persistent(&env, &DataKey::Round(round_id), &round);
+
+        RoundFinalized {
+            round_id,
+            status: round.status.clone(),
+        }
+        .publish(&env);
+
+        Ok(round.status)
+    }
+
+    /// Get a round's state
+    pub fn get_round(env: Env, round_id: u32) -> Result<Round, Error> {
+        read_round(&env, round_id)
+    }
+
+    /// Get a participant's entry in a round, if they committed
+    pub fn get_entry(env: Env, round_id: u32, participant: Address) -> Option<Entry> {
+        read_persistent(&env, &DataKey::Entry(round_id, participant))
+    }
+
+    // ========================================================================
+    // Configuration & Penalties
+    // ========================================================================
+
+    /// Get the parameters for new rounds
+    pub fn get_config(env: Env) -> RoundConfig {
+        env.storage()
+            .instance()
+            .get(&DataKey::Config)
+            .expect("Config not set")
+    }
+
+    /// Set the parameters for new rounds. Rounds already started keep theirs.
+    /// Admin only.
+    pub fn set_config(env: Env, config: RoundConfig) -> Result<(), Error> {
+        require_admin(&env);
+        validate_config(&config)?;
+        env.storage().instance().set(&DataKey::Config, &config);
+        Ok(())
+    }
+
+    /// Get the bond token address
+    pub fn get_bond_token(env: Env) -> Address {
+        bond_token(&env).address
+    }
+
+    /// Slashed bonds not yet withdrawn
+    pub fn get_penalties(env: Env) -> i128 {
+        env.storage()
+            .instance()
+            .get(&DataKey::Penalties)
+            .unwrap_or(0)
+    }
+
+    /// Transfer slashed bonds out of the beacon. Admin only.
+    ///
+    /// # Arguments
+    /// * `to` - Recipient of the bond tokens
+    /// * `amount` - Amount to withdraw (must be positive)
+    pub fn withdraw_penalties(env: Env, to: Address, amount: i128) -> Result<(), Error> {
+        require_admin(&env);
+
+        if amount <= 0 {
+            return Err(Error::InvalidAmount);
+        }
+        let penalties = Self::get_penalties(env.clone());
+        if amount > penalties {
+            return Err(Error::InsufficientPenalties);
+        }
+        env.storage()
+            .instance()
+            .set(&DataKey::Penalties, &(penalties - amount));
+
+        bond_token(&env).transfer(&env.current_contract_address(), &to, &amount);
+
+        Ok(())
+    }
+
+    // ========================================================================
+    // Admin Functions
+    // ========================================================================
+
+    /// Get the current admin address
+    pub fn get_admin(env: Env) -> Address {
+        sgs_game_common::read_admin(&env)
+    }
+
+    /// Get the admin proposed by `propose_admin`, if any
+    pub fn get_pending_admin(env: Env) -> Option<Address> {
+        sgs_game_common::read_pending_admin(&env)
+    }
+
+    /// Propose a new admin. They take over once they call `accept_admin`.
+    pub fn propose_admin(env: Env, new_admin: Address) {
+        sgs_game_common::propose_admin(&env, &new_admin);
+    }
+
+    /// Accept a pending admin transfer. Pending admin only.
+    pub fn accept_admin(env: Env) -> Result<(), CommonError> {
+        sgs_game_common::accept_admin(&env)?;
+        Ok(())
+    }
+
+    /// Grant a role to an account (`Role::Operator` starts rounds)
+    pub fn grant_role(env: Env, role: Role, account: Address) {
+        sgs_game_common::grant_role(&env, role, &account);
+    }
+
+    /// Revoke a role from an account
+    pub fn revoke_role(env: Env, role: Role, account: Address) {
+        sgs_game_common::revoke_role(&env, role, &account);
+    }
+
+    /// Check whether an account holds a role (the admin holds all)
+    pub fn has_role(env: Env, role: Role, account: Address) -> bool {
+        sgs_game_common::has_role(&env, role, &account)
+    }
+
+    /// Update the contract WASM hash (upgrade contract)
+    pub fn upgrade(env: Env, new_wasm_hash: BytesN<32>) {
+        require_admin(&env);
+        env.deployer().update_current_contract_wasm(new_wasm_hash);
+    }
+}
+
+// ============================================================================
+// Beacon Interface
+// ============================================================================
+
+#[contractimpl]
+impl RandomnessBeacon for RandomnessBeaconContract {
+    /// Get the random value published for a round
+    fn get_randomness(env: Env, round_id: u32) -> Result<BytesN<32>, soroban_sdk::Error> {
+        match read_round(&env, round_id)?.status {
+            RoundStatus::Open => Err(Error::RoundNotFinalized.into()),
+            RoundStatus::Finalized(value) => Ok(value),
+            RoundStatus::Failed => Err(Error::RoundFailed.into()),
+        }
+    }
+}
+
+#[cfg(test)]
+mod test;
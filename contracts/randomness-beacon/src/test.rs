@@ -0,0 +1,518 @@
+#![cfg(test)]
+
+// Unit tests for the randomness beacon using a Stellar asset contract as the
+// bond token.
+
+use crate::{
+    DataKey, Error, RandomnessBeaconContract, RandomnessBeaconContractClient, RoundConfig,
+    RoundStatus, MAX_PARTICIPANTS, TTL_EXTEND_LEDGERS,
+};
+use sgs_game_common::{RandomnessBeaconClient, Role};
+use soroban_sdk::testutils::storage::{Instance as _, Persistent as _};
+use soroban_sdk::testutils::{Address as _, Ledger as _};
+use soroban_sdk::token::{StellarAssetClient, TokenClient};
+use soroban_sdk::{Address, Bytes, BytesN, Env};
+
+// ============================================================================
+// Test Helpers
+// ============================================================================
+
+const BOND: i128 = 100;
+const COMMIT_LEDGERS: u32 = 10;
+const REVEAL_LEDGERS: u32 = 10;
+
+fn test_config() -> RoundConfig {
+    RoundConfig {
+        bond_amount: BOND,
+        commit_ledgers: COMMIT_LEDGERS,
+        reveal_ledgers: REVEAL_LEDGERS,
+    }
+}
+
+fn setup_test() -> (
+    Env,
+    RandomnessBeaconContractClient<'static>,
+    TokenClient<'static>,
+    Address,
+    Address,
+) {
+    let env = Env::default();
+    // Bond transfers are authorized by the participant below the beacon's
+    // root invocation, as they would be in a real transaction
+    env.mock_all_auths_allowing_non_root_auth();
+    env.ledger().set_sequence_number(100);
+
+    let issuer = Address::generate(&env);
+    let token_id = env.register_stellar_asset_contract_v2(issuer).address();
+
+    let admin = Address::generate(&env);
+    let contract_id = env.register(RandomnessBeaconContract, (&admin, &token_id, test_config()));
+    let client = RandomnessBeaconContractClient::new(&env, &contract_id);
+
+    let participant1 = Address::generate(&env);
+    let participant2 = Address::generate(&env);
+    let asset = StellarAssetClient::new(&env, &token_id);
+    asset.mint(&participant1, &1000);
+    asset.mint(&participant2, &1000);
+    let token = TokenClient::new(&env, &token_id);
+
+    (env, client, token, participant1, participant2)
+}
+
+/// Assert that a Result contains a specific beacon error
+fn assert_beacon_error<T, E>(
+    result: &Result<Result<T, E>, Result<Error, soroban_sdk::InvokeError>>,
+    expected_error: Error,
+) {
+    match result {
+        Err(Ok(actual_error)) => {
+            assert_eq!(
+                *actual_error, expected_error,
+                "Expected error {:?} (code {}), but got {:?} (code {})",
+                expected_error, expected_error as u32, actual_error, *actual_error as u32
+            );
+        }
+        Err(Err(_invoke_error)) => {
+            panic!(
+                "Expected contract error {:?} (code {}), but got invocation error",
+                expected_error, expected_error as u32
+            );
+        }
+        Ok(Err(_conv_error)) => {
+            panic!(
+                "Expected contract error {:?} (code {}), but got conversion error",
+                expected_error, expected_error as u32
+            );
+        }
+        Ok(Ok(_)) => {
+            panic!(
+                "Expected error {:?} (code {}), but operation succeeded",
+                expected_error, expected_error as u32
+            );
+        }
+    }
+}
+
+/// Deterministic per-participant secret for tests
+fn test_secret(env: &Env, participant: &Address) -> BytesN<32> {
+    env.crypto()
+        .keccak256(&participant.to_string().to_bytes())
+        .into()
+}
+
+/// Commitment hash: keccak256(secret || participant_address)
+fn make_commitment(env: &Env, secret: &BytesN<32>, participant: &Address) -> BytesN<32> {
+    let mut data = Bytes::from(secret.clone());
+    data.append(&participant.to_string().to_bytes());
+    env.crypto().keccak256(&data).into()
+}
+
+/// Commit `participant`'s test secret to a round
+fn commit(
+    env: &Env,
+    client: &RandomnessBeaconContractClient,
+    round_id: u32,
+    participant: &Address,
+) {
+    let commitment = make_commitment(env, &test_secret(env, participant), participant);
+    client.commit(&round_id, participant, &commitment);
+}
+
+/// Reveal `participant`'s test secret for a round
+fn reveal(
+    env: &Env,
+    client: &RandomnessBeaconContractClient,
+    round_id: u32,
+    participant: &Address,
+) {
+    client.reveal(&round_id, participant, &test_secret(env, participant));
+}
+
+fn advance_ledgers(env: &Env, ledgers: u32) {
+    let sequence = env.ledger().sequence();
+    env.ledger().set_sequence_number(sequence + ledgers);
+}
+
+/// Start a round with the admin as operator
+fn start_round(client: &RandomnessBeaconContractClient) -> u32 {
+    client.start_round(&client.get_admin())
+}
+
+// ============================================================================
+// Round Flow Tests
+// ============================================================================
+
+#[test]
+fn test_round_publishes_randomness() {
+    let (env, client, token, participant1, participant2) = setup_test();
+
+    let round_id = start_round(&client);
+    assert_eq!(round_id, 1);
+    commit(&env, &client, round_id, &participant1);
+    commit(&env, &client, round_id, &participant2);
+    assert_eq!(token.balance(&participant1), 1000 - BOND);
+    assert_eq!(token.balance(&client.address), 2 * BOND);
+
+    advance_ledgers(&env, COMMIT_LEDGERS + 1);
+    reveal(&env, &client, round_id, &participant1);
+    reveal(&env, &client, round_id, &participant2);
+    assert_eq!(token.balance(&participant1), 1000);
+    assert_eq!(token.balance(&participant2), 1000);
+
+    advance_ledgers(&env, REVEAL_LEDGERS);
+    let status = client.finalize(&round_id);
+
+    // keccak256(round_id || secret1 || secret2), secrets in commit order
+    let mut seed_bytes = Bytes::from_array(&env, &round_id.to_be_bytes());
+    seed_bytes.append(&test_secret(&env, &participant1).into());
+    seed_bytes.append(&test_secret(&env, &participant2).into());
+    let expected: BytesN<32> = env.crypto().keccak256(&seed_bytes).into();
+
+    assert_eq!(status, RoundStatus::Finalized(expected.clone()));
+    assert_eq!(client.get_randomness(&round_id), expected);
+    assert_eq!(client.get_penalties(), 0);
+}
+
+#[test]
+fn test_games_read_randomness_through_client() {
+    let (env, client, _token, participant1, _participant2) = setup_test();
+    let beacon = RandomnessBeaconClient::new(&env, &client.address);
+
+    let round_id = start_round(&client);
+    commit(&env, &client, round_id, &participant1);
+
+    let result = beacon.try_get_randomness(&round_id);
+    assert_eq!(result, Err(Ok(Error::RoundNotFinalized.into())));
+    let result = beacon.try_get_randomness(&99);
+    assert_eq!(result, Err(Ok(Error::RoundNotFound.into())));
+
+    advance_ledgers(&env, COMMIT_LEDGERS + 1);
+    reveal(&env, &client, round_id, &participant1);
+    advance_ledgers(&env, REVEAL_LEDGERS);
+    client.finalize(&round_id);
+
+    assert_eq!(
+        beacon.get_randomness(&round_id),
+        client.get_randomness(&round_id)
+    );
+}
+
+#[test]
+fn test_rounds_get_distinct_values() {
+    let (env, client, _token, participant1, _participant2) = setup_test();
+
+    // Same participant and secret in both rounds
+    let round1 = start_round(&client);
+    let round2 = start_round(&client);
+    commit(&env, &client, round1, &participant1);
+    commit(&env, &client, round2, &participant1);
+
+    advance_ledgers(&env, COMMIT_LEDGERS + 1);
+    reveal(&env, &client, round1, &participant1);
+    reveal(&env, &client, round2, &participant1);
+    advance_ledgers(&env, REVEAL_LEDGERS);
+    client.finalize(&round1);
+    client.finalize(&round2);
+
+    assert_ne!(
+        client.get_randomness(&round1),
+        client.get_randomness(&round2)
+    );
+}
+
+// ============================================================================
+// Window Tests
+// ============================================================================
+
+#[test]
+fn test_commit_window_closes() {
+    let (env, client, _token, participant1, participant2) = setup_test();
+
+    let round_id = start_round(&client);
+    advance_ledgers(&env, COMMIT_LEDGERS);
+    commit(&env, &client, round_id, &participant1);
+
+    advance_ledgers(&env, 1);
+    let commitment = make_commitment(&env, &test_secret(&env, &participant2), &participant2);
+    let result = client.try_commit(&round_id, &participant2, &commitment);
+    assert_beacon_error(&result, Error::CommitWindowClosed);
+}
+
+#[test]
+fn test_reveal_only_in_reveal_window() {
+    let (env, client, _token, participant1, participant2) = setup_test();
+
+    let round_id = start_round(&client);
+    commit(&env, &client, round_id, &participant1);
+    commit(&env, &client, round_id, &participant2);
+
+    // Revealing during the commit window would let later committers react
+    let secret1 = test_secret(&env, &participant1);
+    let result = client.try_reveal(&round_id, &participant1, &secret1);
+    assert_beacon_error(&result, Error::RevealWindowNotOpen);
+
+    advance_ledgers(&env, COMMIT_LEDGERS + 1);
+    reveal(&env, &client, round_id, &participant1);
+
+    advance_ledgers(&env, REVEAL_LEDGERS);
+    let secret2 = test_secret(&env, &participant2);
+    let result = client.try_reveal(&round_id, &participant2, &secret2);
+    assert_beacon_error(&result, Error::RevealWindowClosed);
+}
+
+#[test]
+fn test_finalize_only_after_reveal_window() {
+    let (env, client, _token, participant1, _participant2) = setup_test();
+
+    let round_id = start_round(&client);
+    commit(&env, &client, round_id, &participant1);
+    advance_ledgers(&env, COMMIT_LEDGERS + 1);
+    reveal(&env, &client, round_id, &participant1);
+
+    let result = client.try_finalize(&round_id);
+    assert_beacon_error(&result, Error::RoundNotOver);
+    let result = client.try_get_randomness(&round_id);
+    assert_eq!(result, Err(Ok(Error::RoundNotFinalized.into())));
+
+    advance_ledgers(&env, REVEAL_LEDGERS);
+    client.finalize(&round_id);
+    let result = client.try_finalize(&round_id);
+    assert_beacon_error(&result, Error::RoundAlreadyFinalized);
+}
+
+// ============================================================================
+// Commitment Tests
+// ============================================================================
+
+#[test]
+fn test_reveal_must_match_commitment() {
+    let (env, client, _token, participant1, participant2) = setup_test();
+
+    let round_id = start_round(&client);
+    commit(&env, &client, round_id, &participant1);
+    let result = client.try_commit(
+        &round_id,
+        &participant1,
+        &make_commitment(&env, &test_secret(&env, &participant1), &participant1),
+    );
+    assert_beacon_error(&result, Error::AlreadyCommitted);
+
+    advance_ledgers(&env, COMMIT_LEDGERS + 1);
+
+    let other_secret = BytesN::from_array(&env, &[7u8; 32]);
+    let result = client.try_reveal(&round_id, &participant1, &other_secret);
+    assert_beacon_error(&result, Error::CommitmentMismatch);
+
+    let result = client.try_reveal(&round_id, &participant2, &other_secret);
+    assert_beacon_error(&result, Error::NotCommitted);
+
+    reveal(&env, &client, round_id, &participant1);
+    let secret1 = test_secret(&env, &participant1);
+    let result = client.try_reveal(&round_id, &participant1, &secret1);
+    assert_beacon_error(&result, Error::AlreadyRevealed);
+}
+
+#[test]
+fn test_round_full() {
+    let (env, client, token, _participant1, _participant2) = setup_test();
+    let asset = StellarAssetClient::new(&env, &token.address);
+
+    let round_id = start_round(&client);
+    for _ in 0..MAX_PARTICIPANTS {
+        let participant = Address::generate(&env);
+        asset.mint(&participant, &BOND);
+        commit(&env, &client, round_id, &participant);
+    }
+
+    let late = Address::generate(&env);
+    asset.mint(&late, &BOND);
+    let commitment = make_commitment(&env, &test_secret(&env, &late), &late);
+    let result = client.try_commit(&round_id, &late, &commitment);
+    assert_beacon_error(&result, Error::RoundFull);
+}
+
+// ============================================================================
+// Penalty Tests
+// ============================================================================
+
+#[test]
+fn test_non_revealer_bond_is_slashed() {
+    let (env, client, token, participant1, participant2) = setup_test();
+
+    let round_id = start_round(&client);
+    commit(&env, &client, round_id, &participant1);
+    commit(&env, &client, round_id, &participant2);
+
+    advance_ledgers(&env, COMMIT_LEDGERS + 1);
+    reveal(&env, &client, round_id, &participant1);
+    advance_ledgers(&env, REVEAL_LEDGERS);
+
+    // Only participant1's secret goes into the value
+    let mut seed_bytes = Bytes::from_array(&env, &round_id.to_be_bytes());
+    seed_bytes.append(&test_secret(&env, &participant1).into());
+    let expected: BytesN<32> = env.crypto().keccak256(&seed_bytes).into();
+    assert_eq!(client.finalize(&round_id), RoundStatus::Finalized(expected));
+
+    assert_eq!(token.balance(&participant1), 1000);
+    assert_eq!(token.balance(&participant2), 1000 - BOND);
+    assert_eq!(client.get_penalties(), BOND);
+    assert_eq!(token.balance(&client.address), BOND);
+}
+
+#[test]
+fn test_round_fails_without_reveals() {
+    let (env, client, _token, participant1, _participant2) = setup_test();
+
+    let round_id = start_round(&client);
+    commit(&env, &client, round_id, &participant1);
+    advance_ledgers(&env, COMMIT_LEDGERS + REVEAL_LEDGERS + 1);
+
+    assert_eq!(client.finalize(&round_id), RoundStatus::Failed);
+    assert_eq!(client.get_penalties(), BOND);
+    let result = client.try_get_randomness(&round_id);
+    assert_eq!(result, Err(Ok(Error::RoundFailed.into())));
+}
+
+#[test]
+fn test_withdraw_penalties() {
+    let (env, client, token, participant1, _participant2) = setup_test();
+
+    let round_id = start_round(&client);
+    commit(&env, &client, round_id, &participant1);
+    advance_ledgers(&env, COMMIT_LEDGERS + REVEAL_LEDGERS + 1);
+    client.finalize(&round_id);
+
+    let treasury = Address::generate(&env);
+    let result = client.try_withdraw_penalties(&treasury, &0);
+    assert_beacon_error(&result, Error::InvalidAmount);
+    let result = client.try_withdraw_penalties(&treasury, &(BOND + 1));
+    assert_beacon_error(&result, Error::InsufficientPenalties);
+
+    client.withdraw_penalties(&treasury, &BOND);
+    assert_eq!(token.balance(&treasury), BOND);
+    assert_eq!(client.get_penalties(), 0);
+}
+
+// ============================================================================
+// Admin Function Tests
+// ============================================================================
+
+#[test]
+fn test_operator_starts_rounds() {
+    let (env, client, _token, _participant1, _participant2) = setup_test();
+
+    let operator = Address::generate(&env);
+    let result = client.try_start_round(&operator);
+    assert!(result.is_err());
+
+    client.grant_role(&Role::Operator, &operator);
+    assert_eq!(client.start_round(&operator), 1);
+
+    client.revoke_role(&Role::Operator, &operator);
+    let result = client.try_start_round(&operator);
+    assert!(result.is_err());
+}
+
+#[test]
+fn test_config_applies_to_new_rounds() {
+    let (env, client, _token, _participant1, _participant2) = setup_test();
+
+    let round1 = start_round(&client);
+
+    let config = RoundConfig {
+        bond_amount: 50,
+        commit_ledgers: 5,
+        reveal_ledgers: 20,
+    };
+    client.set_config(&config);
+    assert_eq!(client.get_config(), config);
+
+    let round2 = start_round(&client);
+    let sequence = env.ledger().sequence();
+
+    let round = client.get_round(&round1);
+    assert_eq!(round.bond_amount, BOND);
+    assert_eq!(round.commit_deadline, sequence + COMMIT_LEDGERS);
+
+    let round = client.get_round(&round2);
+    assert_eq!(round.bond_amount, 50);
+    assert_eq!(round.commit_deadline, sequence + 5);
+    assert_eq!(round.reveal_deadline, sequence + 25);
+
+    let result = client.try_set_config(&RoundConfig {
+        bond_amount: 0,
+        commit_ledgers: 5,
+        reveal_ledgers: 5,
+    });
+    assert_beacon_error(&result, Error::InvalidConfig);
+}
+
+#[test]
+fn test_admin_transfer_requires_accept() {
+    let (env, client, _token, _participant1, _participant2) = setup_test();
+
+    let admin = client.get_admin();
+    let new_admin = Address::generate(&env);
+
+    client.propose_admin(&new_admin);
+    assert_eq!(client.get_admin(), admin);
+    assert_eq!(client.get_pending_admin(), Some(new_admin.clone()));
+
+    client.accept_admin();
+    assert_eq!(client.get_admin(), new_admin);
+}
+
+// ============================================================================
+// Storage TTL Tests
+// ============================================================================
+
+#[test]
+fn test_reads_extend_round_ttl() {
+    let (env, client, _token, participant1, _participant2) = setup_test();
+
+    let round_id = start_round(&client);
+    commit(&env, &client, round_id, &participant1);
+    advance_ledgers(&env, COMMIT_LEDGERS + 1);
+    reveal(&env, &client, round_id, &participant1);
+    advance_ledgers(&env, REVEAL_LEDGERS);
+    client.finalize(&round_id);
+
+    let round_key = DataKey::Round(round_id);
+    let entry_key = DataKey::Entry(round_id, participant1.clone());
+    let ttl = |key: &DataKey| {
+        env.as_contract(&client.address, || env.storage().persistent().get_ttl(key))
+    };
+
+    // Nothing writes to a finished round; games reading its value keep it alive
+    advance_ledgers(&env, ttl(&round_key) - 100);
+    assert_eq!(ttl(&round_key), 100);
+    client.get_randomness(&round_id);
+    assert_eq!(ttl(&round_key), TTL_EXTEND_LEDGERS);
+
+    assert!(ttl(&entry_key) <= 100);
+    client.get_entry(&round_id, &participant1);
+    assert_eq!(ttl(&entry_key), TTL_EXTEND_LEDGERS);
+
+    advance_ledgers(&env, TTL_EXTEND_LEDGERS - 100);
+    client.get_round(&round_id);
+    assert_eq!(ttl(&round_key), TTL_EXTEND_LEDGERS);
+}
+
+#[test]
+fn test_round_activity_extends_instance_ttl() {
+    let (env, client, _token, participant1, _participant2) = setup_test();
+    let instance_ttl = || env.as_contract(&client.address, || env.storage().instance().get_ttl());
+
+    let round_id = start_round(&client);
+    assert_eq!(instance_ttl(), TTL_EXTEND_LEDGERS);
+
+    commit(&env, &client, round_id, &participant1);
+    advance_ledgers(&env, COMMIT_LEDGERS + 1);
+    reveal(&env, &client, round_id, &participant1);
+    advance_ledgers(&env, REVEAL_LEDGERS);
+
+    // Admin, config and bond token live in instance storage; finalizing keeps them alive
+    advance_ledgers(&env, instance_ttl() - 100);
+    client.finalize(&round_id);
+    assert_eq!(instance_ttl(), TTL_EXTEND_LEDGERS);
+}
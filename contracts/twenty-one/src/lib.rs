@@ -5,32 +5,45 @@
 //! A two-player card game where players try to get as close to 21 as possible without going over.
 //! Each player is dealt 2 cards and can choose to "hit" (draw another card) or "stick" (end their turn).
 //! Cards are valued 1-13 where Ace=1, 2-10=face value, Jack/Queen/King=10.
+//! Equal hand values at the reveal are reported to the Game Hub as a draw.
+//!
+//! Cards come from a deck seed both players contribute to. At `start_game`
+//! each player commits to `keccak256(seed || hand_commitment || player_address)`,
+//! where `hand_commitment = keccak256(hand_secret || player_address)` binds a
+//! second, private secret. Once both have revealed their seed and hand
+//! commitment with `reveal_seed`, the deck seed is
+//! `keccak256(session_id || seed1 || seed2)`. Every card also gets 32 bytes of
+//! entropy from the contract's PRNG when it is dealt (the opening cards when
+//! the second seed is revealed, later ones by `hit`), so a player's card `i`
+//! is dealt from `card_seed = keccak256(deck_seed || hand_secret || i || entropy_i)`
+//! as `u64::from_be_bytes(card_seed[0..8]) % 13 + 1`.
+//! Nobody knows a card's entropy before the transaction dealing it is applied,
+//! so knowing both seeds and their own hand secret does not tell a player
+//! whether their next `hit` busts.
+//!
+//! Hands stay hidden while play continues: the contract only records the
+//! entropy of each card drawn, and each player computes their own cards
+//! off-chain from `get_game` and their hand secret, which never leaves their
+//! device before `reveal_hand`. Once both
+//! players have stuck, each reveals their hand secret and card list with
+//! `reveal_hand`; the contract re-derives the cards and rejects any list that
+//! does not match. `reveal_winner` then settles the game. A hand over 21 is
+//! bust and loses to any other hand; two bust hands are a draw.
 //!
 //! **Game Hub Integration:**
 //! This game is Game Hub-aware and enforces all games to be played through the
 //! Game Hub contract. Games cannot be started or completed without points involvement.
 
+use sgs_game_common::{
+    hub_client, require_not_paused, write_game, GameCallbacks, GameContract, GameInfo,
+    SessionStatus, StakeSemantics,
+};
 use soroban_sdk::{
-    Address, Bytes, BytesN, Env, IntoVal, contract, contractclient, contracterror,
-    contractimpl, contracttype, vec
+    Address, Bytes, BytesN, Env, IntoVal, String, Vec, contract, contracterror, contractimpl,
+    contracttype, vec
 };
 
-// Import GameHub contract interface
-// This allows us to call into the GameHub contract
-#[contractclient(name = "GameHubClient")]
-pub trait GameHub {
-    fn start_game(
-        env: Env,
-        game_id: Address,
-        session_id: u32,
-        player1: Address,
-        player2: Address,
-        player1_points: i128,
-        player2_points: i128,
-    );
-
-    fn end_game(env: Env, session_id: u32, player1_won: bool);
-}
+pub use sgs_game_common::GameOutcome;
 
 // ============================================================================
 // Errors
@@ -44,13 +57,17 @@ pub enum Error {
     NotPlayer = 2,
     AlreadyStuck = 3,
     GameAlreadyEnded = 4,
-    PlayerBusted = 5,
     BothPlayersNotStuck = 6,
-    OpponentNotStuck = 7,
-    Draw = 8,
     SelfPlay = 9,
-    RoundOverflow = 10,
     InvalidHandData = 11,
+    PlayerNotStalling = 12,
+    ContractPaused = 13,
+    SessionAlreadyExists = 14,
+    DeckNotReady = 15,
+    AlreadyRevealed = 16,
+    CommitmentMismatch = 17,
+    HandNotRevealed = 18,
+    HandMismatch = 19,
 }
 
 // ============================================================================
@@ -68,6 +85,13 @@ pub enum Error {
 // Data Types
 // ============================================================================
 
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum GameStatus {
+    Active,
+    Ended(GameOutcome),
+}
+
 #[contracttype]
 #[derive(Clone, Debug, Eq, PartialEq)]
 pub struct Game {
@@ -75,32 +99,33 @@ pub struct Game {
     pub player2: Address,
     pub player1_points: i128,
     pub player2_points: i128,
-    pub player1_hand: Bytes,  // Each byte represents a card (1-13)
-    pub player2_hand: Bytes,  // Each byte represents a card (1-13)
+    pub player1_hand: Bytes,  // Each byte represents a card (1-13); empty until revealed
+    pub player2_hand: Bytes,  // Each byte represents a card (1-13); empty until revealed
     pub player1_stuck: bool,
     pub player2_stuck: bool,
+    /// Commitments to each player's deck seed, given at `start_game`
+    pub player1_seed_commitment: BytesN<32>,
+    pub player2_seed_commitment: BytesN<32>,
+    /// Revealed deck seeds, set by `reveal_seed`
+    pub player1_seed: Option<BytesN<32>>,
+    pub player2_seed: Option<BytesN<32>>,
+    /// Commitments to each player's hand secret, set by `reveal_seed`
+    pub player1_hand_commitment: Option<BytesN<32>>,
+    pub player2_hand_commitment: Option<BytesN<32>>,
+    /// Entropy drawn for each card a player has been dealt, in draw order;
+    /// the cards stay hidden until `reveal_hand`
+    pub player1_draws: Vec<BytesN<32>>,
+    pub player2_draws: Vec<BytesN<32>>,
     pub winner: Option<Address>,
-    pub round: u32,
+    pub status: GameStatus,
 }
 
 #[contracttype]
 #[derive(Clone)]
 pub enum DataKey {
     Game(u32),
-    GameHubAddress,
-    Admin,
 }
 
-// ============================================================================
-// Storage TTL Management
-// ============================================================================
-// TTL (Time To Live) ensures game data doesn't expire unexpectedly
-// Games are stored in temporary storage with a minimum 30-day retention
-
-/// TTL for game storage (30 days in ledgers, ~5 seconds per ledger)
-/// 30 days = 30 * 24 * 60 * 60 / 5 = 518,400 ledgers
-const GAME_TTL_LEDGERS: u32 = 518_400;
-
 // ============================================================================
 // Helper Functions
 // ============================================================================
@@ -127,11 +152,97 @@ fn calculate_hand_value(hand: &Bytes) -> Result<u32, Error> {
     Ok(total)
 }
 
-/// Deal a card (1-13) using deterministic PRNG
-/// The seed is passed in (as Hash from keccak256)
-fn deal_card(env: &Env, seed: BytesN<32>) -> u8 {
-    env.prng().seed(seed.into());
-    env.prng().gen_range::<u64>(1..=13) as u8
+/// Deal a card (1-13) from a card seed: its first 8 bytes as a big-endian
+/// u64, mod 13, plus 1. Plain arithmetic, so clients can derive their own
+/// cards off-chain.
+fn deal_card(card_seed: &BytesN<32>) -> u8 {
+    let mut word = [0u8; 8];
+    word.copy_from_slice(&card_seed.to_array()[..8]);
+    (u64::from_be_bytes(word) % 13 + 1) as u8
+}
+
+/// Compute a seed commitment: keccak256(seed || hand_commitment || player_address)
+fn compute_seed_commitment(
+    env: &Env,
+    seed: &BytesN<32>,
+    hand_commitment: &BytesN<32>,
+    player: &Address,
+) -> BytesN<32> {
+    let mut data = Bytes::from(seed.clone());
+    data.append(&hand_commitment.clone().into());
+    data.append(&player.to_string().to_bytes());
+    env.crypto().keccak256(&data).into()
+}
+
+/// Compute a hand commitment: keccak256(hand_secret || player_address)
+fn compute_hand_commitment(env: &Env, hand_secret: &BytesN<32>, player: &Address) -> BytesN<32> {
+    let mut data = Bytes::from(hand_secret.clone());
+    data.append(&player.to_string().to_bytes());
+    env.crypto().keccak256(&data).into()
+}
+
+/// Deck seed for a game: keccak256(session_id || player1_seed || player2_seed),
+/// or `DeckNotReady` until both seeds are revealed
+fn deck_seed(env: &Env, session_id: u32, game: &Game) -> Result<BytesN<32>, Error> {
+    let seed1 = game.player1_seed.clone().ok_or(Error::DeckNotReady)?;
+    let seed2 = game.player2_seed.clone().ok_or(Error::DeckNotReady)?;
+
+    let mut seed_bytes = Bytes::from_array(env, &session_id.to_be_bytes());
+    seed_bytes.append(&seed1.into());
+    seed_bytes.append(&seed2.into());
+    Ok(env.crypto().keccak256(&seed_bytes).into())
+}
+
+/// Derive a player's hand from their hand secret.
+/// Card `i` is drawn from keccak256(deck_seed || hand_secret || i || draws[i]).
+fn derive_hand(
+    env: &Env,
+    deck_seed: &BytesN<32>,
+    hand_secret: &BytesN<32>,
+    draws: &Vec<BytesN<32>>,
+) -> Bytes {
+    let mut hand = Bytes::new(env);
+    for (i, entropy) in draws.iter().enumerate() {
+        let mut card_seed_bytes = Bytes::from(deck_seed.clone());
+        card_seed_bytes.append(&hand_secret.clone().into());
+        card_seed_bytes.append(&Bytes::from_array(env, &(i as u32).to_be_bytes()));
+        card_seed_bytes.append(&entropy.into());
+        let card_seed = env.crypto().keccak256(&card_seed_bytes);
+        hand.push_back(deal_card(&card_seed.into()));
+    }
+    hand
+}
+
+/// Deal a card into a hidden hand: record fresh entropy from the PRNG, which
+/// is not known until the dealing transaction is applied
+fn draw(env: &Env, draws: &mut Vec<BytesN<32>>) {
+    draws.push_back(env.prng().gen());
+}
+
+/// Check `hand_secret` against `player`'s hand commitment and derive the
+/// cards they have drawn so far
+fn read_hand(
+    env: &Env,
+    session_id: u32,
+    game: &Game,
+    player: &Address,
+    hand_secret: &BytesN<32>,
+) -> Result<Bytes, Error> {
+    let deck_seed = deck_seed(env, session_id, game)?;
+
+    let (hand_commitment, draws) = if *player == game.player1 {
+        (&game.player1_hand_commitment, &game.player1_draws)
+    } else if *player == game.player2 {
+        (&game.player2_hand_commitment, &game.player2_draws)
+    } else {
+        return Err(Error::NotPlayer);
+    };
+
+    if hand_commitment.as_ref() != Some(&compute_hand_commitment(env, hand_secret, player)) {
+        return Err(Error::CommitmentMismatch);
+    }
+
+    Ok(derive_hand(env, &deck_seed, hand_secret, draws))
 }
 
 // ============================================================================
@@ -150,25 +261,26 @@ impl TwentyOneContract {
     /// * `game_hub` - Address of the GameHub contract
     pub fn __constructor(env: Env, admin: Address, game_hub: Address) {
         // Store admin and GameHub address
-        env.storage().instance().set(&DataKey::Admin, &admin);
-        env.storage()
-            .instance()
-            .set(&DataKey::GameHubAddress, &game_hub);
+        sgs_game_common::init(&env, &admin, &game_hub);
     }
 
     /// Start a new game between two players with points.
     /// This creates a session in the Game Hub and locks points before starting the game.
-    /// Each player is dealt 2 cards to start.
+    /// Each player commits to a deck seed and a hand commitment; the opening
+    /// 2 cards each are dealt once both are revealed with `reveal_seed`.
     ///
     /// **CRITICAL:** This method requires authorization from THIS contract (not players).
     /// The Game Hub will call `game_id.require_auth()` which checks this contract's address.
     ///
     /// # Arguments
-    /// * `session_id` - Unique session identifier (u32)
+    /// * `session_id` - Session ID reserved on the Game Hub (`reserve_session`)
     /// * `player1` - Address of first player
     /// * `player2` - Address of second player
     /// * `player1_points` - Points amount committed by player 1
     /// * `player2_points` - Points amount committed by player 2
+    /// * `player1_seed_commitment` - `keccak256(seed || hand_commitment || player1_address)`
+    /// * `player2_seed_commitment` - `keccak256(seed || hand_commitment || player2_address)`
+    #[allow(clippy::too_many_arguments)]
     pub fn start_game(
         env: Env,
         session_id: u32,
@@ -176,25 +288,37 @@ impl TwentyOneContract {
         player2: Address,
         player1_points: i128,
         player2_points: i128,
+        player1_seed_commitment: BytesN<32>,
+        player2_seed_commitment: BytesN<32>,
     ) -> Result<(), Error> {
+        require_not_paused(&env, Error::ContractPaused)?;
+
         // Prevent self-play: Player 1 and Player 2 must be different
         if player1 == player2 {
             return Err(Error::SelfPlay);
         }
 
-        // Require authentication from both players (they consent to committing points)
-        player1.require_auth_for_args(vec![&env, session_id.into_val(&env), player1_points.into_val(&env)]);
-        player2.require_auth_for_args(vec![&env, session_id.into_val(&env), player2_points.into_val(&env)]);
-
-        // Get GameHub address
-        let game_hub_addr: Address = env
-            .storage()
-            .instance()
-            .get(&DataKey::GameHubAddress)
-            .expect("GameHub address not set");
+        // Require authentication from both players (they consent to committing
+        // points and to their own seed commitment)
+        player1.require_auth_for_args(vec![
+            &env,
+            session_id.into_val(&env),
+            player1_points.into_val(&env),
+            player1_seed_commitment.into_val(&env),
+        ]);
+        player2.require_auth_for_args(vec![
+            &env,
+            session_id.into_val(&env),
+            player2_points.into_val(&env),
+            player2_seed_commitment.into_val(&env),
+        ]);
+
+        if env.storage().temporary().has(&DataKey::Game(session_id)) {
+            return Err(Error::SessionAlreadyExists);
+        }
 
         // Create GameHub client
-        let game_hub = GameHubClient::new(&env, &game_hub_addr);
+        let game_hub = hub_client(&env);
 
         // Call the Game Hub to start the session and lock points
         // This requires THIS contract's authorization (env.current_contract_address())
@@ -207,77 +331,120 @@ impl TwentyOneContract {
             &player2_points,
         );
 
-        // Generate deterministic seed for card dealing
-        // Seed components (all deterministic and identical between sim/submit):
-        // 1. Session ID - unique per game
-        // 2. Player addresses - both players contribute
-        //
-        // Note: We do NOT include ledger sequence or timestamp because those differ
-        // between simulation and submission.
-        let mut seed_bytes = Bytes::new(&env);
-        seed_bytes.append(&Bytes::from_array(&env, &session_id.to_be_bytes()));
-        seed_bytes.append(&player1.to_string().to_bytes());
-        seed_bytes.append(&player2.to_string().to_bytes());
-        let base_seed = env.crypto().keccak256(&seed_bytes);
-
-        // Deal initial hands (2 cards each)
-        // Use different seeds for each card to ensure variety
-        let mut player1_hand = Bytes::new(&env);
-        let mut player2_hand = Bytes::new(&env);
-
-        // Deal 2 cards to player1
-        for i in 0..2 {
-            let mut card_seed_bytes = Bytes::new(&env);
-            card_seed_bytes.append(&Bytes::from(base_seed.clone()));
-            card_seed_bytes.append(&Bytes::from_array(&env, &[i, 1])); // [card_index, player]
-            let card_seed = env.crypto().keccak256(&card_seed_bytes);
-            player1_hand.push_back(deal_card(&env, card_seed.into()));
-        }
-
-        // Deal 2 cards to player2
-        for i in 0..2 {
-            let mut card_seed_bytes = Bytes::new(&env);
-            card_seed_bytes.append(&Bytes::from(base_seed.clone()));
-            card_seed_bytes.append(&Bytes::from_array(&env, &[i, 2])); // [card_index, player]
-            let card_seed = env.crypto().keccak256(&card_seed_bytes);
-            player2_hand.push_back(deal_card(&env, card_seed.into()));
-        }
-
+        // Hands stay empty until both deck seeds are revealed
         // Create game
         let game = Game {
             player1: player1.clone(),
             player2: player2.clone(),
             player1_points,
             player2_points,
-            player1_hand,
-            player2_hand,
+            player1_hand: Bytes::new(&env),
+            player2_hand: Bytes::new(&env),
             player1_stuck: false,
             player2_stuck: false,
+            player1_seed_commitment,
+            player2_seed_commitment,
+            player1_seed: None,
+            player2_seed: None,
+            player1_hand_commitment: None,
+            player2_hand_commitment: None,
+            player1_draws: Vec::new(&env),
+            player2_draws: Vec::new(&env),
             winner: None,
-            round: 1,
+            status: GameStatus::Active,
         };
 
-        // Store game in temporary storage with 30-day TTL
-        let game_key = DataKey::Game(session_id);
-        env.storage().temporary().set(&game_key, &game);
+        // Store game in temporary storage, retained for at least 30 days
+        write_game(&env, &DataKey::Game(session_id), &game);
 
-        // Set TTL to ensure game is retained for at least 30 days
-        env.storage()
+        // Event emitted by GameHub contract (GameStarted)
+
+        Ok(())
+    }
+
+    /// Reveal the deck seed and hand commitment committed at `start_game`.
+    /// Once both seeds are revealed, each player is dealt 2 hidden cards.
+    ///
+    /// # Arguments
+    /// * `session_id` - The session ID of the game
+    /// * `player` - Address of the player revealing
+    /// * `seed` - The seed behind the player's commitment
+    /// * `hand_commitment` - `keccak256(hand_secret || player_address)`
+    pub fn reveal_seed(
+        env: Env,
+        session_id: u32,
+        player: Address,
+        seed: BytesN<32>,
+        hand_commitment: BytesN<32>,
+    ) -> Result<(), Error> {
+        require_not_paused(&env, Error::ContractPaused)?;
+
+        player.require_auth();
+
+        // Get game from temporary storage
+        let key = DataKey::Game(session_id);
+        let mut game: Game = env
+            .storage()
             .temporary()
-            .extend_ttl(&game_key, GAME_TTL_LEDGERS, GAME_TTL_LEDGERS);
+            .get(&key)
+            .ok_or(Error::GameNotFound)?;
 
-        // Event emitted by GameHub contract (GameStarted)
+        // Check game is still active
+        if game.status != GameStatus::Active {
+            return Err(Error::GameAlreadyEnded);
+        }
+
+        let expected = compute_seed_commitment(&env, &seed, &hand_commitment, &player);
+
+        // Check the seed against the appropriate player's commitment
+        if player == game.player1 {
+            if game.player1_seed.is_some() {
+                return Err(Error::AlreadyRevealed);
+            }
+            if expected != game.player1_seed_commitment {
+                return Err(Error::CommitmentMismatch);
+            }
+            game.player1_seed = Some(seed);
+            game.player1_hand_commitment = Some(hand_commitment);
+        } else if player == game.player2 {
+            if game.player2_seed.is_some() {
+                return Err(Error::AlreadyRevealed);
+            }
+            if expected != game.player2_seed_commitment {
+                return Err(Error::CommitmentMismatch);
+            }
+            game.player2_seed = Some(seed);
+            game.player2_hand_commitment = Some(hand_commitment);
+        } else {
+            return Err(Error::NotPlayer);
+        }
+
+        // Deal initial hands (2 cards each) once both seeds are in
+        if game.player1_seed.is_some() && game.player2_seed.is_some() {
+            for _ in 0..2 {
+                draw(&env, &mut game.player1_draws);
+                draw(&env, &mut game.player2_draws);
+            }
+        }
+
+        // Store updated game
+        env.storage().temporary().set(&key, &game);
 
         Ok(())
     }
 
     /// Player draws another card ("hit").
-    /// If the player's hand value exceeds 21, they bust and lose immediately.
+    /// The card's entropy is drawn now, so it cannot be known before this
+    /// call. The card stays hidden (the player derives it off-chain from
+    /// `get_game` and their hand secret); a hand over 21 only loses once it
+    /// is revealed.
     ///
     /// # Arguments
     /// * `session_id` - The session ID of the game
     /// * `player` - Address of the player drawing a card
     pub fn hit(env: Env, session_id: u32, player: Address) -> Result<(), Error> {
+        require_not_paused(&env, Error::ContractPaused)?;
+
         player.require_auth();
 
         // Get game from temporary storage
@@ -288,8 +455,8 @@ impl TwentyOneContract {
             .get(&key)
             .ok_or(Error::GameNotFound)?;
 
-        // Check game is still active (no winner yet)
-        if game.winner.is_some() {
+        // Check game is still active
+        if game.status != GameStatus::Active {
             return Err(Error::GameAlreadyEnded);
         }
 
@@ -309,58 +476,16 @@ impl TwentyOneContract {
             return Err(Error::AlreadyStuck);
         }
 
-        // Generate seed for new card based on current hand size
-        let mut seed_bytes = Bytes::new(&env);
-        seed_bytes.append(&Bytes::from_array(&env, &session_id.to_be_bytes()));
-        seed_bytes.append(&player.to_string().to_bytes());
-
-        let card_count = if is_player1 {
-            game.player1_hand.len()
-        } else {
-            game.player2_hand.len()
-        };
-
-        seed_bytes.append(&Bytes::from_array(&env, &(card_count as u32).to_be_bytes()));
-        seed_bytes.append(&Bytes::from_array(&env, &game.round.to_be_bytes()));
-
-        let card_seed = env.crypto().keccak256(&seed_bytes);
-        let new_card = deal_card(&env, card_seed.into());
+        // Nothing to draw until the deck seed is known
+        if game.player1_seed.is_none() || game.player2_seed.is_none() {
+            return Err(Error::DeckNotReady);
+        }
 
-        // Add card to player's hand
+        // Draw the next card into the player's hidden hand
         if is_player1 {
-            game.player1_hand.push_back(new_card);
-
-            // Check if player busted
-            let hand_value = calculate_hand_value(&game.player1_hand)?;
-            if hand_value > 21 {
-                // Player 1 busted, player 2 wins
-                // Call GameHub FIRST (before setting winner)
-                Self::end_game_with_hub(&env, session_id, false)?;
-
-                // Only set winner AFTER GameHub succeeds
-                game.winner = Some(game.player2.clone());
-                env.storage().temporary().set(&key, &game);
-
-                // Return Ok - caller should check game.winner to see if game ended
-                return Ok(());
-            }
+            draw(&env, &mut game.player1_draws);
         } else {
-            game.player2_hand.push_back(new_card);
-
-            // Check if player busted
-            let hand_value = calculate_hand_value(&game.player2_hand)?;
-            if hand_value > 21 {
-                // Player 2 busted, player 1 wins
-                // Call GameHub FIRST (before setting winner)
-                Self::end_game_with_hub(&env, session_id, true)?;
-
-                // Only set winner AFTER GameHub succeeds
-                game.winner = Some(game.player1.clone());
-                env.storage().temporary().set(&key, &game);
-
-                // Return Ok - caller should check game.winner to see if game ended
-                return Ok(());
-            }
+            draw(&env, &mut game.player2_draws);
         }
 
         // Store updated game
@@ -376,6 +501,8 @@ impl TwentyOneContract {
     /// * `session_id` - The session ID of the game
     /// * `player` - Address of the player sticking
     pub fn stick(env: Env, session_id: u32, player: Address) -> Result<(), Error> {
+        require_not_paused(&env, Error::ContractPaused)?;
+
         player.require_auth();
 
         // Get game from temporary storage
@@ -386,11 +513,16 @@ impl TwentyOneContract {
             .get(&key)
             .ok_or(Error::GameNotFound)?;
 
-        // Check game is still active (no winner yet)
-        if game.winner.is_some() {
+        // Check game is still active
+        if game.status != GameStatus::Active {
             return Err(Error::GameAlreadyEnded);
         }
 
+        // Nothing to stick on until the cards are dealt
+        if game.player1_seed.is_none() || game.player2_seed.is_none() {
+            return Err(Error::DeckNotReady);
+        }
+
         // Mark player as stuck
         if player == game.player1 {
             if game.player1_stuck {
@@ -412,17 +544,85 @@ impl TwentyOneContract {
         Ok(())
     }
 
+    /// Reveal a player's hand once both players have stuck.
+    /// The contract re-derives the cards from the hand secret and only
+    /// accepts `hand` if it is exactly the cards the player drew.
+    ///
+    /// # Arguments
+    /// * `session_id` - The session ID of the game
+    /// * `player` - Address of the player revealing
+    /// * `hand_secret` - The secret behind the player's hand commitment
+    /// * `hand` - The player's cards in draw order, as derived off-chain
+    pub fn reveal_hand(
+        env: Env,
+        session_id: u32,
+        player: Address,
+        hand_secret: BytesN<32>,
+        hand: Bytes,
+    ) -> Result<(), Error> {
+        require_not_paused(&env, Error::ContractPaused)?;
+
+        player.require_auth();
+
+        // Get game from temporary storage
+        let key = DataKey::Game(session_id);
+        let mut game: Game = env
+            .storage()
+            .temporary()
+            .get(&key)
+            .ok_or(Error::GameNotFound)?;
+
+        // Check game is still active
+        if game.status != GameStatus::Active {
+            return Err(Error::GameAlreadyEnded);
+        }
+
+        // Hands stay hidden until nobody can draw any more cards
+        if !game.player1_stuck || !game.player2_stuck {
+            return Err(Error::BothPlayersNotStuck);
+        }
+
+        let revealed = if player == game.player1 {
+            &game.player1_hand
+        } else if player == game.player2 {
+            &game.player2_hand
+        } else {
+            return Err(Error::NotPlayer);
+        };
+        if !revealed.is_empty() {
+            return Err(Error::AlreadyRevealed);
+        }
+
+        if read_hand(&env, session_id, &game, &player, &hand_secret)? != hand {
+            return Err(Error::HandMismatch);
+        }
+
+        if player == game.player1 {
+            game.player1_hand = hand;
+        } else {
+            game.player2_hand = hand;
+        }
+
+        // Store updated game
+        env.storage().temporary().set(&key, &game);
+
+        Ok(())
+    }
+
     /// Reveal the winner of the game and submit outcome to GameHub.
-    /// Can only be called after both players have stuck.
-    /// This calculates hand values, determines the winner (closest to 21),
-    /// and handles draws by dealing new hands.
+    /// Can only be called after both players have revealed their hands.
+    /// This calculates hand values and determines the winner (closest to 21
+    /// without going over). Equal hand values and two busts end the game in
+    /// a draw.
     ///
     /// # Arguments
     /// * `session_id` - The session ID of the game
     ///
     /// # Returns
-    /// * `Address` - Address of the winning player
-    pub fn reveal_winner(env: Env, session_id: u32) -> Result<Address, Error> {
+    /// * `Option<Address>` - Address of the winning player, or `None` for a draw
+    pub fn reveal_winner(env: Env, session_id: u32) -> Result<Option<Address>, Error> {
+        require_not_paused(&env, Error::ContractPaused)?;
+
         // Get game from temporary storage
         let key = DataKey::Game(session_id);
         let mut game: Game = env
@@ -431,9 +631,9 @@ impl TwentyOneContract {
             .get(&key)
             .ok_or(Error::GameNotFound)?;
 
-        // Check if game already ended (has a winner)
-        if let Some(winner) = &game.winner {
-            return Ok(winner.clone());
+        // Check if game already ended
+        if game.status != GameStatus::Active {
+            return Ok(game.winner);
         }
 
         // Check both players have stuck
@@ -441,71 +641,41 @@ impl TwentyOneContract {
             return Err(Error::BothPlayersNotStuck);
         }
 
-        // Calculate hand values
+        // Check both hands are revealed
+        if game.player1_hand.is_empty() || game.player2_hand.is_empty() {
+            return Err(Error::HandNotRevealed);
+        }
+
+        // Calculate hand values; a bust hand counts as 0
         let player1_value = calculate_hand_value(&game.player1_hand)?;
         let player2_value = calculate_hand_value(&game.player2_hand)?;
+        let player1_value = if player1_value > 21 { 0 } else { player1_value };
+        let player2_value = if player2_value > 21 { 0 } else { player2_value };
 
-        // Determine winner (closest to 21 without going over)
-        // Note: Bust conditions are already handled in hit(), so values should be <= 21
-        let winner = if player1_value > player2_value {
+        // Determine outcome (closest to 21 without going over)
+        let outcome = if player1_value > player2_value {
             // Player 1 is closer to 21
-            game.player1.clone()
+            GameOutcome::Player1Won
         } else if player2_value > player1_value {
             // Player 2 is closer to 21
-            game.player2.clone()
+            GameOutcome::Player2Won
         } else {
-            // Draw - deal new hands and continue
-            game.round = game.round.checked_add(1).ok_or(Error::RoundOverflow)?;
-
-            // Reset stuck flags
-            game.player1_stuck = false;
-            game.player2_stuck = false;
-
-            // Clear hands
-            game.player1_hand = Bytes::new(&env);
-            game.player2_hand = Bytes::new(&env);
-
-            // Deal new hands (2 cards each)
-            let mut seed_bytes = Bytes::new(&env);
-            seed_bytes.append(&Bytes::from_array(&env, &session_id.to_be_bytes()));
-            seed_bytes.append(&game.player1.to_string().to_bytes());
-            seed_bytes.append(&game.player2.to_string().to_bytes());
-            seed_bytes.append(&Bytes::from_array(&env, &game.round.to_be_bytes()));
-            let base_seed = env.crypto().keccak256(&seed_bytes);
-
-            // Deal 2 cards to player1
-            for i in 0..2 {
-                let mut card_seed_bytes = Bytes::new(&env);
-                card_seed_bytes.append(&Bytes::from(base_seed.clone()));
-                card_seed_bytes.append(&Bytes::from_array(&env, &[i, 1])); // [card_index, player]
-                let card_seed = env.crypto().keccak256(&card_seed_bytes);
-                game.player1_hand.push_back(deal_card(&env, card_seed.into()));
-            }
-
-            // Deal 2 cards to player2
-            for i in 0..2 {
-                let mut card_seed_bytes = Bytes::new(&env);
-                card_seed_bytes.append(&Bytes::from(base_seed.clone()));
-                card_seed_bytes.append(&Bytes::from_array(&env, &[i, 2])); // [card_index, player]
-                let card_seed = env.crypto().keccak256(&card_seed_bytes);
-                game.player2_hand.push_back(deal_card(&env, card_seed.into()));
-            }
-
-            // Store updated game and return error to indicate draw
-            env.storage().temporary().set(&key, &game);
-
-            return Err(Error::Draw);
+            GameOutcome::Draw
         };
 
         // Call GameHub FIRST (before setting winner)
-        let player1_won = winner == game.player1;
-        Self::end_game_with_hub(&env, session_id, player1_won)?;
+        Self::end_game_with_hub(&env, session_id, &outcome)?;
 
-        // Only update game with winner AFTER GameHub succeeds
-        game.winner = Some(winner.clone());
+        // Only update game with outcome AFTER GameHub succeeds
+        game.winner = match outcome {
+            GameOutcome::Player1Won => Some(game.player1.clone()),
+            GameOutcome::Player2Won => Some(game.player2.clone()),
+            _ => None,
+        };
+        game.status = GameStatus::Ended(outcome);
         env.storage().temporary().set(&key, &game);
 
-        Ok(winner)
+        Ok(game.winner)
     }
 
     /// Get game information.
@@ -514,7 +684,7 @@ impl TwentyOneContract {
     /// * `session_id` - The session ID of the game
     ///
     /// # Returns
-    /// * `Game` - The game state (includes hands and winner after game ends)
+    /// * `Game` - The game state (hands are empty until revealed with `reveal_hand`)
     pub fn get_game(env: Env, session_id: u32) -> Result<Game, Error> {
         let key = DataKey::Game(session_id);
         env.storage()
@@ -523,7 +693,7 @@ impl TwentyOneContract {
             .ok_or(Error::GameNotFound)
     }
 
-    /// Get the current hand value for a player.
+    /// Get the value of a player's revealed hand.
     ///
     /// # Arguments
     /// * `session_id` - The session ID of the game
@@ -539,13 +709,17 @@ impl TwentyOneContract {
             .get(&key)
             .ok_or(Error::GameNotFound)?;
 
-        if player == game.player1 {
-            calculate_hand_value(&game.player1_hand)
+        let hand = if player == game.player1 {
+            &game.player1_hand
         } else if player == game.player2 {
-            calculate_hand_value(&game.player2_hand)
+            &game.player2_hand
         } else {
-            Err(Error::NotPlayer)
+            return Err(Error::NotPlayer);
+        };
+        if hand.is_empty() {
+            return Err(Error::HandNotRevealed);
         }
+        calculate_hand_value(hand)
     }
 
     // ========================================================================
@@ -553,99 +727,185 @@ impl TwentyOneContract {
     // ========================================================================
 
     /// Helper to end game with the Game Hub
-    fn end_game_with_hub(env: &Env, session_id: u32, player1_won: bool) -> Result<(), Error> {
-        // Get GameHub address
-        let game_hub_addr: Address = env
-            .storage()
-            .instance()
-            .get(&DataKey::GameHubAddress)
-            .expect("GameHub address not set");
-
+    fn end_game_with_hub(env: &Env, session_id: u32, outcome: &GameOutcome) -> Result<(), Error> {
         // Create GameHub client
-        let game_hub = GameHubClient::new(env, &game_hub_addr);
+        let game_hub = hub_client(env);
 
         // Call the Game Hub to end the session
         // This unlocks points and updates standings
         // Event emitted by the Game Hub contract (GameEnded)
-        game_hub.end_game(&session_id, &player1_won);
+        game_hub.end_game_with_outcome(&session_id, outcome);
 
         Ok(())
     }
+}
 
-    // ========================================================================
-    // Admin Functions
-    // ========================================================================
+// ============================================================================
+// Game Interface
+// ============================================================================
 
-    /// Get the current admin address
-    ///
-    /// # Returns
-    /// * `Address` - The admin address
-    pub fn get_admin(env: Env) -> Address {
-        env.storage()
-            .instance()
-            .get(&DataKey::Admin)
-            .expect("Admin not set")
+#[contractimpl]
+impl GameContract for TwentyOneContract {
+    /// Describe the game: two players, winner takes both stakes
+    fn game_info(env: Env) -> GameInfo {
+        GameInfo {
+            name: String::from_str(&env, "Twenty-One"),
+            rules_version: 5,
+            min_players: 2,
+            max_players: 2,
+            stakes: StakeSemantics::WinnerTakesAll,
+        }
     }
 
-    /// Set a new admin address
+    /// Get the status of a session, or `None` if it does not exist (or expired)
+    fn get_session_status(env: Env, session_id: u32) -> Option<SessionStatus> {
+        let game: Game = env.storage().temporary().get(&DataKey::Game(session_id))?;
+        Some(match game.status {
+            GameStatus::Active => SessionStatus::Active,
+            GameStatus::Ended(outcome) => SessionStatus::Ended(outcome),
+        })
+    }
+
+    /// Concede the game. The opponent wins and the Game Hub settles the points.
     ///
     /// # Arguments
-    /// * `new_admin` - The new admin address
-    pub fn set_admin(env: Env, new_admin: Address) {
-        let admin: Address = env
+    /// * `session_id` - The session ID of the game
+    /// * `player` - Address of the player conceding
+    fn forfeit(env: Env, session_id: u32, player: Address) -> Result<(), soroban_sdk::Error> {
+        require_not_paused(&env, Error::ContractPaused)?;
+
+        player.require_auth();
+
+        let key = DataKey::Game(session_id);
+        let mut game: Game = env
             .storage()
-            .instance()
-            .get(&DataKey::Admin)
-            .expect("Admin not set");
-        admin.require_auth();
+            .temporary()
+            .get(&key)
+            .ok_or(Error::GameNotFound)?;
 
-        env.storage().instance().set(&DataKey::Admin, &new_admin);
-    }
+        if game.status != GameStatus::Active {
+            return Err(Error::GameAlreadyEnded.into());
+        }
 
-    /// Get the current GameHub contract address
-    ///
-    /// # Returns
-    /// * `Address` - The GameHub contract address
-    pub fn get_hub(env: Env) -> Address {
-        env.storage()
-            .instance()
-            .get(&DataKey::GameHubAddress)
-            .expect("GameHub address not set")
+        let opponent = if player == game.player1 {
+            game.player2.clone()
+        } else if player == game.player2 {
+            game.player1.clone()
+        } else {
+            return Err(Error::NotPlayer.into());
+        };
+
+        let outcome = GameOutcome::Forfeit(player);
+        game.winner = Some(opponent);
+        game.status = GameStatus::Ended(outcome.clone());
+        env.storage().temporary().set(&key, &game);
+
+        // Event emitted by the Game Hub contract (GameEnded)
+        hub_client(&env).end_game_with_outcome(&session_id, &outcome);
+
+        Ok(())
     }
+}
+
+// ============================================================================
+// Game Hub Callbacks
+// ============================================================================
 
-    /// Set a new GameHub contract address
+#[contractimpl]
+impl GameCallbacks for TwentyOneContract {
+    /// Called by the Game Hub when a timeout is claimed against `player`.
+    /// Only accepted if `player` is holding up the current phase while their
+    /// opponent is done with it: revealing seeds, sticking, or revealing
+    /// hands. The game then ends as a forfeit by `player`. The hub settles
+    /// the points.
     ///
     /// # Arguments
-    /// * `new_hub` - The new GameHub contract address
-    pub fn set_hub(env: Env, new_hub: Address) {
-        let admin: Address = env
+    /// * `session_id` - The session ID of the game
+    /// * `player` - Address of the player being forfeited
+    fn on_session_forfeited(
+        env: Env,
+        session_id: u32,
+        player: Address,
+    ) -> Result<(), soroban_sdk::Error> {
+        Self::get_hub(env.clone()).require_auth();
+
+        let key = DataKey::Game(session_id);
+        let mut game: Game = env
             .storage()
-            .instance()
-            .get(&DataKey::Admin)
-            .expect("Admin not set");
-        admin.require_auth();
+            .temporary()
+            .get(&key)
+            .ok_or(Error::GameNotFound)?;
 
-        env.storage()
-            .instance()
-            .set(&DataKey::GameHubAddress, &new_hub);
+        if game.status != GameStatus::Active {
+            return Err(Error::GameAlreadyEnded.into());
+        }
+
+        let revealing_seeds = game.player1_seed.is_none() || game.player2_seed.is_none();
+        let playing = !game.player1_stuck || !game.player2_stuck;
+        let (stalling, opponent) = if player == game.player1 {
+            let stalling = if revealing_seeds {
+                game.player1_seed.is_none() && game.player2_seed.is_some()
+            } else if playing {
+                !game.player1_stuck && game.player2_stuck
+            } else {
+                game.player1_hand.is_empty() && !game.player2_hand.is_empty()
+            };
+            (stalling, game.player2.clone())
+        } else if player == game.player2 {
+            let stalling = if revealing_seeds {
+                game.player2_seed.is_none() && game.player1_seed.is_some()
+            } else if playing {
+                !game.player2_stuck && game.player1_stuck
+            } else {
+                game.player2_hand.is_empty() && !game.player1_hand.is_empty()
+            };
+            (stalling, game.player1.clone())
+        } else {
+            return Err(Error::NotPlayer.into());
+        };
+        if !stalling {
+            return Err(Error::PlayerNotStalling.into());
+        }
+
+        game.winner = Some(opponent);
+        game.status = GameStatus::Ended(GameOutcome::Forfeit(player));
+        env.storage().temporary().set(&key, &game);
+
+        Ok(())
     }
 
-    /// Update the contract WASM hash (upgrade contract)
+    /// Called by the Game Hub when it cancels the session (timeout or pause).
+    /// The game ends without a winner; the hub refunds both stakes.
     ///
     /// # Arguments
-    /// * `new_wasm_hash` - The hash of the new WASM binary
-    pub fn upgrade(env: Env, new_wasm_hash: BytesN<32>) {
-        let admin: Address = env
+    /// * `session_id` - The session ID of the game
+    fn on_session_cancelled(env: Env, session_id: u32) -> Result<(), soroban_sdk::Error> {
+        Self::get_hub(env.clone()).require_auth();
+
+        let key = DataKey::Game(session_id);
+        let mut game: Game = env
             .storage()
-            .instance()
-            .get(&DataKey::Admin)
-            .expect("Admin not set");
-        admin.require_auth();
+            .temporary()
+            .get(&key)
+            .ok_or(Error::GameNotFound)?;
+
+        if game.status != GameStatus::Active {
+            return Err(Error::GameAlreadyEnded.into());
+        }
 
-        env.deployer().update_current_contract_wasm(new_wasm_hash);
+        game.status = GameStatus::Ended(GameOutcome::Cancelled);
+        env.storage().temporary().set(&key, &game);
+
+        Ok(())
     }
 }
 
+// ============================================================================
+// Admin Functions
+// ============================================================================
+
+sgs_game_common::game_admin_entrypoints!(TwentyOneContract);
+
 // ============================================================================
 // Tests
 // ============================================================================
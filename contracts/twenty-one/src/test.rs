@@ -7,9 +7,10 @@
 // For full integration tests with the real GameHub contract, see:
 // contracts/game_hub/src/tests/twenty_one_integration.rs
 
-use crate::{Error, TwentyOneContract, TwentyOneContractClient};
+use crate::{Error, GameOutcome, GameStatus, TwentyOneContract, TwentyOneContractClient};
+use sgs_game_common::{SessionStatus, StakeSemantics};
 use soroban_sdk::testutils::{Address as _, Ledger as _};
-use soroban_sdk::{contract, contractimpl, Address, Bytes, BytesN, Env};
+use soroban_sdk::{contract, contractimpl, Address, Bytes, BytesN, Env, String};
 
 // ============================================================================
 // Mock GameHub for Unit Testing
@@ -36,6 +37,10 @@ impl MockGameHub {
         // Mock implementation - does nothing
     }
 
+    pub fn end_game_with_outcome(_env: Env, _session_id: u32, _outcome: GameOutcome) {
+        // Mock implementation - does nothing
+    }
+
     pub fn add_game(_env: Env, _game_address: Address) {
         // Mock implementation - does nothing
     }
@@ -151,84 +156,205 @@ fn calculate_hand_value_helper(hand: &Bytes) -> u32 {
     total
 }
 
+/// Deterministic per-player deck seed for tests
+fn test_seed(env: &Env, player: &Address) -> BytesN<32> {
+    env.crypto()
+        .keccak256(&player.to_string().to_bytes())
+        .into()
+}
+
+/// Deterministic per-player hand secret for tests
+fn test_hand_secret(env: &Env, player: &Address) -> BytesN<32> {
+    env.crypto()
+        .keccak256(&test_seed(env, player).into())
+        .into()
+}
+
+/// Hand commitment: keccak256(hand_secret || player_address)
+fn hand_commitment(env: &Env, player: &Address) -> BytesN<32> {
+    let mut data = Bytes::from(test_hand_secret(env, player));
+    data.append(&player.to_string().to_bytes());
+    env.crypto().keccak256(&data).into()
+}
+
+/// Seed commitment: keccak256(seed || hand_commitment || player_address)
+fn make_commitment(env: &Env, seed: &BytesN<32>, player: &Address) -> BytesN<32> {
+    let mut data = Bytes::from(seed.clone());
+    data.append(&hand_commitment(env, player).into());
+    data.append(&player.to_string().to_bytes());
+    env.crypto().keccak256(&data).into()
+}
+
+/// Reveal `player`'s test seed and hand commitment
+fn reveal_seed(env: &Env, client: &TwentyOneContractClient, session_id: u32, player: &Address) {
+    client.reveal_seed(
+        &session_id,
+        player,
+        &test_seed(env, player),
+        &hand_commitment(env, player),
+    );
+}
+
+/// `player`'s hidden hand, derived off-chain the way a client does: from the
+/// public game state and their test hand secret
+fn hand(env: &Env, client: &TwentyOneContractClient, session_id: u32, player: &Address) -> Bytes {
+    let game = client.get_game(&session_id);
+    let mut seed_bytes = Bytes::from_array(env, &session_id.to_be_bytes());
+    seed_bytes.append(&game.player1_seed.unwrap().into());
+    seed_bytes.append(&game.player2_seed.unwrap().into());
+    let deck_seed: BytesN<32> = env.crypto().keccak256(&seed_bytes).into();
+
+    let draws = if *player == game.player1 {
+        game.player1_draws
+    } else {
+        game.player2_draws
+    };
+    let mut cards = Bytes::new(env);
+    for (i, entropy) in draws.iter().enumerate() {
+        let mut card_seed_bytes = Bytes::from(deck_seed.clone());
+        card_seed_bytes.append(&test_hand_secret(env, player).into());
+        card_seed_bytes.append(&Bytes::from_array(env, &(i as u32).to_be_bytes()));
+        card_seed_bytes.append(&entropy.into());
+        let card_seed = env.crypto().keccak256(&card_seed_bytes).to_array();
+        let word: [u8; 8] = card_seed[..8].try_into().unwrap();
+        cards.push_back((u64::from_be_bytes(word) % 13 + 1) as u8);
+    }
+    cards
+}
+
+/// Both players stick, then both reveal their hands
+fn stick_and_reveal(
+    env: &Env,
+    client: &TwentyOneContractClient,
+    session_id: u32,
+    player1: &Address,
+    player2: &Address,
+) {
+    client.stick(&session_id, player1);
+    client.stick(&session_id, player2);
+    for player in [player1, player2] {
+        let cards = hand(env, client, session_id, player);
+        client.reveal_hand(&session_id, player, &test_hand_secret(env, player), &cards);
+    }
+}
+
+/// Start a game committing to each player's test seed, without revealing
+fn start_sealed(
+    env: &Env,
+    client: &TwentyOneContractClient,
+    session_id: u32,
+    player1: &Address,
+    player2: &Address,
+    player1_points: i128,
+    player2_points: i128,
+) {
+    client.start_game(
+        &session_id,
+        player1,
+        player2,
+        &player1_points,
+        &player2_points,
+        &make_commitment(env, &test_seed(env, player1), player1),
+        &make_commitment(env, &test_seed(env, player2), player2),
+    );
+}
+
+/// Start a game with equal points and reveal both seeds, dealing the opening hands
+fn start_and_deal(
+    env: &Env,
+    client: &TwentyOneContractClient,
+    session_id: u32,
+    player1: &Address,
+    player2: &Address,
+    points: i128,
+) {
+    start_sealed(env, client, session_id, player1, player2, points, points);
+    reveal_seed(env, client, session_id, player1);
+    reveal_seed(env, client, session_id, player2);
+}
+
 // ============================================================================
 // Basic Game Flow Tests
 // ============================================================================
 
 #[test]
 fn test_complete_game_simple() {
-    let (_env, client, _hub, player1, player2) = setup_test();
+    let (env, client, _hub, player1, player2) = setup_test();
 
     let session_id = 1u32;
     let points = 100_0000000;
 
     // Start game
-    client.start_game(&session_id, &player1, &player2, &points, &points);
+    start_and_deal(&env, &client, session_id, &player1, &player2, points);
 
     // Get game to verify initial state
     let game = client.get_game(&session_id);
-    assert!(game.winner.is_none()); // Game is still active
+    assert!(game.winner.is_none());
+    assert_eq!(game.status, GameStatus::Active);
     assert_eq!(game.player1, player1);
     assert_eq!(game.player2, player2);
     assert_eq!(game.player1_points, points);
     assert_eq!(game.player2_points, points);
-    assert_eq!(game.player1_hand.len(), 2); // 2 cards dealt
-    assert_eq!(game.player2_hand.len(), 2); // 2 cards dealt
-    assert_eq!(game.player1_stuck, false);
-    assert_eq!(game.player2_stuck, false);
+    assert_eq!(game.player1_draws.len(), 2); // 2 cards dealt
+    assert_eq!(game.player2_draws.len(), 2); // 2 cards dealt
+    assert!(game.player1_hand.is_empty()); // hidden until revealed
+    assert!(game.player2_hand.is_empty());
+    assert!(!game.player1_stuck);
+    assert!(!game.player2_stuck);
 
-    // Both players stick immediately (no hits)
-    client.stick(&session_id, &player1);
-    client.stick(&session_id, &player2);
+    // Both players stick immediately (no hits) and reveal their hands
+    stick_and_reveal(&env, &client, session_id, &player1, &player2);
 
     // Reveal winner
     let winner = client.reveal_winner(&session_id);
-    assert!(winner == player1 || winner == player2);
+    assert!(winner.is_none() || winner == Some(player1) || winner == Some(player2));
 
     // Verify game is ended
     let final_game = client.get_game(&session_id);
-    assert!(final_game.winner.is_some());
-    assert_eq!(final_game.winner.unwrap(), winner);
+    assert_ne!(final_game.status, GameStatus::Active);
+    assert_eq!(final_game.winner, winner);
 }
 
 #[test]
 fn test_initial_cards_dealt() {
-    let (_env, client, _hub, player1, player2) = setup_test();
+    let (env, client, _hub, player1, player2) = setup_test();
 
     let session_id = 2u32;
-    client.start_game(&session_id, &player1, &player2, &100_0000000, &100_0000000);
+    start_and_deal(&env, &client, session_id, &player1, &player2, 100_0000000);
 
-    let game = client.get_game(&session_id);
+    let player1_hand = hand(&env, &client, session_id, &player1);
+    let player2_hand = hand(&env, &client, session_id, &player2);
 
     // Each player should have exactly 2 cards
-    assert_eq!(game.player1_hand.len(), 2);
-    assert_eq!(game.player2_hand.len(), 2);
+    assert_eq!(player1_hand.len(), 2);
+    assert_eq!(player2_hand.len(), 2);
 
     // Cards should be in valid range (1-13)
-    for i in 0..game.player1_hand.len() {
-        let card = game.player1_hand.get(i).unwrap();
-        assert!(card >= 1 && card <= 13, "Card should be between 1-13");
+    for i in 0..player1_hand.len() {
+        let card = player1_hand.get(i).unwrap();
+        assert!((1..=13).contains(&card), "Card should be between 1-13");
     }
-    for i in 0..game.player2_hand.len() {
-        let card = game.player2_hand.get(i).unwrap();
-        assert!(card >= 1 && card <= 13, "Card should be between 1-13");
+    for i in 0..player2_hand.len() {
+        let card = player2_hand.get(i).unwrap();
+        assert!((1..=13).contains(&card), "Card should be between 1-13");
     }
 }
 
 #[test]
 fn test_get_hand_value() {
-    let (_env, client, _hub, player1, player2) = setup_test();
+    let (env, client, _hub, player1, player2) = setup_test();
 
     let session_id = 3u32;
-    client.start_game(&session_id, &player1, &player2, &100_0000000, &100_0000000);
+    start_and_deal(&env, &client, session_id, &player1, &player2, 100_0000000);
+    stick_and_reveal(&env, &client, session_id, &player1, &player2);
 
     // Get hand values
     let player1_value = client.get_hand_value(&session_id, &player1);
     let player2_value = client.get_hand_value(&session_id, &player2);
 
     // Hand values should be reasonable (2-20 for 2 cards, since max is 10 per card)
-    assert!(player1_value >= 2 && player1_value <= 20);
-    assert!(player2_value >= 2 && player2_value <= 20);
+    assert!((2..=20).contains(&player1_value));
+    assert!((2..=20).contains(&player2_value));
 
     // Verify hand value matches calculation
     let game = client.get_game(&session_id);
@@ -241,27 +367,31 @@ fn test_get_hand_value() {
 
 #[test]
 fn test_hit_adds_card() {
-    let (_env, client, _hub, player1, player2) = setup_test();
+    let (env, client, _hub, player1, player2) = setup_test();
 
     let session_id = 4u32;
-    client.start_game(&session_id, &player1, &player2, &100_0000000, &100_0000000);
+    start_and_deal(&env, &client, session_id, &player1, &player2, 100_0000000);
 
-    let initial_game = client.get_game(&session_id);
-    let initial_hand_size = initial_game.player1_hand.len();
+    let initial_hand = hand(&env, &client, session_id, &player1);
 
     // Player 1 hits
     client.hit(&session_id, &player1);
 
     let after_hit_game = client.get_game(&session_id);
-    assert_eq!(after_hit_game.player1_hand.len(), initial_hand_size + 1);
+    assert_eq!(after_hit_game.player1_draws.len(), 3);
+
+    // The new card is appended; earlier cards don't change
+    let after_hit_hand = hand(&env, &client, session_id, &player1);
+    assert_eq!(after_hit_hand.len(), initial_hand.len() + 1);
+    assert_eq!(after_hit_hand.slice(0..initial_hand.len()), initial_hand);
 }
 
 #[test]
 fn test_stick_prevents_further_hits() {
-    let (_env, client, _hub, player1, player2) = setup_test();
+    let (env, client, _hub, player1, player2) = setup_test();
 
     let session_id = 5u32;
-    client.start_game(&session_id, &player1, &player2, &100_0000000, &100_0000000);
+    start_and_deal(&env, &client, session_id, &player1, &player2, 100_0000000);
 
     // Player 1 sticks
     client.stick(&session_id, &player1);
@@ -273,30 +403,17 @@ fn test_stick_prevents_further_hits() {
 
 #[test]
 fn test_multiple_hits_allowed() {
-    let (_env, client, _hub, player1, player2) = setup_test();
+    let (env, client, _hub, player1, player2) = setup_test();
 
     let session_id = 6u32;
-    client.start_game(&session_id, &player1, &player2, &100_0000000, &100_0000000);
-
-    let initial_game = client.get_game(&session_id);
-    let initial_hand_size = initial_game.player1_hand.len();
+    start_and_deal(&env, &client, session_id, &player1, &player2, 100_0000000);
 
-    // Player 1 hits multiple times (be careful not to bust in deterministic test)
-    // This test may fail if player1 busts, but we're testing the mechanics
-    let result1 = client.try_hit(&session_id, &player1);
-
-    // If first hit succeeds (didn't bust), try another
-    if result1.is_ok() {
-        let mid_game = client.get_game(&session_id);
-        assert_eq!(mid_game.player1_hand.len(), initial_hand_size + 1);
+    // Hits never end the game: a bust only shows once the hand is revealed
+    client.hit(&session_id, &player1);
+    client.hit(&session_id, &player1);
 
-        let result2 = client.try_hit(&session_id, &player1);
-        // Could succeed or fail (bust), both are valid
-        if result2.is_ok() {
-            let final_game = client.get_game(&session_id);
-            assert_eq!(final_game.player1_hand.len(), initial_hand_size + 2);
-        }
-    }
+    assert_eq!(client.get_game(&session_id).player1_draws.len(), 4);
+    assert_eq!(hand(&env, &client, session_id, &player1).len(), 4);
 }
 
 // ============================================================================
@@ -305,14 +422,13 @@ fn test_multiple_hits_allowed() {
 
 #[test]
 fn test_closer_to_21_wins() {
-    let (_env, client, _hub, player1, player2) = setup_test();
+    let (env, client, _hub, player1, player2) = setup_test();
 
     let session_id = 7u32;
-    client.start_game(&session_id, &player1, &player2, &100_0000000, &100_0000000);
+    start_and_deal(&env, &client, session_id, &player1, &player2, 100_0000000);
 
-    // Both players stick
-    client.stick(&session_id, &player1);
-    client.stick(&session_id, &player2);
+    // Both players stick and reveal
+    stick_and_reveal(&env, &client, session_id, &player1, &player2);
 
     let winner = client.reveal_winner(&session_id);
 
@@ -323,19 +439,23 @@ fn test_closer_to_21_wins() {
 
     // Winner should be closer to 21
     if player1_value > player2_value {
-        assert_eq!(winner, player1);
+        assert_eq!(winner, Some(player1));
+        assert_eq!(game.status, GameStatus::Ended(GameOutcome::Player1Won));
     } else if player2_value > player1_value {
-        assert_eq!(winner, player2);
+        assert_eq!(winner, Some(player2));
+        assert_eq!(game.status, GameStatus::Ended(GameOutcome::Player2Won));
+    } else {
+        assert_eq!(winner, None);
+        assert_eq!(game.status, GameStatus::Ended(GameOutcome::Draw));
     }
-    // If equal, test will continue (draw handling)
 }
 
 #[test]
 fn test_reveal_winner_requires_both_stuck() {
-    let (_env, client, _hub, player1, player2) = setup_test();
+    let (env, client, _hub, player1, player2) = setup_test();
 
     let session_id = 8u32;
-    client.start_game(&session_id, &player1, &player2, &100_0000000, &100_0000000);
+    start_and_deal(&env, &client, session_id, &player1, &player2, 100_0000000);
 
     // Only player1 sticks
     client.stick(&session_id, &player1);
@@ -350,61 +470,65 @@ fn test_reveal_winner_requires_both_stuck() {
 // ============================================================================
 
 #[test]
-fn test_bust_detection() {
-    let (_env, client, _hub, player1, player2) = setup_test();
+fn test_bust_loses_at_reveal() {
+    let (env, client, _hub, player1, player2) = setup_test();
 
     let session_id = 9u32;
-    client.start_game(&session_id, &player1, &player2, &100_0000000, &100_0000000);
+    start_and_deal(&env, &client, session_id, &player1, &player2, 100_0000000);
 
-    // Keep hitting until player1 busts
+    // Keep hitting until player1's hidden hand is over 21
     // Note: With enough hits, player will eventually bust (hand value > 21)
     let mut busted = false;
     for _ in 0..20 {
-        // Hit succeeds even when busting (returns Ok), but game ends
         client.hit(&session_id, &player1);
-
-        // Check if game ended (player busted)
-        let game = client.get_game(&session_id);
-        if game.winner.is_some() {
+        if calculate_hand_value_helper(&hand(&env, &client, session_id, &player1)) > 21 {
             busted = true;
-            assert_eq!(game.winner.unwrap(), player2, "Player 2 should win when player 1 busts");
-
-            // After a bust, subsequent operations should fail with GameAlreadyEnded
-            let result = client.try_hit(&session_id, &player1);
-            assert_twenty_one_error(&result, Error::GameAlreadyEnded);
             break;
         }
     }
-
     assert!(busted, "Player should have busted after 20 hits");
+
+    // The game goes on until both hands are revealed
+    assert_eq!(client.get_game(&session_id).status, GameStatus::Active);
+    stick_and_reveal(&env, &client, session_id, &player1, &player2);
+
+    let winner = client.reveal_winner(&session_id);
+    assert_eq!(
+        winner,
+        Some(player2),
+        "Player 2 should win when player 1 busts"
+    );
+    assert!(client.get_hand_value(&session_id, &player1) > 21);
 }
 
 #[test]
-fn test_cannot_hit_after_bust() {
-    let (_env, client, _hub, player1, player2) = setup_test();
+fn test_both_bust_is_draw() {
+    let (env, client, _hub, player1, player2) = setup_test();
 
     let session_id = 10u32;
-    client.start_game(&session_id, &player1, &player2, &100_0000000, &100_0000000);
-
-    // Hit until bust (game ends)
-    let mut did_bust = false;
-    for _ in 0..20 {
-        client.hit(&session_id, &player1);
-
-        // Check if game ended (player busted)
-        let game = client.get_game(&session_id);
-        if game.winner.is_some() {
-            did_bust = true;
-            assert_eq!(game.winner.unwrap(), player2);
-
-            // Try to hit again after busting - should fail with GameAlreadyEnded
-            let result = client.try_hit(&session_id, &player1);
-            assert_twenty_one_error(&result, Error::GameAlreadyEnded);
-            break;
+    start_and_deal(&env, &client, session_id, &player1, &player2, 100_0000000);
+
+    // Hit both hands until they bust
+    for player in [&player1, &player2] {
+        let mut did_bust = false;
+        for _ in 0..20 {
+            client.hit(&session_id, player);
+            if calculate_hand_value_helper(&hand(&env, &client, session_id, player)) > 21 {
+                did_bust = true;
+                break;
+            }
         }
+        assert!(did_bust, "Player should have busted after 20 hits");
     }
 
-    assert!(did_bust, "Player should have busted after 20 hits");
+    stick_and_reveal(&env, &client, session_id, &player1, &player2);
+
+    let winner = client.reveal_winner(&session_id);
+    assert_eq!(winner, None);
+    assert_eq!(
+        client.get_game(&session_id).status,
+        GameStatus::Ended(GameOutcome::Draw)
+    );
 }
 
 // ============================================================================
@@ -412,15 +536,14 @@ fn test_cannot_hit_after_bust() {
 // ============================================================================
 
 #[test]
-fn test_draw_starts_new_round() {
-    let (_env, client, _hub, player1, player2) = setup_test();
+fn test_draw_ends_game() {
+    let (env, client, _hub, player1, player2) = setup_test();
 
     let session_id = 11u32;
-    client.start_game(&session_id, &player1, &player2, &100_0000000, &100_0000000);
+    start_and_deal(&env, &client, session_id, &player1, &player2, 100_0000000);
 
-    // Both players stick immediately
-    client.stick(&session_id, &player1);
-    client.stick(&session_id, &player2);
+    // Both players stick immediately and reveal
+    stick_and_reveal(&env, &client, session_id, &player1, &player2);
 
     let game = client.get_game(&session_id);
     let player1_value = calculate_hand_value_helper(&game.player1_hand);
@@ -428,17 +551,16 @@ fn test_draw_starts_new_round() {
 
     // Only test draw behavior if hands are equal
     if player1_value == player2_value {
-        // This should return Draw error
-        let result = client.try_reveal_winner(&session_id);
-        assert_twenty_one_error(&result, Error::Draw);
+        // A draw has no winner and ends the game
+        let winner = client.reveal_winner(&session_id);
+        assert_eq!(winner, None);
 
-        // Verify new round was created
         let game_after = client.get_game(&session_id);
-        assert_eq!(game_after.round, 2); // Round should increment
-        assert_eq!(game_after.player1_stuck, false); // Flags reset
-        assert_eq!(game_after.player2_stuck, false);
-        assert_eq!(game_after.player1_hand.len(), 2); // New cards dealt
-        assert_eq!(game_after.player2_hand.len(), 2);
+        assert_eq!(game_after.status, GameStatus::Ended(GameOutcome::Draw));
+        assert!(game_after.winner.is_none());
+
+        let hit_result = client.try_hit(&session_id, &player1);
+        assert_twenty_one_error(&hit_result, Error::GameAlreadyEnded);
     }
     // If not a draw, test passes (no assertion needed)
 }
@@ -449,10 +571,10 @@ fn test_draw_starts_new_round() {
 
 #[test]
 fn test_cannot_stick_twice() {
-    let (_env, client, _hub, player1, player2) = setup_test();
+    let (env, client, _hub, player1, player2) = setup_test();
 
     let session_id = 12u32;
-    client.start_game(&session_id, &player1, &player2, &100_0000000, &100_0000000);
+    start_and_deal(&env, &client, session_id, &player1, &player2, 100_0000000);
 
     // First stick succeeds
     client.stick(&session_id, &player1);
@@ -468,7 +590,7 @@ fn test_non_player_cannot_hit() {
     let non_player = Address::generate(&env);
 
     let session_id = 13u32;
-    client.start_game(&session_id, &player1, &player2, &100_0000000, &100_0000000);
+    start_and_deal(&env, &client, session_id, &player1, &player2, 100_0000000);
 
     // Non-player tries to hit
     let result = client.try_hit(&session_id, &non_player);
@@ -481,7 +603,7 @@ fn test_non_player_cannot_stick() {
     let non_player = Address::generate(&env);
 
     let session_id = 14u32;
-    client.start_game(&session_id, &player1, &player2, &100_0000000, &100_0000000);
+    start_and_deal(&env, &client, session_id, &player1, &player2, 100_0000000);
 
     // Non-player tries to stick
     let result = client.try_stick(&session_id, &non_player);
@@ -494,7 +616,7 @@ fn test_non_player_cannot_get_hand_value() {
     let non_player = Address::generate(&env);
 
     let session_id = 15u32;
-    client.start_game(&session_id, &player1, &player2, &100_0000000, &100_0000000);
+    start_and_deal(&env, &client, session_id, &player1, &player2, 100_0000000);
 
     // Non-player tries to get hand value
     let result = client.try_get_hand_value(&session_id, &non_player);
@@ -535,70 +657,59 @@ fn test_cannot_get_nonexistent_game() {
 
 #[test]
 fn test_cannot_hit_after_game_ended() {
-    let (_env, client, _hub, player1, player2) = setup_test();
+    let (env, client, _hub, player1, player2) = setup_test();
 
     let session_id = 16u32;
-    client.start_game(&session_id, &player1, &player2, &100_0000000, &100_0000000);
+    start_and_deal(&env, &client, session_id, &player1, &player2, 100_0000000);
 
-    // Both players stick
-    client.stick(&session_id, &player1);
-    client.stick(&session_id, &player2);
+    // Both players stick and reveal
+    stick_and_reveal(&env, &client, session_id, &player1, &player2);
 
-    // Reveal winner (may panic if draw, but that's okay)
-    let result = client.try_reveal_winner(&session_id);
-    if result.is_ok() {
-        // Game ended successfully
-        // Try to hit after game ended
-        let hit_result = client.try_hit(&session_id, &player1);
-        assert_twenty_one_error(&hit_result, Error::GameAlreadyEnded);
-    }
+    // Reveal winner (a draw also ends the game)
+    client.reveal_winner(&session_id);
+
+    // Try to hit after game ended
+    let hit_result = client.try_hit(&session_id, &player1);
+    assert_twenty_one_error(&hit_result, Error::GameAlreadyEnded);
 }
 
 #[test]
 fn test_cannot_stick_after_game_ended() {
-    let (_env, client, _hub, player1, player2) = setup_test();
+    let (env, client, _hub, player1, player2) = setup_test();
 
     let session_id = 17u32;
-    client.start_game(&session_id, &player1, &player2, &100_0000000, &100_0000000);
+    start_and_deal(&env, &client, session_id, &player1, &player2, 100_0000000);
 
-    // Player 1 sticks
-    client.stick(&session_id, &player1);
-
-    // Player 2 sticks
-    client.stick(&session_id, &player2);
+    // Both players stick and reveal
+    stick_and_reveal(&env, &client, session_id, &player1, &player2);
 
     // Reveal winner
-    let result = client.try_reveal_winner(&session_id);
-    if result.is_ok() {
-        // Game ended, verify stuck flag is set
-        let game = client.get_game(&session_id);
-        assert!(game.player1_stuck);
-
-        // Try to stick again after game ended
-        let stick_result = client.try_stick(&session_id, &player1);
-        assert_twenty_one_error(&stick_result, Error::GameAlreadyEnded);
-    }
+    client.reveal_winner(&session_id);
+
+    // Game ended, verify stuck flag is set
+    let game = client.get_game(&session_id);
+    assert!(game.player1_stuck);
+
+    // Try to stick again after game ended
+    let stick_result = client.try_stick(&session_id, &player1);
+    assert_twenty_one_error(&stick_result, Error::GameAlreadyEnded);
 }
 
 #[test]
 fn test_reveal_winner_idempotent() {
-    let (_env, client, _hub, player1, player2) = setup_test();
+    let (env, client, _hub, player1, player2) = setup_test();
 
     let session_id = 18u32;
-    client.start_game(&session_id, &player1, &player2, &100_0000000, &100_0000000);
+    start_and_deal(&env, &client, session_id, &player1, &player2, 100_0000000);
 
-    client.stick(&session_id, &player1);
-    client.stick(&session_id, &player2);
+    stick_and_reveal(&env, &client, session_id, &player1, &player2);
 
     // First reveal
-    let result1 = client.try_reveal_winner(&session_id);
-    if result1.is_ok() {
-        let winner1 = result1.unwrap().unwrap();
+    let winner1 = client.reveal_winner(&session_id);
 
-        // Second reveal should return same winner (idempotent)
-        let winner2 = client.reveal_winner(&session_id);
-        assert_eq!(winner1, winner2);
-    }
+    // Second reveal should return same winner (idempotent)
+    let winner2 = client.reveal_winner(&session_id);
+    assert_eq!(winner1, winner2);
 }
 
 // ============================================================================
@@ -615,26 +726,15 @@ fn test_multiple_games_independent() {
     let session2 = 21u32;
 
     // Start two games
-    client.start_game(&session1, &player1, &player2, &100_0000000, &100_0000000);
-    client.start_game(&session2, &player3, &player4, &50_0000000, &50_0000000);
-
-    // Play both games independently (use try_ methods to handle potential busts)
-    let _ = client.try_hit(&session1, &player1);
-    let _ = client.try_hit(&session2, &player3);
-
-    // Only continue if games haven't ended from busts
-    let game1_check = client.get_game(&session1);
-    let game2_check = client.get_game(&session2);
+    start_and_deal(&env, &client, session1, &player1, &player2, 100_0000000);
+    start_and_deal(&env, &client, session2, &player3, &player4, 50_0000000);
 
-    if game1_check.winner.is_none() {
-        client.stick(&session1, &player1);
-        client.stick(&session1, &player2);
-    }
+    // Play both games independently
+    client.hit(&session1, &player1);
+    client.hit(&session2, &player3);
 
-    if game2_check.winner.is_none() {
-        client.stick(&session2, &player3);
-        client.stick(&session2, &player4);
-    }
+    stick_and_reveal(&env, &client, session1, &player1, &player2);
+    client.reveal_winner(&session1);
 
     // Verify both games exist and are independent
     let game1 = client.get_game(&session1);
@@ -642,6 +742,9 @@ fn test_multiple_games_independent() {
 
     assert_eq!(game1.player1, player1);
     assert_eq!(game2.player1, player3);
+    assert_ne!(game1.status, GameStatus::Active);
+    assert_eq!(game2.status, GameStatus::Active);
+    assert_eq!(game2.player1_draws.len(), 3);
 }
 
 #[test]
@@ -653,8 +756,8 @@ fn test_multiple_sessions() {
     let session1 = 22u32;
     let session2 = 23u32;
 
-    client.start_game(&session1, &player1, &player2, &100_0000000, &100_0000000);
-    client.start_game(&session2, &player3, &player4, &50_0000000, &50_0000000);
+    start_and_deal(&env, &client, session1, &player1, &player2, 100_0000000);
+    start_and_deal(&env, &client, session2, &player3, &player4, 50_0000000);
 
     // Verify both games exist and are independent
     let game1 = client.get_game(&session1);
@@ -668,23 +771,26 @@ fn test_multiple_sessions() {
 
 #[test]
 fn test_asymmetric_points() {
-    let (_env, client, _hub, player1, player2) = setup_test();
+    let (env, client, _hub, player1, player2) = setup_test();
 
     let session_id = 24u32;
     let points1 = 200_0000000;
     let points2 = 50_0000000;
 
-    client.start_game(&session_id, &player1, &player2, &points1, &points2);
+    start_sealed(
+        &env, &client, session_id, &player1, &player2, points1, points2,
+    );
+    reveal_seed(&env, &client, session_id, &player1);
+    reveal_seed(&env, &client, session_id, &player2);
 
     let game = client.get_game(&session_id);
     assert_eq!(game.player1_points, points1);
     assert_eq!(game.player2_points, points2);
 
-    client.stick(&session_id, &player1);
-    client.stick(&session_id, &player2);
+    stick_and_reveal(&env, &client, session_id, &player1, &player2);
 
-    // Try to reveal winner (may be draw)
-    let _ = client.try_reveal_winner(&session_id);
+    // Reveal winner (may be draw)
+    client.reveal_winner(&session_id);
 }
 
 // ============================================================================
@@ -695,29 +801,30 @@ fn test_asymmetric_points() {
 fn test_face_cards_worth_10() {
     // This is a deterministic test of card value logic
     // We can't control what cards are dealt, but we can verify the hand value calculation
-    let (_env, client, _hub, player1, player2) = setup_test();
+    let (env, client, _hub, player1, player2) = setup_test();
 
     let session_id = 25u32;
-    client.start_game(&session_id, &player1, &player2, &100_0000000, &100_0000000);
+    start_and_deal(&env, &client, session_id, &player1, &player2, 100_0000000);
 
-    let game = client.get_game(&session_id);
+    let player1_hand = hand(&env, &client, session_id, &player1);
 
     // Manually verify card values
-    for i in 0..game.player1_hand.len() {
-        let card = game.player1_hand.get(i).unwrap() as u32;
+    for i in 0..player1_hand.len() {
+        let card = player1_hand.get(i).unwrap() as u32;
         let expected_value = if card >= 10 { 10 } else { card };
 
         // Verify this matches our expectation
-        assert!(expected_value >= 1 && expected_value <= 10);
+        assert!((1..=10).contains(&expected_value));
     }
 }
 
 #[test]
 fn test_hand_value_calculation() {
-    let (_env, client, _hub, player1, player2) = setup_test();
+    let (env, client, _hub, player1, player2) = setup_test();
 
     let session_id = 26u32;
-    client.start_game(&session_id, &player1, &player2, &100_0000000, &100_0000000);
+    start_and_deal(&env, &client, session_id, &player1, &player2, 100_0000000);
+    stick_and_reveal(&env, &client, session_id, &player1, &player2);
 
     // Get hand value from contract
     let contract_value = client.get_hand_value(&session_id, &player1);
@@ -729,6 +836,241 @@ fn test_hand_value_calculation() {
     assert_eq!(contract_value, expected_value);
 }
 
+#[test]
+fn test_cannot_start_over_live_session() {
+    let (env, client, _hub, player1, player2) = setup_test();
+
+    let session_id = 30u32;
+    start_and_deal(&env, &client, session_id, &player1, &player2, 100);
+
+    let player3 = Address::generate(&env);
+    let commitment = make_commitment(&env, &test_seed(&env, &player3), &player3);
+    let result = client.try_start_game(
+        &session_id,
+        &player3,
+        &player2,
+        &100,
+        &100,
+        &commitment,
+        &commitment,
+    );
+    assert_twenty_one_error(&result, Error::SessionAlreadyExists);
+    assert_eq!(client.get_game(&session_id).player1, player1);
+}
+
+// ============================================================================
+// Game Hub Callback Tests
+// ============================================================================
+
+#[test]
+fn test_hub_forfeits_stalling_player() {
+    let (env, client, hub, player1, player2) = setup_test();
+
+    let session_id = 40u32;
+    start_and_deal(&env, &client, session_id, &player1, &player2, 100);
+    client.stick(&session_id, &player1);
+
+    client.on_session_forfeited(&session_id, &player2);
+    assert_eq!(env.auths()[0].0, hub.address);
+
+    let game = client.get_game(&session_id);
+    assert_eq!(game.winner, Some(player1));
+    assert_eq!(
+        game.status,
+        GameStatus::Ended(GameOutcome::Forfeit(player2.clone()))
+    );
+
+    let result = client.try_hit(&session_id, &player2);
+    assert_twenty_one_error(&result, Error::GameAlreadyEnded);
+}
+
+#[test]
+fn test_hub_forfeit_rejected_for_waiting_player() {
+    let (env, client, _hub, player1, player2) = setup_test();
+
+    let session_id = 41u32;
+    start_and_deal(&env, &client, session_id, &player1, &player2, 100);
+
+    // Nobody has moved yet, so neither player is holding up the game
+    let result = client.try_on_session_forfeited(&session_id, &player2);
+    assert_eq!(result, Err(Ok(Error::PlayerNotStalling.into())));
+
+    // player1 has moved and is waiting on player2
+    client.stick(&session_id, &player1);
+    let result = client.try_on_session_forfeited(&session_id, &player1);
+    assert_eq!(result, Err(Ok(Error::PlayerNotStalling.into())));
+    assert_eq!(client.get_game(&session_id).status, GameStatus::Active);
+}
+
+#[test]
+fn test_hub_forfeits_player_who_has_not_revealed() {
+    let (env, client, _hub, player1, player2) = setup_test();
+
+    let session_id = 43u32;
+    start_sealed(&env, &client, session_id, &player1, &player2, 100, 100);
+
+    // Neither seed is revealed, so nobody is stalling yet
+    let result = client.try_on_session_forfeited(&session_id, &player1);
+    assert_eq!(result, Err(Ok(Error::PlayerNotStalling.into())));
+
+    // player2 reveals and waits on player1 to deal the cards
+    reveal_seed(&env, &client, session_id, &player2);
+    let result = client.try_on_session_forfeited(&session_id, &player2);
+    assert_eq!(result, Err(Ok(Error::PlayerNotStalling.into())));
+
+    client.on_session_forfeited(&session_id, &player1);
+    let game = client.get_game(&session_id);
+    assert_eq!(game.winner, Some(player2));
+    assert_eq!(
+        game.status,
+        GameStatus::Ended(GameOutcome::Forfeit(player1.clone()))
+    );
+}
+
+#[test]
+fn test_hub_forfeits_player_who_has_not_revealed_hand() {
+    let (env, client, _hub, player1, player2) = setup_test();
+
+    let session_id = 44u32;
+    start_and_deal(&env, &client, session_id, &player1, &player2, 100);
+    client.stick(&session_id, &player1);
+    client.stick(&session_id, &player2);
+
+    // Both stuck, no hands revealed: nobody is holding up the game yet
+    let result = client.try_on_session_forfeited(&session_id, &player2);
+    assert_eq!(result, Err(Ok(Error::PlayerNotStalling.into())));
+
+    let cards = hand(&env, &client, session_id, &player1);
+    client.reveal_hand(
+        &session_id,
+        &player1,
+        &test_hand_secret(&env, &player1),
+        &cards,
+    );
+    let result = client.try_on_session_forfeited(&session_id, &player1);
+    assert_eq!(result, Err(Ok(Error::PlayerNotStalling.into())));
+
+    client.on_session_forfeited(&session_id, &player2);
+    assert_eq!(client.get_game(&session_id).winner, Some(player1));
+}
+
+#[test]
+fn test_hub_cancels_session() {
+    let (env, client, _hub, player1, player2) = setup_test();
+
+    let session_id = 42u32;
+    start_and_deal(&env, &client, session_id, &player1, &player2, 100);
+
+    client.on_session_cancelled(&session_id);
+
+    let game = client.get_game(&session_id);
+    assert!(game.winner.is_none());
+    assert_eq!(game.status, GameStatus::Ended(GameOutcome::Cancelled));
+
+    let result = client.try_on_session_cancelled(&session_id);
+    assert_eq!(result, Err(Ok(Error::GameAlreadyEnded.into())));
+}
+
+// ============================================================================
+// Game Interface Tests
+// ============================================================================
+
+#[test]
+fn test_game_info() {
+    let (env, client, _hub, _player1, _player2) = setup_test();
+
+    let info = client.game_info();
+    assert_eq!(info.name, String::from_str(&env, "Twenty-One"));
+    assert_eq!((info.min_players, info.max_players), (2, 2));
+    assert_eq!(info.stakes, StakeSemantics::WinnerTakesAll);
+}
+
+#[test]
+fn test_forfeit_ends_game_for_opponent() {
+    let (env, client, _hub, player1, player2) = setup_test();
+
+    let session_id = 60u32;
+    assert_eq!(client.get_session_status(&session_id), None);
+    start_and_deal(&env, &client, session_id, &player1, &player2, 100);
+    client.stick(&session_id, &player1);
+
+    let outsider = Address::generate(&env);
+    let result = client.try_forfeit(&session_id, &outsider);
+    assert_eq!(result, Err(Ok(Error::NotPlayer.into())));
+
+    client.forfeit(&session_id, &player2);
+    assert_eq!(env.auths()[0].0, player2);
+    assert_eq!(client.get_game(&session_id).winner, Some(player1));
+    assert_eq!(
+        client.get_session_status(&session_id),
+        Some(SessionStatus::Ended(GameOutcome::Forfeit(player2.clone())))
+    );
+
+    let result = client.try_hit(&session_id, &player2);
+    assert_twenty_one_error(&result, Error::GameAlreadyEnded);
+}
+
+// ============================================================================
+// Pause Tests
+// ============================================================================
+
+#[test]
+fn test_pause_blocks_gameplay() {
+    let (env, client, _hub, player1, player2) = setup_test();
+
+    let session_id = 50u32;
+    start_and_deal(&env, &client, session_id, &player1, &player2, 100);
+
+    client.pause();
+    assert!(client.is_paused());
+
+    let commitment = make_commitment(&env, &test_seed(&env, &player1), &player1);
+    let result = client.try_start_game(
+        &51,
+        &player1,
+        &player2,
+        &100,
+        &100,
+        &commitment,
+        &commitment,
+    );
+    assert_twenty_one_error(&result, Error::ContractPaused);
+    let result = client.try_hit(&session_id, &player1);
+    assert_twenty_one_error(&result, Error::ContractPaused);
+    let result = client.try_stick(&session_id, &player1);
+    assert_twenty_one_error(&result, Error::ContractPaused);
+    let result = client.try_forfeit(&session_id, &player1);
+    assert_eq!(result, Err(Ok(Error::ContractPaused.into())));
+    let result = client.try_reveal_seed(
+        &session_id,
+        &player1,
+        &test_seed(&env, &player1),
+        &hand_commitment(&env, &player1),
+    );
+    assert_twenty_one_error(&result, Error::ContractPaused);
+    let secret = test_hand_secret(&env, &player1);
+    let result = client.try_reveal_hand(&session_id, &player1, &secret, &Bytes::new(&env));
+    assert_twenty_one_error(&result, Error::ContractPaused);
+
+    client.unpause();
+    client.hit(&session_id, &player1);
+}
+
+#[test]
+fn test_hub_can_cancel_while_paused() {
+    let (env, client, _hub, player1, player2) = setup_test();
+
+    let session_id = 52u32;
+    start_and_deal(&env, &client, session_id, &player1, &player2, 100);
+
+    client.pause();
+    client.on_session_cancelled(&session_id);
+    assert_eq!(
+        client.get_game(&session_id).status,
+        GameStatus::Ended(GameOutcome::Cancelled)
+    );
+}
+
 // ============================================================================
 // Admin Function Tests
 // ============================================================================
@@ -764,7 +1106,7 @@ fn test_get_hub() {
 }
 
 #[test]
-fn test_set_admin() {
+fn test_admin_transfer() {
     let env = Env::default();
     env.mock_all_auths();
 
@@ -775,8 +1117,12 @@ fn test_set_admin() {
     let contract_id = env.register(TwentyOneContract, (&admin, &hub_addr));
     let client = TwentyOneContractClient::new(&env, &contract_id);
 
-    // Set new admin
-    client.set_admin(&new_admin);
+    // Propose new admin; nothing changes until they accept
+    client.propose_admin(&new_admin);
+    assert_eq!(client.get_admin(), admin);
+    assert_eq!(client.get_pending_admin(), Some(new_admin.clone()));
+
+    client.accept_admin();
 
     let retrieved_admin = client.get_admin();
     assert_eq!(retrieved_admin, new_admin);
@@ -826,35 +1172,226 @@ fn test_upgrade_function_exists() {
 // ============================================================================
 
 #[test]
-fn test_deterministic_card_dealing() {
-    let (_env, client, _hub, player1, player2) = setup_test();
+fn test_cards_not_determined_by_seeds() {
+    let (env, client, hub, player1, player2) = setup_test();
 
     let session_id = 27u32;
+    start_and_deal(&env, &client, session_id, &player1, &player2, 100_0000000);
+    client.hit(&session_id, &player1);
 
-    // Start first game
-    client.start_game(&session_id, &player1, &player2, &100_0000000, &100_0000000);
-    let game1 = client.get_game(&session_id);
+    // Same session, seeds and hand secrets on a fresh contract instance: the
+    // deck seed is the same, but every card mixes in entropy drawn when it is
+    // dealt, so knowing the seeds does not tell anyone what comes next
+    let admin = Address::generate(&env);
+    let contract_id = env.register(TwentyOneContract, (&admin, &hub.address));
+    let client2 = TwentyOneContractClient::new(&env, &contract_id);
+    start_and_deal(&env, &client2, session_id, &player1, &player2, 100_0000000);
+    client2.hit(&session_id, &player1);
 
-    // Start second game with same session_id in new environment (should be identical)
-    let (_env2, client2, _hub2, player1_2, player2_2) = setup_test();
-    client2.start_game(&session_id, &player1_2, &player2_2, &100_0000000, &100_0000000);
+    let game1 = client.get_game(&session_id);
     let game2 = client2.get_game(&session_id);
-
-    // Note: Since we generate new addresses each time, the cards will be different
-    // But we can verify that within the same session, cards are consistent
-    assert_eq!(game1.player1_hand.len(), 2);
-    assert_eq!(game2.player1_hand.len(), 2);
+    assert_eq!(game1.player1_draws.len(), 3);
+    assert_eq!(game2.player1_draws.len(), 3);
+    assert_ne!(game1.player1_draws, game2.player1_draws);
+    assert_ne!(
+        hand(&env, &client, session_id, &player1),
+        hand(&env, &client2, session_id, &player1)
+    );
+
+    // A hit only appends entropy for the new card; earlier draws stay put
+    let before = client.get_game(&session_id).player2_draws;
+    client.hit(&session_id, &player2);
+    let after = client.get_game(&session_id).player2_draws;
+    assert_eq!(after.len(), before.len() + 1);
+    assert_eq!(after.slice(0..before.len()), before);
 }
 
 #[test]
-fn test_round_counter() {
-    let (_env, client, _hub, player1, player2) = setup_test();
+fn test_initial_status_active() {
+    let (env, client, _hub, player1, player2) = setup_test();
 
     let session_id = 28u32;
-    client.start_game(&session_id, &player1, &player2, &100_0000000, &100_0000000);
+    start_and_deal(&env, &client, session_id, &player1, &player2, 100_0000000);
+
+    let game = client.get_game(&session_id);
+    assert_eq!(game.status, GameStatus::Active);
+}
+
+// ============================================================================
+// Sealed Deck Tests
+// ============================================================================
+
+#[test]
+fn test_no_cards_until_both_seeds_revealed() {
+    let (env, client, _hub, player1, player2) = setup_test();
+
+    let session_id = 70u32;
+    start_sealed(&env, &client, session_id, &player1, &player2, 100, 100);
+    reveal_seed(&env, &client, session_id, &player1);
+
+    let game = client.get_game(&session_id);
+    assert_eq!(game.player1_draws.len(), 0);
+    assert_eq!(game.player2_draws.len(), 0);
+
+    let result = client.try_hit(&session_id, &player1);
+    assert_twenty_one_error(&result, Error::DeckNotReady);
+    let result = client.try_stick(&session_id, &player1);
+    assert_twenty_one_error(&result, Error::DeckNotReady);
+
+    reveal_seed(&env, &client, session_id, &player2);
+    let game = client.get_game(&session_id);
+    assert_eq!(game.player1_draws.len(), 2);
+    assert_eq!(game.player2_draws.len(), 2);
+}
+
+#[test]
+fn test_reveal_seed_must_match_commitment() {
+    let (env, client, _hub, player1, player2) = setup_test();
+
+    let session_id = 71u32;
+    start_sealed(&env, &client, session_id, &player1, &player2, 100, 100);
+
+    // player2 cannot reveal player1's seed, nor a seed they did not commit to
+    let seed1 = test_seed(&env, &player1);
+    let result = client.try_reveal_seed(
+        &session_id,
+        &player2,
+        &seed1,
+        &hand_commitment(&env, &player2),
+    );
+    assert_twenty_one_error(&result, Error::CommitmentMismatch);
+
+    // The hand commitment is bound by the seed commitment too
+    let seed2 = test_seed(&env, &player2);
+    let result = client.try_reveal_seed(
+        &session_id,
+        &player2,
+        &seed2,
+        &hand_commitment(&env, &player1),
+    );
+    assert_twenty_one_error(&result, Error::CommitmentMismatch);
+
+    reveal_seed(&env, &client, session_id, &player1);
+    let result = client.try_reveal_seed(
+        &session_id,
+        &player1,
+        &seed1,
+        &hand_commitment(&env, &player1),
+    );
+    assert_twenty_one_error(&result, Error::AlreadyRevealed);
+
+    let non_player = Address::generate(&env);
+    let seed = test_seed(&env, &non_player);
+    let result = client.try_reveal_seed(
+        &session_id,
+        &non_player,
+        &seed,
+        &hand_commitment(&env, &non_player),
+    );
+    assert_twenty_one_error(&result, Error::NotPlayer);
+}
+
+#[test]
+fn test_hands_depend_on_both_seeds() {
+    let (env, client, hub, player1, player2) = setup_test();
+
+    let session_id = 72u32;
+    start_and_deal(&env, &client, session_id, &player1, &player2, 100);
+
+    // Same session and player1 seed, but player2 commits to another seed
+    let admin = Address::generate(&env);
+    let contract_id = env.register(TwentyOneContract, (&admin, &hub.address));
+    let client2 = TwentyOneContractClient::new(&env, &contract_id);
+    let other_seed = BytesN::from_array(&env, &[7u8; 32]);
+    client2.start_game(
+        &session_id,
+        &player1,
+        &player2,
+        &100,
+        &100,
+        &make_commitment(&env, &test_seed(&env, &player1), &player1),
+        &make_commitment(&env, &other_seed, &player2),
+    );
+    reveal_seed(&env, &client2, session_id, &player1);
+    client2.reveal_seed(
+        &session_id,
+        &player2,
+        &other_seed,
+        &hand_commitment(&env, &player2),
+    );
+
+    // Changing player2's seed changes player1's cards too
+    assert_ne!(
+        hand(&env, &client, session_id, &player1),
+        hand(&env, &client2, session_id, &player1)
+    );
+}
+
+// ============================================================================
+// Hidden Hand Tests
+// ============================================================================
+
+#[test]
+fn test_hands_hidden_during_play() {
+    let (env, client, _hub, player1, player2) = setup_test();
+
+    let session_id = 80u32;
+    start_and_deal(&env, &client, session_id, &player1, &player2, 100);
+    client.hit(&session_id, &player1);
 
+    // Only the per-card entropy is public; the cards also need the hand secret
     let game = client.get_game(&session_id);
-    assert_eq!(game.round, 1); // First round
+    assert!(game.player1_hand.is_empty());
+    assert!(game.player2_hand.is_empty());
+    assert_eq!((game.player1_draws.len(), game.player2_draws.len()), (3, 2));
+    let result = client.try_get_hand_value(&session_id, &player1);
+    assert_twenty_one_error(&result, Error::HandNotRevealed);
+}
+
+#[test]
+fn test_reveal_hand_must_match_dealt_cards() {
+    let (env, client, _hub, player1, player2) = setup_test();
+
+    let session_id = 81u32;
+    start_and_deal(&env, &client, session_id, &player1, &player2, 100);
+    client.hit(&session_id, &player1);
+
+    let secret1 = test_hand_secret(&env, &player1);
+    let cards1 = hand(&env, &client, session_id, &player1);
+
+    // No reveals while the opponent can still draw
+    client.stick(&session_id, &player1);
+    let result = client.try_reveal_hand(&session_id, &player1, &secret1, &cards1);
+    assert_twenty_one_error(&result, Error::BothPlayersNotStuck);
+    client.stick(&session_id, &player2);
+
+    // A different card list, or one without the last hit, is rejected
+    let mut better_cards = cards1.clone();
+    better_cards.set(0, if cards1.get(0).unwrap() == 13 { 12 } else { 13 });
+    let result = client.try_reveal_hand(&session_id, &player1, &secret1, &better_cards);
+    assert_twenty_one_error(&result, Error::HandMismatch);
+    let first_two = cards1.slice(0..2);
+    let result = client.try_reveal_hand(&session_id, &player1, &secret1, &first_two);
+    assert_twenty_one_error(&result, Error::HandMismatch);
+
+    // So is a secret that does not match the hand commitment
+    let secret2 = test_hand_secret(&env, &player2);
+    let result = client.try_reveal_hand(&session_id, &player1, &secret2, &cards1);
+    assert_twenty_one_error(&result, Error::CommitmentMismatch);
+
+    client.reveal_hand(&session_id, &player1, &secret1, &cards1);
+    assert_eq!(client.get_game(&session_id).player1_hand, cards1);
+    let result = client.try_reveal_hand(&session_id, &player1, &secret1, &cards1);
+    assert_twenty_one_error(&result, Error::AlreadyRevealed);
+
+    // The winner waits for both hands
+    let result = client.try_reveal_winner(&session_id);
+    assert_twenty_one_error(&result, Error::HandNotRevealed);
+
+    let cards2 = hand(&env, &client, session_id, &player2);
+    client.reveal_hand(&session_id, &player2, &secret2, &cards2);
+    client.reveal_winner(&session_id);
+    assert_ne!(client.get_game(&session_id).status, GameStatus::Active);
 }
 
 // ============================================================================
@@ -863,10 +1400,19 @@ fn test_round_counter() {
 
 #[test]
 fn test_cannot_play_against_self() {
-    let (_env, client, _hub, player1, _player2) = setup_test();
+    let (env, client, _hub, player1, _player2) = setup_test();
 
     let session_id = 29u32;
+    let commitment = make_commitment(&env, &test_seed(&env, &player1), &player1);
     // Try to start game where player1 plays against themselves
-    let result = client.try_start_game(&session_id, &player1, &player1, &100_0000000, &100_0000000);
+    let result = client.try_start_game(
+        &session_id,
+        &player1,
+        &player1,
+        &100_0000000,
+        &100_0000000,
+        &commitment,
+        &commitment,
+    );
     assert_twenty_one_error(&result, Error::SelfPlay);
 }
@@ -13,29 +13,16 @@
 //! The `accuse_zk` path submits an UltraHonk proof to an on-chain verifier contract
 //! that checks it using Protocol 25's BN254 host functions (g1_mul, g1_add, pairing_check).
 
+use sgs_game_common::{
+    hub_client, require_not_paused, require_role, write_game, GameCallbacks, GameContract,
+    GameHubClient, GameInfo, Role, SessionStatus, StakeSemantics,
+};
 use soroban_sdk::{
-    contract, contractclient, contracterror, contractimpl, contracttype, vec, Address, Bytes,
-    BytesN, Env, IntoVal,
+    contract, contractclient, contracterror, contractimpl, contracttype, symbol_short, vec,
+    Address, Bytes, BytesN, Env, IntoVal, String,
 };
 
-// ============================================================================
-// Game Hub Interface
-// ============================================================================
-
-#[contractclient(name = "GameHubClient")]
-pub trait GameHub {
-    fn start_game(
-        env: Env,
-        game_id: Address,
-        session_id: u32,
-        player1: Address,
-        player2: Address,
-        player1_points: i128,
-        player2_points: i128,
-    );
-
-    fn end_game(env: Env, session_id: u32, player1_won: bool);
-}
+pub use sgs_game_common::GameOutcome;
 
 // ============================================================================
 // UltraHonk Verifier Interface (Protocol 25)
@@ -60,7 +47,7 @@ pub enum Error {
     CaseNotFound = 2,
     /// Player is not part of this game session
     NotPlayer = 3,
-    /// Game has already ended (solved or abandoned)
+    /// Game has already ended (solved, abandoned or forfeited)
     GameAlreadyEnded = 4,
     /// Game is not in active state
     GameNotActive = 5,
@@ -76,6 +63,10 @@ pub enum Error {
     VerifierNotSet = 10,
     /// Poseidon2 commitment not set for this case
     PoseidonCommitmentNotSet = 11,
+    /// Contract is paused by the admin
+    ContractPaused = 12,
+    /// Player is not holding up the game, so cannot be forfeited
+    PlayerNotStalling = 13,
 }
 
 // ============================================================================
@@ -88,6 +79,8 @@ pub enum GameStatus {
     Active,
     Solved,
     Abandoned,
+    /// A player conceded; `winner` is their opponent
+    Forfeited,
 }
 
 #[contracttype]
@@ -117,8 +110,6 @@ pub struct PlayerStats {
 #[contracttype]
 #[derive(Clone)]
 pub enum DataKey {
-    Admin,
-    GameHubAddress,
     Case(u32),
     Game(u32),
     PlayerStats(Address),
@@ -126,13 +117,6 @@ pub enum DataKey {
     PoseidonCommitment(u32),
 }
 
-// ============================================================================
-// Constants
-// ============================================================================
-
-/// 30 days in ledgers (~5 seconds per ledger)
-const GAME_TTL_LEDGERS: u32 = 518_400;
-
 /// Max suspects (1-9), weapons (1-5), rooms (1-5)
 const MAX_SUSPECT_ID: u32 = 9;
 const MAX_WEAPON_ID: u32 = 5;
@@ -185,30 +169,23 @@ pub struct ZkDetectiveContract;
 impl ZkDetectiveContract {
     /// Initialize the contract with admin and Game Hub addresses.
     pub fn __constructor(env: Env, admin: Address, game_hub: Address) {
-        env.storage().instance().set(&DataKey::Admin, &admin);
-        env.storage()
-            .instance()
-            .set(&DataKey::GameHubAddress, &game_hub);
+        sgs_game_common::init(&env, &admin, &game_hub);
     }
 
     // ========================================================================
-    // Case Management (Admin)
+    // Case Management (Operator)
     // ========================================================================
 
     /// Register a new case with its solution commitment hash.
     /// The commitment is `keccak256(suspect_id || weapon_id || room_id || salt)`.
-    /// Admin only.
+    /// Operator only.
     pub fn create_case(
         env: Env,
+        caller: Address,
         case_id: u32,
         commitment: BytesN<32>,
     ) -> Result<(), Error> {
-        let admin: Address = env
-            .storage()
-            .instance()
-            .get(&DataKey::Admin)
-            .expect("Admin not set");
-        admin.require_auth();
+        require_role(&env, Role::Operator, &caller);
 
         let key = DataKey::Case(case_id);
         if env.storage().persistent().has(&key) {
@@ -244,6 +221,8 @@ impl ZkDetectiveContract {
         player2_points: i128,
         case_id: u32,
     ) -> Result<(), Error> {
+        require_not_paused(&env, Error::ContractPaused)?;
+
         // Validate case exists
         let case_key = DataKey::Case(case_id);
         if !env.storage().persistent().has(&case_key) {
@@ -269,12 +248,7 @@ impl ZkDetectiveContract {
         ]);
 
         // Call Game Hub
-        let game_hub_addr: Address = env
-            .storage()
-            .instance()
-            .get(&DataKey::GameHubAddress)
-            .expect("GameHub address not set");
-        let game_hub = GameHubClient::new(&env, &game_hub_addr);
+        let game_hub = hub_client(&env);
 
         game_hub.start_game(
             &env.current_contract_address(),
@@ -300,10 +274,7 @@ impl ZkDetectiveContract {
             winner: None,
         };
 
-        env.storage().temporary().set(&game_key, &game);
-        env.storage()
-            .temporary()
-            .extend_ttl(&game_key, GAME_TTL_LEDGERS, GAME_TTL_LEDGERS);
+        write_game(&env, &game_key, &game);
 
         Ok(())
     }
@@ -317,6 +288,8 @@ impl ZkDetectiveContract {
         clues_inspected: u32,
         rooms_visited: u32,
     ) -> Result<(), Error> {
+        require_not_paused(&env, Error::ContractPaused)?;
+
         player.require_auth();
 
         let key = DataKey::Game(session_id);
@@ -337,10 +310,7 @@ impl ZkDetectiveContract {
         game.clues_inspected = clues_inspected;
         game.rooms_visited = rooms_visited;
 
-        env.storage().temporary().set(&key, &game);
-        env.storage()
-            .temporary()
-            .extend_ttl(&key, GAME_TTL_LEDGERS, GAME_TTL_LEDGERS);
+        write_game(&env, &key, &game);
 
         Ok(())
     }
@@ -357,6 +327,8 @@ impl ZkDetectiveContract {
         room_id: u32,
         salt: BytesN<32>,
     ) -> Result<bool, Error> {
+        require_not_paused(&env, Error::ContractPaused)?;
+
         player.require_auth();
 
         validate_accusation_ids(suspect_id, weapon_id, room_id)?;
@@ -395,15 +367,11 @@ impl ZkDetectiveContract {
             game.solve_ledger = env.ledger().sequence();
 
             // Call Game Hub end_game
-            let game_hub_addr: Address = env
-                .storage()
-                .instance()
-                .get(&DataKey::GameHubAddress)
-                .expect("GameHub address not set");
-            let game_hub = GameHubClient::new(&env, &game_hub_addr);
+            let game_hub = hub_client(&env);
 
             let player1_won = player == game.player1;
             game_hub.end_game(&session_id, &player1_won);
+            Self::report_solve(&game_hub, session_id, &player, &game);
 
             // Update player stats
             Self::update_player_stats(&env, &player, &game);
@@ -411,22 +379,15 @@ impl ZkDetectiveContract {
             game.wrong_accusations += 1;
         }
 
-        env.storage().temporary().set(&key, &game);
-        env.storage()
-            .temporary()
-            .extend_ttl(&key, GAME_TTL_LEDGERS, GAME_TTL_LEDGERS);
+        write_game(&env, &key, &game);
 
         Ok(is_correct)
     }
 
     /// Abandon a game session (timeout, player quits). Admin only.
+    /// The Game Hub session is cancelled so both stakes are refunded.
     pub fn abandon_game(env: Env, session_id: u32) -> Result<(), Error> {
-        let admin: Address = env
-            .storage()
-            .instance()
-            .get(&DataKey::Admin)
-            .expect("Admin not set");
-        admin.require_auth();
+        sgs_game_common::require_admin(&env);
 
         let key = DataKey::Game(session_id);
         let mut game: GameState = env
@@ -441,10 +402,10 @@ impl ZkDetectiveContract {
 
         game.status = GameStatus::Abandoned;
 
-        env.storage().temporary().set(&key, &game);
-        env.storage()
-            .temporary()
-            .extend_ttl(&key, GAME_TTL_LEDGERS, GAME_TTL_LEDGERS);
+        let game_hub = hub_client(&env);
+        game_hub.end_game_with_outcome(&session_id, &GameOutcome::Cancelled);
+
+        write_game(&env, &key, &game);
 
         Ok(())
     }
@@ -455,37 +416,29 @@ impl ZkDetectiveContract {
 
     /// Set the UltraHonk verifier contract address. Admin only.
     pub fn set_verifier(env: Env, verifier: Address) {
-        let admin: Address = env
-            .storage()
-            .instance()
-            .get(&DataKey::Admin)
-            .expect("Admin not set");
-        admin.require_auth();
+        sgs_game_common::require_admin(&env);
 
         env.storage()
             .instance()
             .set(&DataKey::VerifierAddress, &verifier);
     }
 
-    /// Set the Poseidon2 commitment for a case. Admin only.
+    /// Set the Poseidon2 commitment for a case. Operator only.
     /// This is the Poseidon2 hash of [suspect, weapon, room, salt] used by the ZK circuit.
-    pub fn set_poseidon_commitment(env: Env, case_id: u32, commitment: BytesN<32>) {
-        let admin: Address = env
-            .storage()
-            .instance()
-            .get(&DataKey::Admin)
-            .expect("Admin not set");
-        admin.require_auth();
+    pub fn set_poseidon_commitment(
+        env: Env,
+        caller: Address,
+        case_id: u32,
+        commitment: BytesN<32>,
+    ) {
+        require_role(&env, Role::Operator, &caller);
 
         let key = DataKey::PoseidonCommitment(case_id);
         env.storage().persistent().set(&key, &commitment);
     }
 
     /// Get the Poseidon2 commitment for a case.
-    pub fn get_poseidon_commitment(
-        env: Env,
-        case_id: u32,
-    ) -> Result<BytesN<32>, Error> {
+    pub fn get_poseidon_commitment(env: Env, case_id: u32) -> Result<BytesN<32>, Error> {
         let key = DataKey::PoseidonCommitment(case_id);
         env.storage()
             .persistent()
@@ -507,6 +460,8 @@ impl ZkDetectiveContract {
         proof_bytes: Bytes,
         public_inputs: Bytes,
     ) -> Result<bool, Error> {
+        require_not_paused(&env, Error::ContractPaused)?;
+
         player.require_auth();
 
         let key = DataKey::Game(session_id);
@@ -541,15 +496,11 @@ impl ZkDetectiveContract {
             game.solve_ledger = env.ledger().sequence();
 
             // Call Game Hub end_game
-            let game_hub_addr: Address = env
-                .storage()
-                .instance()
-                .get(&DataKey::GameHubAddress)
-                .expect("GameHub address not set");
-            let game_hub = GameHubClient::new(&env, &game_hub_addr);
+            let game_hub = hub_client(&env);
 
             let player1_won = player == game.player1;
             game_hub.end_game(&session_id, &player1_won);
+            Self::report_solve(&game_hub, session_id, &player, &game);
 
             // Update player stats
             Self::update_player_stats(&env, &player, &game);
@@ -557,10 +508,7 @@ impl ZkDetectiveContract {
             game.wrong_accusations += 1;
         }
 
-        env.storage().temporary().set(&key, &game);
-        env.storage()
-            .temporary()
-            .extend_ttl(&key, GAME_TTL_LEDGERS, GAME_TTL_LEDGERS);
+        write_game(&env, &key, &game);
 
         Ok(is_correct)
     }
@@ -588,60 +536,6 @@ impl ZkDetectiveContract {
         })
     }
 
-    /// Get admin address.
-    pub fn get_admin(env: Env) -> Address {
-        env.storage()
-            .instance()
-            .get(&DataKey::Admin)
-            .expect("Admin not set")
-    }
-
-    /// Set a new admin. Current admin only.
-    pub fn set_admin(env: Env, new_admin: Address) {
-        let admin: Address = env
-            .storage()
-            .instance()
-            .get(&DataKey::Admin)
-            .expect("Admin not set");
-        admin.require_auth();
-
-        env.storage().instance().set(&DataKey::Admin, &new_admin);
-    }
-
-    /// Get Game Hub address.
-    pub fn get_hub(env: Env) -> Address {
-        env.storage()
-            .instance()
-            .get(&DataKey::GameHubAddress)
-            .expect("GameHub address not set")
-    }
-
-    /// Set a new Game Hub address. Admin only.
-    pub fn set_hub(env: Env, new_hub: Address) {
-        let admin: Address = env
-            .storage()
-            .instance()
-            .get(&DataKey::Admin)
-            .expect("Admin not set");
-        admin.require_auth();
-
-        env.storage()
-            .instance()
-            .set(&DataKey::GameHubAddress, &new_hub);
-    }
-
-    /// Upgrade contract WASM. Admin only.
-    pub fn upgrade(env: Env, new_wasm_hash: BytesN<32>) {
-        let admin: Address = env
-            .storage()
-            .instance()
-            .get(&DataKey::Admin)
-            .expect("Admin not set");
-        admin.require_auth();
-
-        env.deployer().update_current_contract_wasm(new_wasm_hash);
-    }
-
     // ========================================================================
     // Internal Helpers
     // ========================================================================
@@ -665,19 +559,24 @@ impl ZkDetectiveContract {
         (base_score - time_penalty - accusation_penalty + exploration_bonus).max(0)
     }
 
+    /// Report a solve to the Game Hub, which awards achievements on these stats.
+    /// `solved` counts every solve; `perfect` counts solves without a wrong accusation.
+    fn report_solve(game_hub: &GameHubClient, session_id: u32, player: &Address, game: &GameState) {
+        game_hub.report_stat(&session_id, player, &symbol_short!("solved"), &1);
+        if game.wrong_accusations == 0 {
+            game_hub.report_stat(&session_id, player, &symbol_short!("perfect"), &1);
+        }
+    }
+
     /// Update player stats after solving a case.
     fn update_player_stats(env: &Env, player: &Address, game: &GameState) {
         let key = DataKey::PlayerStats(player.clone());
 
-        let mut stats: PlayerStats =
-            env.storage()
-                .persistent()
-                .get(&key)
-                .unwrap_or(PlayerStats {
-                    best_score: 0,
-                    cases_solved: 0,
-                    total_games: 0,
-                });
+        let mut stats: PlayerStats = env.storage().persistent().get(&key).unwrap_or(PlayerStats {
+            best_score: 0,
+            cases_solved: 0,
+            total_games: 0,
+        });
 
         let score = Self::compute_score(game);
 
@@ -691,5 +590,130 @@ impl ZkDetectiveContract {
     }
 }
 
+// ============================================================================
+// Game Interface
+// ============================================================================
+
+#[contractimpl]
+impl GameContract for ZkDetectiveContract {
+    /// Describe the game: two players race on one case, winner takes both stakes
+    fn game_info(env: Env) -> GameInfo {
+        GameInfo {
+            name: String::from_str(&env, "ZK Detective"),
+            rules_version: 1,
+            min_players: 2,
+            max_players: 2,
+            stakes: StakeSemantics::WinnerTakesAll,
+        }
+    }
+
+    /// Get the status of a session, or `None` if it does not exist (or expired).
+    /// A solved case ends as a win for the solver; an abandoned one as cancelled.
+    fn get_session_status(env: Env, session_id: u32) -> Option<SessionStatus> {
+        let game: GameState = env.storage().temporary().get(&DataKey::Game(session_id))?;
+        let player1_won = game.winner.as_ref() == Some(&game.player1);
+        Some(match game.status {
+            GameStatus::Active => SessionStatus::Active,
+            GameStatus::Solved if player1_won => SessionStatus::Ended(GameOutcome::Player1Won),
+            GameStatus::Solved => SessionStatus::Ended(GameOutcome::Player2Won),
+            GameStatus::Abandoned => SessionStatus::Ended(GameOutcome::Cancelled),
+            GameStatus::Forfeited if player1_won => {
+                SessionStatus::Ended(GameOutcome::Forfeit(game.player2))
+            }
+            GameStatus::Forfeited => SessionStatus::Ended(GameOutcome::Forfeit(game.player1)),
+        })
+    }
+
+    /// Concede the race. The opponent wins and the Game Hub settles the points.
+    fn forfeit(env: Env, session_id: u32, player: Address) -> Result<(), soroban_sdk::Error> {
+        require_not_paused(&env, Error::ContractPaused)?;
+        player.require_auth();
+
+        let key = DataKey::Game(session_id);
+        let mut game: GameState = env
+            .storage()
+            .temporary()
+            .get(&key)
+            .ok_or(Error::GameNotFound)?;
+
+        if game.status != GameStatus::Active {
+            return Err(Error::GameAlreadyEnded.into());
+        }
+
+        let opponent = if player == game.player1 {
+            game.player2.clone()
+        } else if player == game.player2 {
+            game.player1.clone()
+        } else {
+            return Err(Error::NotPlayer.into());
+        };
+
+        game.status = GameStatus::Forfeited;
+        game.winner = Some(opponent);
+        write_game(&env, &key, &game);
+
+        hub_client(&env).end_game_with_outcome(&session_id, &GameOutcome::Forfeit(player));
+
+        Ok(())
+    }
+}
+
+// ============================================================================
+// Game Hub Callbacks
+// ============================================================================
+
+#[contractimpl]
+impl GameCallbacks for ZkDetectiveContract {
+    /// Called by the Game Hub when a timeout is claimed against `player`.
+    /// Both players race on the same case with no turns, so neither is ever
+    /// holding up the other and the forfeit is always rejected. A stalled
+    /// game is cancelled by the hub once the grace period has passed.
+    fn on_session_forfeited(
+        env: Env,
+        session_id: u32,
+        player: Address,
+    ) -> Result<(), soroban_sdk::Error> {
+        Self::get_hub(env.clone()).require_auth();
+
+        let game = Self::get_game(env, session_id)?;
+        if game.status != GameStatus::Active {
+            return Err(Error::GameAlreadyEnded.into());
+        }
+        if player != game.player1 && player != game.player2 {
+            return Err(Error::NotPlayer.into());
+        }
+
+        Err(Error::PlayerNotStalling.into())
+    }
+
+    /// Called by the Game Hub when it cancels the session (timeout or pause).
+    /// The game is abandoned without a winner; the hub refunds both stakes.
+    fn on_session_cancelled(env: Env, session_id: u32) -> Result<(), soroban_sdk::Error> {
+        Self::get_hub(env.clone()).require_auth();
+
+        let key = DataKey::Game(session_id);
+        let mut game: GameState = env
+            .storage()
+            .temporary()
+            .get(&key)
+            .ok_or(Error::GameNotFound)?;
+
+        if game.status != GameStatus::Active {
+            return Err(Error::GameAlreadyEnded.into());
+        }
+
+        game.status = GameStatus::Abandoned;
+        env.storage().temporary().set(&key, &game);
+
+        Ok(())
+    }
+}
+
+// ============================================================================
+// Admin Functions
+// ============================================================================
+
+sgs_game_common::game_admin_entrypoints!(ZkDetectiveContract);
+
 #[cfg(test)]
 mod test;
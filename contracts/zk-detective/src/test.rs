@@ -1,8 +1,11 @@
 #![cfg(test)]
 
-use crate::{Error, GameStatus, ZkDetectiveContract, ZkDetectiveContractClient};
+use crate::{Error, GameOutcome, GameStatus, ZkDetectiveContract, ZkDetectiveContractClient};
+use sgs_game_common::{Role, SessionStatus, StakeSemantics};
 use soroban_sdk::testutils::{Address as _, Ledger as _, LedgerInfo};
-use soroban_sdk::{contract, contractimpl, Address, Bytes, BytesN, Env};
+use soroban_sdk::{
+    contract, contractimpl, symbol_short, Address, Bytes, BytesN, Env, String, Symbol,
+};
 
 // ============================================================================
 // Mock GameHub
@@ -26,7 +29,18 @@ impl MockGameHub {
 
     pub fn end_game(_env: Env, _session_id: u32, _player1_won: bool) {}
 
+    pub fn end_game_with_outcome(_env: Env, _session_id: u32, _outcome: GameOutcome) {}
+
     pub fn add_game(_env: Env, _game_address: Address) {}
+
+    pub fn report_stat(env: Env, _session_id: u32, player: Address, key: Symbol, value: i128) {
+        let total = Self::get_stat(env.clone(), player.clone(), key.clone()) + value;
+        env.storage().instance().set(&(player, key), &total);
+    }
+
+    pub fn get_stat(env: Env, player: Address, key: Symbol) -> i128 {
+        env.storage().instance().get(&(player, key)).unwrap_or(0)
+    }
 }
 
 // ============================================================================
@@ -140,7 +154,7 @@ fn setup_with_case() -> (
     let (env, client, admin, player1, player2) = setup_test();
     let salt = case_salt(&env);
     let commitment = make_commitment(&env, 1, 1, 1, &salt);
-    client.create_case(&1u32, &commitment);
+    client.create_case(&admin, &1u32, &commitment);
     (env, client, admin, player1, player2, salt)
 }
 
@@ -171,7 +185,7 @@ fn test_constructor_sets_admin() {
 
 #[test]
 fn test_constructor_sets_hub() {
-    let (env, client, _admin, _p1, _p2) = setup_test();
+    let (_env, client, _admin, _p1, _p2) = setup_test();
     // Hub address should be set (we can't check the exact value easily,
     // but get_hub should not panic)
     let _hub = client.get_hub();
@@ -183,10 +197,10 @@ fn test_constructor_sets_hub() {
 
 #[test]
 fn test_create_case() {
-    let (env, client, _admin, _p1, _p2) = setup_test();
+    let (env, client, admin, _p1, _p2) = setup_test();
     let salt = case_salt(&env);
     let commitment = make_commitment(&env, 1, 1, 1, &salt);
-    client.create_case(&1u32, &commitment);
+    client.create_case(&admin, &1u32, &commitment);
 
     let stored = client.get_case(&1u32);
     assert_eq!(stored, commitment);
@@ -194,14 +208,14 @@ fn test_create_case() {
 
 #[test]
 fn test_create_multiple_cases() {
-    let (env, client, _admin, _p1, _p2) = setup_test();
+    let (env, client, admin, _p1, _p2) = setup_test();
     let salt = case_salt(&env);
 
     let comm1 = make_commitment(&env, 1, 1, 1, &salt);
     let comm2 = make_commitment(&env, 2, 3, 4, &salt);
 
-    client.create_case(&1u32, &comm1);
-    client.create_case(&2u32, &comm2);
+    client.create_case(&admin, &1u32, &comm1);
+    client.create_case(&admin, &2u32, &comm2);
 
     assert_eq!(client.get_case(&1u32), comm1);
     assert_eq!(client.get_case(&2u32), comm2);
@@ -209,13 +223,13 @@ fn test_create_multiple_cases() {
 
 #[test]
 fn test_create_case_duplicate_rejected() {
-    let (env, client, _admin, _p1, _p2) = setup_test();
+    let (env, client, admin, _p1, _p2) = setup_test();
     let salt = case_salt(&env);
     let commitment = make_commitment(&env, 1, 1, 1, &salt);
 
-    client.create_case(&1u32, &commitment);
+    client.create_case(&admin, &1u32, &commitment);
 
-    let result = client.try_create_case(&1u32, &commitment);
+    let result = client.try_create_case(&admin, &1u32, &commitment);
     assert_detective_error(&result, Error::CaseAlreadyExists);
 }
 
@@ -368,6 +382,24 @@ fn test_correct_after_wrong() {
     assert_eq!(game.wrong_accusations, 1);
 }
 
+#[test]
+fn test_solve_reports_stats_to_hub() {
+    let (env, client, _admin, player1, player2, salt) = setup_with_game();
+    let hub = MockGameHubClient::new(&env, &client.get_hub());
+
+    client.accuse(&1u32, &player1, &1u32, &1u32, &1u32, &salt);
+    assert_eq!(hub.get_stat(&player1, &symbol_short!("solved")), 1);
+    assert_eq!(hub.get_stat(&player1, &symbol_short!("perfect")), 1);
+
+    // A solve after a wrong accusation is not perfect
+    client.start_game(&2u32, &player1, &player2, &100, &100, &1u32);
+    let wrong_salt = BytesN::from_array(&env, &[0xFF; 32]);
+    client.accuse(&2u32, &player2, &9u32, &5u32, &5u32, &wrong_salt);
+    client.accuse(&2u32, &player2, &1u32, &1u32, &1u32, &salt);
+    assert_eq!(hub.get_stat(&player2, &symbol_short!("solved")), 1);
+    assert_eq!(hub.get_stat(&player2, &symbol_short!("perfect")), 0);
+}
+
 #[test]
 fn test_accuse_not_player() {
     let (env, client, _admin, _player1, _player2, salt) = setup_with_game();
@@ -481,6 +513,42 @@ fn test_accuse_after_abandon() {
     assert_detective_error(&result, Error::GameNotActive);
 }
 
+// ============================================================================
+// Game Hub Callback Tests
+// ============================================================================
+
+#[test]
+fn test_hub_cancels_session() {
+    let (env, client, _admin, player1, _player2, salt) = setup_with_game();
+
+    client.on_session_cancelled(&1u32);
+    assert_eq!(env.auths()[0].0, client.get_hub());
+
+    let game = client.get_game(&1u32);
+    assert_eq!(game.status, GameStatus::Abandoned);
+    assert!(game.winner.is_none());
+
+    let result = client.try_accuse(&1u32, &player1, &1u32, &1u32, &1u32, &salt);
+    assert_detective_error(&result, Error::GameNotActive);
+    let result = client.try_on_session_cancelled(&1u32);
+    assert_eq!(result, Err(Ok(Error::GameAlreadyEnded.into())));
+}
+
+#[test]
+fn test_hub_forfeit_always_rejected() {
+    let (env, client, _admin, player1, player2, _salt) = setup_with_game();
+
+    let result = client.try_on_session_forfeited(&1u32, &player1);
+    assert_eq!(result, Err(Ok(Error::PlayerNotStalling.into())));
+    let result = client.try_on_session_forfeited(&1u32, &player2);
+    assert_eq!(result, Err(Ok(Error::PlayerNotStalling.into())));
+
+    let outsider = Address::generate(&env);
+    let result = client.try_on_session_forfeited(&1u32, &outsider);
+    assert_eq!(result, Err(Ok(Error::NotPlayer.into())));
+    assert_eq!(client.get_game(&1u32).status, GameStatus::Active);
+}
+
 // ============================================================================
 // Player Stats / Leaderboard Tests
 // ============================================================================
@@ -561,7 +629,7 @@ fn test_player_stats_exploration_bonus() {
 
 #[test]
 fn test_player_stats_best_score_updates() {
-    let (env, client, admin, player1, player2, _salt) = setup_with_case();
+    let (env, client, _admin, player1, player2, _salt) = setup_with_case();
 
     let salt = case_salt(&env);
 
@@ -582,7 +650,10 @@ fn test_player_stats_best_score_updates() {
     let second_score = client.get_player_stats(&player1).best_score;
     let stats = client.get_player_stats(&player1);
 
-    assert!(second_score > first_score, "Clean solve should have higher score");
+    assert!(
+        second_score > first_score,
+        "Clean solve should have higher score"
+    );
     assert_eq!(stats.cases_solved, 2);
     assert_eq!(stats.total_games, 2);
 }
@@ -592,14 +663,39 @@ fn test_player_stats_best_score_updates() {
 // ============================================================================
 
 #[test]
-fn test_set_admin() {
+fn test_admin_transfer_requires_accept() {
     let (env, client, admin, _p1, _p2) = setup_test();
     let new_admin = Address::generate(&env);
 
-    client.set_admin(&new_admin);
+    client.propose_admin(&new_admin);
+    assert_eq!(client.get_admin(), admin);
+
+    client.accept_admin();
+    assert_eq!(env.auths()[0].0, new_admin);
     assert_eq!(client.get_admin(), new_admin);
 }
 
+#[test]
+fn test_operator_manages_cases() {
+    let (env, client, _admin, _p1, _p2) = setup_test();
+    let operator = Address::generate(&env);
+    let salt = case_salt(&env);
+    let commitment = make_commitment(&env, 1, 1, 1, &salt);
+
+    let result = client.try_create_case(&operator, &1u32, &commitment);
+    assert!(result.is_err());
+
+    client.grant_role(&Role::Operator, &operator);
+    client.create_case(&operator, &1u32, &commitment);
+    assert_eq!(env.auths()[0].0, operator);
+    client.set_poseidon_commitment(&operator, &1u32, &commitment);
+    assert_eq!(client.get_poseidon_commitment(&1u32), commitment);
+
+    client.revoke_role(&Role::Operator, &operator);
+    let result = client.try_create_case(&operator, &2u32, &commitment);
+    assert!(result.is_err());
+}
+
 #[test]
 fn test_set_hub() {
     let (env, client, _admin, _p1, _p2) = setup_test();
@@ -609,18 +705,107 @@ fn test_set_hub() {
     assert_eq!(client.get_hub(), new_hub);
 }
 
+#[test]
+fn test_pause_blocks_gameplay_but_not_abandon() {
+    let (_env, client, _admin, player1, player2, salt) = setup_with_game();
+
+    client.pause();
+    assert!(client.is_paused());
+
+    let result = client.try_start_game(&2u32, &player1, &player2, &100, &100, &1u32);
+    assert_detective_error(&result, Error::ContractPaused);
+    let result = client.try_update_progress(&1u32, &player1, &5u32, &3u32);
+    assert_detective_error(&result, Error::ContractPaused);
+    let result = client.try_accuse(&1u32, &player1, &1u32, &1u32, &1u32, &salt);
+    assert_detective_error(&result, Error::ContractPaused);
+    let result = client.try_forfeit(&1u32, &player1);
+    assert_eq!(result, Err(Ok(Error::ContractPaused.into())));
+
+    client.abandon_game(&1u32);
+    assert_eq!(client.get_game(&1u32).status, GameStatus::Abandoned);
+
+    client.unpause();
+    client.start_game(&2u32, &player1, &player2, &100, &100, &1u32);
+}
+
+// ============================================================================
+// Game Interface Tests
+// ============================================================================
+
+#[test]
+fn test_game_info() {
+    let (env, client, _admin, _player1, _player2) = setup_test();
+
+    let info = client.game_info();
+    assert_eq!(info.name, String::from_str(&env, "ZK Detective"));
+    assert_eq!((info.min_players, info.max_players), (2, 2));
+    assert_eq!(info.stakes, StakeSemantics::WinnerTakesAll);
+}
+
+#[test]
+fn test_session_status_maps_game_status() {
+    let (env, client, _admin, player1, player2, salt) = setup_with_game();
+    assert_eq!(
+        client.get_session_status(&1u32),
+        Some(SessionStatus::Active)
+    );
+    assert_eq!(client.get_session_status(&9u32), None);
+
+    client.accuse(&1u32, &player2, &1u32, &1u32, &1u32, &salt);
+    assert_eq!(
+        client.get_session_status(&1u32),
+        Some(SessionStatus::Ended(GameOutcome::Player2Won))
+    );
+
+    client.start_game(&2u32, &player1, &player2, &100, &100, &1u32);
+    client.abandon_game(&2u32);
+    assert_eq!(
+        client.get_session_status(&2u32),
+        Some(SessionStatus::Ended(GameOutcome::Cancelled))
+    );
+
+    let outsider = Address::generate(&env);
+    client.start_game(&3u32, &player1, &outsider, &100, &100, &1u32);
+    client.forfeit(&3u32, &player1);
+    assert_eq!(
+        client.get_session_status(&3u32),
+        Some(SessionStatus::Ended(GameOutcome::Forfeit(player1)))
+    );
+}
+
+#[test]
+fn test_forfeit_ends_game_for_opponent() {
+    let (env, client, _admin, player1, player2, salt) = setup_with_game();
+
+    let outsider = Address::generate(&env);
+    let result = client.try_forfeit(&1u32, &outsider);
+    assert_eq!(result, Err(Ok(Error::NotPlayer.into())));
+
+    client.forfeit(&1u32, &player2);
+    assert_eq!(env.auths()[0].0, player2);
+
+    let game = client.get_game(&1u32);
+    assert_eq!(game.status, GameStatus::Forfeited);
+    assert_eq!(game.winner, Some(player1.clone()));
+
+    let result = client.try_accuse(&1u32, &player1, &1u32, &1u32, &1u32, &salt);
+    assert_detective_error(&result, Error::GameNotActive);
+    let result = client.try_forfeit(&1u32, &player1);
+    assert_eq!(result, Err(Ok(Error::GameAlreadyEnded.into())));
+}
+
 // ============================================================================
 // Full Game Flow Tests
 // ============================================================================
 
 #[test]
 fn test_full_game_flow() {
-    let (env, client, _admin, player1, player2) = setup_test();
+    let (env, client, admin, player1, player2) = setup_test();
 
     // 1. Create case: victor (1), poison_vial (1), bedroom (1)
     let salt = case_salt(&env);
     let commitment = make_commitment(&env, 1, 1, 1, &salt);
-    client.create_case(&1u32, &commitment);
+    client.create_case(&admin, &1u32, &commitment);
 
     // 2. Start game
     let points: i128 = 100_0000000;
@@ -668,7 +853,7 @@ fn test_player2_can_solve() {
 
 #[test]
 fn test_commitment_integrity() {
-    let (env, client, _admin, _p1, _p2) = setup_test();
+    let (env, _client, _admin, _p1, _p2) = setup_test();
 
     // Different solutions produce different commitments
     let salt = case_salt(&env);
@@ -677,9 +862,18 @@ fn test_commitment_integrity() {
     let comm_c = make_commitment(&env, 1, 2, 1, &salt);
     let comm_d = make_commitment(&env, 1, 1, 2, &salt);
 
-    assert_ne!(comm_a, comm_b, "Different suspect should produce different hash");
-    assert_ne!(comm_a, comm_c, "Different weapon should produce different hash");
-    assert_ne!(comm_a, comm_d, "Different room should produce different hash");
+    assert_ne!(
+        comm_a, comm_b,
+        "Different suspect should produce different hash"
+    );
+    assert_ne!(
+        comm_a, comm_c,
+        "Different weapon should produce different hash"
+    );
+    assert_ne!(
+        comm_a, comm_d,
+        "Different room should produce different hash"
+    );
 
     // Same inputs produce same commitment
     let comm_a2 = make_commitment(&env, 1, 1, 1, &salt);
@@ -700,9 +894,9 @@ fn test_set_verifier() {
 
 #[test]
 fn test_set_poseidon_commitment() {
-    let (env, client, _admin, _p1, _p2) = setup_test();
+    let (env, client, admin, _p1, _p2) = setup_test();
     let commitment = BytesN::from_array(&env, &[0x42; 32]);
-    client.set_poseidon_commitment(&1u32, &commitment);
+    client.set_poseidon_commitment(&admin, &1u32, &commitment);
 
     let stored = client.get_poseidon_commitment(&1u32);
     assert_eq!(stored, commitment);
@@ -6,34 +6,22 @@
 //! Players find a hidden target in an image and commit a hash of their
 //! coordinates. After both commit, they reveal. The admin then resolves
 //! the game by revealing the true target (verified against a pre-committed hash).
+//! Equal distances are broken by the earlier commitment; a full tie is a draw.
 //!
 //! **ZK rationale:** Submitting coordinates publicly would reveal the answer
 //! to opponents. Cryptographic commitments let players prove they found the
 //! target without leaking coordinates until both have committed.
 
+use sgs_game_common::{
+    hub_client, require_not_paused, require_role, write_game, GameCallbacks, GameContract,
+    GameInfo, Role, SessionStatus, StakeSemantics,
+};
 use soroban_sdk::{
-    contract, contractclient, contracterror, contractimpl, contracttype, vec, Address, Bytes,
-    BytesN, Env, IntoVal,
+    contract, contracterror, contractimpl, contracttype, vec, Address, Bytes, BytesN, Env, IntoVal,
+    String,
 };
 
-// ============================================================================
-// Game Hub Interface
-// ============================================================================
-
-#[contractclient(name = "GameHubClient")]
-pub trait GameHub {
-    fn start_game(
-        env: Env,
-        game_id: Address,
-        session_id: u32,
-        player1: Address,
-        player2: Address,
-        player1_points: i128,
-        player2_points: i128,
-    );
-
-    fn end_game(env: Env, session_id: u32, player1_won: bool);
-}
+pub use sgs_game_common::GameOutcome;
 
 // ============================================================================
 // Errors
@@ -54,12 +42,22 @@ pub enum Error {
     CommitmentMismatch = 9,
     GameAlreadyEnded = 10,
     InvalidTargetReveal = 11,
+    ContractPaused = 12,
+    SessionAlreadyExists = 13,
+    PlayerNotStalling = 14,
 }
 
 // ============================================================================
 // Data Types
 // ============================================================================
 
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum GameStatus {
+    Active,
+    Ended(GameOutcome),
+}
+
 #[contracttype]
 #[derive(Clone, Debug, Eq, PartialEq)]
 pub struct Scene {
@@ -88,24 +86,16 @@ pub struct Game {
     pub player2_y: Option<u32>,
     // Resolution
     pub winner: Option<Address>,
+    pub status: GameStatus,
 }
 
 #[contracttype]
 #[derive(Clone)]
 pub enum DataKey {
-    Admin,
-    GameHubAddress,
     Scene(u32),
     Game(u32),
 }
 
-// ============================================================================
-// Constants
-// ============================================================================
-
-/// 30 days in ledgers (~5 seconds per ledger)
-const GAME_TTL_LEDGERS: u32 = 518_400;
-
 // ============================================================================
 // Helper Functions
 // ============================================================================
@@ -122,8 +112,29 @@ fn is_within_tolerance(px: u32, py: u32, tx: u32, ty: u32, tolerance: u32) -> bo
     distance_squared(px, py, tx, ty) <= (tolerance as u64) * (tolerance as u64)
 }
 
+/// Closer guess wins; equal distances go to the earlier commitment.
+fn closer_outcome(dist1: u64, dist2: u64, ledger1: u32, ledger2: u32) -> GameOutcome {
+    if dist1 < dist2 {
+        GameOutcome::Player1Won
+    } else if dist2 < dist1 {
+        GameOutcome::Player2Won
+    } else if ledger1 < ledger2 {
+        GameOutcome::Player1Won
+    } else if ledger2 < ledger1 {
+        GameOutcome::Player2Won
+    } else {
+        GameOutcome::Draw
+    }
+}
+
 /// Compute the commitment hash: keccak256(x || y || salt || player_address)
-fn compute_commitment(env: &Env, x: u32, y: u32, salt: &BytesN<32>, player: &Address) -> BytesN<32> {
+fn compute_commitment(
+    env: &Env,
+    x: u32,
+    y: u32,
+    salt: &BytesN<32>,
+    player: &Address,
+) -> BytesN<32> {
     let mut data = Bytes::new(env);
     data.append(&Bytes::from_array(env, &x.to_be_bytes()));
     data.append(&Bytes::from_array(env, &y.to_be_bytes()));
@@ -153,24 +164,18 @@ pub struct ZkSeekContract;
 #[contractimpl]
 impl ZkSeekContract {
     pub fn __constructor(env: Env, admin: Address, game_hub: Address) {
-        env.storage().instance().set(&DataKey::Admin, &admin);
-        env.storage()
-            .instance()
-            .set(&DataKey::GameHubAddress, &game_hub);
+        sgs_game_common::init(&env, &admin, &game_hub);
     }
 
+    /// Register a scene with its target commitment. Operator only.
     pub fn create_scene(
         env: Env,
+        caller: Address,
         scene_id: u32,
         target_commitment: BytesN<32>,
         tolerance: u32,
     ) {
-        let admin: Address = env
-            .storage()
-            .instance()
-            .get(&DataKey::Admin)
-            .expect("Admin not set");
-        admin.require_auth();
+        require_role(&env, Role::Operator, &caller);
 
         let scene = Scene {
             target_commitment,
@@ -183,12 +188,7 @@ impl ZkSeekContract {
     }
 
     pub fn deactivate_scene(env: Env, scene_id: u32) -> Result<(), Error> {
-        let admin: Address = env
-            .storage()
-            .instance()
-            .get(&DataKey::Admin)
-            .expect("Admin not set");
-        admin.require_auth();
+        sgs_game_common::require_admin(&env);
 
         let key = DataKey::Scene(scene_id);
         let mut scene: Scene = env
@@ -212,6 +212,8 @@ impl ZkSeekContract {
         player2_points: i128,
         scene_id: u32,
     ) -> Result<(), Error> {
+        require_not_paused(&env, Error::ContractPaused)?;
+
         if player1 == player2 {
             panic!("Cannot play against yourself");
         }
@@ -238,12 +240,11 @@ impl ZkSeekContract {
             player2_points.into_val(&env),
         ]);
 
-        let game_hub_addr: Address = env
-            .storage()
-            .instance()
-            .get(&DataKey::GameHubAddress)
-            .expect("GameHub address not set");
-        let game_hub = GameHubClient::new(&env, &game_hub_addr);
+        if env.storage().temporary().has(&DataKey::Game(session_id)) {
+            return Err(Error::SessionAlreadyExists);
+        }
+
+        let game_hub = hub_client(&env);
 
         game_hub.start_game(
             &env.current_contract_address(),
@@ -269,13 +270,10 @@ impl ZkSeekContract {
             player2_x: None,
             player2_y: None,
             winner: None,
+            status: GameStatus::Active,
         };
 
-        let game_key = DataKey::Game(session_id);
-        env.storage().temporary().set(&game_key, &game);
-        env.storage()
-            .temporary()
-            .extend_ttl(&game_key, GAME_TTL_LEDGERS, GAME_TTL_LEDGERS);
+        write_game(&env, &DataKey::Game(session_id), &game);
 
         Ok(())
     }
@@ -286,6 +284,8 @@ impl ZkSeekContract {
         player: Address,
         commitment: BytesN<32>,
     ) -> Result<(), Error> {
+        require_not_paused(&env, Error::ContractPaused)?;
+
         player.require_auth();
 
         let key = DataKey::Game(session_id);
@@ -295,7 +295,7 @@ impl ZkSeekContract {
             .get(&key)
             .ok_or(Error::GameNotFound)?;
 
-        if game.winner.is_some() {
+        if game.status != GameStatus::Active {
             return Err(Error::GameAlreadyEnded);
         }
 
@@ -317,10 +317,7 @@ impl ZkSeekContract {
             return Err(Error::NotPlayer);
         }
 
-        env.storage().temporary().set(&key, &game);
-        env.storage()
-            .temporary()
-            .extend_ttl(&key, GAME_TTL_LEDGERS, GAME_TTL_LEDGERS);
+        write_game(&env, &key, &game);
 
         Ok(())
     }
@@ -333,6 +330,8 @@ impl ZkSeekContract {
         y: u32,
         salt: BytesN<32>,
     ) -> Result<(), Error> {
+        require_not_paused(&env, Error::ContractPaused)?;
+
         player.require_auth();
 
         let key = DataKey::Game(session_id);
@@ -342,7 +341,7 @@ impl ZkSeekContract {
             .get(&key)
             .ok_or(Error::GameNotFound)?;
 
-        if game.winner.is_some() {
+        if game.status != GameStatus::Active {
             return Err(Error::GameAlreadyEnded);
         }
 
@@ -374,27 +373,23 @@ impl ZkSeekContract {
             return Err(Error::NotPlayer);
         }
 
-        env.storage().temporary().set(&key, &game);
-        env.storage()
-            .temporary()
-            .extend_ttl(&key, GAME_TTL_LEDGERS, GAME_TTL_LEDGERS);
+        write_game(&env, &key, &game);
 
         Ok(())
     }
 
+    /// Reveal the scene target and settle the game. Operator only.
     pub fn resolve_game(
         env: Env,
+        caller: Address,
         session_id: u32,
         target_x: u32,
         target_y: u32,
         scene_salt: BytesN<32>,
-    ) -> Result<Address, Error> {
-        let admin: Address = env
-            .storage()
-            .instance()
-            .get(&DataKey::Admin)
-            .expect("Admin not set");
-        admin.require_auth();
+    ) -> Result<Option<Address>, Error> {
+        require_not_paused(&env, Error::ContractPaused)?;
+
+        require_role(&env, Role::Operator, &caller);
 
         let key = DataKey::Game(session_id);
         let mut game: Game = env
@@ -403,7 +398,7 @@ impl ZkSeekContract {
             .get(&key)
             .ok_or(Error::GameNotFound)?;
 
-        if game.winner.is_some() {
+        if game.status != GameStatus::Active {
             return Err(Error::GameAlreadyEnded);
         }
 
@@ -438,57 +433,30 @@ impl ZkSeekContract {
         let p1_in = is_within_tolerance(p1x, p1y, target_x, target_y, scene.tolerance);
         let p2_in = is_within_tolerance(p2x, p2y, target_x, target_y, scene.tolerance);
 
-        let winner = if p1_in && p2_in {
-            if dist1 < dist2 {
-                game.player1.clone()
-            } else if dist2 < dist1 {
-                game.player2.clone()
-            } else {
-                let l1 = game.player1_commit_ledger.unwrap();
-                let l2 = game.player2_commit_ledger.unwrap();
-                if l1 <= l2 {
-                    game.player1.clone()
-                } else {
-                    game.player2.clone()
-                }
-            }
+        let l1 = game.player1_commit_ledger.unwrap();
+        let l2 = game.player2_commit_ledger.unwrap();
+
+        let outcome = if p1_in == p2_in {
+            closer_outcome(dist1, dist2, l1, l2)
         } else if p1_in {
-            game.player1.clone()
-        } else if p2_in {
-            game.player2.clone()
+            GameOutcome::Player1Won
         } else {
-            if dist1 < dist2 {
-                game.player1.clone()
-            } else if dist2 < dist1 {
-                game.player2.clone()
-            } else {
-                let l1 = game.player1_commit_ledger.unwrap();
-                let l2 = game.player2_commit_ledger.unwrap();
-                if l1 <= l2 {
-                    game.player1.clone()
-                } else {
-                    game.player2.clone()
-                }
-            }
+            GameOutcome::Player2Won
         };
 
-        game.winner = Some(winner.clone());
-        env.storage().temporary().set(&key, &game);
-        env.storage()
-            .temporary()
-            .extend_ttl(&key, GAME_TTL_LEDGERS, GAME_TTL_LEDGERS);
+        game.winner = match outcome {
+            GameOutcome::Player1Won => Some(game.player1.clone()),
+            GameOutcome::Player2Won => Some(game.player2.clone()),
+            _ => None,
+        };
+        game.status = GameStatus::Ended(outcome.clone());
+        write_game(&env, &key, &game);
 
-        let game_hub_addr: Address = env
-            .storage()
-            .instance()
-            .get(&DataKey::GameHubAddress)
-            .expect("GameHub address not set");
-        let game_hub = GameHubClient::new(&env, &game_hub_addr);
+        let game_hub = hub_client(&env);
 
-        let player1_won = winner == game.player1;
-        game_hub.end_game(&session_id, &player1_won);
+        game_hub.end_game_with_outcome(&session_id, &outcome);
 
-        Ok(winner)
+        Ok(game.winner)
     }
 
     pub fn get_game(env: Env, session_id: u32) -> Result<Game, Error> {
@@ -506,56 +474,159 @@ impl ZkSeekContract {
             .get(&key)
             .ok_or(Error::SceneNotFound)
     }
+}
 
-    pub fn get_admin(env: Env) -> Address {
-        env.storage()
-            .instance()
-            .get(&DataKey::Admin)
-            .expect("Admin not set")
+// ============================================================================
+// Game Interface
+// ============================================================================
+
+#[contractimpl]
+impl GameContract for ZkSeekContract {
+    /// Describe the game: two players, winner takes both stakes
+    fn game_info(env: Env) -> GameInfo {
+        GameInfo {
+            name: String::from_str(&env, "ZK Seek"),
+            rules_version: 1,
+            min_players: 2,
+            max_players: 2,
+            stakes: StakeSemantics::WinnerTakesAll,
+        }
+    }
+
+    /// Get the status of a session, or `None` if it does not exist (or expired)
+    fn get_session_status(env: Env, session_id: u32) -> Option<SessionStatus> {
+        let game: Game = env.storage().temporary().get(&DataKey::Game(session_id))?;
+        Some(match game.status {
+            GameStatus::Active => SessionStatus::Active,
+            GameStatus::Ended(outcome) => SessionStatus::Ended(outcome),
+        })
     }
 
-    pub fn set_admin(env: Env, new_admin: Address) {
-        let admin: Address = env
+    /// Concede the game. The opponent wins and the Game Hub settles the points.
+    ///
+    /// # Arguments
+    /// * `session_id` - The session ID of the game
+    /// * `player` - Address of the player conceding
+    fn forfeit(env: Env, session_id: u32, player: Address) -> Result<(), soroban_sdk::Error> {
+        require_not_paused(&env, Error::ContractPaused)?;
+
+        player.require_auth();
+
+        let key = DataKey::Game(session_id);
+        let mut game: Game = env
             .storage()
-            .instance()
-            .get(&DataKey::Admin)
-            .expect("Admin not set");
-        admin.require_auth();
+            .temporary()
+            .get(&key)
+            .ok_or(Error::GameNotFound)?;
 
-        env.storage().instance().set(&DataKey::Admin, &new_admin);
-    }
+        if game.status != GameStatus::Active {
+            return Err(Error::GameAlreadyEnded.into());
+        }
 
-    pub fn get_hub(env: Env) -> Address {
-        env.storage()
-            .instance()
-            .get(&DataKey::GameHubAddress)
-            .expect("GameHub address not set")
+        let opponent = if player == game.player1 {
+            game.player2.clone()
+        } else if player == game.player2 {
+            game.player1.clone()
+        } else {
+            return Err(Error::NotPlayer.into());
+        };
+
+        let outcome = GameOutcome::Forfeit(player);
+        game.winner = Some(opponent);
+        game.status = GameStatus::Ended(outcome.clone());
+        env.storage().temporary().set(&key, &game);
+
+        // Event emitted by the Game Hub contract (GameEnded)
+        hub_client(&env).end_game_with_outcome(&session_id, &outcome);
+
+        Ok(())
     }
+}
+
+// ============================================================================
+// Game Hub Callbacks
+// ============================================================================
+
+#[contractimpl]
+impl GameCallbacks for ZkSeekContract {
+    /// Called by the Game Hub when a timeout is claimed against `player`.
+    /// Only accepted if `player` has not committed while their opponent has,
+    /// or has not revealed while their opponent has. The hub settles the points.
+    fn on_session_forfeited(
+        env: Env,
+        session_id: u32,
+        player: Address,
+    ) -> Result<(), soroban_sdk::Error> {
+        Self::get_hub(env.clone()).require_auth();
 
-    pub fn set_hub(env: Env, new_hub: Address) {
-        let admin: Address = env
+        let key = DataKey::Game(session_id);
+        let mut game: Game = env
             .storage()
-            .instance()
-            .get(&DataKey::Admin)
-            .expect("Admin not set");
-        admin.require_auth();
+            .temporary()
+            .get(&key)
+            .ok_or(Error::GameNotFound)?;
 
-        env.storage()
-            .instance()
-            .set(&DataKey::GameHubAddress, &new_hub);
+        if game.status != GameStatus::Active {
+            return Err(Error::GameAlreadyEnded.into());
+        }
+
+        let committing = game.player1_commitment.is_none() || game.player2_commitment.is_none();
+        let (stalling, opponent) = if player == game.player1 {
+            let stalling = if committing {
+                game.player1_commitment.is_none() && game.player2_commitment.is_some()
+            } else {
+                game.player1_x.is_none() && game.player2_x.is_some()
+            };
+            (stalling, game.player2.clone())
+        } else if player == game.player2 {
+            let stalling = if committing {
+                game.player2_commitment.is_none() && game.player1_commitment.is_some()
+            } else {
+                game.player2_x.is_none() && game.player1_x.is_some()
+            };
+            (stalling, game.player1.clone())
+        } else {
+            return Err(Error::NotPlayer.into());
+        };
+        if !stalling {
+            return Err(Error::PlayerNotStalling.into());
+        }
+
+        game.winner = Some(opponent);
+        game.status = GameStatus::Ended(GameOutcome::Forfeit(player));
+        env.storage().temporary().set(&key, &game);
+
+        Ok(())
     }
 
-    pub fn upgrade(env: Env, new_wasm_hash: BytesN<32>) {
-        let admin: Address = env
+    /// Called by the Game Hub when it cancels the session (timeout or pause).
+    /// The game ends without a winner; the hub refunds both stakes.
+    fn on_session_cancelled(env: Env, session_id: u32) -> Result<(), soroban_sdk::Error> {
+        Self::get_hub(env.clone()).require_auth();
+
+        let key = DataKey::Game(session_id);
+        let mut game: Game = env
             .storage()
-            .instance()
-            .get(&DataKey::Admin)
-            .expect("Admin not set");
-        admin.require_auth();
+            .temporary()
+            .get(&key)
+            .ok_or(Error::GameNotFound)?;
+
+        if game.status != GameStatus::Active {
+            return Err(Error::GameAlreadyEnded.into());
+        }
 
-        env.deployer().update_current_contract_wasm(new_wasm_hash);
+        game.status = GameStatus::Ended(GameOutcome::Cancelled);
+        env.storage().temporary().set(&key, &game);
+
+        Ok(())
     }
 }
 
+// ============================================================================
+// Admin Functions
+// ============================================================================
+
+sgs_game_common::game_admin_entrypoints!(ZkSeekContract);
+
 #[cfg(test)]
 mod test;
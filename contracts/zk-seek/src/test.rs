@@ -1,8 +1,9 @@
 #![cfg(test)]
 
-use crate::{Error, ZkSeekContract, ZkSeekContractClient};
+use crate::{Error, GameOutcome, GameStatus, ZkSeekContract, ZkSeekContractClient};
+use sgs_game_common::{Role, SessionStatus, StakeSemantics};
 use soroban_sdk::testutils::{Address as _, Ledger as _, LedgerInfo};
-use soroban_sdk::{contract, contractimpl, Address, Bytes, BytesN, Env};
+use soroban_sdk::{contract, contractimpl, Address, Bytes, BytesN, Env, String};
 
 // ============================================================================
 // Mock GameHub
@@ -26,6 +27,8 @@ impl MockGameHub {
 
     pub fn end_game(_env: Env, _session_id: u32, _player1_won: bool) {}
 
+    pub fn end_game_with_outcome(_env: Env, _session_id: u32, _outcome: GameOutcome) {}
+
     pub fn add_game(_env: Env, _game_address: Address) {}
 }
 
@@ -139,14 +142,14 @@ fn assert_zk_seek_error<T, E>(
 
 #[test]
 fn test_full_game_flow() {
-    let (env, client, _admin, player1, player2) = setup_test();
+    let (env, client, admin, player1, player2) = setup_test();
 
     let target_x: u32 = 300;
     let target_y: u32 = 450;
     let s_salt = scene_salt(&env);
     let target_comm = make_target_commitment(&env, target_x, target_y, &s_salt);
 
-    client.create_scene(&1u32, &target_comm, &50u32);
+    client.create_scene(&admin, &1u32, &target_comm, &50u32);
 
     let session_id = 42u32;
     let points: i128 = 100_0000000;
@@ -170,20 +173,24 @@ fn test_full_game_flow() {
     client.reveal(&session_id, &player1, &305u32, &448u32, &salt1);
     client.reveal(&session_id, &player2, &310u32, &455u32, &salt2);
 
-    let winner = client.resolve_game(&session_id, &target_x, &target_y, &s_salt);
-    assert_eq!(winner, player1);
+    let winner = client.resolve_game(&admin, &session_id, &target_x, &target_y, &s_salt);
+    assert_eq!(winner, Some(player1.clone()));
 
     let final_game = client.get_game(&session_id);
     assert_eq!(final_game.winner, Some(player1));
+    assert_eq!(
+        final_game.status,
+        GameStatus::Ended(GameOutcome::Player1Won)
+    );
 }
 
 #[test]
 fn test_commitment_mismatch() {
-    let (env, client, _admin, player1, player2) = setup_test();
+    let (env, client, admin, player1, player2) = setup_test();
 
     let s_salt = scene_salt(&env);
     let target_comm = make_target_commitment(&env, 300, 450, &s_salt);
-    client.create_scene(&1u32, &target_comm, &50u32);
+    client.create_scene(&admin, &1u32, &target_comm, &50u32);
 
     client.start_game(&1u32, &player1, &player2, &100, &100, &1u32);
 
@@ -201,11 +208,11 @@ fn test_commitment_mismatch() {
 
 #[test]
 fn test_cannot_commit_twice() {
-    let (env, client, _admin, player1, player2) = setup_test();
+    let (env, client, admin, player1, player2) = setup_test();
 
     let s_salt = scene_salt(&env);
     let target_comm = make_target_commitment(&env, 300, 450, &s_salt);
-    client.create_scene(&1u32, &target_comm, &50u32);
+    client.create_scene(&admin, &1u32, &target_comm, &50u32);
 
     client.start_game(&1u32, &player1, &player2, &100, &100, &1u32);
 
@@ -220,11 +227,11 @@ fn test_cannot_commit_twice() {
 
 #[test]
 fn test_cannot_reveal_before_both_commit() {
-    let (env, client, _admin, player1, player2) = setup_test();
+    let (env, client, admin, player1, player2) = setup_test();
 
     let s_salt = scene_salt(&env);
     let target_comm = make_target_commitment(&env, 300, 450, &s_salt);
-    client.create_scene(&1u32, &target_comm, &50u32);
+    client.create_scene(&admin, &1u32, &target_comm, &50u32);
 
     client.start_game(&1u32, &player1, &player2, &100, &100, &1u32);
 
@@ -238,11 +245,11 @@ fn test_cannot_reveal_before_both_commit() {
 
 #[test]
 fn test_earlier_commit_breaks_tie() {
-    let (env, client, _admin, player1, player2) = setup_test();
+    let (env, client, admin, player1, player2) = setup_test();
 
     let s_salt = scene_salt(&env);
     let target_comm = make_target_commitment(&env, 100, 100, &s_salt);
-    client.create_scene(&1u32, &target_comm, &50u32);
+    client.create_scene(&admin, &1u32, &target_comm, &50u32);
 
     client.start_game(&1u32, &player1, &player2, &100, &100, &1u32);
 
@@ -259,8 +266,40 @@ fn test_earlier_commit_breaks_tie() {
     client.reveal(&1u32, &player1, &110u32, &100u32, &salt1);
     client.reveal(&1u32, &player2, &110u32, &100u32, &salt2);
 
-    let winner = client.resolve_game(&1u32, &100u32, &100u32, &s_salt);
-    assert_eq!(winner, player1, "Earlier commit should break tie");
+    let winner = client.resolve_game(&admin, &1u32, &100u32, &100u32, &s_salt);
+    assert_eq!(winner, Some(player1), "Earlier commit should break tie");
+}
+
+#[test]
+fn test_full_tie_is_draw() {
+    let (env, client, admin, player1, player2) = setup_test();
+
+    let s_salt = scene_salt(&env);
+    let target_comm = make_target_commitment(&env, 100, 100, &s_salt);
+    client.create_scene(&admin, &1u32, &target_comm, &50u32);
+
+    client.start_game(&1u32, &player1, &player2, &100, &100, &1u32);
+
+    // Same distance, committed in the same ledger
+    let salt1 = random_salt(&env);
+    let comm1 = make_commitment(&env, 110, 100, &salt1, &player1);
+    client.submit_commitment(&1u32, &player1, &comm1);
+
+    let salt2 = random_salt_2(&env);
+    let comm2 = make_commitment(&env, 100, 110, &salt2, &player2);
+    client.submit_commitment(&1u32, &player2, &comm2);
+
+    client.reveal(&1u32, &player1, &110u32, &100u32, &salt1);
+    client.reveal(&1u32, &player2, &100u32, &110u32, &salt2);
+
+    let winner = client.resolve_game(&admin, &1u32, &100u32, &100u32, &s_salt);
+    assert_eq!(winner, None);
+
+    let game = client.get_game(&1u32);
+    assert_eq!(game.status, GameStatus::Ended(GameOutcome::Draw));
+
+    let result = client.try_resolve_game(&admin, &1u32, &100u32, &100u32, &s_salt);
+    assert_zk_seek_error(&result, Error::GameAlreadyEnded);
 }
 
 #[test]
@@ -274,11 +313,169 @@ fn test_scene_not_found() {
 #[test]
 #[should_panic(expected = "Cannot play against yourself")]
 fn test_self_play_rejected() {
-    let (env, client, _admin, player1, _player2) = setup_test();
+    let (env, client, admin, player1, _player2) = setup_test();
 
     let s_salt = scene_salt(&env);
     let target_comm = make_target_commitment(&env, 300, 450, &s_salt);
-    client.create_scene(&1u32, &target_comm, &50u32);
+    client.create_scene(&admin, &1u32, &target_comm, &50u32);
 
     client.start_game(&1u32, &player1, &player1, &100, &100, &1u32);
 }
+
+#[test]
+fn test_pause_blocks_gameplay() {
+    let (env, client, admin, player1, player2) = setup_test();
+
+    let s_salt = scene_salt(&env);
+    let target_comm = make_target_commitment(&env, 300, 450, &s_salt);
+    client.create_scene(&admin, &1u32, &target_comm, &50u32);
+    client.start_game(&1u32, &player1, &player2, &100, &100, &1u32);
+
+    client.pause();
+    assert!(client.is_paused());
+
+    let result = client.try_start_game(&2u32, &player1, &player2, &100, &100, &1u32);
+    assert_zk_seek_error(&result, Error::ContractPaused);
+
+    let salt1 = random_salt(&env);
+    let comm1 = make_commitment(&env, 305, 448, &salt1, &player1);
+    let result = client.try_submit_commitment(&1u32, &player1, &comm1);
+    assert_zk_seek_error(&result, Error::ContractPaused);
+
+    client.unpause();
+    client.submit_commitment(&1u32, &player1, &comm1);
+}
+
+#[test]
+fn test_cannot_start_over_live_session() {
+    let (env, client, admin, player1, player2) = setup_test();
+
+    let s_salt = scene_salt(&env);
+    let target_comm = make_target_commitment(&env, 300, 450, &s_salt);
+    client.create_scene(&admin, &1u32, &target_comm, &50u32);
+    client.start_game(&1u32, &player1, &player2, &100, &100, &1u32);
+
+    let player3 = Address::generate(&env);
+    let result = client.try_start_game(&1u32, &player3, &player2, &100, &100, &1u32);
+    assert_zk_seek_error(&result, Error::SessionAlreadyExists);
+    assert_eq!(client.get_game(&1u32).player1, player1);
+}
+
+#[test]
+fn test_hub_forfeits_player_who_has_not_revealed() {
+    let (env, client, admin, player1, player2) = setup_test();
+
+    let s_salt = scene_salt(&env);
+    let target_comm = make_target_commitment(&env, 300, 450, &s_salt);
+    client.create_scene(&admin, &1u32, &target_comm, &50u32);
+    client.start_game(&1u32, &player1, &player2, &100, &100, &1u32);
+
+    let salt1 = random_salt(&env);
+    let comm1 = make_commitment(&env, 305, 448, &salt1, &player1);
+    client.submit_commitment(&1u32, &player1, &comm1);
+
+    // player1 has committed and is waiting on player2
+    let result = client.try_on_session_forfeited(&1u32, &player1);
+    assert_eq!(result, Err(Ok(Error::PlayerNotStalling.into())));
+
+    let salt2 = random_salt_2(&env);
+    let comm2 = make_commitment(&env, 310, 455, &salt2, &player2);
+    client.submit_commitment(&1u32, &player2, &comm2);
+    client.reveal(&1u32, &player2, &310u32, &455u32, &salt2);
+
+    client.on_session_forfeited(&1u32, &player1);
+    assert_eq!(env.auths()[0].0, client.get_hub());
+
+    let game = client.get_game(&1u32);
+    assert_eq!(game.winner, Some(player2));
+    assert_eq!(
+        game.status,
+        GameStatus::Ended(GameOutcome::Forfeit(player1.clone()))
+    );
+    let result = client.try_reveal(&1u32, &player1, &305u32, &448u32, &salt1);
+    assert_zk_seek_error(&result, Error::GameAlreadyEnded);
+}
+
+#[test]
+fn test_hub_cancels_session() {
+    let (env, client, admin, player1, player2) = setup_test();
+
+    let s_salt = scene_salt(&env);
+    let target_comm = make_target_commitment(&env, 300, 450, &s_salt);
+    client.create_scene(&admin, &1u32, &target_comm, &50u32);
+    client.start_game(&1u32, &player1, &player2, &100, &100, &1u32);
+
+    client.on_session_cancelled(&1u32);
+
+    let game = client.get_game(&1u32);
+    assert!(game.winner.is_none());
+    assert_eq!(game.status, GameStatus::Ended(GameOutcome::Cancelled));
+
+    let result = client.try_on_session_cancelled(&1u32);
+    assert_eq!(result, Err(Ok(Error::GameAlreadyEnded.into())));
+}
+
+#[test]
+fn test_game_info() {
+    let (env, client, _admin, _player1, _player2) = setup_test();
+
+    let info = client.game_info();
+    assert_eq!(info.name, String::from_str(&env, "ZK Seek"));
+    assert_eq!((info.min_players, info.max_players), (2, 2));
+    assert_eq!(info.stakes, StakeSemantics::WinnerTakesAll);
+}
+
+#[test]
+fn test_forfeit_ends_game_for_opponent() {
+    let (env, client, admin, player1, player2) = setup_test();
+
+    let s_salt = scene_salt(&env);
+    let target_comm = make_target_commitment(&env, 300, 450, &s_salt);
+    client.create_scene(&admin, &1u32, &target_comm, &50u32);
+    assert_eq!(client.get_session_status(&1u32), None);
+    client.start_game(&1u32, &player1, &player2, &100, &100, &1u32);
+
+    let outsider = Address::generate(&env);
+    let result = client.try_forfeit(&1u32, &outsider);
+    assert_eq!(result, Err(Ok(Error::NotPlayer.into())));
+
+    client.forfeit(&1u32, &player1);
+    assert_eq!(env.auths()[0].0, player1);
+    assert_eq!(client.get_game(&1u32).winner, Some(player2));
+    assert_eq!(
+        client.get_session_status(&1u32),
+        Some(SessionStatus::Ended(GameOutcome::Forfeit(player1.clone())))
+    );
+
+    let result = client.try_forfeit(&1u32, &player1);
+    assert_eq!(result, Err(Ok(Error::GameAlreadyEnded.into())));
+}
+
+#[test]
+fn test_operator_creates_scenes_and_resolves_games() {
+    let (env, client, _admin, player1, player2) = setup_test();
+    let operator = Address::generate(&env);
+
+    let s_salt = scene_salt(&env);
+    let target_comm = make_target_commitment(&env, 300, 450, &s_salt);
+    let result = client.try_create_scene(&operator, &1u32, &target_comm, &50u32);
+    assert!(result.is_err());
+
+    client.grant_role(&Role::Operator, &operator);
+    client.create_scene(&operator, &1u32, &target_comm, &50u32);
+    assert_eq!(env.auths()[0].0, operator);
+
+    client.start_game(&1u32, &player1, &player2, &100, &100, &1u32);
+    let salt1 = random_salt(&env);
+    let comm1 = make_commitment(&env, 305, 448, &salt1, &player1);
+    client.submit_commitment(&1u32, &player1, &comm1);
+    let salt2 = random_salt_2(&env);
+    let comm2 = make_commitment(&env, 310, 455, &salt2, &player2);
+    client.submit_commitment(&1u32, &player2, &comm2);
+    client.reveal(&1u32, &player1, &305u32, &448u32, &salt1);
+    client.reveal(&1u32, &player2, &310u32, &455u32, &salt2);
+
+    let winner = client.resolve_game(&operator, &1u32, &300u32, &450u32, &s_salt);
+    assert_eq!(winner, Some(player1));
+    assert_eq!(env.auths()[0].0, operator);
+}
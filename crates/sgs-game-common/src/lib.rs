@@ -0,0 +1,447 @@
+#![no_std]
+
+//! # Game Common
+//!
+//! Code every Stellar Game Studio game contract needs, in one place:
+//!
+//! - `GameHubClient` and `GameOutcome` to call the Game Hub.
+//! - Typed helpers for the admin, Game Hub address and pause flag, which
+//!   live in instance storage under `CommonKey`.
+//! - Two-step admin transfer (`propose_admin` then `accept_admin`) and
+//!   admin-granted roles such as `Role::Operator`, checked with
+//!   `require_role`. Accepting with nothing proposed fails with
+//!   `CommonError::NoPendingAdmin`.
+//! - `GAME_TTL_LEDGERS` and `write_game` for game state in temporary storage.
+//! - The `GameContract` trait every game implements (`game_info`,
+//!   `get_session_status`, `forfeit`), so lobbies and hub tools can work with
+//!   any game through `GameContractClient`.
+//! - The `GameCallbacks` trait every game implements for the calls the Game
+//!   Hub makes when it settles a session itself (timeouts, cancellations).
+//! - The `RandomnessBeacon` interface, so games can read a round's published
+//!   random value from the randomness beacon through `RandomnessBeaconClient`.
+//! - `game_admin_entrypoints!`, which generates the standard admin
+//!   entrypoints (admin transfer, roles, hub address, pause, `upgrade`) for a
+//!   contract.
+//!
+//! A game keeps its own `DataKey` for game state; the storage keys here use
+//! the same variant names the games always used, so upgrading an existing
+//! deployment keeps its admin and hub address.
+
+use soroban_sdk::xdr::{ScErrorCode, ScErrorType};
+use soroban_sdk::{
+    contractclient, contracterror, contracttype, panic_with_error, Address, BytesN, Env, IntoVal,
+    String, Symbol, Val,
+};
+
+// ============================================================================
+// Errors
+// ============================================================================
+
+/// Errors returned by the shared admin entrypoints. Codes start at 100 so
+/// they never collide with a game's own `Error` codes.
+#[contracterror]
+#[derive(Copy, Clone, Debug, Eq, PartialEq, PartialOrd, Ord)]
+#[repr(u32)]
+pub enum CommonError {
+    /// No admin transfer has been proposed
+    NoPendingAdmin = 100,
+}
+
+// ============================================================================
+// Game Hub Interface
+// ============================================================================
+
+/// Session outcome reported to the Game Hub (mirrors the hub's `GameOutcome`)
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum GameOutcome {
+    Player1Won,
+    Player2Won,
+    Draw,
+    Cancelled,
+    Forfeit(Address),
+}
+
+/// The Game Hub entrypoints games call
+#[contractclient(name = "GameHubClient")]
+pub trait GameHub {
+    fn start_game(
+        env: Env,
+        game_id: Address,
+        session_id: u32,
+        player1: Address,
+        player2: Address,
+        player1_points: i128,
+        player2_points: i128,
+    );
+
+    fn end_game(env: Env, session_id: u32, player1_won: bool);
+
+    fn end_game_with_outcome(env: Env, session_id: u32, outcome: GameOutcome);
+
+    fn report_stat(env: Env, session_id: u32, player: Address, key: Symbol, value: i128);
+}
+
+// ============================================================================
+// Game Interface
+// ============================================================================
+
+/// How the hub settles a game's stakes
+#[contracttype]
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum StakeSemantics {
+    /// Each player stakes; the winner takes every stake. Draws and
+    /// cancellations refund them.
+    WinnerTakesAll,
+    /// Each player stakes; the hub splits the pot by finishing position
+    Placements,
+}
+
+/// Static description of a game, for lobbies and hub tools
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct GameInfo {
+    pub name: String,
+    /// Bumped whenever a change to the rules could change an outcome
+    pub rules_version: u32,
+    pub min_players: u32,
+    pub max_players: u32,
+    pub stakes: StakeSemantics,
+}
+
+/// Game-independent view of a session's status
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum SessionStatus {
+    Active,
+    Ended(GameOutcome),
+}
+
+/// Entry points every game contract exposes
+#[contractclient(name = "GameContractClient")]
+pub trait GameContract {
+    /// Describe the game
+    fn game_info(env: Env) -> GameInfo;
+
+    /// Status of a session, or `None` if the game holds no such session
+    fn get_session_status(env: Env, session_id: u32) -> Option<SessionStatus>;
+
+    /// `player` concedes the session. The game ends as `Forfeit(player)` and
+    /// the result is reported to the Game Hub. Requires `player`'s auth.
+    fn forfeit(env: Env, session_id: u32, player: Address) -> Result<(), soroban_sdk::Error>;
+}
+
+/// Entry points the hub calls on a game contract when it settles a session
+/// itself (`claim_timeout`, `cancel_paused_session`) rather than waiting for
+/// the game. Every game implements both so its own state ends with the
+/// hub's. Implementations should check the hub's auth and must not call back
+/// into the hub.
+#[contractclient(name = "GameCallbacksClient")]
+pub trait GameCallbacks {
+    /// `player` is being forfeited for stalling. The game must fail the call
+    /// unless `player` is the one holding up play, and otherwise end its game.
+    fn on_session_forfeited(
+        env: Env,
+        session_id: u32,
+        player: Address,
+    ) -> Result<(), soroban_sdk::Error>;
+
+    /// The session was cancelled and all stakes refunded
+    fn on_session_cancelled(env: Env, session_id: u32) -> Result<(), soroban_sdk::Error>;
+}
+
+// ============================================================================
+// Randomness Beacon Interface
+// ============================================================================
+
+/// The randomness beacon entrypoint games call
+#[contractclient(name = "RandomnessBeaconClient")]
+pub trait RandomnessBeacon {
+    /// Random value published for `round_id`. Fails until the round is
+    /// finalized, and for rounds that failed because nobody revealed.
+    fn get_randomness(env: Env, round_id: u32) -> Result<BytesN<32>, soroban_sdk::Error>;
+}
+
+// ============================================================================
+// Storage
+// ============================================================================
+
+/// Instance storage keys shared by every game
+#[contracttype]
+#[derive(Clone)]
+pub enum CommonKey {
+    Admin,
+    GameHubAddress,
+    Paused,
+    PendingAdmin,
+    Role(Role, Address),
+}
+
+/// TTL for game storage (30 days in ledgers, ~5 seconds per ledger)
+/// 30 days = 30 * 24 * 60 * 60 / 5 = 518,400 ledgers
+pub const GAME_TTL_LEDGERS: u32 = 518_400;
+
+/// Store the admin and Game Hub address. Call from `__constructor`.
+pub fn init(env: &Env, admin: &Address, game_hub: &Address) {
+    write_admin(env, admin);
+    write_hub(env, game_hub);
+}
+
+/// Get the admin address
+pub fn read_admin(env: &Env) -> Address {
+    env.storage()
+        .instance()
+        .get(&CommonKey::Admin)
+        .expect("Admin not set")
+}
+
+pub fn write_admin(env: &Env, admin: &Address) {
+    env.storage().instance().set(&CommonKey::Admin, admin);
+}
+
+/// Require the admin's authorization and return the admin address
+pub fn require_admin(env: &Env) -> Address {
+    let admin = read_admin(env);
+    admin.require_auth();
+    admin
+}
+
+/// Admin proposed by `propose_admin`, waiting to accept
+pub fn read_pending_admin(env: &Env) -> Option<Address> {
+    env.storage().instance().get(&CommonKey::PendingAdmin)
+}
+
+/// Propose `new_admin` as the next admin. Nothing changes until they call
+/// `accept_admin`; a later proposal replaces this one. Admin only.
+pub fn propose_admin(env: &Env, new_admin: &Address) {
+    require_admin(env);
+    env.storage()
+        .instance()
+        .set(&CommonKey::PendingAdmin, new_admin);
+}
+
+/// Complete a transfer started by `propose_admin`. Requires the pending
+/// admin's authorization and returns the new admin, or
+/// `CommonError::NoPendingAdmin` if no transfer was proposed.
+pub fn accept_admin(env: &Env) -> Result<Address, CommonError> {
+    let pending = read_pending_admin(env).ok_or(CommonError::NoPendingAdmin)?;
+    pending.require_auth();
+
+    write_admin(env, &pending);
+    env.storage().instance().remove(&CommonKey::PendingAdmin);
+    Ok(pending)
+}
+
+/// Get the Game Hub contract address
+pub fn read_hub(env: &Env) -> Address {
+    env.storage()
+        .instance()
+        .get(&CommonKey::GameHubAddress)
+        .expect("GameHub address not set")
+}
+
+pub fn write_hub(env: &Env, game_hub: &Address) {
+    env.storage()
+        .instance()
+        .set(&CommonKey::GameHubAddress, game_hub);
+}
+
+/// Client for the configured Game Hub
+pub fn hub_client(env: &Env) -> GameHubClient<'_> {
+    GameHubClient::new(env, &read_hub(env))
+}
+
+/// Check whether the admin has paused gameplay
+pub fn is_paused(env: &Env) -> bool {
+    env.storage()
+        .instance()
+        .get(&CommonKey::Paused)
+        .unwrap_or(false)
+}
+
+/// Fail with the game's own `paused` error while the admin has paused gameplay
+pub fn require_not_paused<E>(env: &Env, paused: E) -> Result<(), E> {
+    if is_paused(env) {
+        return Err(paused);
+    }
+    Ok(())
+}
+
+pub fn write_paused(env: &Env, paused: bool) {
+    env.storage().instance().set(&CommonKey::Paused, &paused);
+}
+
+// ============================================================================
+// Roles
+// ============================================================================
+
+/// Roles the admin can grant. The admin implicitly holds every role.
+#[contracttype]
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum Role {
+    /// Day-to-day game operations (creating cases and scenes, resolving
+    /// games). Cannot change the admin, the hub address or the WASM.
+    Operator,
+}
+
+/// Check whether `account` holds `role`
+pub fn has_role(env: &Env, role: Role, account: &Address) -> bool {
+    *account == read_admin(env)
+        || env
+            .storage()
+            .instance()
+            .has(&CommonKey::Role(role, account.clone()))
+}
+
+/// Grant `role` to `account`. Admin only.
+pub fn grant_role(env: &Env, role: Role, account: &Address) {
+    require_admin(env);
+    env.storage()
+        .instance()
+        .set(&CommonKey::Role(role, account.clone()), &true);
+}
+
+/// Revoke `role` from `account`. Admin only.
+pub fn revoke_role(env: &Env, role: Role, account: &Address) {
+    require_admin(env);
+    env.storage()
+        .instance()
+        .remove(&CommonKey::Role(role, account.clone()));
+}
+
+/// Require `caller`'s authorization and that they hold `role`. Fails with an
+/// `Auth` host error otherwise.
+pub fn require_role(env: &Env, role: Role, caller: &Address) {
+    caller.require_auth();
+    if !has_role(env, role, caller) {
+        panic_with_error!(
+            env,
+            soroban_sdk::Error::from_type_and_code(ScErrorType::Auth, ScErrorCode::InvalidAction)
+        );
+    }
+}
+
+/// Store game state in temporary storage and extend it to `GAME_TTL_LEDGERS`,
+/// so an active game never expires mid-play
+pub fn write_game<K, V>(env: &Env, key: &K, game: &V)
+where
+    K: IntoVal<Env, Val>,
+    V: IntoVal<Env, Val>,
+{
+    env.storage().temporary().set(key, game);
+    env.storage()
+        .temporary()
+        .extend_ttl(key, GAME_TTL_LEDGERS, GAME_TTL_LEDGERS);
+}
+
+// ============================================================================
+// Admin Entrypoints
+// ============================================================================
+
+/// Generate the standard admin entrypoints for a game contract:
+/// `get_admin`, `get_pending_admin`, `propose_admin`, `accept_admin`,
+/// `grant_role`, `revoke_role`, `has_role`, `get_hub`, `set_hub`, `pause`,
+/// `unpause`, `is_paused` and `upgrade`. Everything but the getters and
+/// `accept_admin` is admin only.
+///
+/// ```ignore
+/// #[contract]
+/// pub struct MyGameContract;
+///
+/// sgs_game_common::game_admin_entrypoints!(MyGameContract);
+/// ```
+#[macro_export]
+macro_rules! game_admin_entrypoints {
+    ($contract:ident) => {
+        #[soroban_sdk::contractimpl]
+        impl $contract {
+            /// Get the current admin address
+            pub fn get_admin(env: soroban_sdk::Env) -> soroban_sdk::Address {
+                $crate::read_admin(&env)
+            }
+
+            /// Get the admin proposed by `propose_admin`, if any
+            pub fn get_pending_admin(env: soroban_sdk::Env) -> Option<soroban_sdk::Address> {
+                $crate::read_pending_admin(&env)
+            }
+
+            /// Propose a new admin. They take over once they call
+            /// `accept_admin`.
+            pub fn propose_admin(env: soroban_sdk::Env, new_admin: soroban_sdk::Address) {
+                $crate::propose_admin(&env, &new_admin);
+            }
+
+            /// Accept a pending admin transfer. Pending admin only.
+            pub fn accept_admin(env: soroban_sdk::Env) -> Result<(), $crate::CommonError> {
+                $crate::accept_admin(&env)?;
+                Ok(())
+            }
+
+            /// Grant a role to an account
+            pub fn grant_role(
+                env: soroban_sdk::Env,
+                role: $crate::Role,
+                account: soroban_sdk::Address,
+            ) {
+                $crate::grant_role(&env, role, &account);
+            }
+
+            /// Revoke a role from an account
+            pub fn revoke_role(
+                env: soroban_sdk::Env,
+                role: $crate::Role,
+                account: soroban_sdk::Address,
+            ) {
+                $crate::revoke_role(&env, role, &account);
+            }
+
+            /// Check whether an account holds a role (the admin holds all)
+            pub fn has_role(
+                env: soroban_sdk::Env,
+                role: $crate::Role,
+                account: soroban_sdk::Address,
+            ) -> bool {
+                $crate::has_role(&env, role, &account)
+            }
+
+            /// Get the current GameHub contract address
+            pub fn get_hub(env: soroban_sdk::Env) -> soroban_sdk::Address {
+                $crate::read_hub(&env)
+            }
+
+            /// Set a new GameHub contract address
+            pub fn set_hub(env: soroban_sdk::Env, new_hub: soroban_sdk::Address) {
+                $crate::require_admin(&env);
+                $crate::write_hub(&env, &new_hub);
+            }
+
+            /// Pause gameplay: new games and moves fail with `ContractPaused`
+            /// until `unpause` is called. Hub callbacks still run, so sessions
+            /// can be cancelled and refunded through the hub.
+            pub fn pause(env: soroban_sdk::Env) {
+                $crate::require_admin(&env);
+                $crate::write_paused(&env, true);
+            }
+
+            /// Resume gameplay after `pause`
+            pub fn unpause(env: soroban_sdk::Env) {
+                $crate::require_admin(&env);
+                $crate::write_paused(&env, false);
+            }
+
+            /// Check whether the contract is paused
+            pub fn is_paused(env: soroban_sdk::Env) -> bool {
+                $crate::is_paused(&env)
+            }
+
+            /// Update the contract WASM hash (upgrade contract)
+            pub fn upgrade(env: soroban_sdk::Env, new_wasm_hash: soroban_sdk::BytesN<32>) {
+                $crate::require_admin(&env);
+                env.deployer().update_current_contract_wasm(new_wasm_hash);
+            }
+        }
+    };
+}
+
+#[cfg(test)]
+mod test;
@@ -0,0 +1,177 @@
+#![cfg(test)]
+
+use crate::{require_role, write_game, CommonError, Role, GAME_TTL_LEDGERS};
+use soroban_sdk::testutils::storage::Temporary as _;
+use soroban_sdk::testutils::Address as _;
+use soroban_sdk::{contract, contractimpl, Address, Env, IntoVal};
+
+// ============================================================================
+// Test Contract
+// ============================================================================
+
+#[contract]
+pub struct TestGame;
+
+#[contractimpl]
+impl TestGame {
+    pub fn __constructor(env: Env, admin: Address, game_hub: Address) {
+        crate::init(&env, &admin, &game_hub);
+    }
+
+    pub fn operate(env: Env, caller: Address) {
+        require_role(&env, Role::Operator, &caller);
+    }
+}
+
+crate::game_admin_entrypoints!(TestGame);
+
+fn setup_test() -> (Env, TestGameClient<'static>, Address, Address) {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let admin = Address::generate(&env);
+    let hub = Address::generate(&env);
+    let contract_id = env.register(TestGame, (&admin, &hub));
+    let client = TestGameClient::new(&env, &contract_id);
+
+    (env, client, admin, hub)
+}
+
+// ============================================================================
+// Admin Entrypoint Tests
+// ============================================================================
+
+#[test]
+fn test_constructor_stores_admin_and_hub() {
+    let (_env, client, admin, hub) = setup_test();
+
+    assert_eq!(client.get_admin(), admin);
+    assert_eq!(client.get_hub(), hub);
+    assert!(!client.is_paused());
+}
+
+#[test]
+fn test_setters_require_admin() {
+    let (env, client, admin, _hub) = setup_test();
+
+    let new_hub = Address::generate(&env);
+    client.set_hub(&new_hub);
+    assert_eq!(env.auths()[0].0, admin);
+    assert_eq!(client.get_hub(), new_hub);
+
+    client.pause();
+    assert_eq!(env.auths()[0].0, admin);
+    assert!(client.is_paused());
+    client.unpause();
+    assert!(!client.is_paused());
+}
+
+#[test]
+#[should_panic]
+fn test_propose_admin_without_auth_fails() {
+    let env = Env::default();
+    let admin = Address::generate(&env);
+    let hub = Address::generate(&env);
+    let contract_id = env.register(TestGame, (&admin, &hub));
+    let client = TestGameClient::new(&env, &contract_id);
+
+    client.propose_admin(&Address::generate(&env));
+}
+
+#[test]
+fn test_admin_transfer_takes_effect_on_accept() {
+    let (env, client, admin, _hub) = setup_test();
+
+    let new_admin = Address::generate(&env);
+    client.propose_admin(&new_admin);
+    assert_eq!(env.auths()[0].0, admin);
+    assert_eq!(client.get_admin(), admin);
+    assert_eq!(client.get_pending_admin(), Some(new_admin.clone()));
+
+    client.accept_admin();
+    assert_eq!(env.auths()[0].0, new_admin);
+    assert_eq!(client.get_admin(), new_admin);
+    assert_eq!(client.get_pending_admin(), None);
+
+    // Nothing left to accept
+    assert_eq!(
+        client.try_accept_admin(),
+        Err(Ok(CommonError::NoPendingAdmin))
+    );
+}
+
+// ============================================================================
+// Role Tests
+// ============================================================================
+
+#[test]
+fn test_operator_role_grant_and_revoke() {
+    let (env, client, admin, _hub) = setup_test();
+    let operator = Address::generate(&env);
+
+    // The admin holds every role
+    assert!(client.has_role(&Role::Operator, &admin));
+    client.operate(&admin);
+
+    assert!(client.try_operate(&operator).is_err());
+
+    client.grant_role(&Role::Operator, &operator);
+    assert_eq!(env.auths()[0].0, admin);
+    assert!(client.has_role(&Role::Operator, &operator));
+    client.operate(&operator);
+    assert_eq!(env.auths()[0].0, operator);
+
+    client.revoke_role(&Role::Operator, &operator);
+    assert!(!client.has_role(&Role::Operator, &operator));
+    assert!(client.try_operate(&operator).is_err());
+}
+
+#[test]
+#[should_panic]
+fn test_operator_cannot_set_hub() {
+    let env = Env::default();
+    let admin = Address::generate(&env);
+    let operator = Address::generate(&env);
+    let contract_id = env.register(TestGame, (&admin, &Address::generate(&env)));
+    let client = TestGameClient::new(&env, &contract_id);
+
+    env.mock_auths(&[soroban_sdk::testutils::MockAuth {
+        address: &admin,
+        invoke: &soroban_sdk::testutils::MockAuthInvoke {
+            contract: &contract_id,
+            fn_name: "grant_role",
+            args: (Role::Operator, operator.clone()).into_val(&env),
+            sub_invokes: &[],
+        },
+    }]);
+    client.grant_role(&Role::Operator, &operator);
+
+    // Only the operator signs; set_hub needs the admin
+    env.mock_auths(&[soroban_sdk::testutils::MockAuth {
+        address: &operator,
+        invoke: &soroban_sdk::testutils::MockAuthInvoke {
+            contract: &contract_id,
+            fn_name: "set_hub",
+            args: (operator.clone(),).into_val(&env),
+            sub_invokes: &[],
+        },
+    }]);
+    client.set_hub(&operator);
+}
+
+// ============================================================================
+// Storage Tests
+// ============================================================================
+
+#[test]
+fn test_write_game_extends_ttl() {
+    let (env, client, _admin, _hub) = setup_test();
+
+    env.as_contract(&client.address, || {
+        let key = 1u32;
+        write_game(&env, &key, &7u32);
+
+        assert_eq!(env.storage().temporary().get(&key), Some(7u32));
+        assert!(env.storage().temporary().get_ttl(&key) >= GAME_TTL_LEDGERS);
+    });
+}
@@ -97,5 +97,6 @@ console.log(`    --source ${adminSecret} \\`);
 console.log(`    --rpc-url ${rpcUrl} \\`);
 console.log(`    --network-passphrase "${networkPassphrase}" \\`);
 console.log('    -- set_poseidon_commitment \\');
+console.log(`    --caller ${env.VITE_DEV_ADMIN_ADDRESS} \\`);
 console.log(`    --case_id ${CASE_ID} \\`);
 console.log('    --commitment <POSEIDON2_HASH_HEX>');
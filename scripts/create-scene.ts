@@ -22,10 +22,11 @@ const tolerance = Number(args[3] || '150');
 
 const env = await readEnvFile('.env');
 const adminSecret = getEnvValue(env, 'VITE_DEV_ADMIN_SECRET');
+const adminAddress = getEnvValue(env, 'VITE_DEV_ADMIN_ADDRESS');
 const contractId = getEnvValue(env, 'VITE_ZK_SEEK_CONTRACT_ID');
 
-if (!adminSecret) {
-  console.error('❌ VITE_DEV_ADMIN_SECRET not found in .env');
+if (!adminSecret || !adminAddress) {
+  console.error('❌ VITE_DEV_ADMIN_SECRET or VITE_DEV_ADMIN_ADDRESS not found in .env');
   process.exit(1);
 }
 if (!contractId) {
@@ -60,6 +61,7 @@ try {
     --network testnet \
     -- \
     create_scene \
+    --caller ${adminAddress} \
     --scene_id ${sceneId} \
     --target_commitment ${commitmentHex} \
     --tolerance ${tolerance}`;
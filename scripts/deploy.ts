@@ -278,7 +278,7 @@ if (shouldEnsureMock) {
     console.log(`Deploying ${mock.packageName}...`);
     try {
       const result =
-        await $`stellar contract deploy --wasm ${mock.wasmPath} --source-account ${adminSecret} --network ${NETWORK}`.text();
+        await $`stellar contract deploy --wasm ${mock.wasmPath} --source-account ${adminSecret} --network ${NETWORK} -- --admin ${adminAddress}`.text();
       mockGameHubId = result.trim();
       deployed[mock.packageName] = mockGameHubId;
       console.log(`✅ ${mock.packageName} deployed: ${mockGameHubId}\n`);
@@ -289,6 +289,22 @@ if (shouldEnsureMock) {
   }
 }
 
+// The hub escrows points from player balances, so the dev players need some
+// before they can start a game. Points use 7 decimals (1000 points each).
+const DEV_PLAYER_POINTS = "10000000000";
+if (mockGameHubId) {
+  for (const identity of ['player1', 'player2']) {
+    const player = walletAddresses[identity];
+    try {
+      await $`stellar contract invoke --id ${mockGameHubId} --source-account ${adminSecret} --network ${NETWORK} -- add_points --player ${player} --amount ${DEV_PLAYER_POINTS}`.quiet();
+      console.log(`✅ Funded ${identity} with points on ${mock.packageName}`);
+    } catch (error) {
+      console.warn(`⚠️  Could not fund ${identity} with points; the hub admin must call add_points for ${player}`);
+    }
+  }
+  console.log();
+}
+
 for (const contract of contracts) {
   if (contract.isMockHub) continue;
 
@@ -305,7 +321,16 @@ for (const contract of contracts) {
       await $`stellar contract deploy --wasm-hash ${wasmHash} --source-account ${adminSecret} --network ${NETWORK} -- --admin ${adminAddress} --game-hub ${mockGameHubId}`.text();
     const contractId = deployResult.trim();
     deployed[contract.packageName] = contractId;
-    console.log(`✅ ${contract.packageName} deployed: ${contractId}\n`);
+    console.log(`✅ ${contract.packageName} deployed: ${contractId}`);
+
+    // The hub only accepts sessions from registered games.
+    console.log("  Registering with Game Hub...");
+    try {
+      await $`stellar contract invoke --id ${mockGameHubId} --source-account ${adminSecret} --network ${NETWORK} -- add_game --game_id ${contractId}`.quiet();
+      console.log(`✅ ${contract.packageName} registered with ${mock.packageName}\n`);
+    } catch (error) {
+      console.warn(`⚠️  Could not register ${contract.packageName} with ${mock.packageName}; the hub admin must call add_game\n`);
+    }
   } catch (error) {
     console.error(`❌ Failed to deploy ${contract.packageName}:`, error);
     process.exit(1);
@@ -44,6 +44,7 @@ function buildCommitment(): string {
 // ── Main ────────────────────────────────────────────────────────────────
 const contractId = env.VITE_ZK_DETECTIVE_CONTRACT_ID;
 const adminSecret = env.VITE_DEV_ADMIN_SECRET;
+const adminAddress = env.VITE_DEV_ADMIN_ADDRESS;
 const rpcUrl = env.VITE_SOROBAN_RPC_URL ?? 'https://soroban-testnet.stellar.org';
 const networkPassphrase = env.VITE_NETWORK_PASSPHRASE ?? 'Test SDF Network ; September 2015';
 
@@ -53,8 +54,8 @@ if (!contractId) {
   process.exit(1);
 }
 
-if (!adminSecret) {
-  console.error('Error: VITE_DEV_ADMIN_SECRET not set in .env');
+if (!adminSecret || !adminAddress) {
+  console.error('Error: VITE_DEV_ADMIN_SECRET or VITE_DEV_ADMIN_ADDRESS not set in .env');
   process.exit(1);
 }
 
@@ -72,6 +73,7 @@ const cmd = [
   `--rpc-url ${rpcUrl}`,
   `--network-passphrase "${networkPassphrase}"`,
   '-- create_case',
+  `--caller ${adminAddress}`,
   `--case_id ${CASE_ID}`,
   `--commitment ${commitmentHex}`,
 ].join(' ');
@@ -2,27 +2,15 @@ import { useState, useEffect, useRef } from 'react';
 import { DiceDuelService } from './diceDuelService';
 import { requestCache, createCacheKey } from '@/utils/requestCache';
 import { useWallet } from '@/hooks/useWallet';
-import { DICE_DUEL_CONTRACT } from '@/utils/constants';
+import { MOCK_GAME_HUB_CONTRACT, DICE_DUEL_CONTRACT } from '@/utils/constants';
 import { getFundedSimulationSourceAddress } from '@/utils/simulationUtils';
 import { devWalletService, DevWalletService } from '@/services/devWalletService';
+import { GameHubService } from '@/services/gameHubService';
 import type { Game } from './bindings';
 
-const createRandomSessionId = (): number => {
-  if (typeof crypto !== 'undefined' && crypto.getRandomValues) {
-    let value = 0;
-    const buffer = new Uint32Array(1);
-    while (value === 0) {
-      crypto.getRandomValues(buffer);
-      value = buffer[0];
-    }
-    return value;
-  }
-
-  return (Math.floor(Math.random() * 0xffffffff) >>> 0) || 1;
-};
-
 // Create service instance with the contract ID
 const diceDuelService = new DiceDuelService(DICE_DUEL_CONTRACT);
+const gameHubService = new GameHubService(MOCK_GAME_HUB_CONTRACT);
 
 const DICE_PIPS: Record<number, Array<[number, number]>> = {
   1: [[50, 50]],
@@ -117,8 +105,8 @@ export function DiceDuelGame({
 }: DiceDuelGameProps) {
   const DEFAULT_POINTS = '0.1';
   const { getContractSigner, walletType } = useWallet();
-  // Use a random session ID that fits in u32 (avoid 0 because UI validation treats <=0 as invalid)
-  const [sessionId, setSessionId] = useState<number>(() => createRandomSessionId());
+  // Session IDs are issued by the Game Hub when Player 1 prepares the game (0 until then)
+  const [sessionId, setSessionId] = useState<number>(0);
   const [player1Address, setPlayer1Address] = useState(userAddress);
   const [player1Points, setPlayer1Points] = useState(DEFAULT_POINTS);
   const [gameState, setGameState] = useState<Game | null>(null);
@@ -192,9 +180,9 @@ export function DiceDuelGame({
       setGameState(game);
 
       // Determine game phase based on state
-      if (game && game.winner !== null && game.winner !== undefined) {
+      if (game && game.status.tag === 'Ended') {
         setGamePhase('complete');
-      } else if (game && game.player1_rolled && game.player2_rolled) {
+      } else if (game && game.player1_commitment && game.player2_commitment) {
         setGamePhase('reveal');
       } else {
         setGamePhase('roll');
@@ -435,10 +423,18 @@ export function DiceDuelGame({
         const placeholderPlayer2Address = await getFundedSimulationSourceAddress([player1Address, userAddress]);
         const placeholderP2Points = p1Points; // Same as P1 for simulation
 
+        // The hub only accepts session IDs it reserved for this game and player
+        const reservedSessionId = await gameHubService.reserveSession(
+          DICE_DUEL_CONTRACT,
+          player1Address,
+          signer
+        );
+        setSessionId(reservedSessionId);
+
         console.log('Preparing transaction for Player 1 to sign...');
         console.log('Using placeholder Player 2 values for simulation only');
         const authEntryXDR = await diceDuelService.prepareStartGame(
-          sessionId,
+          reservedSessionId,
           player1Address,
           placeholderPlayer2Address,
           p1Points,
@@ -454,7 +450,7 @@ export function DiceDuelGame({
         const pollInterval = setInterval(async () => {
           try {
             // Try to load the game
-            const game = await diceDuelService.getGame(sessionId);
+            const game = await diceDuelService.getGame(reservedSessionId);
             if (game) {
               console.log('Game found! Player 2 has finalized the transaction. Transitioning to roll phase...');
               clearInterval(pollInterval);
@@ -555,7 +551,11 @@ export function DiceDuelGame({
           throw new Error('Quickstart requires two different dev wallets.');
         }
 
-        const quickstartSessionId = createRandomSessionId();
+        const quickstartSessionId = await gameHubService.reserveSession(
+          DICE_DUEL_CONTRACT,
+          player1AddressQuickstart,
+          player1Signer
+        );
         setSessionId(quickstartSessionId);
         setPlayer1Address(player1AddressQuickstart);
         setCreateMode('create');
@@ -759,15 +759,15 @@ export function DiceDuelGame({
         setLoadSessionId('');
 
         // Determine game phase based on game state
-        if (game.winner !== null && game.winner !== undefined) {
-          // Game is complete - show reveal phase with winner
+        if (game.status.tag === 'Ended') {
+          // Game is complete - show the result
           setGamePhase('complete');
           const isWinner = game.winner === userAddress;
           setSuccess(isWinner ? '🎉 You won this game!' : 'Game complete. Winner revealed.');
-        } else if (game.player1_rolled && game.player2_rolled) {
-          // Both players rolled, waiting for reveal
+        } else if (game.player1_commitment && game.player2_commitment) {
+          // Both players rolled, waiting for reveals
           setGamePhase('reveal');
-          setSuccess('Game loaded! Both players have rolled. You can reveal the winner.');
+          setSuccess('Game loaded! Both players have rolled. Reveal your secret.');
         } else {
           // Still in rolling phase
           setGamePhase('roll');
@@ -860,10 +860,31 @@ export function DiceDuelGame({
     });
   };
 
+  const handleRevealSecret = async () => {
+    await runAction(async () => {
+      try {
+        setLoading(true);
+        setError(null);
+        setSuccess(null);
+
+        const signer = getContractSigner();
+        await diceDuelService.revealSecret(sessionId, userAddress, signer);
+
+        setSuccess('Secret revealed');
+        await loadGameState();
+      } catch (err) {
+        console.error('Reveal secret error:', err);
+        setError(err instanceof Error ? err.message : 'Failed to reveal secret');
+      } finally {
+        setLoading(false);
+      }
+    });
+  };
+
   const waitForWinner = async () => {
     let updatedGame = await diceDuelService.getGame(sessionId);
     let attempts = 0;
-    while (attempts < 5 && (!updatedGame || updatedGame.winner === null || updatedGame.winner === undefined)) {
+    while (attempts < 5 && (!updatedGame || updatedGame.status.tag !== 'Ended')) {
       await new Promise((resolve) => setTimeout(resolve, 1000));
       updatedGame = await diceDuelService.getGame(sessionId);
       attempts += 1;
@@ -907,7 +928,12 @@ export function DiceDuelGame({
 
   const isPlayer1 = gameState && gameState.player1 === userAddress;
   const isPlayer2 = gameState && gameState.player2 === userAddress;
-  const hasRolled = isPlayer1 ? !!gameState?.player1_rolled : isPlayer2 ? !!gameState?.player2_rolled : false;
+  const player1Rolled = !!gameState?.player1_commitment;
+  const player2Rolled = !!gameState?.player2_commitment;
+  const hasRolled = isPlayer1 ? player1Rolled : isPlayer2 ? player2Rolled : false;
+  const hasRevealed = isPlayer1 ? !!gameState?.player1_secret : isPlayer2 ? !!gameState?.player2_secret : false;
+  const bothRevealed = !!gameState?.player1_secret && !!gameState?.player2_secret;
+  const hasSavedSecret = (isPlayer1 || isPlayer2) ? diceDuelService.hasSavedSecret(sessionId, userAddress) : false;
 
   const player1Dice = [gameState?.player1_die1 ?? null, gameState?.player1_die2 ?? null];
   const player2Dice = [gameState?.player2_die1 ?? null, gameState?.player2_die2 ?? null];
@@ -991,9 +1017,11 @@ export function DiceDuelGame({
               <p className="text-sm text-gray-200 font-semibold mt-1">
                 Roll two dice each. Highest total wins. Ties favor Player 1.
               </p>
-              <p className="text-xs text-gray-400 font-mono mt-1">
-                Session ID: {sessionId}
-              </p>
+              {sessionId > 0 && (
+                <p className="text-xs text-gray-400 font-mono mt-1">
+                  Session ID: {sessionId}
+                </p>
+              )}
             </div>
             <button
               onClick={() => {
@@ -1141,7 +1169,7 @@ export function DiceDuelGame({
 
           <div className="pt-4 border-t-2 border-gray-100 space-y-4">
             <p className="text-xs font-semibold text-gray-600">
-              Session ID: {sessionId}
+              Session ID: {sessionId || 'issued by the Game Hub when you sign'}
             </p>
 
             {!exportedAuthEntryXDR ? (
@@ -1361,10 +1389,10 @@ export function DiceDuelGame({
                 Points: {(Number(gameState.player1_points) / 10000000).toFixed(2)}
               </div>
               <div className="mt-4 flex items-center gap-3 dice-tray">
-                <DiceFace value={gameState.player1_die1 ?? null} tone="gold" rolling={player1Rolling} rolled={gameState.player1_rolled} />
-                <DiceFace value={gameState.player1_die2 ?? null} tone="gold" rolling={player1Rolling} rolled={gameState.player1_rolled} />
+                <DiceFace value={gameState.player1_die1 ?? null} tone="gold" rolling={player1Rolling} rolled={player1Rolled} />
+                <DiceFace value={gameState.player1_die2 ?? null} tone="gold" rolling={player1Rolling} rolled={player1Rolled} />
                 <div className="text-xs font-bold text-gray-600">
-                  {gameState.player1_rolled ? 'Rolled' : 'Waiting'}
+                  {player1Rolled ? 'Rolled' : 'Waiting'}
                 </div>
               </div>
             </div>
@@ -1378,10 +1406,10 @@ export function DiceDuelGame({
                 Points: {(Number(gameState.player2_points) / 10000000).toFixed(2)}
               </div>
               <div className="mt-4 flex items-center gap-3 dice-tray">
-                <DiceFace value={gameState.player2_die1 ?? null} tone="red" rolling={player2Rolling} rolled={gameState.player2_rolled} />
-                <DiceFace value={gameState.player2_die2 ?? null} tone="red" rolling={player2Rolling} rolled={gameState.player2_rolled} />
+                <DiceFace value={gameState.player2_die1 ?? null} tone="red" rolling={player2Rolling} rolled={player2Rolled} />
+                <DiceFace value={gameState.player2_die2 ?? null} tone="red" rolling={player2Rolling} rolled={player2Rolled} />
                 <div className="text-xs font-bold text-gray-600">
-                  {gameState.player2_rolled ? 'Rolled' : 'Waiting'}
+                  {player2Rolled ? 'Rolled' : 'Waiting'}
                 </div>
               </div>
             </div>
@@ -1413,13 +1441,48 @@ export function DiceDuelGame({
       )}
 
       {/* REVEAL PHASE */}
-      {gamePhase === 'reveal' && gameState && (
+      {gamePhase === 'reveal' && gameState && !bothRevealed && (
         <div className="space-y-6">
           <div className="p-8 bg-gradient-to-br from-amber-50 via-orange-50 to-rose-50 border-2 border-amber-300 rounded-2xl text-center shadow-xl">
-            <div className="text-6xl mb-4">🎰</div>
+            <div className="text-6xl mb-4">🔒</div>
             <h3 className="text-2xl font-black text-gray-900 mb-3">
               Both Players Rolled!
             </h3>
+            {(isPlayer1 || isPlayer2) && !hasRevealed && hasSavedSecret && (
+              <>
+                <p className="text-sm font-semibold text-gray-700 mb-6">
+                  The dice are seeded by both players' secrets. Reveal yours.
+                </p>
+                <button
+                  onClick={handleRevealSecret}
+                  disabled={isBusy}
+                  className="px-10 py-4 rounded-xl font-bold text-white text-lg bg-gradient-to-r from-amber-500 via-orange-500 to-rose-500 hover:from-amber-600 hover:via-orange-600 hover:to-rose-600 disabled:from-gray-200 disabled:to-gray-300 disabled:text-gray-500 transition-all shadow-xl hover:shadow-2xl transform hover:scale-105 disabled:transform-none"
+                >
+                  {loading ? 'Revealing...' : 'Reveal My Secret'}
+                </button>
+              </>
+            )}
+            {(isPlayer1 || isPlayer2) && !hasRevealed && !hasSavedSecret && (
+              <p className="text-sm font-semibold text-red-700">
+                Your roll was committed on another device. Reveal it from there.
+              </p>
+            )}
+            {hasRevealed && (
+              <p className="text-sm font-semibold text-gray-700">
+                ✓ Your secret is revealed. Waiting for the other player...
+              </p>
+            )}
+          </div>
+        </div>
+      )}
+
+      {gamePhase === 'reveal' && gameState && bothRevealed && (
+        <div className="space-y-6">
+          <div className="p-8 bg-gradient-to-br from-amber-50 via-orange-50 to-rose-50 border-2 border-amber-300 rounded-2xl text-center shadow-xl">
+            <div className="text-6xl mb-4">🎰</div>
+            <h3 className="text-2xl font-black text-gray-900 mb-3">
+              Both Secrets Revealed!
+            </h3>
             <p className="text-sm font-semibold text-gray-700 mb-6">
               The house is ready. Reveal the dice.
             </p>
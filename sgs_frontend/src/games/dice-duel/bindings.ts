@@ -39,68 +39,142 @@ export const networks = {
 } as const
 
 
+export const Errors = {
+  1: {message:"GameNotFound"},
+  2: {message:"NotPlayer"},
+  3: {message:"AlreadyRolled"},
+  4: {message:"BothPlayersNotRolled"},
+  5: {message:"GameAlreadyEnded"},
+  6: {message:"PlayerNotStalling"},
+  7: {message:"ContractPaused"},
+  8: {message:"SessionAlreadyExists"},
+  9: {message:"AlreadyRevealed"},
+  10: {message:"CommitmentMismatch"},
+  11: {message:"BothPlayersNotRevealed"}
+}
+
+export type GameStatus = {tag: "Active", values: void} | {tag: "Ended", values: readonly [GameOutcome]};
+
 export interface Game {
   player1: string;
+  /**
+   * Commitments to each player's secret, set by `roll`
+   */
+  player1_commitment: Option<Buffer>;
   player1_die1: Option<u32>;
   player1_die2: Option<u32>;
   player1_points: i128;
-  player1_rolled: boolean;
+  /**
+   * Revealed secrets, set by `reveal_secret`
+   */
+  player1_secret: Option<Buffer>;
   player2: string;
+  player2_commitment: Option<Buffer>;
   player2_die1: Option<u32>;
   player2_die2: Option<u32>;
   player2_points: i128;
-  player2_rolled: boolean;
+  player2_secret: Option<Buffer>;
+  status: GameStatus;
   winner: Option<string>;
 }
 
-export const Errors = {
-  1: {message:"GameNotFound"},
-  2: {message:"NotPlayer"},
-  3: {message:"AlreadyRolled"},
-  4: {message:"BothPlayersNotRolled"},
-  5: {message:"GameAlreadyEnded"}
+export type DataKey = {tag: "Game", values: readonly [u32]};
+
+export const CommonError = {
+  100: {message:"NoPendingAdmin"}
+}
+
+/**
+ * Session outcome reported to the Game Hub (mirrors the hub's `GameOutcome`)
+ */
+export type GameOutcome = {tag: "Player1Won", values: void} | {tag: "Player2Won", values: void} | {tag: "Draw", values: void} | {tag: "Cancelled", values: void} | {tag: "Forfeit", values: readonly [string]};
+
+/**
+ * How the hub settles a game's stakes
+ */
+export type StakeSemantics = {tag: "WinnerTakesAll", values: void} | {tag: "Placements", values: void};
+
+/**
+ * Static description of a game, for lobbies and hub tools
+ */
+export interface GameInfo {
+  max_players: u32;
+  min_players: u32;
+  name: string;
+  /**
+   * Bumped whenever a change to the rules could change an outcome
+   */
+  rules_version: u32;
+  stakes: StakeSemantics;
 }
 
-export type DataKey = {tag: "Game", values: readonly [u32]} | {tag: "GameHubAddress", values: void} | {tag: "Admin", values: void};
+/**
+ * Game-independent view of a session's status
+ */
+export type SessionStatus = {tag: "Active", values: void} | {tag: "Ended", values: readonly [GameOutcome]};
+
+/**
+ * Roles the admin can grant. The admin implicitly holds every role.
+ */
+export type Role = {tag: "Operator", values: void};
 
 export interface Client {
   /**
-   * Construct and simulate a roll transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
-   * Commit a roll for the current game.
-   * Both players must roll before the winner can be revealed.
+   * Construct and simulate a start_game transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Start a new game between two players with points.
+   * This creates a session in the Game Hub and locks points before starting the game.
+   * 
+   * **CRITICAL:** This method requires authorization from THIS contract (not players).
+   * The Game Hub will call `game_id.require_auth()` which checks this contract's address.
    * 
    * # Arguments
-   * * `session_id` - The session ID of the game
-   * * `player` - Address of the player rolling the dice
+   * * `session_id` - Session ID reserved on the Game Hub (`reserve_session`)
+   * * `player1` - Address of first player
+   * * `player2` - Address of second player
+   * * `player1_points` - Points amount committed by player 1
+   * * `player2_points` - Points amount committed by player 2
    */
-  roll: ({session_id, player}: {session_id: u32, player: string}, options?: MethodOptions) => Promise<AssembledTransaction<Result<void>>>
+  start_game: ({session_id, player1, player2, player1_points, player2_points}: {session_id: u32, player1: string, player2: string, player1_points: i128, player2_points: i128}, options?: MethodOptions) => Promise<AssembledTransaction<Result<void>>>
 
   /**
-   * Construct and simulate a get_hub transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
-   * Get the current GameHub contract address
+   * Construct and simulate a roll transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Commit a roll for the current game.
+   * Both players must roll, then reveal their secrets, before the winner
+   * can be revealed.
    * 
-   * # Returns
-   * * `Address` - The GameHub contract address
+   * # Arguments
+   * * `session_id` - The session ID of the game
+   * * `player` - Address of the player rolling the dice
+   * * `commitment` - `keccak256(secret || player_address)` for a 32-byte
+   * secret chosen by the player
    */
-  get_hub: (options?: MethodOptions) => Promise<AssembledTransaction<string>>
+  roll: ({session_id, player, commitment}: {session_id: u32, player: string, commitment: Buffer}, options?: MethodOptions) => Promise<AssembledTransaction<Result<void>>>
 
   /**
-   * Construct and simulate a set_hub transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
-   * Set a new GameHub contract address
+   * Construct and simulate a reveal_secret transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Reveal the secret committed in `roll`.
+   * Can only be called after both players have rolled.
    * 
    * # Arguments
-   * * `new_hub` - The new GameHub contract address
+   * * `session_id` - The session ID of the game
+   * * `player` - Address of the player revealing
+   * * `secret` - The secret behind the player's commitment
    */
-  set_hub: ({new_hub}: {new_hub: string}, options?: MethodOptions) => Promise<AssembledTransaction<null>>
+  reveal_secret: ({session_id, player, secret}: {session_id: u32, player: string, secret: Buffer}, options?: MethodOptions) => Promise<AssembledTransaction<Result<void>>>
 
   /**
-   * Construct and simulate a upgrade transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
-   * Update the contract WASM hash (upgrade contract)
+   * Construct and simulate a reveal_winner transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Reveal the winner of the game and submit outcome to GameHub.
+   * Can only be called after both players have revealed their secrets.
+   * This generates dice rolls for both players, determines the winner, and ends the session.
    * 
    * # Arguments
-   * * `new_wasm_hash` - The hash of the new WASM binary
+   * * `session_id` - The session ID of the game
+   * 
+   * # Returns
+   * * `Option<Address>` - Address of the winning player, or `None` for a draw
    */
-  upgrade: ({new_wasm_hash}: {new_wasm_hash: Buffer}, options?: MethodOptions) => Promise<AssembledTransaction<null>>
+  reveal_winner: ({session_id}: {session_id: u32}, options?: MethodOptions) => Promise<AssembledTransaction<Result<Option<string>>>>
 
   /**
    * Construct and simulate a get_game transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
@@ -115,53 +189,130 @@ export interface Client {
   get_game: ({session_id}: {session_id: u32}, options?: MethodOptions) => Promise<AssembledTransaction<Result<Game>>>
 
   /**
-   * Construct and simulate a get_admin transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
-   * Get the current admin address
+   * Construct and simulate a on_session_forfeited transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Called by the Game Hub when a timeout is claimed against `player`.
+   * Only accepted if `player` has not rolled while their opponent has, or
+   * has not revealed their secret while their opponent has; the game then
+   * ends as a forfeit by `player`. The hub settles the points.
    * 
-   * # Returns
-   * * `Address` - The admin address
+   * # Arguments
+   * * `session_id` - The session ID of the game
+   * * `player` - Address of the player being forfeited
    */
-  get_admin: (options?: MethodOptions) => Promise<AssembledTransaction<string>>
+  on_session_forfeited: ({session_id, player}: {session_id: u32, player: string}, options?: MethodOptions) => Promise<AssembledTransaction<Result<void>>>
 
   /**
-   * Construct and simulate a set_admin transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
-   * Set a new admin address
+   * Construct and simulate a on_session_cancelled transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Called by the Game Hub when it cancels the session (timeout or pause).
+   * The game ends without a winner; the hub refunds both stakes.
    * 
    * # Arguments
-   * * `new_admin` - The new admin address
+   * * `session_id` - The session ID of the game
    */
-  set_admin: ({new_admin}: {new_admin: string}, options?: MethodOptions) => Promise<AssembledTransaction<null>>
+  on_session_cancelled: ({session_id}: {session_id: u32}, options?: MethodOptions) => Promise<AssembledTransaction<Result<void>>>
 
   /**
-   * Construct and simulate a start_game transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
-   * Start a new game between two players with points.
-   * This creates a session in the Game Hub and locks points before starting the game.
-   * 
-   * **CRITICAL:** This method requires authorization from THIS contract (not players).
-   * The Game Hub will call `game_id.require_auth()` which checks this contract's address.
-   * 
-   * # Arguments
-   * * `session_id` - Unique session identifier (u32)
-   * * `player1` - Address of first player
-   * * `player2` - Address of second player
-   * * `player1_points` - Points amount committed by player 1
-   * * `player2_points` - Points amount committed by player 2
+   * Construct and simulate a game_info transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Describe the game: two players, winner takes both stakes
    */
-  start_game: ({session_id, player1, player2, player1_points, player2_points}: {session_id: u32, player1: string, player2: string, player1_points: i128, player2_points: i128}, options?: MethodOptions) => Promise<AssembledTransaction<Result<void>>>
+  game_info: (options?: MethodOptions) => Promise<AssembledTransaction<GameInfo>>
 
   /**
-   * Construct and simulate a reveal_winner transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
-   * Reveal the winner of the game and submit outcome to GameHub.
-   * Can only be called after both players have rolled.
-   * This generates dice rolls for both players, determines the winner, and ends the session.
+   * Construct and simulate a get_session_status transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Get the status of a session, or `None` if it does not exist (or expired)
+   */
+  get_session_status: ({session_id}: {session_id: u32}, options?: MethodOptions) => Promise<AssembledTransaction<Option<SessionStatus>>>
+
+  /**
+   * Construct and simulate a forfeit transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Concede the game. The opponent wins and the Game Hub settles the points.
    * 
    * # Arguments
    * * `session_id` - The session ID of the game
-   * 
-   * # Returns
-   * * `Address` - Address of the winning player
+   * * `player` - Address of the player conceding
+   */
+  forfeit: ({session_id, player}: {session_id: u32, player: string}, options?: MethodOptions) => Promise<AssembledTransaction<Result<void>>>
+
+  /**
+   * Construct and simulate a get_admin transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Get the current admin address
+   */
+  get_admin: (options?: MethodOptions) => Promise<AssembledTransaction<string>>
+
+  /**
+   * Construct and simulate a get_pending_admin transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Get the admin proposed by `propose_admin`, if any
+   */
+  get_pending_admin: (options?: MethodOptions) => Promise<AssembledTransaction<Option<string>>>
+
+  /**
+   * Construct and simulate a propose_admin transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Propose a new admin. They take over once they call
+   * `accept_admin`.
+   */
+  propose_admin: ({new_admin}: {new_admin: string}, options?: MethodOptions) => Promise<AssembledTransaction<null>>
+
+  /**
+   * Construct and simulate a accept_admin transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Accept a pending admin transfer. Pending admin only.
+   */
+  accept_admin: (options?: MethodOptions) => Promise<AssembledTransaction<Result<void>>>
+
+  /**
+   * Construct and simulate a grant_role transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Grant a role to an account
+   */
+  grant_role: ({role, account}: {role: Role, account: string}, options?: MethodOptions) => Promise<AssembledTransaction<null>>
+
+  /**
+   * Construct and simulate a revoke_role transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Revoke a role from an account
+   */
+  revoke_role: ({role, account}: {role: Role, account: string}, options?: MethodOptions) => Promise<AssembledTransaction<null>>
+
+  /**
+   * Construct and simulate a has_role transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Check whether an account holds a role (the admin holds all)
+   */
+  has_role: ({role, account}: {role: Role, account: string}, options?: MethodOptions) => Promise<AssembledTransaction<boolean>>
+
+  /**
+   * Construct and simulate a get_hub transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Get the current GameHub contract address
+   */
+  get_hub: (options?: MethodOptions) => Promise<AssembledTransaction<string>>
+
+  /**
+   * Construct and simulate a set_hub transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Set a new GameHub contract address
+   */
+  set_hub: ({new_hub}: {new_hub: string}, options?: MethodOptions) => Promise<AssembledTransaction<null>>
+
+  /**
+   * Construct and simulate a pause transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Pause gameplay: new games and moves fail with `ContractPaused`
+   * until `unpause` is called. Hub callbacks still run, so sessions
+   * can be cancelled and refunded through the hub.
    */
-  reveal_winner: ({session_id}: {session_id: u32}, options?: MethodOptions) => Promise<AssembledTransaction<Result<string>>>
+  pause: (options?: MethodOptions) => Promise<AssembledTransaction<null>>
+
+  /**
+   * Construct and simulate a unpause transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Resume gameplay after `pause`
+   */
+  unpause: (options?: MethodOptions) => Promise<AssembledTransaction<null>>
+
+  /**
+   * Construct and simulate a is_paused transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Check whether the contract is paused
+   */
+  is_paused: (options?: MethodOptions) => Promise<AssembledTransaction<boolean>>
+
+  /**
+   * Construct and simulate a upgrade transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Update the contract WASM hash (upgrade contract)
+   */
+  upgrade: ({new_wasm_hash}: {new_wasm_hash: Buffer}, options?: MethodOptions) => Promise<AssembledTransaction<null>>
 
 }
 export class Client extends ContractClient {
@@ -183,31 +334,66 @@ export class Client extends ContractClient {
   }
   constructor(public readonly options: ContractClientOptions) {
     super(
-      new ContractSpec([ "AAAAAQAAAAAAAAAAAAAABEdhbWUAAAALAAAAAAAAAAdwbGF5ZXIxAAAAABMAAAAAAAAADHBsYXllcjFfZGllMQAAA+gAAAAEAAAAAAAAAAxwbGF5ZXIxX2RpZTIAAAPoAAAABAAAAAAAAAAOcGxheWVyMV9wb2ludHMAAAAAAAsAAAAAAAAADnBsYXllcjFfcm9sbGVkAAAAAAABAAAAAAAAAAdwbGF5ZXIyAAAAABMAAAAAAAAADHBsYXllcjJfZGllMQAAA+gAAAAEAAAAAAAAAAxwbGF5ZXIyX2RpZTIAAAPoAAAABAAAAAAAAAAOcGxheWVyMl9wb2ludHMAAAAAAAsAAAAAAAAADnBsYXllcjJfcm9sbGVkAAAAAAABAAAAAAAAAAZ3aW5uZXIAAAAAA+gAAAAT",
-        "AAAABAAAAAAAAAAAAAAABUVycm9yAAAAAAAABQAAAAAAAAAMR2FtZU5vdEZvdW5kAAAAAQAAAAAAAAAJTm90UGxheWVyAAAAAAAAAgAAAAAAAAANQWxyZWFkeVJvbGxlZAAAAAAAAAMAAAAAAAAAFEJvdGhQbGF5ZXJzTm90Um9sbGVkAAAABAAAAAAAAAAQR2FtZUFscmVhZHlFbmRlZAAAAAU=",
-        "AAAAAgAAAAAAAAAAAAAAB0RhdGFLZXkAAAAAAwAAAAEAAAAAAAAABEdhbWUAAAABAAAABAAAAAAAAAAAAAAADkdhbWVIdWJBZGRyZXNzAAAAAAAAAAAAAAAAAAVBZG1pbgAAAA==",
-        "AAAAAAAAAMpDb21taXQgYSByb2xsIGZvciB0aGUgY3VycmVudCBnYW1lLgpCb3RoIHBsYXllcnMgbXVzdCByb2xsIGJlZm9yZSB0aGUgd2lubmVyIGNhbiBiZSByZXZlYWxlZC4KCiMgQXJndW1lbnRzCiogYHNlc3Npb25faWRgIC0gVGhlIHNlc3Npb24gSUQgb2YgdGhlIGdhbWUKKiBgcGxheWVyYCAtIEFkZHJlc3Mgb2YgdGhlIHBsYXllciByb2xsaW5nIHRoZSBkaWNlAAAAAAAEcm9sbAAAAAIAAAAAAAAACnNlc3Npb25faWQAAAAAAAQAAAAAAAAABnBsYXllcgAAAAAAEwAAAAEAAAPpAAAD7QAAAAAAAAAD",
-        "AAAAAAAAAF5HZXQgdGhlIGN1cnJlbnQgR2FtZUh1YiBjb250cmFjdCBhZGRyZXNzCgojIFJldHVybnMKKiBgQWRkcmVzc2AgLSBUaGUgR2FtZUh1YiBjb250cmFjdCBhZGRyZXNzAAAAAAAHZ2V0X2h1YgAAAAAAAAAAAQAAABM=",
-        "AAAAAAAAAF5TZXQgYSBuZXcgR2FtZUh1YiBjb250cmFjdCBhZGRyZXNzCgojIEFyZ3VtZW50cwoqIGBuZXdfaHViYCAtIFRoZSBuZXcgR2FtZUh1YiBjb250cmFjdCBhZGRyZXNzAAAAAAAHc2V0X2h1YgAAAAABAAAAAAAAAAduZXdfaHViAAAAABMAAAAA",
-        "AAAAAAAAAHFVcGRhdGUgdGhlIGNvbnRyYWN0IFdBU00gaGFzaCAodXBncmFkZSBjb250cmFjdCkKCiMgQXJndW1lbnRzCiogYG5ld193YXNtX2hhc2hgIC0gVGhlIGhhc2ggb2YgdGhlIG5ldyBXQVNNIGJpbmFyeQAAAAAAAAd1cGdyYWRlAAAAAAEAAAAAAAAADW5ld193YXNtX2hhc2gAAAAAAAPuAAAAIAAAAAA=",
-        "AAAAAAAAAJNHZXQgZ2FtZSBpbmZvcm1hdGlvbi4KCiMgQXJndW1lbnRzCiogYHNlc3Npb25faWRgIC0gVGhlIHNlc3Npb24gSUQgb2YgdGhlIGdhbWUKCiMgUmV0dXJucwoqIGBHYW1lYCAtIFRoZSBnYW1lIHN0YXRlIChpbmNsdWRlcyBkaWNlIGFmdGVyIGdhbWUgZW5kcykAAAAACGdldF9nYW1lAAAAAQAAAAAAAAAKc2Vzc2lvbl9pZAAAAAAABAAAAAEAAAPpAAAH0AAAAARHYW1lAAAAAw==",
-        "AAAAAAAAAEhHZXQgdGhlIGN1cnJlbnQgYWRtaW4gYWRkcmVzcwoKIyBSZXR1cm5zCiogYEFkZHJlc3NgIC0gVGhlIGFkbWluIGFkZHJlc3MAAAAJZ2V0X2FkbWluAAAAAAAAAAAAAAEAAAAT",
-        "AAAAAAAAAEpTZXQgYSBuZXcgYWRtaW4gYWRkcmVzcwoKIyBBcmd1bWVudHMKKiBgbmV3X2FkbWluYCAtIFRoZSBuZXcgYWRtaW4gYWRkcmVzcwAAAAAACXNldF9hZG1pbgAAAAAAAAEAAAAAAAAACW5ld19hZG1pbgAAAAAAABMAAAAA",
-        "AAAAAAAAAipTdGFydCBhIG5ldyBnYW1lIGJldHdlZW4gdHdvIHBsYXllcnMgd2l0aCBwb2ludHMuClRoaXMgY3JlYXRlcyBhIHNlc3Npb24gaW4gdGhlIEdhbWUgSHViIGFuZCBsb2NrcyBwb2ludHMgYmVmb3JlIHN0YXJ0aW5nIHRoZSBnYW1lLgoKKipDUklUSUNBTDoqKiBUaGlzIG1ldGhvZCByZXF1aXJlcyBhdXRob3JpemF0aW9uIGZyb20gVEhJUyBjb250cmFjdCAobm90IHBsYXllcnMpLgpUaGUgR2FtZSBIdWIgd2lsbCBjYWxsIGBnYW1lX2lkLnJlcXVpcmVfYXV0aCgpYCB3aGljaCBjaGVja3MgdGhpcyBjb250cmFjdCdzIGFkZHJlc3MuCgojIEFyZ3VtZW50cwoqIGBzZXNzaW9uX2lkYCAtIFVuaXF1ZSBzZXNzaW9uIGlkZW50aWZpZXIgKHUzMikKKiBgcGxheWVyMWAgLSBBZGRyZXNzIG9mIGZpcnN0IHBsYXllcgoqIGBwbGF5ZXIyYCAtIEFkZHJlc3Mgb2Ygc2Vjb25kIHBsYXllcgoqIGBwbGF5ZXIxX3BvaW50c2AgLSBQb2ludHMgYW1vdW50IGNvbW1pdHRlZCBieSBwbGF5ZXIgMQoqIGBwbGF5ZXIyX3BvaW50c2AgLSBQb2ludHMgYW1vdW50IGNvbW1pdHRlZCBieSBwbGF5ZXIgMgAAAAAACnN0YXJ0X2dhbWUAAAAAAAUAAAAAAAAACnNlc3Npb25faWQAAAAAAAQAAAAAAAAAB3BsYXllcjEAAAAAEwAAAAAAAAAHcGxheWVyMgAAAAATAAAAAAAAAA5wbGF5ZXIxX3BvaW50cwAAAAAACwAAAAAAAAAOcGxheWVyMl9wb2ludHMAAAAAAAsAAAABAAAD6QAAA+0AAAAAAAAAAw==",
+      new ContractSpec([ "AAAABAAAAAAAAAAAAAAABUVycm9yAAAAAAAACwAAAAAAAAAMR2FtZU5vdEZvdW5kAAAAAQAAAAAAAAAJTm90UGxheWVyAAAAAAAAAgAAAAAAAAANQWxyZWFkeVJvbGxlZAAAAAAAAAMAAAAAAAAAFEJvdGhQbGF5ZXJzTm90Um9sbGVkAAAABAAAAAAAAAAQR2FtZUFscmVhZHlFbmRlZAAAAAUAAAAAAAAAEVBsYXllck5vdFN0YWxsaW5nAAAAAAAABgAAAAAAAAAOQ29udHJhY3RQYXVzZWQAAAAAAAcAAAAAAAAAFFNlc3Npb25BbHJlYWR5RXhpc3RzAAAACAAAAAAAAAAPQWxyZWFkeVJldmVhbGVkAAAAAAkAAAAAAAAAEkNvbW1pdG1lbnRNaXNtYXRjaAAAAAAACgAAAAAAAAAWQm90aFBsYXllcnNOb3RSZXZlYWxlZAAAAAAACw==",
+        "AAAAAgAAAAAAAAAAAAAACkdhbWVTdGF0dXMAAAAAAAIAAAAAAAAAAAAAAAZBY3RpdmUAAAAAAAEAAAAAAAAABUVuZGVkAAAAAAAAAQAAB9AAAAALR2FtZU91dGNvbWUA",
+        "AAAAAQAAAAAAAAAAAAAABEdhbWUAAAAOAAAAAAAAAAdwbGF5ZXIxAAAAABMAAAAyQ29tbWl0bWVudHMgdG8gZWFjaCBwbGF5ZXIncyBzZWNyZXQsIHNldCBieSBgcm9sbGAAAAAAABJwbGF5ZXIxX2NvbW1pdG1lbnQAAAAAA+gAAAPuAAAAIAAAAAAAAAAMcGxheWVyMV9kaWUxAAAD6AAAAAQAAAAAAAAADHBsYXllcjFfZGllMgAAA+gAAAAEAAAAAAAAAA5wbGF5ZXIxX3BvaW50cwAAAAAACwAAAChSZXZlYWxlZCBzZWNyZXRzLCBzZXQgYnkgYHJldmVhbF9zZWNyZXRgAAAADnBsYXllcjFfc2VjcmV0AAAAAAPoAAAD7gAAACAAAAAAAAAAB3BsYXllcjIAAAAAEwAAAAAAAAAScGxheWVyMl9jb21taXRtZW50AAAAAAPoAAAD7gAAACAAAAAAAAAADHBsYXllcjJfZGllMQAAA+gAAAAEAAAAAAAAAAxwbGF5ZXIyX2RpZTIAAAPoAAAABAAAAAAAAAAOcGxheWVyMl9wb2ludHMAAAAAAAsAAAAAAAAADnBsYXllcjJfc2VjcmV0AAAAAAPoAAAD7gAAACAAAAAAAAAABnN0YXR1cwAAAAAH0AAAAApHYW1lU3RhdHVzAAAAAAAAAAAABndpbm5lcgAAAAAD6AAAABM=",
+        "AAAAAgAAAAAAAAAAAAAAB0RhdGFLZXkAAAAAAQAAAAEAAAAAAAAABEdhbWUAAAABAAAABA==",
+        "AAAABAAAAHpFcnJvcnMgcmV0dXJuZWQgYnkgdGhlIHNoYXJlZCBhZG1pbiBlbnRyeXBvaW50cy4gQ29kZXMgc3RhcnQgYXQgMTAwIHNvCnRoZXkgbmV2ZXIgY29sbGlkZSB3aXRoIGEgZ2FtZSdzIG93biBgRXJyb3JgIGNvZGVzLgAAAAAAAAAAAAtDb21tb25FcnJvcgAAAAABAAAAI05vIGFkbWluIHRyYW5zZmVyIGhhcyBiZWVuIHByb3Bvc2VkAAAAAA5Ob1BlbmRpbmdBZG1pbgAAAAAAZA==",
+        "AAAAAgAAAEpTZXNzaW9uIG91dGNvbWUgcmVwb3J0ZWQgdG8gdGhlIEdhbWUgSHViIChtaXJyb3JzIHRoZSBodWIncyBgR2FtZU91dGNvbWVgKQAAAAAAAAAAAAtHYW1lT3V0Y29tZQAAAAAFAAAAAAAAAAAAAAAKUGxheWVyMVdvbgAAAAAAAAAAAAAAAAAKUGxheWVyMldvbgAAAAAAAAAAAAAAAAAERHJhdwAAAAAAAAAAAAAACUNhbmNlbGxlZAAAAAAAAAEAAAAAAAAAB0ZvcmZlaXQAAAAAAQAAABM=",
+        "AAAAAgAAACNIb3cgdGhlIGh1YiBzZXR0bGVzIGEgZ2FtZSdzIHN0YWtlcwAAAAAAAAAADlN0YWtlU2VtYW50aWNzAAAAAAACAAAAAAAAAFZFYWNoIHBsYXllciBzdGFrZXM7IHRoZSB3aW5uZXIgdGFrZXMgZXZlcnkgc3Rha2UuIERyYXdzIGFuZApjYW5jZWxsYXRpb25zIHJlZnVuZCB0aGVtLgAAAAAADldpbm5lclRha2VzQWxsAAAAAAAAAAAAQEVhY2ggcGxheWVyIHN0YWtlczsgdGhlIGh1YiBzcGxpdHMgdGhlIHBvdCBieSBmaW5pc2hpbmcgcG9zaXRpb24AAAAKUGxhY2VtZW50cwAA",
+        "AAAAAQAAADdTdGF0aWMgZGVzY3JpcHRpb24gb2YgYSBnYW1lLCBmb3IgbG9iYmllcyBhbmQgaHViIHRvb2xzAAAAAAAAAAAIR2FtZUluZm8AAAAFAAAAAAAAAAttYXhfcGxheWVycwAAAAAEAAAAAAAAAAttaW5fcGxheWVycwAAAAAEAAAAAAAAAARuYW1lAAAAEAAAAD1CdW1wZWQgd2hlbmV2ZXIgYSBjaGFuZ2UgdG8gdGhlIHJ1bGVzIGNvdWxkIGNoYW5nZSBhbiBvdXRjb21lAAAAAAAADXJ1bGVzX3ZlcnNpb24AAAAAAAAEAAAAAAAAAAZzdGFrZXMAAAAAB9AAAAAOU3Rha2VTZW1hbnRpY3MAAA==",
+        "AAAAAgAAACtHYW1lLWluZGVwZW5kZW50IHZpZXcgb2YgYSBzZXNzaW9uJ3Mgc3RhdHVzAAAAAAAAAAANU2Vzc2lvblN0YXR1cwAAAAAAAAIAAAAAAAAAAAAAAAZBY3RpdmUAAAAAAAEAAAAAAAAABUVuZGVkAAAAAAAAAQAAB9AAAAALR2FtZU91dGNvbWUA",
+        "AAAAAgAAAEFSb2xlcyB0aGUgYWRtaW4gY2FuIGdyYW50LiBUaGUgYWRtaW4gaW1wbGljaXRseSBob2xkcyBldmVyeSByb2xlLgAAAAAAAAAAAAAEUm9sZQAAAAEAAAAAAAAAfkRheS10by1kYXkgZ2FtZSBvcGVyYXRpb25zIChjcmVhdGluZyBjYXNlcyBhbmQgc2NlbmVzLCByZXNvbHZpbmcKZ2FtZXMpLiBDYW5ub3QgY2hhbmdlIHRoZSBhZG1pbiwgdGhlIGh1YiBhZGRyZXNzIG9yIHRoZSBXQVNNLgAAAAAACE9wZXJhdG9y",
         "AAAAAAAAAKNJbml0aWFsaXplIHRoZSBjb250cmFjdCB3aXRoIEdhbWVIdWIgYWRkcmVzcyBhbmQgYWRtaW4KCiMgQXJndW1lbnRzCiogYGFkbWluYCAtIEFkbWluIGFkZHJlc3MgKGNhbiB1cGdyYWRlIGNvbnRyYWN0KQoqIGBnYW1lX2h1YmAgLSBBZGRyZXNzIG9mIHRoZSBHYW1lSHViIGNvbnRyYWN0AAAAAA1fX2NvbnN0cnVjdG9yAAAAAAAAAgAAAAAAAAAFYWRtaW4AAAAAAAATAAAAAAAAAAhnYW1lX2h1YgAAABMAAAAA",
-        "AAAAAAAAAThSZXZlYWwgdGhlIHdpbm5lciBvZiB0aGUgZ2FtZSBhbmQgc3VibWl0IG91dGNvbWUgdG8gR2FtZUh1Yi4KQ2FuIG9ubHkgYmUgY2FsbGVkIGFmdGVyIGJvdGggcGxheWVycyBoYXZlIHJvbGxlZC4KVGhpcyBnZW5lcmF0ZXMgZGljZSByb2xscyBmb3IgYm90aCBwbGF5ZXJzLCBkZXRlcm1pbmVzIHRoZSB3aW5uZXIsIGFuZCBlbmRzIHRoZSBzZXNzaW9uLgoKIyBBcmd1bWVudHMKKiBgc2Vzc2lvbl9pZGAgLSBUaGUgc2Vzc2lvbiBJRCBvZiB0aGUgZ2FtZQoKIyBSZXR1cm5zCiogYEFkZHJlc3NgIC0gQWRkcmVzcyBvZiB0aGUgd2lubmluZyBwbGF5ZXIAAAANcmV2ZWFsX3dpbm5lcgAAAAAAAAEAAAAAAAAACnNlc3Npb25faWQAAAAAAAQAAAABAAAD6QAAABMAAAAD" ]),
+        "AAAAAAAAAkJTdGFydCBhIG5ldyBnYW1lIGJldHdlZW4gdHdvIHBsYXllcnMgd2l0aCBwb2ludHMuClRoaXMgY3JlYXRlcyBhIHNlc3Npb24gaW4gdGhlIEdhbWUgSHViIGFuZCBsb2NrcyBwb2ludHMgYmVmb3JlIHN0YXJ0aW5nIHRoZSBnYW1lLgoKKipDUklUSUNBTDoqKiBUaGlzIG1ldGhvZCByZXF1aXJlcyBhdXRob3JpemF0aW9uIGZyb20gVEhJUyBjb250cmFjdCAobm90IHBsYXllcnMpLgpUaGUgR2FtZSBIdWIgd2lsbCBjYWxsIGBnYW1lX2lkLnJlcXVpcmVfYXV0aCgpYCB3aGljaCBjaGVja3MgdGhpcyBjb250cmFjdCdzIGFkZHJlc3MuCgojIEFyZ3VtZW50cwoqIGBzZXNzaW9uX2lkYCAtIFNlc3Npb24gSUQgcmVzZXJ2ZWQgb24gdGhlIEdhbWUgSHViIChgcmVzZXJ2ZV9zZXNzaW9uYCkKKiBgcGxheWVyMWAgLSBBZGRyZXNzIG9mIGZpcnN0IHBsYXllcgoqIGBwbGF5ZXIyYCAtIEFkZHJlc3Mgb2Ygc2Vjb25kIHBsYXllcgoqIGBwbGF5ZXIxX3BvaW50c2AgLSBQb2ludHMgYW1vdW50IGNvbW1pdHRlZCBieSBwbGF5ZXIgMQoqIGBwbGF5ZXIyX3BvaW50c2AgLSBQb2ludHMgYW1vdW50IGNvbW1pdHRlZCBieSBwbGF5ZXIgMgAAAAAACnN0YXJ0X2dhbWUAAAAAAAUAAAAAAAAACnNlc3Npb25faWQAAAAAAAQAAAAAAAAAB3BsYXllcjEAAAAAEwAAAAAAAAAHcGxheWVyMgAAAAATAAAAAAAAAA5wbGF5ZXIxX3BvaW50cwAAAAAACwAAAAAAAAAOcGxheWVyMl9wb2ludHMAAAAAAAsAAAABAAAD6QAAAAIAAAAD",
+        "AAAAAAAAAUdDb21taXQgYSByb2xsIGZvciB0aGUgY3VycmVudCBnYW1lLgpCb3RoIHBsYXllcnMgbXVzdCByb2xsLCB0aGVuIHJldmVhbCB0aGVpciBzZWNyZXRzLCBiZWZvcmUgdGhlIHdpbm5lcgpjYW4gYmUgcmV2ZWFsZWQuCgojIEFyZ3VtZW50cwoqIGBzZXNzaW9uX2lkYCAtIFRoZSBzZXNzaW9uIElEIG9mIHRoZSBnYW1lCiogYHBsYXllcmAgLSBBZGRyZXNzIG9mIHRoZSBwbGF5ZXIgcm9sbGluZyB0aGUgZGljZQoqIGBjb21taXRtZW50YCAtIGBrZWNjYWsyNTYoc2VjcmV0IHx8IHBsYXllcl9hZGRyZXNzKWAgZm9yIGEgMzItYnl0ZQpzZWNyZXQgY2hvc2VuIGJ5IHRoZSBwbGF5ZXIAAAAABHJvbGwAAAADAAAAAAAAAApzZXNzaW9uX2lkAAAAAAAEAAAAAAAAAAZwbGF5ZXIAAAAAABMAAAAAAAAACmNvbW1pdG1lbnQAAAAAA+4AAAAgAAAAAQAAA+kAAAACAAAAAw==",
+        "AAAAAAAAAPZSZXZlYWwgdGhlIHNlY3JldCBjb21taXR0ZWQgaW4gYHJvbGxgLgpDYW4gb25seSBiZSBjYWxsZWQgYWZ0ZXIgYm90aCBwbGF5ZXJzIGhhdmUgcm9sbGVkLgoKIyBBcmd1bWVudHMKKiBgc2Vzc2lvbl9pZGAgLSBUaGUgc2Vzc2lvbiBJRCBvZiB0aGUgZ2FtZQoqIGBwbGF5ZXJgIC0gQWRkcmVzcyBvZiB0aGUgcGxheWVyIHJldmVhbGluZwoqIGBzZWNyZXRgIC0gVGhlIHNlY3JldCBiZWhpbmQgdGhlIHBsYXllcidzIGNvbW1pdG1lbnQAAAAAAA1yZXZlYWxfc2VjcmV0AAAAAAAAAwAAAAAAAAAKc2Vzc2lvbl9pZAAAAAAABAAAAAAAAAAGcGxheWVyAAAAAAATAAAAAAAAAAZzZWNyZXQAAAAAA+4AAAAgAAAAAQAAA+kAAAACAAAAAw==",
+        "AAAAAAAAAWZSZXZlYWwgdGhlIHdpbm5lciBvZiB0aGUgZ2FtZSBhbmQgc3VibWl0IG91dGNvbWUgdG8gR2FtZUh1Yi4KQ2FuIG9ubHkgYmUgY2FsbGVkIGFmdGVyIGJvdGggcGxheWVycyBoYXZlIHJldmVhbGVkIHRoZWlyIHNlY3JldHMuClRoaXMgZ2VuZXJhdGVzIGRpY2Ugcm9sbHMgZm9yIGJvdGggcGxheWVycywgZGV0ZXJtaW5lcyB0aGUgd2lubmVyLCBhbmQgZW5kcyB0aGUgc2Vzc2lvbi4KCiMgQXJndW1lbnRzCiogYHNlc3Npb25faWRgIC0gVGhlIHNlc3Npb24gSUQgb2YgdGhlIGdhbWUKCiMgUmV0dXJucwoqIGBPcHRpb248QWRkcmVzcz5gIC0gQWRkcmVzcyBvZiB0aGUgd2lubmluZyBwbGF5ZXIsIG9yIGBOb25lYCBmb3IgYSBkcmF3AAAAAAANcmV2ZWFsX3dpbm5lcgAAAAAAAAEAAAAAAAAACnNlc3Npb25faWQAAAAAAAQAAAABAAAD6QAAA+gAAAATAAAAAw==",
+        "AAAAAAAAAJNHZXQgZ2FtZSBpbmZvcm1hdGlvbi4KCiMgQXJndW1lbnRzCiogYHNlc3Npb25faWRgIC0gVGhlIHNlc3Npb24gSUQgb2YgdGhlIGdhbWUKCiMgUmV0dXJucwoqIGBHYW1lYCAtIFRoZSBnYW1lIHN0YXRlIChpbmNsdWRlcyBkaWNlIGFmdGVyIGdhbWUgZW5kcykAAAAACGdldF9nYW1lAAAAAQAAAAAAAAAKc2Vzc2lvbl9pZAAAAAAABAAAAAEAAAPpAAAH0AAAAARHYW1lAAAAAw==",
+        "AAAAAAAAAXVDYWxsZWQgYnkgdGhlIEdhbWUgSHViIHdoZW4gYSB0aW1lb3V0IGlzIGNsYWltZWQgYWdhaW5zdCBgcGxheWVyYC4KT25seSBhY2NlcHRlZCBpZiBgcGxheWVyYCBoYXMgbm90IHJvbGxlZCB3aGlsZSB0aGVpciBvcHBvbmVudCBoYXMsIG9yCmhhcyBub3QgcmV2ZWFsZWQgdGhlaXIgc2VjcmV0IHdoaWxlIHRoZWlyIG9wcG9uZW50IGhhczsgdGhlIGdhbWUgdGhlbgplbmRzIGFzIGEgZm9yZmVpdCBieSBgcGxheWVyYC4gVGhlIGh1YiBzZXR0bGVzIHRoZSBwb2ludHMuCgojIEFyZ3VtZW50cwoqIGBzZXNzaW9uX2lkYCAtIFRoZSBzZXNzaW9uIElEIG9mIHRoZSBnYW1lCiogYHBsYXllcmAgLSBBZGRyZXNzIG9mIHRoZSBwbGF5ZXIgYmVpbmcgZm9yZmVpdGVkAAAAAAAAFG9uX3Nlc3Npb25fZm9yZmVpdGVkAAAAAgAAAAAAAAAKc2Vzc2lvbl9pZAAAAAAABAAAAAAAAAAGcGxheWVyAAAAAAATAAAAAQAAA+kAAAACAAAAAw==",
+        "AAAAAAAAALxDYWxsZWQgYnkgdGhlIEdhbWUgSHViIHdoZW4gaXQgY2FuY2VscyB0aGUgc2Vzc2lvbiAodGltZW91dCBvciBwYXVzZSkuClRoZSBnYW1lIGVuZHMgd2l0aG91dCBhIHdpbm5lcjsgdGhlIGh1YiByZWZ1bmRzIGJvdGggc3Rha2VzLgoKIyBBcmd1bWVudHMKKiBgc2Vzc2lvbl9pZGAgLSBUaGUgc2Vzc2lvbiBJRCBvZiB0aGUgZ2FtZQAAABRvbl9zZXNzaW9uX2NhbmNlbGxlZAAAAAEAAAAAAAAACnNlc3Npb25faWQAAAAAAAQAAAABAAAD6QAAAAIAAAAD",
+        "AAAAAAAAADhEZXNjcmliZSB0aGUgZ2FtZTogdHdvIHBsYXllcnMsIHdpbm5lciB0YWtlcyBib3RoIHN0YWtlcwAAAAlnYW1lX2luZm8AAAAAAAAAAAAAAQAAB9AAAAAIR2FtZUluZm8=",
+        "AAAAAAAAAEhHZXQgdGhlIHN0YXR1cyBvZiBhIHNlc3Npb24sIG9yIGBOb25lYCBpZiBpdCBkb2VzIG5vdCBleGlzdCAob3IgZXhwaXJlZCkAAAASZ2V0X3Nlc3Npb25fc3RhdHVzAAAAAAABAAAAAAAAAApzZXNzaW9uX2lkAAAAAAAEAAAAAQAAA+gAAAfQAAAADVNlc3Npb25TdGF0dXMAAAA=",
+        "AAAAAAAAAK5Db25jZWRlIHRoZSBnYW1lLiBUaGUgb3Bwb25lbnQgd2lucyBhbmQgdGhlIEdhbWUgSHViIHNldHRsZXMgdGhlIHBvaW50cy4KCiMgQXJndW1lbnRzCiogYHNlc3Npb25faWRgIC0gVGhlIHNlc3Npb24gSUQgb2YgdGhlIGdhbWUKKiBgcGxheWVyYCAtIEFkZHJlc3Mgb2YgdGhlIHBsYXllciBjb25jZWRpbmcAAAAAAAdmb3JmZWl0AAAAAAIAAAAAAAAACnNlc3Npb25faWQAAAAAAAQAAAAAAAAABnBsYXllcgAAAAAAEwAAAAEAAAPpAAAAAgAAAAM=",
+        "AAAAAAAAAB1HZXQgdGhlIGN1cnJlbnQgYWRtaW4gYWRkcmVzcwAAAAAAAAlnZXRfYWRtaW4AAAAAAAAAAAAAAQAAABM=",
+        "AAAAAAAAADFHZXQgdGhlIGFkbWluIHByb3Bvc2VkIGJ5IGBwcm9wb3NlX2FkbWluYCwgaWYgYW55AAAAAAAAEWdldF9wZW5kaW5nX2FkbWluAAAAAAAAAAAAAAEAAAPoAAAAEw==",
+        "AAAAAAAAAEJQcm9wb3NlIGEgbmV3IGFkbWluLiBUaGV5IHRha2Ugb3ZlciBvbmNlIHRoZXkgY2FsbApgYWNjZXB0X2FkbWluYC4AAAAAAA1wcm9wb3NlX2FkbWluAAAAAAAAAQAAAAAAAAAJbmV3X2FkbWluAAAAAAAAEwAAAAA=",
+        "AAAAAAAAADRBY2NlcHQgYSBwZW5kaW5nIGFkbWluIHRyYW5zZmVyLiBQZW5kaW5nIGFkbWluIG9ubHkuAAAADGFjY2VwdF9hZG1pbgAAAAAAAAABAAAD6QAAAAIAAAfQAAAAC0NvbW1vbkVycm9yAA==",
+        "AAAAAAAAABpHcmFudCBhIHJvbGUgdG8gYW4gYWNjb3VudAAAAAAACmdyYW50X3JvbGUAAAAAAAIAAAAAAAAABHJvbGUAAAfQAAAABFJvbGUAAAAAAAAAB2FjY291bnQAAAAAEwAAAAA=",
+        "AAAAAAAAAB1SZXZva2UgYSByb2xlIGZyb20gYW4gYWNjb3VudAAAAAAAAAtyZXZva2Vfcm9sZQAAAAACAAAAAAAAAARyb2xlAAAH0AAAAARSb2xlAAAAAAAAAAdhY2NvdW50AAAAABMAAAAA",
+        "AAAAAAAAADtDaGVjayB3aGV0aGVyIGFuIGFjY291bnQgaG9sZHMgYSByb2xlICh0aGUgYWRtaW4gaG9sZHMgYWxsKQAAAAAIaGFzX3JvbGUAAAACAAAAAAAAAARyb2xlAAAH0AAAAARSb2xlAAAAAAAAAAdhY2NvdW50AAAAABMAAAABAAAAAQ==",
+        "AAAAAAAAAChHZXQgdGhlIGN1cnJlbnQgR2FtZUh1YiBjb250cmFjdCBhZGRyZXNzAAAAB2dldF9odWIAAAAAAAAAAAEAAAAT",
+        "AAAAAAAAACJTZXQgYSBuZXcgR2FtZUh1YiBjb250cmFjdCBhZGRyZXNzAAAAAAAHc2V0X2h1YgAAAAABAAAAAAAAAAduZXdfaHViAAAAABMAAAAA",
+        "AAAAAAAAAK1QYXVzZSBnYW1lcGxheTogbmV3IGdhbWVzIGFuZCBtb3ZlcyBmYWlsIHdpdGggYENvbnRyYWN0UGF1c2VkYAp1bnRpbCBgdW5wYXVzZWAgaXMgY2FsbGVkLiBIdWIgY2FsbGJhY2tzIHN0aWxsIHJ1biwgc28gc2Vzc2lvbnMKY2FuIGJlIGNhbmNlbGxlZCBhbmQgcmVmdW5kZWQgdGhyb3VnaCB0aGUgaHViLgAAAAAAAAVwYXVzZQAAAAAAAAAAAAAA",
+        "AAAAAAAAAB1SZXN1bWUgZ2FtZXBsYXkgYWZ0ZXIgYHBhdXNlYAAAAAAAAAd1bnBhdXNlAAAAAAAAAAAA",
+        "AAAAAAAAACRDaGVjayB3aGV0aGVyIHRoZSBjb250cmFjdCBpcyBwYXVzZWQAAAAJaXNfcGF1c2VkAAAAAAAAAAAAAAEAAAAB",
+        "AAAAAAAAADBVcGRhdGUgdGhlIGNvbnRyYWN0IFdBU00gaGFzaCAodXBncmFkZSBjb250cmFjdCkAAAAHdXBncmFkZQAAAAABAAAAAAAAAA1uZXdfd2FzbV9oYXNoAAAAAAAD7gAAACAAAAAA" ]),
       options
     )
   }
   public readonly fromJSON = {
-    roll: this.txFromJSON<Result<void>>,
-        get_hub: this.txFromJSON<string>,
-        set_hub: this.txFromJSON<null>,
-        upgrade: this.txFromJSON<null>,
+    start_game: this.txFromJSON<Result<void>>,
+        roll: this.txFromJSON<Result<void>>,
+        reveal_secret: this.txFromJSON<Result<void>>,
+        reveal_winner: this.txFromJSON<Result<Option<string>>>,
         get_game: this.txFromJSON<Result<Game>>,
+        on_session_forfeited: this.txFromJSON<Result<void>>,
+        on_session_cancelled: this.txFromJSON<Result<void>>,
+        game_info: this.txFromJSON<GameInfo>,
+        get_session_status: this.txFromJSON<Option<SessionStatus>>,
+        forfeit: this.txFromJSON<Result<void>>,
         get_admin: this.txFromJSON<string>,
-        set_admin: this.txFromJSON<null>,
-        start_game: this.txFromJSON<Result<void>>,
-        reveal_winner: this.txFromJSON<Result<string>>
+        get_pending_admin: this.txFromJSON<Option<string>>,
+        propose_admin: this.txFromJSON<null>,
+        accept_admin: this.txFromJSON<Result<void>>,
+        grant_role: this.txFromJSON<null>,
+        revoke_role: this.txFromJSON<null>,
+        has_role: this.txFromJSON<boolean>,
+        get_hub: this.txFromJSON<string>,
+        set_hub: this.txFromJSON<null>,
+        pause: this.txFromJSON<null>,
+        unpause: this.txFromJSON<null>,
+        is_paused: this.txFromJSON<boolean>,
+        upgrade: this.txFromJSON<null>
   }
 }
\ No newline at end of file
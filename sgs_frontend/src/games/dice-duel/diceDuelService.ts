@@ -5,6 +5,7 @@ import { Buffer } from 'buffer';
 import { signAndSendViaLaunchtube } from '@/utils/transactionHelper';
 import { calculateValidUntilLedger } from '@/utils/ledgerUtils';
 import { injectSignedAuthEntry } from '@/utils/authEntryUtils';
+import { randomSecret, keccak256, addressBytes, saveSecret, loadSecret, clearSecret } from '@/utils/commitUtils';
 
 type ClientOptions = contract.ClientOptions;
 
@@ -577,6 +578,8 @@ export class DiceDuelService {
 
   /**
    * Commit a dice roll
+   * Only keccak256(secret || player) is sent; a fresh random secret that seeds
+   * the dice is saved on this device for revealSecret.
    */
   async roll(
     sessionId: number,
@@ -584,10 +587,16 @@ export class DiceDuelService {
     signer: Pick<contract.ClientOptions, 'signTransaction' | 'signAuthEntry'>,
     authTtlMinutes?: number
   ) {
+    const secret = randomSecret();
+    const commitment = keccak256(secret, addressBytes(playerAddress));
+    // Save before sending: without the secret the roll can never be revealed
+    saveSecret(this.contractId, sessionId, playerAddress, secret.toString('hex'));
+
     const client = this.createSigningClient(playerAddress, signer);
     const tx = await client.roll({
       session_id: sessionId,
       player: playerAddress,
+      commitment,
     }, DEFAULT_METHOD_OPTIONS);
     // NOTE: Contract methods automatically simulate - footprint is already prepared
 
@@ -613,7 +622,57 @@ export class DiceDuelService {
   }
 
   /**
-   * Reveal the winner after both players have rolled
+   * Whether this device holds the unrevealed secret of the player's roll
+   */
+  hasSavedSecret(sessionId: number, playerAddress: string): boolean {
+    return loadSecret<string>(this.contractId, sessionId, playerAddress) !== null;
+  }
+
+  /**
+   * Reveal the secret committed from this device (after both players have rolled)
+   */
+  async revealSecret(
+    sessionId: number,
+    playerAddress: string,
+    signer: Pick<contract.ClientOptions, 'signTransaction' | 'signAuthEntry'>,
+    authTtlMinutes?: number
+  ) {
+    const secret = loadSecret<string>(this.contractId, sessionId, playerAddress);
+    if (!secret) {
+      throw new Error('Your roll for this session was not committed from this device, so it cannot be revealed here');
+    }
+
+    const client = this.createSigningClient(playerAddress, signer);
+    const tx = await client.reveal_secret({
+      session_id: sessionId,
+      player: playerAddress,
+      secret: Buffer.from(secret, 'hex'),
+    }, DEFAULT_METHOD_OPTIONS);
+
+    const validUntilLedgerSeq = authTtlMinutes
+      ? await calculateValidUntilLedger(RPC_URL, authTtlMinutes)
+      : await calculateValidUntilLedger(RPC_URL, DEFAULT_AUTH_TTL_MINUTES);
+
+    try {
+      const sentTx = await signAndSendViaLaunchtube(tx, DEFAULT_METHOD_OPTIONS.timeoutInSeconds, validUntilLedgerSeq);
+
+      if (sentTx.getTransactionResponse?.status === 'FAILED') {
+        const errorMessage = this.extractErrorFromDiagnostics(sentTx.getTransactionResponse);
+        throw new Error(`Transaction failed: ${errorMessage}`);
+      }
+
+      clearSecret(this.contractId, sessionId, playerAddress);
+      return sentTx.result;
+    } catch (err) {
+      if (err instanceof Error && err.message.includes('Transaction failed!')) {
+        throw new Error('Transaction failed - check if both players have rolled and you haven\'t already revealed');
+      }
+      throw err;
+    }
+  }
+
+  /**
+   * Reveal the winner after both players have revealed their secrets
    */
   async revealWinner(
     sessionId: number,
@@ -649,7 +708,7 @@ export class DiceDuelService {
 
       if (err instanceof Error && err.message.includes('Transaction failed!')) {
         // This is the SDK error when trying to access .result on a failed transaction
-        throw new Error('Transaction failed - check if both players have rolled and the game is still active');
+        throw new Error('Transaction failed - check if both players have revealed and the game is still active');
       }
 
       throw err;
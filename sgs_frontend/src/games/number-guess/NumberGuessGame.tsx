@@ -2,27 +2,15 @@ import { useState, useEffect, useRef } from 'react';
 import { NumberGuessService } from './numberGuessService';
 import { requestCache, createCacheKey } from '@/utils/requestCache';
 import { useWallet } from '@/hooks/useWallet';
-import { NUMBER_GUESS_CONTRACT } from '@/utils/constants';
+import { MOCK_GAME_HUB_CONTRACT, NUMBER_GUESS_CONTRACT } from '@/utils/constants';
 import { getFundedSimulationSourceAddress } from '@/utils/simulationUtils';
 import { devWalletService, DevWalletService } from '@/services/devWalletService';
+import { GameHubService } from '@/services/gameHubService';
 import type { Game } from './bindings';
 
-const createRandomSessionId = (): number => {
-  if (typeof crypto !== 'undefined' && crypto.getRandomValues) {
-    let value = 0;
-    const buffer = new Uint32Array(1);
-    while (value === 0) {
-      crypto.getRandomValues(buffer);
-      value = buffer[0];
-    }
-    return value;
-  }
-
-  return (Math.floor(Math.random() * 0xffffffff) >>> 0) || 1;
-};
-
 // Create service instance with the contract ID
 const numberGuessService = new NumberGuessService(NUMBER_GUESS_CONTRACT);
+const gameHubService = new GameHubService(MOCK_GAME_HUB_CONTRACT);
 
 interface NumberGuessGameProps {
   userAddress: string;
@@ -46,8 +34,8 @@ export function NumberGuessGame({
 }: NumberGuessGameProps) {
   const DEFAULT_POINTS = '0.1';
   const { getContractSigner, walletType } = useWallet();
-  // Use a random session ID that fits in u32 (avoid 0 because UI validation treats <=0 as invalid)
-  const [sessionId, setSessionId] = useState<number>(() => createRandomSessionId());
+  // Session IDs are issued by the Game Hub when Player 1 prepares the game (0 until then)
+  const [sessionId, setSessionId] = useState<number>(0);
   const [player1Address, setPlayer1Address] = useState(userAddress);
   const [player1Points, setPlayer1Points] = useState(DEFAULT_POINTS);
   const [guess, setGuess] = useState<number | null>(null);
@@ -121,10 +109,10 @@ export function NumberGuessGame({
       setGameState(game);
 
       // Determine game phase based on state
-      if (game && game.winner !== null && game.winner !== undefined) {
+      if (game && game.status.tag === 'Ended') {
         setGamePhase('complete');
-      } else if (game && game.player1_guess !== null && game.player1_guess !== undefined &&
-                 game.player2_guess !== null && game.player2_guess !== undefined) {
+      } else if (game && game.player1_commitment !== null && game.player1_commitment !== undefined &&
+                 game.player2_commitment !== null && game.player2_commitment !== undefined) {
         setGamePhase('reveal');
       } else {
         setGamePhase('guess');
@@ -365,10 +353,18 @@ export function NumberGuessGame({
         const placeholderPlayer2Address = await getFundedSimulationSourceAddress([player1Address, userAddress]);
         const placeholderP2Points = p1Points; // Same as P1 for simulation
 
+        // The hub only accepts session IDs it reserved for this game and player
+        const reservedSessionId = await gameHubService.reserveSession(
+          NUMBER_GUESS_CONTRACT,
+          player1Address,
+          signer
+        );
+        setSessionId(reservedSessionId);
+
         console.log('Preparing transaction for Player 1 to sign...');
         console.log('Using placeholder Player 2 values for simulation only');
         const authEntryXDR = await numberGuessService.prepareStartGame(
-          sessionId,
+          reservedSessionId,
           player1Address,
           placeholderPlayer2Address,
           p1Points,
@@ -384,7 +380,7 @@ export function NumberGuessGame({
         const pollInterval = setInterval(async () => {
           try {
             // Try to load the game
-            const game = await numberGuessService.getGame(sessionId);
+            const game = await numberGuessService.getGame(reservedSessionId);
             if (game) {
               console.log('Game found! Player 2 has finalized the transaction. Transitioning to guess phase...');
               clearInterval(pollInterval);
@@ -485,7 +481,11 @@ export function NumberGuessGame({
           throw new Error('Quickstart requires two different dev wallets.');
         }
 
-        const quickstartSessionId = createRandomSessionId();
+        const quickstartSessionId = await gameHubService.reserveSession(
+          NUMBER_GUESS_CONTRACT,
+          player1AddressQuickstart,
+          player1Signer
+        );
         setSessionId(quickstartSessionId);
         setPlayer1Address(player1AddressQuickstart);
         setCreateMode('create');
@@ -689,16 +689,16 @@ export function NumberGuessGame({
         setLoadSessionId('');
 
         // Determine game phase based on game state
-        if (game.winner !== null && game.winner !== undefined) {
-          // Game is complete - show reveal phase with winner
-          setGamePhase('reveal');
+        if (game.status.tag === 'Ended') {
+          // Game is complete - show the result
+          setGamePhase('complete');
           const isWinner = game.winner === userAddress;
           setSuccess(isWinner ? '🎉 You won this game!' : 'Game complete. Winner revealed.');
-        } else if (game.player1_guess !== null && game.player1_guess !== undefined &&
-            game.player2_guess !== null && game.player2_guess !== undefined) {
-          // Both players guessed, waiting for reveal
+        } else if (game.player1_commitment !== null && game.player1_commitment !== undefined &&
+            game.player2_commitment !== null && game.player2_commitment !== undefined) {
+          // Both guesses sealed, waiting for reveals
           setGamePhase('reveal');
-          setSuccess('Game loaded! Both players have guessed. You can reveal the winner.');
+          setSuccess('Game loaded! Both players have guessed. Reveal your guess.');
         } else {
           // Still in guessing phase
           setGamePhase('guess');
@@ -777,9 +777,9 @@ export function NumberGuessGame({
         setSuccess(null);
 
         const signer = getContractSigner();
-        await numberGuessService.makeGuess(sessionId, userAddress, guess, signer);
+        await numberGuessService.commitGuess(sessionId, userAddress, guess, signer);
 
-        setSuccess(`Guess submitted: ${guess}`);
+        setSuccess(`Guess sealed: ${guess}`);
         await loadGameState();
       } catch (err) {
         console.error('Make guess error:', err);
@@ -790,10 +790,31 @@ export function NumberGuessGame({
     });
   };
 
+  const handleRevealGuess = async () => {
+    await runAction(async () => {
+      try {
+        setLoading(true);
+        setError(null);
+        setSuccess(null);
+
+        const signer = getContractSigner();
+        await numberGuessService.revealGuess(sessionId, userAddress, signer);
+
+        setSuccess('Guess revealed');
+        await loadGameState();
+      } catch (err) {
+        console.error('Reveal guess error:', err);
+        setError(err instanceof Error ? err.message : 'Failed to reveal guess');
+      } finally {
+        setLoading(false);
+      }
+    });
+  };
+
   const waitForWinner = async () => {
     let updatedGame = await numberGuessService.getGame(sessionId);
     let attempts = 0;
-    while (attempts < 5 && (!updatedGame || updatedGame.winner === null || updatedGame.winner === undefined)) {
+    while (attempts < 5 && (!updatedGame || updatedGame.status.tag !== 'Ended')) {
       await new Promise((resolve) => setTimeout(resolve, 1000));
       updatedGame = await numberGuessService.getGame(sessionId);
       attempts += 1;
@@ -835,8 +856,13 @@ export function NumberGuessGame({
 
   const isPlayer1 = gameState && gameState.player1 === userAddress;
   const isPlayer2 = gameState && gameState.player2 === userAddress;
-  const hasGuessed = isPlayer1 ? gameState?.player1_guess !== null && gameState?.player1_guess !== undefined :
-                     isPlayer2 ? gameState?.player2_guess !== null && gameState?.player2_guess !== undefined : false;
+  const hasGuessed = isPlayer1 ? gameState?.player1_commitment !== null && gameState?.player1_commitment !== undefined :
+                     isPlayer2 ? gameState?.player2_commitment !== null && gameState?.player2_commitment !== undefined : false;
+  const hasRevealed = isPlayer1 ? gameState?.player1_guess !== null && gameState?.player1_guess !== undefined :
+                      isPlayer2 ? gameState?.player2_guess !== null && gameState?.player2_guess !== undefined : false;
+  const bothRevealed = gameState?.player1_guess !== null && gameState?.player1_guess !== undefined &&
+                       gameState?.player2_guess !== null && gameState?.player2_guess !== undefined;
+  const savedGuess = (isPlayer1 || isPlayer2) ? numberGuessService.getSavedGuess(sessionId, userAddress) : null;
 
   const winningNumber = gameState?.winning_number;
   const player1Guess = gameState?.player1_guess;
@@ -860,9 +886,11 @@ export function NumberGuessGame({
           <p className="text-sm text-gray-700 font-semibold mt-1">
             Guess a number 1-10. Closest guess wins!
           </p>
-          <p className="text-xs text-gray-500 font-mono mt-1">
-            Session ID: {sessionId}
-          </p>
+          {sessionId > 0 && (
+            <p className="text-xs text-gray-500 font-mono mt-1">
+              Session ID: {sessionId}
+            </p>
+          )}
         </div>
         <button
           onClick={() => {
@@ -1010,7 +1038,7 @@ export function NumberGuessGame({
 
           <div className="pt-4 border-t-2 border-gray-100 space-y-4">
             <p className="text-xs font-semibold text-gray-600">
-              Session ID: {sessionId}
+              Session ID: {sessionId || 'issued by the Game Hub when you sign'}
             </p>
 
             {!exportedAuthEntryXDR ? (
@@ -1230,7 +1258,7 @@ export function NumberGuessGame({
                 Points: {(Number(gameState.player1_points) / 10000000).toFixed(2)}
               </div>
               <div className="mt-3">
-                {gameState.player1_guess !== null && gameState.player1_guess !== undefined ? (
+                {gameState.player1_commitment !== null && gameState.player1_commitment !== undefined ? (
                   <div className="inline-block px-3 py-1 rounded-full bg-gradient-to-r from-green-400 to-emerald-500 text-white text-xs font-bold shadow-md">
                     ✓ Guessed
                   </div>
@@ -1251,7 +1279,7 @@ export function NumberGuessGame({
                 Points: {(Number(gameState.player2_points) / 10000000).toFixed(2)}
               </div>
               <div className="mt-3">
-                {gameState.player2_guess !== null && gameState.player2_guess !== undefined ? (
+                {gameState.player2_commitment !== null && gameState.player2_commitment !== undefined ? (
                   <div className="inline-block px-3 py-1 rounded-full bg-gradient-to-r from-green-400 to-emerald-500 text-white text-xs font-bold shadow-md">
                     ✓ Guessed
                   </div>
@@ -1297,7 +1325,7 @@ export function NumberGuessGame({
           {hasGuessed && (
             <div className="p-4 bg-gradient-to-r from-blue-50 to-cyan-50 border-2 border-blue-200 rounded-xl">
               <p className="text-sm font-semibold text-blue-700">
-                ✓ You've made your guess. Waiting for other player...
+                ✓ Your guess is sealed. Waiting for other player...
               </p>
             </div>
           )}
@@ -1305,12 +1333,47 @@ export function NumberGuessGame({
       )}
 
       {/* REVEAL PHASE */}
-      {gamePhase === 'reveal' && gameState && (
+      {gamePhase === 'reveal' && gameState && !bothRevealed && (
+        <div className="space-y-6">
+          <div className="p-8 bg-gradient-to-br from-yellow-50 via-orange-50 to-amber-50 border-2 border-yellow-300 rounded-2xl text-center shadow-xl">
+            <div className="text-6xl mb-4">🔒</div>
+            <h3 className="text-2xl font-black text-gray-900 mb-3">
+              Both Guesses Are Sealed!
+            </h3>
+            {(isPlayer1 || isPlayer2) && !hasRevealed && savedGuess !== null && (
+              <>
+                <p className="text-sm font-semibold text-gray-700 mb-6">
+                  Reveal your guess of {savedGuess} so the winning number can be drawn
+                </p>
+                <button
+                  onClick={handleRevealGuess}
+                  disabled={isBusy}
+                  className="px-10 py-4 rounded-xl font-bold text-white text-lg bg-gradient-to-r from-yellow-500 via-orange-500 to-amber-500 hover:from-yellow-600 hover:via-orange-600 hover:to-amber-600 disabled:from-gray-200 disabled:to-gray-300 disabled:text-gray-500 transition-all shadow-xl hover:shadow-2xl transform hover:scale-105 disabled:transform-none"
+                >
+                  {loading ? 'Revealing...' : 'Reveal My Guess'}
+                </button>
+              </>
+            )}
+            {(isPlayer1 || isPlayer2) && !hasRevealed && savedGuess === null && (
+              <p className="text-sm font-semibold text-red-700">
+                Your guess was sealed on another device. Reveal it from there.
+              </p>
+            )}
+            {hasRevealed && (
+              <p className="text-sm font-semibold text-gray-700">
+                ✓ Your guess is revealed. Waiting for other player...
+              </p>
+            )}
+          </div>
+        </div>
+      )}
+
+      {gamePhase === 'reveal' && gameState && bothRevealed && (
         <div className="space-y-6">
           <div className="p-8 bg-gradient-to-br from-yellow-50 via-orange-50 to-amber-50 border-2 border-yellow-300 rounded-2xl text-center shadow-xl">
             <div className="text-6xl mb-4">🎊</div>
             <h3 className="text-2xl font-black text-gray-900 mb-3">
-              Both Players Have Guessed!
+              Both Players Have Revealed!
             </h3>
             <p className="text-sm font-semibold text-gray-700 mb-6">
               Click below to reveal the winner
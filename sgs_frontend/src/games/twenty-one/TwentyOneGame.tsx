@@ -2,22 +2,13 @@ import { useState, useEffect, useRef } from 'react';
 import { twentyOneService } from './twentyOneService';
 import { requestCache, createCacheKey } from '@/utils/requestCache';
 import { useWallet } from '@/hooks/useWallet';
+import { MOCK_GAME_HUB_CONTRACT, TWENTY_ONE_CONTRACT } from '@/utils/constants';
 import { getFundedSimulationSourceAddress } from '@/utils/simulationUtils';
 import { devWalletService, DevWalletService } from '@/services/devWalletService';
+import { GameHubService } from '@/services/gameHubService';
+import type { Game } from './bindings';
 
-const createRandomSessionId = (): number => {
-  if (typeof crypto !== 'undefined' && crypto.getRandomValues) {
-    let value = 0;
-    const buffer = new Uint32Array(1);
-    while (value === 0) {
-      crypto.getRandomValues(buffer);
-      value = buffer[0];
-    }
-    return value;
-  }
-
-  return (Math.floor(Math.random() * 0xffffffff) >>> 0) || 1;
-};
+const gameHubService = new GameHubService(MOCK_GAME_HUB_CONTRACT);
 
 interface TwentyOneGameProps {
   userAddress: string;
@@ -30,18 +21,17 @@ interface TwentyOneGameProps {
   onGameComplete: () => void;
 }
 
-interface GameState {
-  player1: string;
-  player2: string;
-  player1_points: bigint;
-  player2_points: bigint;
-  player1_hand: Uint8Array;
-  player2_hand: Uint8Array;
-  player1_stuck: boolean;
-  player2_stuck: boolean;
-  winner: string | null;
-  round: number;
-}
+type GamePhase = 'create' | 'seed' | 'play' | 'reveal' | 'complete';
+
+// Point value of a card (1-13): Ace=1, 2-10=face value, Jack/Queen/King=10
+const cardValue = (card: number) => Math.min(card, 10);
+
+const phaseFor = (game: Game): GamePhase => {
+  if (game.status.tag === 'Ended') return 'complete';
+  if (!game.player1_seed || !game.player2_seed) return 'seed';
+  if (game.player1_stuck && game.player2_stuck) return 'reveal';
+  return 'play';
+};
 
 // Card component with suit and value
 const PlayingCard = ({ value, isHidden = false }: { value: number; isHidden?: boolean }) => {
@@ -108,15 +98,16 @@ export function TwentyOneGame({
   const { getContractSigner, walletType } = useWallet();
   const normalizedUserAddress = userAddress.trim().toUpperCase();
   const normalizeAddress = (address: string) => address.trim().toUpperCase();
-  const [sessionId, setSessionId] = useState<number>(() => createRandomSessionId());
+  // Session IDs are issued by the Game Hub when Player 1 prepares the game (0 until then)
+  const [sessionId, setSessionId] = useState<number>(0);
   const [player1Address, setPlayer1Address] = useState(userAddress);
   const [player1Points, setPlayer1Points] = useState(DEFAULT_POINTS);
-  const [gameState, setGameState] = useState<GameState | null>(null);
+  const [gameState, setGameState] = useState<Game | null>(null);
   const [loading, setLoading] = useState(false);
   const [quickstartLoading, setQuickstartLoading] = useState(false);
   const [error, setError] = useState<string | null>(null);
   const [success, setSuccess] = useState<string | null>(null);
-  const [gamePhase, setGamePhase] = useState<'create' | 'play' | 'reveal' | 'complete'>('create');
+  const [gamePhase, setGamePhase] = useState<GamePhase>('create');
   const [createMode, setCreateMode] = useState<'create' | 'import' | 'load'>('create');
   const [exportedAuthEntryXDR, setExportedAuthEntryXDR] = useState<string | null>(null);
   const [importAuthEntryXDR, setImportAuthEntryXDR] = useState('');
@@ -132,6 +123,7 @@ export function TwentyOneGame({
   const [xdrParseSuccess, setXdrParseSuccess] = useState(false);
   const [player1HandValue, setPlayer1HandValue] = useState<number | null>(null);
   const [player2HandValue, setPlayer2HandValue] = useState<number | null>(null);
+  const [myHand, setMyHand] = useState<number[] | null>(null);
 
   useEffect(() => {
     setPlayer1Address(userAddress);
@@ -184,7 +176,7 @@ export function TwentyOneGame({
       if (game) {
         setGameState(game);
 
-        // Load hand values
+        // Load revealed hand values
         try {
           const p1Value = await twentyOneService.getHandValue(sessionId, game.player1);
           const p2Value = await twentyOneService.getHandValue(sessionId, game.player2);
@@ -194,14 +186,16 @@ export function TwentyOneGame({
           console.log('Error loading hand values:', err);
         }
 
-        // Determine game phase
-        if (game.winner) {
-          setGamePhase('complete');
-        } else if (game.player1_stuck && game.player2_stuck) {
-          setGamePhase('reveal');
+        // Our own cards stay hidden on chain until revealed; derive them locally with our hand secret
+        const isP1 = normalizeAddress(game.player1) === normalizedUserAddress;
+        const ownRevealed = isP1 ? game.player1_hand : game.player2_hand;
+        if (ownRevealed.length > 0) {
+          setMyHand(Array.from(ownRevealed));
         } else {
-          setGamePhase('play');
+          setMyHand(twentyOneService.deriveHand(sessionId, game, isP1 ? game.player1 : game.player2));
         }
+
+        setGamePhase(phaseFor(game));
       }
     } catch (err) {
       setGameState(null);
@@ -217,11 +211,11 @@ export function TwentyOneGame({
   }, [sessionId, gamePhase]);
 
   useEffect(() => {
-    if (gamePhase === 'complete' && gameState?.winner) {
+    if (gamePhase === 'complete' && gameState?.status.tag === 'Ended') {
       console.log('Game completed! Refreshing standings...');
       onStandingsRefresh();
     }
-  }, [gamePhase, gameState?.winner]);
+  }, [gamePhase, gameState?.status.tag]);
 
   // Handle initial values (similar to NumberGuess)
   useEffect(() => {
@@ -234,7 +228,7 @@ export function TwentyOneGame({
           .then((game) => {
             if (game) {
               setGameState(game);
-              setGamePhase('play');
+              setGamePhase(phaseFor(game));
               setSessionId(sessionId);
             } else {
               setCreateMode('import');
@@ -269,7 +263,7 @@ export function TwentyOneGame({
           .then((game) => {
             if (game) {
               setGameState(game);
-              setGamePhase('play');
+              setGamePhase(phaseFor(game));
               setSessionId(parsed.sessionId);
             } else {
               setCreateMode('import');
@@ -354,8 +348,16 @@ export function TwentyOneGame({
         const placeholderPlayer2Address = await getFundedSimulationSourceAddress([player1Address, userAddress]);
         const placeholderP2Points = p1Points;
 
+        // The hub only accepts session IDs it reserved for this game and player
+        const reservedSessionId = await gameHubService.reserveSession(
+          TWENTY_ONE_CONTRACT,
+          player1Address,
+          signer
+        );
+        setSessionId(reservedSessionId);
+
         const authEntryXDR = await twentyOneService.prepareStartGame(
-          sessionId,
+          reservedSessionId,
           player1Address,
           placeholderPlayer2Address,
           p1Points,
@@ -368,13 +370,13 @@ export function TwentyOneGame({
 
         const pollInterval = setInterval(async () => {
           try {
-            const game = await twentyOneService.getGame(sessionId);
+            const game = await twentyOneService.getGame(reservedSessionId);
             if (game) {
               clearInterval(pollInterval);
               setGameState(game);
               setExportedAuthEntryXDR(null);
               setSuccess('Game created! Player 2 has signed and submitted.');
-              setGamePhase('play');
+              setGamePhase('seed');
               onStandingsRefresh();
               setTimeout(() => setSuccess(null), 2000);
             }
@@ -440,7 +442,11 @@ export function TwentyOneGame({
           throw new Error('Quickstart requires two different dev wallets.');
         }
 
-        const quickstartSessionId = createRandomSessionId();
+        const quickstartSessionId = await gameHubService.reserveSession(
+          TWENTY_ONE_CONTRACT,
+          player1AddressQuickstart,
+          player1Signer
+        );
         setSessionId(quickstartSessionId);
         setPlayer1Address(player1AddressQuickstart);
         setCreateMode('create');
@@ -485,7 +491,7 @@ export function TwentyOneGame({
         } catch (err) {
           console.log('Quickstart game not available yet:', err);
         }
-        setGamePhase('play');
+        setGamePhase('seed');
         onStandingsRefresh();
         setSuccess('Quickstart complete! Both players signed and the game is ready.');
         setTimeout(() => setSuccess(null), 2000);
@@ -544,7 +550,7 @@ export function TwentyOneGame({
 
         setSessionId(gameParams.sessionId);
         setSuccess('Game created successfully! Both players signed.');
-        setGamePhase('play');
+        setGamePhase('seed');
 
         setImportAuthEntryXDR('');
         setImportSessionId('');
@@ -593,15 +599,16 @@ export function TwentyOneGame({
         setGameState(game);
         setLoadSessionId('');
 
-        if (game.winner) {
-          setGamePhase('complete');
-          const isWinner = normalizeAddress(game.winner) === normalizedUserAddress;
+        const phase = phaseFor(game);
+        setGamePhase(phase);
+        if (phase === 'complete') {
+          const isWinner = !!game.winner && normalizeAddress(game.winner) === normalizedUserAddress;
           setSuccess(isWinner ? '🎉 You won this game!' : 'Game complete. Winner revealed.');
-        } else if (game.player1_stuck && game.player2_stuck) {
-          setGamePhase('reveal');
-          setSuccess('Game loaded! Both players have stuck. You can reveal the winner.');
+        } else if (phase === 'seed') {
+          setSuccess('Game loaded! Both deck seeds must be revealed before the cards are dealt.');
+        } else if (phase === 'reveal') {
+          setSuccess('Game loaded! Both players have stuck. Reveal your hand.');
         } else {
-          setGamePhase('play');
           setSuccess('Game loaded! Continue playing.');
         }
 
@@ -657,14 +664,6 @@ export function TwentyOneGame({
 
         setSuccess('Card drawn!');
         await loadGameState();
-
-        // Check if player busted
-        if (gameState && gameState.winner) {
-          setTimeout(() => {
-            setSuccess(null);
-            setGamePhase('complete');
-          }, 1000);
-        }
       } catch (err) {
         console.error('Hit error:', err);
         setError(err instanceof Error ? err.message : 'Failed to draw card');
@@ -695,10 +694,52 @@ export function TwentyOneGame({
     });
   };
 
+  const handleRevealSeed = async () => {
+    await runAction(async () => {
+      try {
+        setLoading(true);
+        setError(null);
+        setSuccess(null);
+
+        const signer = getContractSigner();
+        await twentyOneService.revealSeed(sessionId, userAddress, signer);
+
+        setSuccess('Seed revealed! Cards are dealt once both seeds are in.');
+        await loadGameState();
+      } catch (err) {
+        console.error('Reveal seed error:', err);
+        setError(err instanceof Error ? err.message : 'Failed to reveal seed');
+      } finally {
+        setLoading(false);
+      }
+    });
+  };
+
+  const handleRevealHand = async () => {
+    await runAction(async () => {
+      try {
+        setLoading(true);
+        setError(null);
+        setSuccess(null);
+
+        const signer = getContractSigner();
+        await twentyOneService.revealHand(sessionId, userAddress, signer);
+
+        setSuccess('Hand revealed!');
+        await loadGameState();
+      } catch (err) {
+        console.error('Reveal hand error:', err);
+        setError(err instanceof Error ? err.message : 'Failed to reveal hand');
+      } finally {
+        setLoading(false);
+      }
+    });
+  };
+
   const waitForWinner = async () => {
     let updatedGame = await twentyOneService.getGame(sessionId);
     let attempts = 0;
-    while (attempts < 5 && (!updatedGame || !updatedGame.winner)) {
+    while (attempts < 5 && (!updatedGame || updatedGame.status.tag !== 'Ended')) {
       await new Promise((resolve) => setTimeout(resolve, 1000));
       updatedGame = await twentyOneService.getGame(sessionId);
       attempts += 1;
@@ -719,9 +760,9 @@ export function TwentyOneGame({
         await waitForWinner();
         await loadGameState();
 
-        const winner = (winnerResult as any).unwrap ? (winnerResult as any).unwrap() : winnerResult;
-        const isWinner = normalizeAddress(winner) === normalizedUserAddress;
-        setSuccess(isWinner ? '🎉 You won!' : 'Game complete! Winner revealed.');
+        const winner = winnerResult.unwrap();
+        const isWinner = !!winner && normalizeAddress(winner) === normalizedUserAddress;
+        setSuccess(isWinner ? '🎉 You won!' : winner ? 'Game complete! Winner revealed.' : 'Game complete! It\'s a draw.');
 
         onStandingsRefresh();
       } catch (err) {
@@ -736,6 +777,12 @@ export function TwentyOneGame({
   const isPlayer1 = !!gameState && normalizeAddress(gameState.player1) === normalizedUserAddress;
   const isPlayer2 = !!gameState && normalizeAddress(gameState.player2) === normalizedUserAddress;
   const canAct = (isPlayer1 && !gameState?.player1_stuck) || (isPlayer2 && !gameState?.player2_stuck);
+  const hasRevealedSeed = !!gameState && !!(isPlayer1 ? gameState.player1_seed : gameState.player2_seed);
+  const hasRevealedHand = !!gameState && (isPlayer1 ? gameState.player1_hand : gameState.player2_hand).length > 0;
+  const bothHandsRevealed = !!gameState && gameState.player1_hand.length > 0 && gameState.player2_hand.length > 0;
+  const hasSavedSecrets = twentyOneService.hasSavedSecrets(sessionId, userAddress);
+  const opponentDraws = gameState ? (isPlayer1 ? gameState.player2_draws : gameState.player1_draws).length : 0;
+  const myHandValue = myHand ? myHand.reduce((total, card) => total + cardValue(card), 0) : null;
 
   return (
     <div className="min-h-screen bg-gradient-to-br from-green-800 via-green-700 to-green-900 p-8">
@@ -765,13 +812,15 @@ export function TwentyOneGame({
             <p className="text-xl text-green-200 font-semibold">
               Get as close to 21 as you can without going over!
             </p>
-            <p className="text-sm text-green-300 font-mono mt-1">
-              Session ID: {sessionId}
-            </p>
+            {sessionId > 0 && (
+              <p className="text-sm text-green-300 font-mono mt-1">
+                Session ID: {sessionId}
+              </p>
+            )}
           </div>
           <button
             onClick={() => {
-              if (gameState?.winner) {
+              if (gameState?.status.tag === 'Ended') {
                 onGameComplete();
               }
               onBack();
@@ -1010,6 +1059,40 @@ export function TwentyOneGame({
           </div>
         )}
 
+        {/* SEED PHASE */}
+        {gamePhase === 'seed' && gameState && (
+          <div className="bg-white/95 backdrop-blur-xl rounded-2xl p-8 shadow-2xl text-center">
+            <div className="text-7xl mb-4">🎴</div>
+            <h3 className="text-3xl font-black text-gray-900 mb-3">
+              Shuffle the Deck
+            </h3>
+            {!hasRevealedSeed && hasSavedSecrets && (
+              <>
+                <p className="text-lg font-semibold text-gray-700 mb-6">
+                  Reveal your deck seed. The cards are dealt once both players have revealed.
+                </p>
+                <button
+                  onClick={handleRevealSeed}
+                  disabled={isBusy}
+                  className="px-12 py-5 rounded-xl font-black text-white text-2xl bg-gradient-to-r from-yellow-500 to-orange-500 hover:from-yellow-600 hover:to-orange-600 disabled:from-gray-300 disabled:to-gray-400 transition-all shadow-2xl hover:shadow-3xl transform hover:scale-105 disabled:transform-none"
+                >
+                  {loading ? 'Revealing...' : '🔓 Reveal Seed'}
+                </button>
+              </>
+            )}
+            {!hasRevealedSeed && !hasSavedSecrets && (
+              <p className="text-lg font-semibold text-gray-700">
+                Your seed for this game was committed from another device. Reveal it there.
+              </p>
+            )}
+            {hasRevealedSeed && (
+              <p className="text-lg font-semibold text-gray-700">
+                ✓ Seed revealed. Waiting for your opponent to reveal theirs...
+              </p>
+            )}
+          </div>
+        )}
+
         {/* PLAY PHASE - Table View */}
         {gamePhase === 'play' && gameState && (
           <div className="space-y-8">
@@ -1031,8 +1114,8 @@ export function TwentyOneGame({
 
               {/* Opponent's Hand */}
               <div className="flex justify-center gap-2 flex-wrap mb-4">
-                {Array.from(isPlayer1 ? gameState.player2_hand : gameState.player1_hand).map((card, idx) => (
-                  <PlayingCard key={idx} value={card} />
+                {Array.from({ length: opponentDraws }).map((_, idx) => (
+                  <PlayingCard key={idx} value={0} isHidden />
                 ))}
               </div>
 
@@ -1040,7 +1123,7 @@ export function TwentyOneGame({
               <div className="text-center">
                 <div className="inline-block px-6 py-3 rounded-full bg-white/20 backdrop-blur-sm">
                   <span className="text-yellow-400 font-black text-3xl">
-                    {isPlayer1 ? (player2HandValue ?? '?') : (player1HandValue ?? '?')}
+                    ?
                   </span>
                 </div>
                 {(isPlayer1 ? gameState.player2_stuck : gameState.player1_stuck) && (
@@ -1068,7 +1151,7 @@ export function TwentyOneGame({
 
               {/* Your Hand */}
               <div className="flex justify-center gap-2 flex-wrap mb-4">
-                {Array.from(isPlayer1 ? gameState.player1_hand : gameState.player2_hand).map((card, idx) => (
+                {(myHand ?? []).map((card, idx) => (
                   <PlayingCard key={idx} value={card} />
                 ))}
               </div>
@@ -1077,7 +1160,7 @@ export function TwentyOneGame({
               <div className="text-center mb-6">
                 <div className="inline-block px-6 py-3 rounded-full bg-white/20 backdrop-blur-sm">
                   <span className="text-yellow-400 font-black text-4xl">
-                    {isPlayer1 ? (player1HandValue ?? '?') : (player2HandValue ?? '?')}
+                    {myHandValue ?? '?'}
                   </span>
                 </div>
                 {(isPlayer1 ? gameState.player1_stuck : gameState.player2_stuck) && (
@@ -1127,16 +1210,39 @@ export function TwentyOneGame({
             <h3 className="text-3xl font-black text-gray-900 mb-3">
               Both Players Have Stuck!
             </h3>
-            <p className="text-lg font-semibold text-gray-700 mb-6">
-              Click below to reveal the winner
-            </p>
-            <button
-              onClick={handleRevealWinner}
-              disabled={isBusy}
-              className="px-12 py-5 rounded-xl font-black text-white text-2xl bg-gradient-to-r from-yellow-500 to-orange-500 hover:from-yellow-600 hover:to-orange-600 disabled:from-gray-300 disabled:to-gray-400 transition-all shadow-2xl hover:shadow-3xl transform hover:scale-105 disabled:transform-none"
-            >
-              {loading ? 'Revealing...' : '🏆 Reveal Winner'}
-            </button>
+            {!hasRevealedHand && (
+              <>
+                <p className="text-lg font-semibold text-gray-700 mb-6">
+                  Reveal your hand{myHandValue !== null ? ` (${myHandValue})` : ''} to settle the game
+                </p>
+                <button
+                  onClick={handleRevealHand}
+                  disabled={isBusy || !hasSavedSecrets}
+                  className="px-12 py-5 rounded-xl font-black text-white text-2xl bg-gradient-to-r from-blue-600 to-blue-700 hover:from-blue-700 hover:to-blue-800 disabled:from-gray-300 disabled:to-gray-400 transition-all shadow-2xl hover:shadow-3xl transform hover:scale-105 disabled:transform-none"
+                >
+                  {loading ? 'Revealing...' : '🃏 Reveal Hand'}
+                </button>
+              </>
+            )}
+            {hasRevealedHand && !bothHandsRevealed && (
+              <p className="text-lg font-semibold text-gray-700">
+                ✓ Hand revealed. Waiting for your opponent to reveal theirs...
+              </p>
+            )}
+            {bothHandsRevealed && (
+              <>
+                <p className="text-lg font-semibold text-gray-700 mb-6">
+                  Click below to reveal the winner
+                </p>
+                <button
+                  onClick={handleRevealWinner}
+                  disabled={isBusy}
+                  className="px-12 py-5 rounded-xl font-black text-white text-2xl bg-gradient-to-r from-yellow-500 to-orange-500 hover:from-yellow-600 hover:to-orange-600 disabled:from-gray-300 disabled:to-gray-400 transition-all shadow-2xl hover:shadow-3xl transform hover:scale-105 disabled:transform-none"
+                >
+                  {loading ? 'Revealing...' : '🏆 Reveal Winner'}
+                </button>
+              </>
+            )}
           </div>
         )}
 
@@ -1174,6 +1280,12 @@ export function TwentyOneGame({
               </div>
             </div>
 
+            {!gameState.winner && (
+              <div className="p-6 bg-gray-100 border-2 border-gray-300 rounded-xl shadow-lg mb-6">
+                <p className="text-2xl font-black text-gray-800">🤝 It's a draw!</p>
+              </div>
+            )}
+
             {gameState.winner && (
               <div className="p-6 bg-gradient-to-r from-green-100 to-emerald-100 border-2 border-green-300 rounded-xl shadow-lg mb-6">
                 <p className="text-sm font-bold uppercase tracking-wide text-gray-600 mb-2">Winner</p>
@@ -39,40 +39,142 @@ export const networks = {
 } as const
 
 
+export const Errors = {
+  1: {message:"GameNotFound"},
+  2: {message:"NotPlayer"},
+  3: {message:"AlreadyStuck"},
+  4: {message:"GameAlreadyEnded"},
+  6: {message:"BothPlayersNotStuck"},
+  9: {message:"SelfPlay"},
+  11: {message:"InvalidHandData"},
+  12: {message:"PlayerNotStalling"},
+  13: {message:"ContractPaused"},
+  14: {message:"SessionAlreadyExists"},
+  15: {message:"DeckNotReady"},
+  16: {message:"AlreadyRevealed"},
+  17: {message:"CommitmentMismatch"},
+  18: {message:"HandNotRevealed"},
+  19: {message:"HandMismatch"}
+}
+
+export type GameStatus = {tag: "Active", values: void} | {tag: "Ended", values: readonly [GameOutcome]};
+
 export interface Game {
   player1: string;
+  /**
+   * Entropy drawn for each card a player has been dealt, in draw order;
+   * the cards stay hidden until `reveal_hand`
+   */
+  player1_draws: Array<Buffer>;
   player1_hand: Buffer;
+  /**
+   * Commitments to each player's hand secret, set by `reveal_seed`
+   */
+  player1_hand_commitment: Option<Buffer>;
   player1_points: i128;
+  /**
+   * Revealed deck seeds, set by `reveal_seed`
+   */
+  player1_seed: Option<Buffer>;
+  /**
+   * Commitments to each player's deck seed, given at `start_game`
+   */
+  player1_seed_commitment: Buffer;
   player1_stuck: boolean;
   player2: string;
+  player2_draws: Array<Buffer>;
   player2_hand: Buffer;
+  player2_hand_commitment: Option<Buffer>;
   player2_points: i128;
+  player2_seed: Option<Buffer>;
+  player2_seed_commitment: Buffer;
   player2_stuck: boolean;
-  round: u32;
+  status: GameStatus;
   winner: Option<string>;
 }
 
-export const Errors = {
-  1: {message:"GameNotFound"},
-  2: {message:"NotPlayer"},
-  3: {message:"AlreadyStuck"},
-  4: {message:"GameAlreadyEnded"},
-  5: {message:"PlayerBusted"},
-  6: {message:"BothPlayersNotStuck"},
-  7: {message:"OpponentNotStuck"},
-  8: {message:"Draw"},
-  9: {message:"SelfPlay"},
-  10: {message:"RoundOverflow"},
-  11: {message:"InvalidHandData"}
+export type DataKey = {tag: "Game", values: readonly [u32]};
+
+export const CommonError = {
+  100: {message:"NoPendingAdmin"}
+}
+
+/**
+ * Session outcome reported to the Game Hub (mirrors the hub's `GameOutcome`)
+ */
+export type GameOutcome = {tag: "Player1Won", values: void} | {tag: "Player2Won", values: void} | {tag: "Draw", values: void} | {tag: "Cancelled", values: void} | {tag: "Forfeit", values: readonly [string]};
+
+/**
+ * How the hub settles a game's stakes
+ */
+export type StakeSemantics = {tag: "WinnerTakesAll", values: void} | {tag: "Placements", values: void};
+
+/**
+ * Static description of a game, for lobbies and hub tools
+ */
+export interface GameInfo {
+  max_players: u32;
+  min_players: u32;
+  name: string;
+  /**
+   * Bumped whenever a change to the rules could change an outcome
+   */
+  rules_version: u32;
+  stakes: StakeSemantics;
 }
 
-export type DataKey = {tag: "Game", values: readonly [u32]} | {tag: "GameHubAddress", values: void} | {tag: "Admin", values: void};
+/**
+ * Game-independent view of a session's status
+ */
+export type SessionStatus = {tag: "Active", values: void} | {tag: "Ended", values: readonly [GameOutcome]};
+
+/**
+ * Roles the admin can grant. The admin implicitly holds every role.
+ */
+export type Role = {tag: "Operator", values: void};
 
 export interface Client {
+  /**
+   * Construct and simulate a start_game transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Start a new game between two players with points.
+   * This creates a session in the Game Hub and locks points before starting the game.
+   * Each player commits to a deck seed and a hand commitment; the opening
+   * 2 cards each are dealt once both are revealed with `reveal_seed`.
+   * 
+   * **CRITICAL:** This method requires authorization from THIS contract (not players).
+   * The Game Hub will call `game_id.require_auth()` which checks this contract's address.
+   * 
+   * # Arguments
+   * * `session_id` - Session ID reserved on the Game Hub (`reserve_session`)
+   * * `player1` - Address of first player
+   * * `player2` - Address of second player
+   * * `player1_points` - Points amount committed by player 1
+   * * `player2_points` - Points amount committed by player 2
+   * * `player1_seed_commitment` - `keccak256(seed || hand_commitment || player1_address)`
+   * * `player2_seed_commitment` - `keccak256(seed || hand_commitment || player2_address)`
+   */
+  start_game: ({session_id, player1, player2, player1_points, player2_points, player1_seed_commitment, player2_seed_commitment}: {session_id: u32, player1: string, player2: string, player1_points: i128, player2_points: i128, player1_seed_commitment: Buffer, player2_seed_commitment: Buffer}, options?: MethodOptions) => Promise<AssembledTransaction<Result<void>>>
+
+  /**
+   * Construct and simulate a reveal_seed transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Reveal the deck seed and hand commitment committed at `start_game`.
+   * Once both seeds are revealed, each player is dealt 2 hidden cards.
+   * 
+   * # Arguments
+   * * `session_id` - The session ID of the game
+   * * `player` - Address of the player revealing
+   * * `seed` - The seed behind the player's commitment
+   * * `hand_commitment` - `keccak256(hand_secret || player_address)`
+   */
+  reveal_seed: ({session_id, player, seed, hand_commitment}: {session_id: u32, player: string, seed: Buffer, hand_commitment: Buffer}, options?: MethodOptions) => Promise<AssembledTransaction<Result<void>>>
+
   /**
    * Construct and simulate a hit transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
    * Player draws another card ("hit").
-   * If the player's hand value exceeds 21, they bust and lose immediately.
+   * The card's entropy is drawn now, so it cannot be known before this
+   * call. The card stays hidden (the player derives it off-chain from
+   * `get_game` and their hand secret); a hand over 21 only loses once it
+   * is revealed.
    * 
    * # Arguments
    * * `session_id` - The session ID of the game
@@ -92,31 +194,34 @@ export interface Client {
   stick: ({session_id, player}: {session_id: u32, player: string}, options?: MethodOptions) => Promise<AssembledTransaction<Result<void>>>
 
   /**
-   * Construct and simulate a get_hub transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
-   * Get the current GameHub contract address
-   * 
-   * # Returns
-   * * `Address` - The GameHub contract address
-   */
-  get_hub: (options?: MethodOptions) => Promise<AssembledTransaction<string>>
-
-  /**
-   * Construct and simulate a set_hub transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
-   * Set a new GameHub contract address
+   * Construct and simulate a reveal_hand transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Reveal a player's hand once both players have stuck.
+   * The contract re-derives the cards from the hand secret and only
+   * accepts `hand` if it is exactly the cards the player drew.
    * 
    * # Arguments
-   * * `new_hub` - The new GameHub contract address
+   * * `session_id` - The session ID of the game
+   * * `player` - Address of the player revealing
+   * * `hand_secret` - The secret behind the player's hand commitment
+   * * `hand` - The player's cards in draw order, as derived off-chain
    */
-  set_hub: ({new_hub}: {new_hub: string}, options?: MethodOptions) => Promise<AssembledTransaction<null>>
+  reveal_hand: ({session_id, player, hand_secret, hand}: {session_id: u32, player: string, hand_secret: Buffer, hand: Buffer}, options?: MethodOptions) => Promise<AssembledTransaction<Result<void>>>
 
   /**
-   * Construct and simulate a upgrade transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
-   * Update the contract WASM hash (upgrade contract)
+   * Construct and simulate a reveal_winner transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Reveal the winner of the game and submit outcome to GameHub.
+   * Can only be called after both players have revealed their hands.
+   * This calculates hand values and determines the winner (closest to 21
+   * without going over). Equal hand values and two busts end the game in
+   * a draw.
    * 
    * # Arguments
-   * * `new_wasm_hash` - The hash of the new WASM binary
+   * * `session_id` - The session ID of the game
+   * 
+   * # Returns
+   * * `Option<Address>` - Address of the winning player, or `None` for a draw
    */
-  upgrade: ({new_wasm_hash}: {new_wasm_hash: Buffer}, options?: MethodOptions) => Promise<AssembledTransaction<null>>
+  reveal_winner: ({session_id}: {session_id: u32}, options?: MethodOptions) => Promise<AssembledTransaction<Result<Option<string>>>>
 
   /**
    * Construct and simulate a get_game transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
@@ -126,73 +231,149 @@ export interface Client {
    * * `session_id` - The session ID of the game
    * 
    * # Returns
-   * * `Game` - The game state (includes hands and winner after game ends)
+   * * `Game` - The game state (hands are empty until revealed with `reveal_hand`)
    */
   get_game: ({session_id}: {session_id: u32}, options?: MethodOptions) => Promise<AssembledTransaction<Result<Game>>>
 
   /**
-   * Construct and simulate a get_admin transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
-   * Get the current admin address
+   * Construct and simulate a get_hand_value transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Get the value of a player's revealed hand.
+   * 
+   * # Arguments
+   * * `session_id` - The session ID of the game
+   * * `player` - Address of the player
    * 
    * # Returns
-   * * `Address` - The admin address
+   * * `u32` - The total value of the player's hand
    */
-  get_admin: (options?: MethodOptions) => Promise<AssembledTransaction<string>>
+  get_hand_value: ({session_id, player}: {session_id: u32, player: string}, options?: MethodOptions) => Promise<AssembledTransaction<Result<u32>>>
 
   /**
-   * Construct and simulate a set_admin transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
-   * Set a new admin address
+   * Construct and simulate a on_session_forfeited transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Called by the Game Hub when a timeout is claimed against `player`.
+   * Only accepted if `player` is holding up the current phase while their
+   * opponent is done with it: revealing seeds, sticking, or revealing
+   * hands. The game then ends as a forfeit by `player`. The hub settles
+   * the points.
    * 
    * # Arguments
-   * * `new_admin` - The new admin address
+   * * `session_id` - The session ID of the game
+   * * `player` - Address of the player being forfeited
    */
-  set_admin: ({new_admin}: {new_admin: string}, options?: MethodOptions) => Promise<AssembledTransaction<null>>
+  on_session_forfeited: ({session_id, player}: {session_id: u32, player: string}, options?: MethodOptions) => Promise<AssembledTransaction<Result<void>>>
 
   /**
-   * Construct and simulate a start_game transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
-   * Start a new game between two players with points.
-   * This creates a session in the Game Hub and locks points before starting the game.
-   * Each player is dealt 2 cards to start.
-   * 
-   * **CRITICAL:** This method requires authorization from THIS contract (not players).
-   * The Game Hub will call `game_id.require_auth()` which checks this contract's address.
+   * Construct and simulate a on_session_cancelled transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Called by the Game Hub when it cancels the session (timeout or pause).
+   * The game ends without a winner; the hub refunds both stakes.
    * 
    * # Arguments
-   * * `session_id` - Unique session identifier (u32)
-   * * `player1` - Address of first player
-   * * `player2` - Address of second player
-   * * `player1_points` - Points amount committed by player 1
-   * * `player2_points` - Points amount committed by player 2
+   * * `session_id` - The session ID of the game
    */
-  start_game: ({session_id, player1, player2, player1_points, player2_points}: {session_id: u32, player1: string, player2: string, player1_points: i128, player2_points: i128}, options?: MethodOptions) => Promise<AssembledTransaction<Result<void>>>
+  on_session_cancelled: ({session_id}: {session_id: u32}, options?: MethodOptions) => Promise<AssembledTransaction<Result<void>>>
 
   /**
-   * Construct and simulate a reveal_winner transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
-   * Reveal the winner of the game and submit outcome to GameHub.
-   * Can only be called after both players have stuck.
-   * This calculates hand values, determines the winner (closest to 21),
-   * and handles draws by dealing new hands.
-   * 
-   * # Arguments
-   * * `session_id` - The session ID of the game
-   * 
-   * # Returns
-   * * `Address` - Address of the winning player
+   * Construct and simulate a game_info transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Describe the game: two players, winner takes both stakes
    */
-  reveal_winner: ({session_id}: {session_id: u32}, options?: MethodOptions) => Promise<AssembledTransaction<Result<string>>>
+  game_info: (options?: MethodOptions) => Promise<AssembledTransaction<GameInfo>>
 
   /**
-   * Construct and simulate a get_hand_value transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
-   * Get the current hand value for a player.
+   * Construct and simulate a get_session_status transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Get the status of a session, or `None` if it does not exist (or expired)
+   */
+  get_session_status: ({session_id}: {session_id: u32}, options?: MethodOptions) => Promise<AssembledTransaction<Option<SessionStatus>>>
+
+  /**
+   * Construct and simulate a forfeit transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Concede the game. The opponent wins and the Game Hub settles the points.
    * 
    * # Arguments
    * * `session_id` - The session ID of the game
-   * * `player` - Address of the player
-   * 
-   * # Returns
-   * * `u32` - The total value of the player's hand
+   * * `player` - Address of the player conceding
    */
-  get_hand_value: ({session_id, player}: {session_id: u32, player: string}, options?: MethodOptions) => Promise<AssembledTransaction<Result<u32>>>
+  forfeit: ({session_id, player}: {session_id: u32, player: string}, options?: MethodOptions) => Promise<AssembledTransaction<Result<void>>>
+
+  /**
+   * Construct and simulate a get_admin transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Get the current admin address
+   */
+  get_admin: (options?: MethodOptions) => Promise<AssembledTransaction<string>>
+
+  /**
+   * Construct and simulate a get_pending_admin transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Get the admin proposed by `propose_admin`, if any
+   */
+  get_pending_admin: (options?: MethodOptions) => Promise<AssembledTransaction<Option<string>>>
+
+  /**
+   * Construct and simulate a propose_admin transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Propose a new admin. They take over once they call
+   * `accept_admin`.
+   */
+  propose_admin: ({new_admin}: {new_admin: string}, options?: MethodOptions) => Promise<AssembledTransaction<null>>
+
+  /**
+   * Construct and simulate a accept_admin transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Accept a pending admin transfer. Pending admin only.
+   */
+  accept_admin: (options?: MethodOptions) => Promise<AssembledTransaction<Result<void>>>
+
+  /**
+   * Construct and simulate a grant_role transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Grant a role to an account
+   */
+  grant_role: ({role, account}: {role: Role, account: string}, options?: MethodOptions) => Promise<AssembledTransaction<null>>
+
+  /**
+   * Construct and simulate a revoke_role transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Revoke a role from an account
+   */
+  revoke_role: ({role, account}: {role: Role, account: string}, options?: MethodOptions) => Promise<AssembledTransaction<null>>
+
+  /**
+   * Construct and simulate a has_role transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Check whether an account holds a role (the admin holds all)
+   */
+  has_role: ({role, account}: {role: Role, account: string}, options?: MethodOptions) => Promise<AssembledTransaction<boolean>>
+
+  /**
+   * Construct and simulate a get_hub transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Get the current GameHub contract address
+   */
+  get_hub: (options?: MethodOptions) => Promise<AssembledTransaction<string>>
+
+  /**
+   * Construct and simulate a set_hub transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Set a new GameHub contract address
+   */
+  set_hub: ({new_hub}: {new_hub: string}, options?: MethodOptions) => Promise<AssembledTransaction<null>>
+
+  /**
+   * Construct and simulate a pause transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Pause gameplay: new games and moves fail with `ContractPaused`
+   * until `unpause` is called. Hub callbacks still run, so sessions
+   * can be cancelled and refunded through the hub.
+   */
+  pause: (options?: MethodOptions) => Promise<AssembledTransaction<null>>
+
+  /**
+   * Construct and simulate a unpause transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Resume gameplay after `pause`
+   */
+  unpause: (options?: MethodOptions) => Promise<AssembledTransaction<null>>
+
+  /**
+   * Construct and simulate a is_paused transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Check whether the contract is paused
+   */
+  is_paused: (options?: MethodOptions) => Promise<AssembledTransaction<boolean>>
+
+  /**
+   * Construct and simulate a upgrade transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Update the contract WASM hash (upgrade contract)
+   */
+  upgrade: ({new_wasm_hash}: {new_wasm_hash: Buffer}, options?: MethodOptions) => Promise<AssembledTransaction<null>>
 
 }
 export class Client extends ContractClient {
@@ -214,35 +395,72 @@ export class Client extends ContractClient {
   }
   constructor(public readonly options: ContractClientOptions) {
     super(
-      new ContractSpec([ "AAAAAQAAAAAAAAAAAAAABEdhbWUAAAAKAAAAAAAAAAdwbGF5ZXIxAAAAABMAAAAAAAAADHBsYXllcjFfaGFuZAAAAA4AAAAAAAAADnBsYXllcjFfcG9pbnRzAAAAAAALAAAAAAAAAA1wbGF5ZXIxX3N0dWNrAAAAAAAAAQAAAAAAAAAHcGxheWVyMgAAAAATAAAAAAAAAAxwbGF5ZXIyX2hhbmQAAAAOAAAAAAAAAA5wbGF5ZXIyX3BvaW50cwAAAAAACwAAAAAAAAANcGxheWVyMl9zdHVjawAAAAAAAAEAAAAAAAAABXJvdW5kAAAAAAAABAAAAAAAAAAGd2lubmVyAAAAAAPoAAAAEw==",
-        "AAAABAAAAAAAAAAAAAAABUVycm9yAAAAAAAACwAAAAAAAAAMR2FtZU5vdEZvdW5kAAAAAQAAAAAAAAAJTm90UGxheWVyAAAAAAAAAgAAAAAAAAAMQWxyZWFkeVN0dWNrAAAAAwAAAAAAAAAQR2FtZUFscmVhZHlFbmRlZAAAAAQAAAAAAAAADFBsYXllckJ1c3RlZAAAAAUAAAAAAAAAE0JvdGhQbGF5ZXJzTm90U3R1Y2sAAAAABgAAAAAAAAAQT3Bwb25lbnROb3RTdHVjawAAAAcAAAAAAAAABERyYXcAAAAIAAAAAAAAAAhTZWxmUGxheQAAAAkAAAAAAAAADVJvdW5kT3ZlcmZsb3cAAAAAAAAKAAAAAAAAAA9JbnZhbGlkSGFuZERhdGEAAAAACw==",
-        "AAAAAgAAAAAAAAAAAAAAB0RhdGFLZXkAAAAAAwAAAAEAAAAAAAAABEdhbWUAAAABAAAABAAAAAAAAAAAAAAADkdhbWVIdWJBZGRyZXNzAAAAAAAAAAAAAAAAAAVBZG1pbgAAAA==",
-        "AAAAAAAAANRQbGF5ZXIgZHJhd3MgYW5vdGhlciBjYXJkICgiaGl0IikuCklmIHRoZSBwbGF5ZXIncyBoYW5kIHZhbHVlIGV4Y2VlZHMgMjEsIHRoZXkgYnVzdCBhbmQgbG9zZSBpbW1lZGlhdGVseS4KCiMgQXJndW1lbnRzCiogYHNlc3Npb25faWRgIC0gVGhlIHNlc3Npb24gSUQgb2YgdGhlIGdhbWUKKiBgcGxheWVyYCAtIEFkZHJlc3Mgb2YgdGhlIHBsYXllciBkcmF3aW5nIGEgY2FyZAAAAANoaXQAAAAAAgAAAAAAAAAKc2Vzc2lvbl9pZAAAAAAABAAAAAAAAAAGcGxheWVyAAAAAAATAAAAAQAAA+kAAAPtAAAAAAAAAAM=",
-        "AAAAAAAAANZQbGF5ZXIgY2hvb3NlcyB0byBzdGljayAoZW5kIHRoZWlyIHR1cm4gd2l0aCBjdXJyZW50IGhhbmQpLgpJZiBib3RoIHBsYXllcnMgaGF2ZSBzdHVjaywgdGhlIGdhbWUgY2FuIGJlIHJldmVhbGVkLgoKIyBBcmd1bWVudHMKKiBgc2Vzc2lvbl9pZGAgLSBUaGUgc2Vzc2lvbiBJRCBvZiB0aGUgZ2FtZQoqIGBwbGF5ZXJgIC0gQWRkcmVzcyBvZiB0aGUgcGxheWVyIHN0aWNraW5nAAAAAAAFc3RpY2sAAAAAAAACAAAAAAAAAApzZXNzaW9uX2lkAAAAAAAEAAAAAAAAAAZwbGF5ZXIAAAAAABMAAAABAAAD6QAAA+0AAAAAAAAAAw==",
-        "AAAAAAAAAF5HZXQgdGhlIGN1cnJlbnQgR2FtZUh1YiBjb250cmFjdCBhZGRyZXNzCgojIFJldHVybnMKKiBgQWRkcmVzc2AgLSBUaGUgR2FtZUh1YiBjb250cmFjdCBhZGRyZXNzAAAAAAAHZ2V0X2h1YgAAAAAAAAAAAQAAABM=",
-        "AAAAAAAAAF5TZXQgYSBuZXcgR2FtZUh1YiBjb250cmFjdCBhZGRyZXNzCgojIEFyZ3VtZW50cwoqIGBuZXdfaHViYCAtIFRoZSBuZXcgR2FtZUh1YiBjb250cmFjdCBhZGRyZXNzAAAAAAAHc2V0X2h1YgAAAAABAAAAAAAAAAduZXdfaHViAAAAABMAAAAA",
-        "AAAAAAAAAHFVcGRhdGUgdGhlIGNvbnRyYWN0IFdBU00gaGFzaCAodXBncmFkZSBjb250cmFjdCkKCiMgQXJndW1lbnRzCiogYG5ld193YXNtX2hhc2hgIC0gVGhlIGhhc2ggb2YgdGhlIG5ldyBXQVNNIGJpbmFyeQAAAAAAAAd1cGdyYWRlAAAAAAEAAAAAAAAADW5ld193YXNtX2hhc2gAAAAAAAPuAAAAIAAAAAA=",
-        "AAAAAAAAAJ9HZXQgZ2FtZSBpbmZvcm1hdGlvbi4KCiMgQXJndW1lbnRzCiogYHNlc3Npb25faWRgIC0gVGhlIHNlc3Npb24gSUQgb2YgdGhlIGdhbWUKCiMgUmV0dXJucwoqIGBHYW1lYCAtIFRoZSBnYW1lIHN0YXRlIChpbmNsdWRlcyBoYW5kcyBhbmQgd2lubmVyIGFmdGVyIGdhbWUgZW5kcykAAAAACGdldF9nYW1lAAAAAQAAAAAAAAAKc2Vzc2lvbl9pZAAAAAAABAAAAAEAAAPpAAAH0AAAAARHYW1lAAAAAw==",
-        "AAAAAAAAAEhHZXQgdGhlIGN1cnJlbnQgYWRtaW4gYWRkcmVzcwoKIyBSZXR1cm5zCiogYEFkZHJlc3NgIC0gVGhlIGFkbWluIGFkZHJlc3MAAAAJZ2V0X2FkbWluAAAAAAAAAAAAAAEAAAAT",
-        "AAAAAAAAAEpTZXQgYSBuZXcgYWRtaW4gYWRkcmVzcwoKIyBBcmd1bWVudHMKKiBgbmV3X2FkbWluYCAtIFRoZSBuZXcgYWRtaW4gYWRkcmVzcwAAAAAACXNldF9hZG1pbgAAAAAAAAEAAAAAAAAACW5ld19hZG1pbgAAAAAAABMAAAAA",
-        "AAAAAAAAAlFTdGFydCBhIG5ldyBnYW1lIGJldHdlZW4gdHdvIHBsYXllcnMgd2l0aCBwb2ludHMuClRoaXMgY3JlYXRlcyBhIHNlc3Npb24gaW4gdGhlIEdhbWUgSHViIGFuZCBsb2NrcyBwb2ludHMgYmVmb3JlIHN0YXJ0aW5nIHRoZSBnYW1lLgpFYWNoIHBsYXllciBpcyBkZWFsdCAyIGNhcmRzIHRvIHN0YXJ0LgoKKipDUklUSUNBTDoqKiBUaGlzIG1ldGhvZCByZXF1aXJlcyBhdXRob3JpemF0aW9uIGZyb20gVEhJUyBjb250cmFjdCAobm90IHBsYXllcnMpLgpUaGUgR2FtZSBIdWIgd2lsbCBjYWxsIGBnYW1lX2lkLnJlcXVpcmVfYXV0aCgpYCB3aGljaCBjaGVja3MgdGhpcyBjb250cmFjdCdzIGFkZHJlc3MuCgojIEFyZ3VtZW50cwoqIGBzZXNzaW9uX2lkYCAtIFVuaXF1ZSBzZXNzaW9uIGlkZW50aWZpZXIgKHUzMikKKiBgcGxheWVyMWAgLSBBZGRyZXNzIG9mIGZpcnN0IHBsYXllcgoqIGBwbGF5ZXIyYCAtIEFkZHJlc3Mgb2Ygc2Vjb25kIHBsYXllcgoqIGBwbGF5ZXIxX3BvaW50c2AgLSBQb2ludHMgYW1vdW50IGNvbW1pdHRlZCBieSBwbGF5ZXIgMQoqIGBwbGF5ZXIyX3BvaW50c2AgLSBQb2ludHMgYW1vdW50IGNvbW1pdHRlZCBieSBwbGF5ZXIgMgAAAAAAAApzdGFydF9nYW1lAAAAAAAFAAAAAAAAAApzZXNzaW9uX2lkAAAAAAAEAAAAAAAAAAdwbGF5ZXIxAAAAABMAAAAAAAAAB3BsYXllcjIAAAAAEwAAAAAAAAAOcGxheWVyMV9wb2ludHMAAAAAAAsAAAAAAAAADnBsYXllcjJfcG9pbnRzAAAAAAALAAAAAQAAA+kAAAPtAAAAAAAAAAM=",
+      new ContractSpec([ "AAAABAAAAAAAAAAAAAAABUVycm9yAAAAAAAADwAAAAAAAAAMR2FtZU5vdEZvdW5kAAAAAQAAAAAAAAAJTm90UGxheWVyAAAAAAAAAgAAAAAAAAAMQWxyZWFkeVN0dWNrAAAAAwAAAAAAAAAQR2FtZUFscmVhZHlFbmRlZAAAAAQAAAAAAAAAE0JvdGhQbGF5ZXJzTm90U3R1Y2sAAAAABgAAAAAAAAAIU2VsZlBsYXkAAAAJAAAAAAAAAA9JbnZhbGlkSGFuZERhdGEAAAAACwAAAAAAAAARUGxheWVyTm90U3RhbGxpbmcAAAAAAAAMAAAAAAAAAA5Db250cmFjdFBhdXNlZAAAAAAADQAAAAAAAAAUU2Vzc2lvbkFscmVhZHlFeGlzdHMAAAAOAAAAAAAAAAxEZWNrTm90UmVhZHkAAAAPAAAAAAAAAA9BbHJlYWR5UmV2ZWFsZWQAAAAAEAAAAAAAAAASQ29tbWl0bWVudE1pc21hdGNoAAAAAAARAAAAAAAAAA9IYW5kTm90UmV2ZWFsZWQAAAAAEgAAAAAAAAAMSGFuZE1pc21hdGNoAAAAEw==",
+        "AAAAAgAAAAAAAAAAAAAACkdhbWVTdGF0dXMAAAAAAAIAAAAAAAAAAAAAAAZBY3RpdmUAAAAAAAEAAAAAAAAABUVuZGVkAAAAAAAAAQAAB9AAAAALR2FtZU91dGNvbWUA",
+        "AAAAAQAAAAAAAAAAAAAABEdhbWUAAAASAAAAAAAAAAdwbGF5ZXIxAAAAABMAAABtRW50cm9weSBkcmF3biBmb3IgZWFjaCBjYXJkIGEgcGxheWVyIGhhcyBiZWVuIGRlYWx0LCBpbiBkcmF3IG9yZGVyOwp0aGUgY2FyZHMgc3RheSBoaWRkZW4gdW50aWwgYHJldmVhbF9oYW5kYAAAAAAAAA1wbGF5ZXIxX2RyYXdzAAAAAAAD6gAAA+4AAAAgAAAAAAAAAAxwbGF5ZXIxX2hhbmQAAAAOAAAAPkNvbW1pdG1lbnRzIHRvIGVhY2ggcGxheWVyJ3MgaGFuZCBzZWNyZXQsIHNldCBieSBgcmV2ZWFsX3NlZWRgAAAAAAAXcGxheWVyMV9oYW5kX2NvbW1pdG1lbnQAAAAD6AAAA+4AAAAgAAAAAAAAAA5wbGF5ZXIxX3BvaW50cwAAAAAACwAAAClSZXZlYWxlZCBkZWNrIHNlZWRzLCBzZXQgYnkgYHJldmVhbF9zZWVkYAAAAAAAAAxwbGF5ZXIxX3NlZWQAAAPoAAAD7gAAACAAAAA9Q29tbWl0bWVudHMgdG8gZWFjaCBwbGF5ZXIncyBkZWNrIHNlZWQsIGdpdmVuIGF0IGBzdGFydF9nYW1lYAAAAAAAABdwbGF5ZXIxX3NlZWRfY29tbWl0bWVudAAAAAPuAAAAIAAAAAAAAAANcGxheWVyMV9zdHVjawAAAAAAAAEAAAAAAAAAB3BsYXllcjIAAAAAEwAAAAAAAAANcGxheWVyMl9kcmF3cwAAAAAAA+oAAAPuAAAAIAAAAAAAAAAMcGxheWVyMl9oYW5kAAAADgAAAAAAAAAXcGxheWVyMl9oYW5kX2NvbW1pdG1lbnQAAAAD6AAAA+4AAAAgAAAAAAAAAA5wbGF5ZXIyX3BvaW50cwAAAAAACwAAAAAAAAAMcGxheWVyMl9zZWVkAAAD6AAAA+4AAAAgAAAAAAAAABdwbGF5ZXIyX3NlZWRfY29tbWl0bWVudAAAAAPuAAAAIAAAAAAAAAANcGxheWVyMl9zdHVjawAAAAAAAAEAAAAAAAAABnN0YXR1cwAAAAAH0AAAAApHYW1lU3RhdHVzAAAAAAAAAAAABndpbm5lcgAAAAAD6AAAABM=",
+        "AAAAAgAAAAAAAAAAAAAAB0RhdGFLZXkAAAAAAQAAAAEAAAAAAAAABEdhbWUAAAABAAAABA==",
+        "AAAABAAAAHpFcnJvcnMgcmV0dXJuZWQgYnkgdGhlIHNoYXJlZCBhZG1pbiBlbnRyeXBvaW50cy4gQ29kZXMgc3RhcnQgYXQgMTAwIHNvCnRoZXkgbmV2ZXIgY29sbGlkZSB3aXRoIGEgZ2FtZSdzIG93biBgRXJyb3JgIGNvZGVzLgAAAAAAAAAAAAtDb21tb25FcnJvcgAAAAABAAAAI05vIGFkbWluIHRyYW5zZmVyIGhhcyBiZWVuIHByb3Bvc2VkAAAAAA5Ob1BlbmRpbmdBZG1pbgAAAAAAZA==",
+        "AAAAAgAAAEpTZXNzaW9uIG91dGNvbWUgcmVwb3J0ZWQgdG8gdGhlIEdhbWUgSHViIChtaXJyb3JzIHRoZSBodWIncyBgR2FtZU91dGNvbWVgKQAAAAAAAAAAAAtHYW1lT3V0Y29tZQAAAAAFAAAAAAAAAAAAAAAKUGxheWVyMVdvbgAAAAAAAAAAAAAAAAAKUGxheWVyMldvbgAAAAAAAAAAAAAAAAAERHJhdwAAAAAAAAAAAAAACUNhbmNlbGxlZAAAAAAAAAEAAAAAAAAAB0ZvcmZlaXQAAAAAAQAAABM=",
+        "AAAAAgAAACNIb3cgdGhlIGh1YiBzZXR0bGVzIGEgZ2FtZSdzIHN0YWtlcwAAAAAAAAAADlN0YWtlU2VtYW50aWNzAAAAAAACAAAAAAAAAFZFYWNoIHBsYXllciBzdGFrZXM7IHRoZSB3aW5uZXIgdGFrZXMgZXZlcnkgc3Rha2UuIERyYXdzIGFuZApjYW5jZWxsYXRpb25zIHJlZnVuZCB0aGVtLgAAAAAADldpbm5lclRha2VzQWxsAAAAAAAAAAAAQEVhY2ggcGxheWVyIHN0YWtlczsgdGhlIGh1YiBzcGxpdHMgdGhlIHBvdCBieSBmaW5pc2hpbmcgcG9zaXRpb24AAAAKUGxhY2VtZW50cwAA",
+        "AAAAAQAAADdTdGF0aWMgZGVzY3JpcHRpb24gb2YgYSBnYW1lLCBmb3IgbG9iYmllcyBhbmQgaHViIHRvb2xzAAAAAAAAAAAIR2FtZUluZm8AAAAFAAAAAAAAAAttYXhfcGxheWVycwAAAAAEAAAAAAAAAAttaW5fcGxheWVycwAAAAAEAAAAAAAAAARuYW1lAAAAEAAAAD1CdW1wZWQgd2hlbmV2ZXIgYSBjaGFuZ2UgdG8gdGhlIHJ1bGVzIGNvdWxkIGNoYW5nZSBhbiBvdXRjb21lAAAAAAAADXJ1bGVzX3ZlcnNpb24AAAAAAAAEAAAAAAAAAAZzdGFrZXMAAAAAB9AAAAAOU3Rha2VTZW1hbnRpY3MAAA==",
+        "AAAAAgAAACtHYW1lLWluZGVwZW5kZW50IHZpZXcgb2YgYSBzZXNzaW9uJ3Mgc3RhdHVzAAAAAAAAAAANU2Vzc2lvblN0YXR1cwAAAAAAAAIAAAAAAAAAAAAAAAZBY3RpdmUAAAAAAAEAAAAAAAAABUVuZGVkAAAAAAAAAQAAB9AAAAALR2FtZU91dGNvbWUA",
+        "AAAAAgAAAEFSb2xlcyB0aGUgYWRtaW4gY2FuIGdyYW50LiBUaGUgYWRtaW4gaW1wbGljaXRseSBob2xkcyBldmVyeSByb2xlLgAAAAAAAAAAAAAEUm9sZQAAAAEAAAAAAAAAfkRheS10by1kYXkgZ2FtZSBvcGVyYXRpb25zIChjcmVhdGluZyBjYXNlcyBhbmQgc2NlbmVzLCByZXNvbHZpbmcKZ2FtZXMpLiBDYW5ub3QgY2hhbmdlIHRoZSBhZG1pbiwgdGhlIGh1YiBhZGRyZXNzIG9yIHRoZSBXQVNNLgAAAAAACE9wZXJhdG9y",
         "AAAAAAAAAKNJbml0aWFsaXplIHRoZSBjb250cmFjdCB3aXRoIEdhbWVIdWIgYWRkcmVzcyBhbmQgYWRtaW4KCiMgQXJndW1lbnRzCiogYGFkbWluYCAtIEFkbWluIGFkZHJlc3MgKGNhbiB1cGdyYWRlIGNvbnRyYWN0KQoqIGBnYW1lX2h1YmAgLSBBZGRyZXNzIG9mIHRoZSBHYW1lSHViIGNvbnRyYWN0AAAAAA1fX2NvbnN0cnVjdG9yAAAAAAAAAgAAAAAAAAAFYWRtaW4AAAAAAAATAAAAAAAAAAhnYW1lX2h1YgAAABMAAAAA",
-        "AAAAAAAAAUpSZXZlYWwgdGhlIHdpbm5lciBvZiB0aGUgZ2FtZSBhbmQgc3VibWl0IG91dGNvbWUgdG8gR2FtZUh1Yi4KQ2FuIG9ubHkgYmUgY2FsbGVkIGFmdGVyIGJvdGggcGxheWVycyBoYXZlIHN0dWNrLgpUaGlzIGNhbGN1bGF0ZXMgaGFuZCB2YWx1ZXMsIGRldGVybWluZXMgdGhlIHdpbm5lciAoY2xvc2VzdCB0byAyMSksCmFuZCBoYW5kbGVzIGRyYXdzIGJ5IGRlYWxpbmcgbmV3IGhhbmRzLgoKIyBBcmd1bWVudHMKKiBgc2Vzc2lvbl9pZGAgLSBUaGUgc2Vzc2lvbiBJRCBvZiB0aGUgZ2FtZQoKIyBSZXR1cm5zCiogYEFkZHJlc3NgIC0gQWRkcmVzcyBvZiB0aGUgd2lubmluZyBwbGF5ZXIAAAAAAA1yZXZlYWxfd2lubmVyAAAAAAAAAQAAAAAAAAAKc2Vzc2lvbl9pZAAAAAAABAAAAAEAAAPpAAAAEwAAAAM=",
-        "AAAAAAAAAL5HZXQgdGhlIGN1cnJlbnQgaGFuZCB2YWx1ZSBmb3IgYSBwbGF5ZXIuCgojIEFyZ3VtZW50cwoqIGBzZXNzaW9uX2lkYCAtIFRoZSBzZXNzaW9uIElEIG9mIHRoZSBnYW1lCiogYHBsYXllcmAgLSBBZGRyZXNzIG9mIHRoZSBwbGF5ZXIKCiMgUmV0dXJucwoqIGB1MzJgIC0gVGhlIHRvdGFsIHZhbHVlIG9mIHRoZSBwbGF5ZXIncyBoYW5kAAAAAAAOZ2V0X2hhbmRfdmFsdWUAAAAAAAIAAAAAAAAACnNlc3Npb25faWQAAAAAAAQAAAAAAAAABnBsYXllcgAAAAAAEwAAAAEAAAPpAAAABAAAAAM=" ]),
+        "AAAAAAAAA3ZTdGFydCBhIG5ldyBnYW1lIGJldHdlZW4gdHdvIHBsYXllcnMgd2l0aCBwb2ludHMuClRoaXMgY3JlYXRlcyBhIHNlc3Npb24gaW4gdGhlIEdhbWUgSHViIGFuZCBsb2NrcyBwb2ludHMgYmVmb3JlIHN0YXJ0aW5nIHRoZSBnYW1lLgpFYWNoIHBsYXllciBjb21taXRzIHRvIGEgZGVjayBzZWVkIGFuZCBhIGhhbmQgY29tbWl0bWVudDsgdGhlIG9wZW5pbmcKMiBjYXJkcyBlYWNoIGFyZSBkZWFsdCBvbmNlIGJvdGggYXJlIHJldmVhbGVkIHdpdGggYHJldmVhbF9zZWVkYC4KCioqQ1JJVElDQUw6KiogVGhpcyBtZXRob2QgcmVxdWlyZXMgYXV0aG9yaXphdGlvbiBmcm9tIFRISVMgY29udHJhY3QgKG5vdCBwbGF5ZXJzKS4KVGhlIEdhbWUgSHViIHdpbGwgY2FsbCBgZ2FtZV9pZC5yZXF1aXJlX2F1dGgoKWAgd2hpY2ggY2hlY2tzIHRoaXMgY29udHJhY3QncyBhZGRyZXNzLgoKIyBBcmd1bWVudHMKKiBgc2Vzc2lvbl9pZGAgLSBTZXNzaW9uIElEIHJlc2VydmVkIG9uIHRoZSBHYW1lIEh1YiAoYHJlc2VydmVfc2Vzc2lvbmApCiogYHBsYXllcjFgIC0gQWRkcmVzcyBvZiBmaXJzdCBwbGF5ZXIKKiBgcGxheWVyMmAgLSBBZGRyZXNzIG9mIHNlY29uZCBwbGF5ZXIKKiBgcGxheWVyMV9wb2ludHNgIC0gUG9pbnRzIGFtb3VudCBjb21taXR0ZWQgYnkgcGxheWVyIDEKKiBgcGxheWVyMl9wb2ludHNgIC0gUG9pbnRzIGFtb3VudCBjb21taXR0ZWQgYnkgcGxheWVyIDIKKiBgcGxheWVyMV9zZWVkX2NvbW1pdG1lbnRgIC0gYGtlY2NhazI1NihzZWVkIHx8IGhhbmRfY29tbWl0bWVudCB8fCBwbGF5ZXIxX2FkZHJlc3MpYAoqIGBwbGF5ZXIyX3NlZWRfY29tbWl0bWVudGAgLSBga2VjY2FrMjU2KHNlZWQgfHwgaGFuZF9jb21taXRtZW50IHx8IHBsYXllcjJfYWRkcmVzcylgAAAAAAAKc3RhcnRfZ2FtZQAAAAAABwAAAAAAAAAKc2Vzc2lvbl9pZAAAAAAABAAAAAAAAAAHcGxheWVyMQAAAAATAAAAAAAAAAdwbGF5ZXIyAAAAABMAAAAAAAAADnBsYXllcjFfcG9pbnRzAAAAAAALAAAAAAAAAA5wbGF5ZXIyX3BvaW50cwAAAAAACwAAAAAAAAAXcGxheWVyMV9zZWVkX2NvbW1pdG1lbnQAAAAD7gAAACAAAAAAAAAAF3BsYXllcjJfc2VlZF9jb21taXRtZW50AAAAA+4AAAAgAAAAAQAAA+kAAAACAAAAAw==",
+        "AAAAAAAAAWBSZXZlYWwgdGhlIGRlY2sgc2VlZCBhbmQgaGFuZCBjb21taXRtZW50IGNvbW1pdHRlZCBhdCBgc3RhcnRfZ2FtZWAuCk9uY2UgYm90aCBzZWVkcyBhcmUgcmV2ZWFsZWQsIGVhY2ggcGxheWVyIGlzIGRlYWx0IDIgaGlkZGVuIGNhcmRzLgoKIyBBcmd1bWVudHMKKiBgc2Vzc2lvbl9pZGAgLSBUaGUgc2Vzc2lvbiBJRCBvZiB0aGUgZ2FtZQoqIGBwbGF5ZXJgIC0gQWRkcmVzcyBvZiB0aGUgcGxheWVyIHJldmVhbGluZwoqIGBzZWVkYCAtIFRoZSBzZWVkIGJlaGluZCB0aGUgcGxheWVyJ3MgY29tbWl0bWVudAoqIGBoYW5kX2NvbW1pdG1lbnRgIC0gYGtlY2NhazI1NihoYW5kX3NlY3JldCB8fCBwbGF5ZXJfYWRkcmVzcylgAAAAC3JldmVhbF9zZWVkAAAAAAQAAAAAAAAACnNlc3Npb25faWQAAAAAAAQAAAAAAAAABnBsYXllcgAAAAAAEwAAAAAAAAAEc2VlZAAAA+4AAAAgAAAAAAAAAA9oYW5kX2NvbW1pdG1lbnQAAAAD7gAAACAAAAABAAAD6QAAAAIAAAAD",
+        "AAAAAAAAAWRQbGF5ZXIgZHJhd3MgYW5vdGhlciBjYXJkICgiaGl0IikuClRoZSBjYXJkJ3MgZW50cm9weSBpcyBkcmF3biBub3csIHNvIGl0IGNhbm5vdCBiZSBrbm93biBiZWZvcmUgdGhpcwpjYWxsLiBUaGUgY2FyZCBzdGF5cyBoaWRkZW4gKHRoZSBwbGF5ZXIgZGVyaXZlcyBpdCBvZmYtY2hhaW4gZnJvbQpgZ2V0X2dhbWVgIGFuZCB0aGVpciBoYW5kIHNlY3JldCk7IGEgaGFuZCBvdmVyIDIxIG9ubHkgbG9zZXMgb25jZSBpdAppcyByZXZlYWxlZC4KCiMgQXJndW1lbnRzCiogYHNlc3Npb25faWRgIC0gVGhlIHNlc3Npb24gSUQgb2YgdGhlIGdhbWUKKiBgcGxheWVyYCAtIEFkZHJlc3Mgb2YgdGhlIHBsYXllciBkcmF3aW5nIGEgY2FyZAAAAANoaXQAAAAAAgAAAAAAAAAKc2Vzc2lvbl9pZAAAAAAABAAAAAAAAAAGcGxheWVyAAAAAAATAAAAAQAAA+kAAAACAAAAAw==",
+        "AAAAAAAAANZQbGF5ZXIgY2hvb3NlcyB0byBzdGljayAoZW5kIHRoZWlyIHR1cm4gd2l0aCBjdXJyZW50IGhhbmQpLgpJZiBib3RoIHBsYXllcnMgaGF2ZSBzdHVjaywgdGhlIGdhbWUgY2FuIGJlIHJldmVhbGVkLgoKIyBBcmd1bWVudHMKKiBgc2Vzc2lvbl9pZGAgLSBUaGUgc2Vzc2lvbiBJRCBvZiB0aGUgZ2FtZQoqIGBwbGF5ZXJgIC0gQWRkcmVzcyBvZiB0aGUgcGxheWVyIHN0aWNraW5nAAAAAAAFc3RpY2sAAAAAAAACAAAAAAAAAApzZXNzaW9uX2lkAAAAAAAEAAAAAAAAAAZwbGF5ZXIAAAAAABMAAAABAAAD6QAAAAIAAAAD",
+        "AAAAAAAAAZhSZXZlYWwgYSBwbGF5ZXIncyBoYW5kIG9uY2UgYm90aCBwbGF5ZXJzIGhhdmUgc3R1Y2suClRoZSBjb250cmFjdCByZS1kZXJpdmVzIHRoZSBjYXJkcyBmcm9tIHRoZSBoYW5kIHNlY3JldCBhbmQgb25seQphY2NlcHRzIGBoYW5kYCBpZiBpdCBpcyBleGFjdGx5IHRoZSBjYXJkcyB0aGUgcGxheWVyIGRyZXcuCgojIEFyZ3VtZW50cwoqIGBzZXNzaW9uX2lkYCAtIFRoZSBzZXNzaW9uIElEIG9mIHRoZSBnYW1lCiogYHBsYXllcmAgLSBBZGRyZXNzIG9mIHRoZSBwbGF5ZXIgcmV2ZWFsaW5nCiogYGhhbmRfc2VjcmV0YCAtIFRoZSBzZWNyZXQgYmVoaW5kIHRoZSBwbGF5ZXIncyBoYW5kIGNvbW1pdG1lbnQKKiBgaGFuZGAgLSBUaGUgcGxheWVyJ3MgY2FyZHMgaW4gZHJhdyBvcmRlciwgYXMgZGVyaXZlZCBvZmYtY2hhaW4AAAALcmV2ZWFsX2hhbmQAAAAABAAAAAAAAAAKc2Vzc2lvbl9pZAAAAAAABAAAAAAAAAAGcGxheWVyAAAAAAATAAAAAAAAAAtoYW5kX3NlY3JldAAAAAPuAAAAIAAAAAAAAAAEaGFuZAAAAA4AAAABAAAD6QAAAAIAAAAD",
+        "AAAAAAAAAZ1SZXZlYWwgdGhlIHdpbm5lciBvZiB0aGUgZ2FtZSBhbmQgc3VibWl0IG91dGNvbWUgdG8gR2FtZUh1Yi4KQ2FuIG9ubHkgYmUgY2FsbGVkIGFmdGVyIGJvdGggcGxheWVycyBoYXZlIHJldmVhbGVkIHRoZWlyIGhhbmRzLgpUaGlzIGNhbGN1bGF0ZXMgaGFuZCB2YWx1ZXMgYW5kIGRldGVybWluZXMgdGhlIHdpbm5lciAoY2xvc2VzdCB0byAyMQp3aXRob3V0IGdvaW5nIG92ZXIpLiBFcXVhbCBoYW5kIHZhbHVlcyBhbmQgdHdvIGJ1c3RzIGVuZCB0aGUgZ2FtZSBpbgphIGRyYXcuCgojIEFyZ3VtZW50cwoqIGBzZXNzaW9uX2lkYCAtIFRoZSBzZXNzaW9uIElEIG9mIHRoZSBnYW1lCgojIFJldHVybnMKKiBgT3B0aW9uPEFkZHJlc3M+YCAtIEFkZHJlc3Mgb2YgdGhlIHdpbm5pbmcgcGxheWVyLCBvciBgTm9uZWAgZm9yIGEgZHJhdwAAAAAAAA1yZXZlYWxfd2lubmVyAAAAAAAAAQAAAAAAAAAKc2Vzc2lvbl9pZAAAAAAABAAAAAEAAAPpAAAD6AAAABMAAAAD",
+        "AAAAAAAAAKdHZXQgZ2FtZSBpbmZvcm1hdGlvbi4KCiMgQXJndW1lbnRzCiogYHNlc3Npb25faWRgIC0gVGhlIHNlc3Npb24gSUQgb2YgdGhlIGdhbWUKCiMgUmV0dXJucwoqIGBHYW1lYCAtIFRoZSBnYW1lIHN0YXRlIChoYW5kcyBhcmUgZW1wdHkgdW50aWwgcmV2ZWFsZWQgd2l0aCBgcmV2ZWFsX2hhbmRgKQAAAAAIZ2V0X2dhbWUAAAABAAAAAAAAAApzZXNzaW9uX2lkAAAAAAAEAAAAAQAAA+kAAAfQAAAABEdhbWUAAAAD",
+        "AAAAAAAAAMBHZXQgdGhlIHZhbHVlIG9mIGEgcGxheWVyJ3MgcmV2ZWFsZWQgaGFuZC4KCiMgQXJndW1lbnRzCiogYHNlc3Npb25faWRgIC0gVGhlIHNlc3Npb24gSUQgb2YgdGhlIGdhbWUKKiBgcGxheWVyYCAtIEFkZHJlc3Mgb2YgdGhlIHBsYXllcgoKIyBSZXR1cm5zCiogYHUzMmAgLSBUaGUgdG90YWwgdmFsdWUgb2YgdGhlIHBsYXllcidzIGhhbmQAAAAOZ2V0X2hhbmRfdmFsdWUAAAAAAAIAAAAAAAAACnNlc3Npb25faWQAAAAAAAQAAAAAAAAABnBsYXllcgAAAAAAEwAAAAEAAAPpAAAABAAAAAM=",
+        "AAAAAAAAAYZDYWxsZWQgYnkgdGhlIEdhbWUgSHViIHdoZW4gYSB0aW1lb3V0IGlzIGNsYWltZWQgYWdhaW5zdCBgcGxheWVyYC4KT25seSBhY2NlcHRlZCBpZiBgcGxheWVyYCBpcyBob2xkaW5nIHVwIHRoZSBjdXJyZW50IHBoYXNlIHdoaWxlIHRoZWlyCm9wcG9uZW50IGlzIGRvbmUgd2l0aCBpdDogcmV2ZWFsaW5nIHNlZWRzLCBzdGlja2luZywgb3IgcmV2ZWFsaW5nCmhhbmRzLiBUaGUgZ2FtZSB0aGVuIGVuZHMgYXMgYSBmb3JmZWl0IGJ5IGBwbGF5ZXJgLiBUaGUgaHViIHNldHRsZXMKdGhlIHBvaW50cy4KCiMgQXJndW1lbnRzCiogYHNlc3Npb25faWRgIC0gVGhlIHNlc3Npb24gSUQgb2YgdGhlIGdhbWUKKiBgcGxheWVyYCAtIEFkZHJlc3Mgb2YgdGhlIHBsYXllciBiZWluZyBmb3JmZWl0ZWQAAAAAABRvbl9zZXNzaW9uX2ZvcmZlaXRlZAAAAAIAAAAAAAAACnNlc3Npb25faWQAAAAAAAQAAAAAAAAABnBsYXllcgAAAAAAEwAAAAEAAAPpAAAAAgAAAAM=",
+        "AAAAAAAAALxDYWxsZWQgYnkgdGhlIEdhbWUgSHViIHdoZW4gaXQgY2FuY2VscyB0aGUgc2Vzc2lvbiAodGltZW91dCBvciBwYXVzZSkuClRoZSBnYW1lIGVuZHMgd2l0aG91dCBhIHdpbm5lcjsgdGhlIGh1YiByZWZ1bmRzIGJvdGggc3Rha2VzLgoKIyBBcmd1bWVudHMKKiBgc2Vzc2lvbl9pZGAgLSBUaGUgc2Vzc2lvbiBJRCBvZiB0aGUgZ2FtZQAAABRvbl9zZXNzaW9uX2NhbmNlbGxlZAAAAAEAAAAAAAAACnNlc3Npb25faWQAAAAAAAQAAAABAAAD6QAAAAIAAAAD",
+        "AAAAAAAAADhEZXNjcmliZSB0aGUgZ2FtZTogdHdvIHBsYXllcnMsIHdpbm5lciB0YWtlcyBib3RoIHN0YWtlcwAAAAlnYW1lX2luZm8AAAAAAAAAAAAAAQAAB9AAAAAIR2FtZUluZm8=",
+        "AAAAAAAAAEhHZXQgdGhlIHN0YXR1cyBvZiBhIHNlc3Npb24sIG9yIGBOb25lYCBpZiBpdCBkb2VzIG5vdCBleGlzdCAob3IgZXhwaXJlZCkAAAASZ2V0X3Nlc3Npb25fc3RhdHVzAAAAAAABAAAAAAAAAApzZXNzaW9uX2lkAAAAAAAEAAAAAQAAA+gAAAfQAAAADVNlc3Npb25TdGF0dXMAAAA=",
+        "AAAAAAAAAK5Db25jZWRlIHRoZSBnYW1lLiBUaGUgb3Bwb25lbnQgd2lucyBhbmQgdGhlIEdhbWUgSHViIHNldHRsZXMgdGhlIHBvaW50cy4KCiMgQXJndW1lbnRzCiogYHNlc3Npb25faWRgIC0gVGhlIHNlc3Npb24gSUQgb2YgdGhlIGdhbWUKKiBgcGxheWVyYCAtIEFkZHJlc3Mgb2YgdGhlIHBsYXllciBjb25jZWRpbmcAAAAAAAdmb3JmZWl0AAAAAAIAAAAAAAAACnNlc3Npb25faWQAAAAAAAQAAAAAAAAABnBsYXllcgAAAAAAEwAAAAEAAAPpAAAAAgAAAAM=",
+        "AAAAAAAAAB1HZXQgdGhlIGN1cnJlbnQgYWRtaW4gYWRkcmVzcwAAAAAAAAlnZXRfYWRtaW4AAAAAAAAAAAAAAQAAABM=",
+        "AAAAAAAAADFHZXQgdGhlIGFkbWluIHByb3Bvc2VkIGJ5IGBwcm9wb3NlX2FkbWluYCwgaWYgYW55AAAAAAAAEWdldF9wZW5kaW5nX2FkbWluAAAAAAAAAAAAAAEAAAPoAAAAEw==",
+        "AAAAAAAAAEJQcm9wb3NlIGEgbmV3IGFkbWluLiBUaGV5IHRha2Ugb3ZlciBvbmNlIHRoZXkgY2FsbApgYWNjZXB0X2FkbWluYC4AAAAAAA1wcm9wb3NlX2FkbWluAAAAAAAAAQAAAAAAAAAJbmV3X2FkbWluAAAAAAAAEwAAAAA=",
+        "AAAAAAAAADRBY2NlcHQgYSBwZW5kaW5nIGFkbWluIHRyYW5zZmVyLiBQZW5kaW5nIGFkbWluIG9ubHkuAAAADGFjY2VwdF9hZG1pbgAAAAAAAAABAAAD6QAAAAIAAAfQAAAAC0NvbW1vbkVycm9yAA==",
+        "AAAAAAAAABpHcmFudCBhIHJvbGUgdG8gYW4gYWNjb3VudAAAAAAACmdyYW50X3JvbGUAAAAAAAIAAAAAAAAABHJvbGUAAAfQAAAABFJvbGUAAAAAAAAAB2FjY291bnQAAAAAEwAAAAA=",
+        "AAAAAAAAAB1SZXZva2UgYSByb2xlIGZyb20gYW4gYWNjb3VudAAAAAAAAAtyZXZva2Vfcm9sZQAAAAACAAAAAAAAAARyb2xlAAAH0AAAAARSb2xlAAAAAAAAAAdhY2NvdW50AAAAABMAAAAA",
+        "AAAAAAAAADtDaGVjayB3aGV0aGVyIGFuIGFjY291bnQgaG9sZHMgYSByb2xlICh0aGUgYWRtaW4gaG9sZHMgYWxsKQAAAAAIaGFzX3JvbGUAAAACAAAAAAAAAARyb2xlAAAH0AAAAARSb2xlAAAAAAAAAAdhY2NvdW50AAAAABMAAAABAAAAAQ==",
+        "AAAAAAAAAChHZXQgdGhlIGN1cnJlbnQgR2FtZUh1YiBjb250cmFjdCBhZGRyZXNzAAAAB2dldF9odWIAAAAAAAAAAAEAAAAT",
+        "AAAAAAAAACJTZXQgYSBuZXcgR2FtZUh1YiBjb250cmFjdCBhZGRyZXNzAAAAAAAHc2V0X2h1YgAAAAABAAAAAAAAAAduZXdfaHViAAAAABMAAAAA",
+        "AAAAAAAAAK1QYXVzZSBnYW1lcGxheTogbmV3IGdhbWVzIGFuZCBtb3ZlcyBmYWlsIHdpdGggYENvbnRyYWN0UGF1c2VkYAp1bnRpbCBgdW5wYXVzZWAgaXMgY2FsbGVkLiBIdWIgY2FsbGJhY2tzIHN0aWxsIHJ1biwgc28gc2Vzc2lvbnMKY2FuIGJlIGNhbmNlbGxlZCBhbmQgcmVmdW5kZWQgdGhyb3VnaCB0aGUgaHViLgAAAAAAAAVwYXVzZQAAAAAAAAAAAAAA",
+        "AAAAAAAAAB1SZXN1bWUgZ2FtZXBsYXkgYWZ0ZXIgYHBhdXNlYAAAAAAAAAd1bnBhdXNlAAAAAAAAAAAA",
+        "AAAAAAAAACRDaGVjayB3aGV0aGVyIHRoZSBjb250cmFjdCBpcyBwYXVzZWQAAAAJaXNfcGF1c2VkAAAAAAAAAAAAAAEAAAAB",
+        "AAAAAAAAADBVcGRhdGUgdGhlIGNvbnRyYWN0IFdBU00gaGFzaCAodXBncmFkZSBjb250cmFjdCkAAAAHdXBncmFkZQAAAAABAAAAAAAAAA1uZXdfd2FzbV9oYXNoAAAAAAAD7gAAACAAAAAA" ]),
       options
     )
   }
   public readonly fromJSON = {
-    hit: this.txFromJSON<Result<void>>,
+    start_game: this.txFromJSON<Result<void>>,
+        reveal_seed: this.txFromJSON<Result<void>>,
+        hit: this.txFromJSON<Result<void>>,
         stick: this.txFromJSON<Result<void>>,
-        get_hub: this.txFromJSON<string>,
-        set_hub: this.txFromJSON<null>,
-        upgrade: this.txFromJSON<null>,
+        reveal_hand: this.txFromJSON<Result<void>>,
+        reveal_winner: this.txFromJSON<Result<Option<string>>>,
         get_game: this.txFromJSON<Result<Game>>,
+        get_hand_value: this.txFromJSON<Result<u32>>,
+        on_session_forfeited: this.txFromJSON<Result<void>>,
+        on_session_cancelled: this.txFromJSON<Result<void>>,
+        game_info: this.txFromJSON<GameInfo>,
+        get_session_status: this.txFromJSON<Option<SessionStatus>>,
+        forfeit: this.txFromJSON<Result<void>>,
         get_admin: this.txFromJSON<string>,
-        set_admin: this.txFromJSON<null>,
-        start_game: this.txFromJSON<Result<void>>,
-        reveal_winner: this.txFromJSON<Result<string>>,
-        get_hand_value: this.txFromJSON<Result<u32>>
+        get_pending_admin: this.txFromJSON<Option<string>>,
+        propose_admin: this.txFromJSON<null>,
+        accept_admin: this.txFromJSON<Result<void>>,
+        grant_role: this.txFromJSON<null>,
+        revoke_role: this.txFromJSON<null>,
+        has_role: this.txFromJSON<boolean>,
+        get_hub: this.txFromJSON<string>,
+        set_hub: this.txFromJSON<null>,
+        pause: this.txFromJSON<null>,
+        unpause: this.txFromJSON<null>,
+        is_paused: this.txFromJSON<boolean>,
+        upgrade: this.txFromJSON<null>
   }
 }
\ No newline at end of file
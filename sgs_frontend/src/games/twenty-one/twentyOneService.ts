@@ -1,12 +1,21 @@
-import { Client as TwentyOneClient } from './bindings';
+import { Client as TwentyOneClient, type Game } from './bindings';
 import { TWENTY_ONE_CONTRACT, NETWORK_PASSPHRASE, RPC_URL, DEFAULT_METHOD_OPTIONS, DEFAULT_AUTH_TTL_MINUTES, MULTI_SIG_AUTH_TTL_MINUTES } from '@/utils/constants';
 import { contract, Address, authorizeEntry, xdr } from '@stellar/stellar-sdk';
 import { Buffer } from 'buffer';
 import { signAndSendViaLaunchtube } from '@/utils/transactionHelper';
 import { calculateValidUntilLedger } from '@/utils/ledgerUtils';
+import { randomSecret, keccak256, u32Bytes, addressBytes, saveSecret, loadSecret, clearSecret } from '@/utils/commitUtils';
 
 type ClientOptions = contract.ClientOptions;
 
+/**
+ * Secrets behind a player's seed commitment, kept on this device (hex)
+ */
+interface HandSecrets {
+  seed: string;
+  handSecret: string;
+}
+
 /**
  * Service for interacting with the Twenty-One game contract
  */
@@ -43,7 +52,7 @@ export class TwentyOneService {
    * Get game state
    * Returns null if game doesn't exist (instead of throwing)
    */
-  async getGame(sessionId: number): Promise<any | null> {
+  async getGame(sessionId: number): Promise<Game | null> {
     try {
       const tx = await this.baseClient.get_game({ session_id: sessionId });
       const result = await tx.simulate();
@@ -86,6 +95,44 @@ export class TwentyOneService {
     }
   }
 
+  /**
+   * Create a player's seed commitment for start_game.
+   * A fresh deck seed and hand secret are saved on this device; only
+   * keccak256(seed || hand_commitment || player) is returned, where
+   * hand_commitment = keccak256(hand_secret || player).
+   */
+  private createSeedCommitment(sessionId: number, player: string): Buffer {
+    const seed = randomSecret();
+    const handSecret = randomSecret();
+    const handCommitment = keccak256(handSecret, addressBytes(player));
+    saveSecret<HandSecrets>(TWENTY_ONE_CONTRACT, sessionId, player, {
+      seed: seed.toString('hex'),
+      handSecret: handSecret.toString('hex'),
+    });
+    return keccak256(seed, handCommitment, addressBytes(player));
+  }
+
+  /**
+   * Load the secrets saved by createSeedCommitment
+   */
+  private loadHandSecrets(sessionId: number, player: string): { seed: Buffer; handSecret: Buffer } {
+    const secrets = loadSecret<HandSecrets>(TWENTY_ONE_CONTRACT, sessionId, player);
+    if (!secrets) {
+      throw new Error('Your seed for this session was not committed from this device, so your hand cannot be played here');
+    }
+    return {
+      seed: Buffer.from(secrets.seed, 'hex'),
+      handSecret: Buffer.from(secrets.handSecret, 'hex'),
+    };
+  }
+
+  /**
+   * Whether this device holds the secrets behind the player's seed commitment
+   */
+  hasSavedSecrets(sessionId: number, player: string): boolean {
+    return loadSecret<HandSecrets>(TWENTY_ONE_CONTRACT, sessionId, player) !== null;
+  }
+
   /**
    * STEP 1 (Player 1): Prepare a start game transaction and export signed auth entry
    * Uses extended TTL (60 minutes) for multi-sig flow
@@ -107,12 +154,16 @@ export class TwentyOneService {
       publicKey: player2,
     });
 
+    // Player 2 commits to their own seed when they import the auth entry;
+    // Player 1's auth entry does not cover Player 2's commitment
     const tx = await buildClient.start_game({
       session_id: sessionId,
       player1,
       player2,
       player1_points: player1Points,
       player2_points: player2Points,
+      player1_seed_commitment: this.createSeedCommitment(sessionId, player1),
+      player2_seed_commitment: Buffer.alloc(32),
     }, DEFAULT_METHOD_OPTIONS);
 
     console.log('[prepareStartGame] Transaction built and simulated');
@@ -225,6 +276,8 @@ export class TwentyOneService {
       player2,
       player1_points: gameParams.player1Points,
       player2_points: player2Points,
+      player1_seed_commitment: gameParams.player1SeedCommitment,
+      player2_seed_commitment: this.createSeedCommitment(gameParams.sessionId, player2),
     }, DEFAULT_METHOD_OPTIONS);
 
     // Import Player 1's signed auth entry
@@ -266,6 +319,7 @@ export class TwentyOneService {
     sessionId: number;
     player1: string;
     player1Points: bigint;
+    player1SeedCommitment: Buffer;
   } {
     try {
       const authEntry = xdr.SorobanAuthorizationEntry.fromXDR(authEntryXDR, 'base64');
@@ -274,6 +328,7 @@ export class TwentyOneService {
       // - signer address (from credentials)
       // - session_id (arg 0)
       // - signer points (arg 1)
+      // - signer seed commitment (arg 2)
       const credentials = authEntry.credentials();
       if (credentials.switch().name !== 'sorobanCredentialsAddress') {
         throw new Error(`Unsupported credentials type: ${credentials.switch().name}`);
@@ -290,17 +345,19 @@ export class TwentyOneService {
       }
 
       const args = contractFn.args();
-      if (args.length !== 2) {
-        throw new Error(`Invalid number of arguments: ${args.length}. Expected 2`);
+      if (args.length !== 3) {
+        throw new Error(`Invalid number of arguments: ${args.length}. Expected 3`);
       }
 
       const sessionId = args[0].u32();
       const player1Points = args[1].i128().lo().toBigInt();
+      const player1SeedCommitment = Buffer.from(args[2].bytes());
 
       return {
         sessionId,
         player1,
         player1Points,
+        player1SeedCommitment,
       };
     } catch (err) {
       console.error('[parseAuthEntry] Error parsing auth entry:', err);
@@ -308,6 +365,90 @@ export class TwentyOneService {
     }
   }
 
+  /**
+   * Reveal the deck seed committed from this device.
+   * The opening cards are dealt once both players have revealed.
+   */
+  async revealSeed(
+    sessionId: number,
+    player: string,
+    signer: Pick<contract.ClientOptions, 'signTransaction' | 'signAuthEntry'>
+  ) {
+    const { seed, handSecret } = this.loadHandSecrets(sessionId, player);
+
+    const client = this.createSigningClient(player, signer);
+    const tx = await client.reveal_seed({
+      session_id: sessionId,
+      player,
+      seed,
+      hand_commitment: keccak256(handSecret, addressBytes(player)),
+    }, DEFAULT_METHOD_OPTIONS);
+
+    const validUntilLedgerSeq = await calculateValidUntilLedger(RPC_URL, DEFAULT_AUTH_TTL_MINUTES);
+    const sentTx = await signAndSendViaLaunchtube(
+      tx,
+      DEFAULT_METHOD_OPTIONS.timeoutInSeconds,
+      validUntilLedgerSeq
+    );
+    return sentTx.result;
+  }
+
+  /**
+   * Derive the player's own hidden cards on this device, as the contract does:
+   * card i is keccak256(deck_seed || hand_secret || i || entropy_i), first 8 bytes
+   * as a big-endian u64, mod 13, plus 1, where
+   * deck_seed = keccak256(session_id || player1_seed || player2_seed).
+   * The hand secret never leaves this device before revealHand.
+   * Returns null until both seeds are revealed or if this device has no hand secret.
+   */
+  deriveHand(sessionId: number, game: Game, player: string): number[] | null {
+    if (!game.player1_seed || !game.player2_seed || !this.hasSavedSecrets(sessionId, player)) {
+      return null;
+    }
+
+    const { handSecret } = this.loadHandSecrets(sessionId, player);
+    const deckSeed = keccak256(u32Bytes(sessionId), game.player1_seed, game.player2_seed);
+    const draws = player === game.player1 ? game.player1_draws : game.player2_draws;
+
+    return draws.map((entropy, i) => {
+      const cardSeed = keccak256(deckSeed, handSecret, u32Bytes(i), entropy);
+      return Number(cardSeed.readBigUInt64BE(0) % 13n) + 1;
+    });
+  }
+
+  /**
+   * Reveal the player's hand once both players have stuck
+   */
+  async revealHand(
+    sessionId: number,
+    player: string,
+    signer: Pick<contract.ClientOptions, 'signTransaction' | 'signAuthEntry'>
+  ) {
+    const { handSecret } = this.loadHandSecrets(sessionId, player);
+    const game = await this.getGame(sessionId);
+    const hand = game && this.deriveHand(sessionId, game, player);
+    if (!hand) {
+      throw new Error('Failed to read your hand');
+    }
+
+    const client = this.createSigningClient(player, signer);
+    const tx = await client.reveal_hand({
+      session_id: sessionId,
+      player,
+      hand_secret: handSecret,
+      hand: Buffer.from(hand),
+    }, DEFAULT_METHOD_OPTIONS);
+
+    const validUntilLedgerSeq = await calculateValidUntilLedger(RPC_URL, DEFAULT_AUTH_TTL_MINUTES);
+    const sentTx = await signAndSendViaLaunchtube(
+      tx,
+      DEFAULT_METHOD_OPTIONS.timeoutInSeconds,
+      validUntilLedgerSeq
+    );
+    clearSecret(TWENTY_ONE_CONTRACT, sessionId, player);
+    return sentTx.result;
+  }
+
   /**
    * Player hits (draws a card)
    */
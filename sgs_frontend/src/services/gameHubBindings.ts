@@ -0,0 +1,936 @@
+import { Buffer } from "buffer";
+import { Address } from "@stellar/stellar-sdk";
+import {
+  AssembledTransaction,
+  Client as ContractClient,
+  ClientOptions as ContractClientOptions,
+  MethodOptions,
+  Result,
+  Spec as ContractSpec,
+} from "@stellar/stellar-sdk/contract";
+import type {
+  u32,
+  i32,
+  u64,
+  i64,
+  u128,
+  i128,
+  u256,
+  i256,
+  Option,
+  Timepoint,
+  Duration,
+} from "@stellar/stellar-sdk/contract";
+export * from "@stellar/stellar-sdk";
+export * as contract from "@stellar/stellar-sdk/contract";
+export * as rpc from "@stellar/stellar-sdk/rpc";
+
+if (typeof window !== "undefined") {
+  //@ts-ignore Buffer exists
+  window.Buffer = window.Buffer || Buffer;
+}
+
+
+export const networks = {
+  testnet: {
+    networkPassphrase: "Test SDF Network ; September 2015",
+    contractId: "CB4VZAT2U3UC6XFK3N23SKRF2NDCMP3QHJYMCHHFMZO7MRQO6DQ2EMYG",
+  }
+} as const
+
+
+export const Errors = {
+  1: {message:"InvalidAmount"},
+  2: {message:"InsufficientBalance"},
+  3: {message:"SessionNotFound"},
+  4: {message:"SessionAlreadyExists"},
+  5: {message:"SessionAlreadyEnded"},
+  6: {message:"GameNotRegistered"},
+  7: {message:"NotPlayer"},
+  8: {message:"InvalidParticipants"},
+  9: {message:"InvalidPlacements"},
+  10: {message:"InvalidOutcome"},
+  11: {message:"SeasonNotFound"},
+  12: {message:"InvalidSeason"},
+  13: {message:"SeasonClosed"},
+  14: {message:"InvalidFee"},
+  15: {message:"SessionNotExpired"},
+  16: {message:"InsufficientFees"},
+  17: {message:"GameDisabled"},
+  18: {message:"StakeTooLow"},
+  19: {message:"StakeTooHigh"},
+  20: {message:"UnequalStakes"},
+  21: {message:"NegativeStake"},
+  22: {message:"InvalidConfig"},
+  23: {message:"SessionsPaused"},
+  24: {message:"NotPaused"},
+  25: {message:"SessionNotReserved"},
+  26: {message:"AchievementNotFound"},
+  27: {message:"InvalidAchievement"},
+  28: {message:"TooManyAchievements"},
+  29: {message:"NoPendingAdmin"}
+}
+
+/**
+ * How a session ended. Determines how the escrowed pot is settled.
+ */
+export type GameOutcome = {tag: "Player1Won", values: void} | {tag: "Player2Won", values: void} | {tag: "Draw", values: void} | {tag: "Cancelled", values: void} | {tag: "Forfeit", values: readonly [string]};
+
+export type SessionStatus = {tag: "Active", values: void} | {tag: "Ended", values: readonly [GameOutcome]} | {tag: "Placed", values: readonly [Array<string>]};
+
+/**
+ * Per-game rules `start_game` and `start_multiplayer_game` enforce on stakes.
+ * Games without a config use `GameConfig::default()`, which accepts any
+ * non-negative stake.
+ */
+export interface GameConfig {
+  /**
+   * Negative stakes are accepted as a free entry: nothing is escrowed
+   */
+  allow_negative: boolean;
+  /**
+   * New sessions can be opened; running sessions always settle
+   */
+  enabled: boolean;
+  /**
+   * Every player must stake the same amount
+   */
+  equal_stakes: boolean;
+  max_stake: i128;
+  min_stake: i128;
+}
+
+/**
+ * A player's Elo rating in one game
+ */
+export interface Rating {
+  /**
+   * Rated sessions played; players with few games move faster
+   */
+  games_played: u32;
+  rating: i32;
+}
+
+/**
+ * An admin-defined competition window. Sessions settled between
+ * `start_ledger` and `end_ledger` (inclusive) count towards it.
+ */
+export interface Season {
+  closed: boolean;
+  end_ledger: u32;
+  /**
+   * Total points staked in those sessions
+   */
+  points_wagered: i128;
+  /**
+   * Sessions settled during the season, across all games
+   */
+  sessions: u32;
+  start_ledger: u32;
+}
+
+/**
+ * A player's results in one game during one season
+ */
+export interface SeasonStats {
+  losses: u32;
+  /**
+   * Points won minus points staked
+   */
+  net_points: i128;
+  sessions: u32;
+  wins: u32;
+}
+
+export interface LeaderboardEntry {
+  net_points: i128;
+  player: string;
+}
+
+/**
+ * A player's identity and lifetime record across every game on the hub
+ */
+export interface PlayerProfile {
+  /**
+   * Content hash of the avatar image
+   */
+  avatar_hash: Option<Buffer>;
+  /**
+   * Hash of the display name; the name itself lives off-chain
+   */
+  display_name_hash: Option<Buffer>;
+  /**
+   * Settled sessions played, across all games (cancellations excluded)
+   */
+  sessions: u32;
+  /**
+   * Sessions won outright, across all games
+   */
+  wins: u32;
+  xp: u64;
+}
+
+/**
+ * Counter an achievement is measured on
+ */
+export type AchievementMetric = {tag: "Wins", values: void} | {tag: "Sessions", values: void} | {tag: "Xp", values: void} | {tag: "Stat", values: readonly [string]};
+
+/**
+ * Admin-defined milestone. A player earns it once `metric` reaches
+ * `threshold`. `game_id` scopes `Wins` to one game and is required for
+ * `Stat`; `Sessions` and `Xp` are always cross-game.
+ */
+export interface Achievement {
+  game_id: Option<string>;
+  metric: AchievementMetric;
+  threshold: i128;
+}
+
+/**
+ * A soulbound record that a player earned an achievement
+ */
+export interface Badge {
+  achievement_id: u32;
+  earned_ledger: u32;
+}
+
+/**
+ * Permanent summary of an ended session, kept for match history
+ */
+export interface MatchRecord {
+  end_ledger: u32;
+  game_id: string;
+  /**
+   * Every player with the points they staked, in seat order
+   */
+  participants: Array<readonly [string, i128]>;
+  /**
+   * What each player was paid out, in seat order (after any fee)
+   */
+  payouts: Array<i128>;
+  result: SessionStatus;
+  session_id: u32;
+}
+
+/**
+ * Hub-side record of a game session. Stakes stay locked while `Active`.
+ */
+export interface Session {
+  /**
+   * Last ledger before `claim_timeout` can be used
+   */
+  deadline_ledger: u32;
+  end_ledger: u32;
+  /**
+   * House fee taken from winning payouts, fixed when the session starts
+   */
+  fee_bps: u32;
+  game_id: string;
+  /**
+   * Every player with the points they staked, in seat order
+   */
+  participants: Array<readonly [string, i128]>;
+  start_ledger: u32;
+  status: SessionStatus;
+  /**
+   * Token escrowed for this session, or `None` for hub points
+   */
+  token: Option<string>;
+}
+
+/**
+ * A session ID handed out by `reserve_session` but not yet started
+ */
+export interface Reservation {
+  game_id: string;
+  /**
+   * Player who reserved the ID; they must be seated in the session
+   */
+  reserver: string;
+}
+
+export type DataKey = {tag: "Admin", values: void} | {tag: "PendingAdmin", values: void} | {tag: "Balance", values: readonly [string]} | {tag: "Locked", values: readonly [string]} | {tag: "Session", values: readonly [u32]} | {tag: "NextSessionId", values: void} | {tag: "Reservation", values: readonly [u32]} | {tag: "RegisteredGame", values: readonly [string]} | {tag: "GameConfig", values: readonly [string]} | {tag: "Paused", values: void} | {tag: "GamePaused", values: readonly [string]} | {tag: "Rating", values: readonly [string, string]} | {tag: "GameToken", values: readonly [string]} | {tag: "GameFee", values: readonly [string]} | {tag: "Treasury", values: void} | {tag: "AccruedFees", values: readonly [Option<string>]} | {tag: "SeasonCount", values: void} | {tag: "Season", values: readonly [u32]} | {tag: "SeasonStats", values: readonly [u32, string, string]} | {tag: "Leaderboard", values: readonly [u32, string]} | {tag: "Profile", values: readonly [string]} | {tag: "GameWins", values: readonly [string, string]} | {tag: "AchievementCount", values: void} | {tag: "Achievement", values: readonly [u32]} | {tag: "ActiveAchievements", values: void} | {tag: "Badges", values: readonly [string]} | {tag: "PlayerStat", values: readonly [string, string, string]} | {tag: "MatchRecord", values: readonly [u32]} | {tag: "PlayerMatchCount", values: readonly [string]} | {tag: "PlayerMatch", values: readonly [string, u32]} | {tag: "GameMatchCount", values: readonly [string]} | {tag: "GameMatch", values: readonly [string, u32]};
+
+export interface Client {
+  /**
+   * Construct and simulate a start_game transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Start a game session and lock both players' points in escrow.
+   * Fails unless one of the players reserved `session_id` for this game with
+   * `reserve_session` and no session with this ID was started yet.
+   * 
+   * **Auth:** Requires authorization from `game_id`, which must be registered.
+   * 
+   * # Arguments
+   * * `game_id` - Address of the game contract calling this method
+   * * `session_id` - ID a player reserved for this game with `reserve_session`
+   * * `player1` - Address of first player
+   * * `player2` - Address of second player
+   * * `player1_points` - Points locked from player 1's balance
+   * * `player2_points` - Points locked from player 2's balance
+   */
+  start_game: ({game_id, session_id, player1, player2, player1_points, player2_points}: {game_id: string, session_id: u32, player1: string, player2: string, player1_points: i128, player2_points: i128}, options?: MethodOptions) => Promise<AssembledTransaction<Result<void>>>
+
+  /**
+   * Construct and simulate a start_multiplayer_game transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Start a session with any number of players (2 to 16) sharing one pot.
+   * Each participant's stake is locked from their balance.
+   * 
+   * **Auth:** Requires authorization from `game_id`, which must be registered.
+   * 
+   * # Arguments
+   * * `game_id` - Address of the game contract calling this method
+   * * `session_id` - ID a player reserved for this game with `reserve_session`
+   * * `participants` - Each player with the points locked from their balance
+   */
+  start_multiplayer_game: ({game_id, session_id, participants}: {game_id: string, session_id: u32, participants: Array<readonly [string, i128]>}, options?: MethodOptions) => Promise<AssembledTransaction<Result<void>>>
+
+  /**
+   * Construct and simulate a end_game transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * End a game session and pay the pot to the winner.
+   * Fails for unknown sessions and for sessions that were already ended.
+   * 
+   * **Auth:** Requires authorization from the game contract that started the session.
+   * 
+   * # Arguments
+   * * `session_id` - The game session being ended
+   * * `player1_won` - True if player1 won, false if player2 won
+   */
+  end_game: ({session_id, player1_won}: {session_id: u32, player1_won: boolean}, options?: MethodOptions) => Promise<AssembledTransaction<Result<void>>>
+
+  /**
+   * Construct and simulate a end_game_with_outcome transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * End a game session with any outcome and settle the pot accordingly.
+   * 
+   * Settlement rules:
+   * - `Player1Won` / `Player2Won`: the winner takes the whole pot
+   * - `Draw` / `Cancelled`: each player gets their own stake back
+   * - `Forfeit(player)`: the opponent of `player` takes the whole pot
+   * 
+   * **Auth:** Requires authorization from the game contract that started the session.
+   * 
+   * # Arguments
+   * * `session_id` - The game session being ended
+   * * `outcome` - How the session ended
+   */
+  end_game_with_outcome: ({session_id, outcome}: {session_id: u32, outcome: GameOutcome}, options?: MethodOptions) => Promise<AssembledTransaction<Result<void>>>
+
+  /**
+   * Construct and simulate a end_game_with_placements transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * End a session by finishing position and split the pot by placement.
+   * 
+   * `placements` lists every participant exactly once, first place first.
+   * The pot is split using the hub's `PAYOUT_SPLITS_BPS` table:
+   * - 2 players: winner takes all
+   * - 3-4 players: 70% / 30%
+   * - 5+ players: 50% / 30% / 20%
+   * 
+   * **Auth:** Requires authorization from the game contract that started the session.
+   * 
+   * # Arguments
+   * * `session_id` - The game session being ended
+   * * `placements` - Participants ordered from first to last place
+   */
+  end_game_with_placements: ({session_id, placements}: {session_id: u32, placements: Array<string>}, options?: MethodOptions) => Promise<AssembledTransaction<Result<void>>>
+
+  /**
+   * Construct and simulate a claim_timeout transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Settle a session whose game has stopped progressing.
+   * 
+   * Once the session's `deadline_ledger` has passed, a player in a
+   * two-player session can claim for `TIMEOUT_GRACE_LEDGERS`: their opponent
+   * forfeits, provided the game contract accepts `on_session_forfeited` for
+   * that opponent. Players in larger sessions have no single opponent to
+   * blame and must wait out the grace period. After the grace period anyone
+   * can claim, and the session is cancelled with every stake refunded; the
+   * game is told through `on_session_cancelled` on a best-effort basis so it
+   * cannot block refunds.
+   * 
+   * **Auth:** Requires authorization from `claimant`.
+   * 
+   * # Arguments
+   * * `session_id` - The stalled game session
+   * * `claimant` - Address claiming the timeout
+   * 
+   * # Returns
+   * * `GameOutcome` - `Forfeit(opponent)` or `Cancelled`
+   */
+  claim_timeout: ({session_id, claimant}: {session_id: u32, claimant: string}, options?: MethodOptions) => Promise<AssembledTransaction<Result<GameOutcome>>>
+
+  /**
+   * Construct and simulate a add_game transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Allow a game contract to open sessions. Admin only.
+   */
+  add_game: ({game_id}: {game_id: string}, options?: MethodOptions) => Promise<AssembledTransaction<null>>
+
+  /**
+   * Construct and simulate a remove_game transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Stop a game contract from opening new sessions. Admin only.
+   * Sessions it already started can still be ended.
+   */
+  remove_game: ({game_id}: {game_id: string}, options?: MethodOptions) => Promise<AssembledTransaction<null>>
+
+  /**
+   * Construct and simulate a is_game_registered transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Check whether a game contract is on the allowlist
+   */
+  is_game_registered: ({game_id}: {game_id: string}, options?: MethodOptions) => Promise<AssembledTransaction<boolean>>
+
+  /**
+   * Construct and simulate a set_game_config transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Set the stake rules for a registered game. Admin only.
+   * Applies to sessions opened from now on.
+   * 
+   * # Arguments
+   * * `game_id` - Registered game contract
+   * * `config` - Stake limits and enabled flag
+   */
+  set_game_config: ({game_id, config}: {game_id: string, config: GameConfig}, options?: MethodOptions) => Promise<AssembledTransaction<Result<void>>>
+
+  /**
+   * Construct and simulate a get_game_config transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Get a game's stake rules (the default config if none was set)
+   */
+  get_game_config: ({game_id}: {game_id: string}, options?: MethodOptions) => Promise<AssembledTransaction<GameConfig>>
+
+  /**
+   * Construct and simulate a pause transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Stop new sessions from opening, for one game or for the whole hub.
+   * Admin only. Running sessions can still be settled by their game or
+   * cancelled with `cancel_paused_session`.
+   * 
+   * # Arguments
+   * * `game_id` - Game to pause, or `None` to pause every game
+   */
+  pause: ({game_id}: {game_id: Option<string>}, options?: MethodOptions) => Promise<AssembledTransaction<null>>
+
+  /**
+   * Construct and simulate a unpause transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Lift a pause set with `pause`. Admin only. Unpausing the hub does not
+   * lift per-game pauses.
+   * 
+   * # Arguments
+   * * `game_id` - Game to unpause, or `None` to lift the global pause
+   */
+  unpause: ({game_id}: {game_id: Option<string>}, options?: MethodOptions) => Promise<AssembledTransaction<null>>
+
+  /**
+   * Construct and simulate a is_paused transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Check whether sessions are paused, for one game (counting the global
+   * pause) or, with `None`, for the whole hub
+   */
+  is_paused: ({game_id}: {game_id: Option<string>}, options?: MethodOptions) => Promise<AssembledTransaction<boolean>>
+
+  /**
+   * Construct and simulate a cancel_paused_session transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Cancel a running session of a paused game, refunding every stake.
+   * The game is told through `on_session_cancelled` on a best-effort basis.
+   * 
+   * **Auth:** Requires authorization from `caller`, who must be a player in
+   * the session or the admin.
+   * 
+   * # Arguments
+   * * `session_id` - The session to cancel
+   * * `caller` - Player or admin requesting the refund
+   */
+  cancel_paused_session: ({session_id, caller}: {session_id: u32, caller: string}, options?: MethodOptions) => Promise<AssembledTransaction<Result<void>>>
+
+  /**
+   * Construct and simulate a set_game_token transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Stake a game's future sessions in `token` instead of hub points. Admin only.
+   * Sessions already running keep the mode they started with.
+   * 
+   * # Arguments
+   * * `game_id` - Game contract whose sessions use the token
+   * * `token` - Token contract (e.g. a Stellar Asset Contract)
+   */
+  set_game_token: ({game_id, token}: {game_id: string, token: string}, options?: MethodOptions) => Promise<AssembledTransaction<Result<void>>>
+
+  /**
+   * Construct and simulate a remove_game_token transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Switch a game's future sessions back to hub points. Admin only.
+   */
+  remove_game_token: ({game_id}: {game_id: string}, options?: MethodOptions) => Promise<AssembledTransaction<null>>
+
+  /**
+   * Construct and simulate a get_game_token transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Get the token a game's sessions are staked in, if any
+   */
+  get_game_token: ({game_id}: {game_id: string}, options?: MethodOptions) => Promise<AssembledTransaction<Option<string>>>
+
+  /**
+   * Construct and simulate a set_game_fee transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Set the house fee taken from a game's winning payouts. Admin only.
+   * Sessions already running keep the fee they started with.
+   * 
+   * # Arguments
+   * * `game_id` - Game contract the fee applies to
+   * * `fee_bps` - Share of each winning payout kept by the hub (max 10_000)
+   */
+  set_game_fee: ({game_id, fee_bps}: {game_id: string, fee_bps: u32}, options?: MethodOptions) => Promise<AssembledTransaction<Result<void>>>
+
+  /**
+   * Construct and simulate a get_game_fee transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Get a game's house fee in basis points (0 if none was set)
+   */
+  get_game_fee: ({game_id}: {game_id: string}, options?: MethodOptions) => Promise<AssembledTransaction<u32>>
+
+  /**
+   * Construct and simulate a set_treasury transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Set the address fee withdrawals are paid to. Admin only.
+   */
+  set_treasury: ({treasury}: {treasury: string}, options?: MethodOptions) => Promise<AssembledTransaction<null>>
+
+  /**
+   * Construct and simulate a get_treasury transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Get the treasury address (the admin until one is set)
+   */
+  get_treasury: (options?: MethodOptions) => Promise<AssembledTransaction<string>>
+
+  /**
+   * Construct and simulate a get_accrued_fees transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Get the fees accrued and not yet withdrawn in one asset
+   * 
+   * # Arguments
+   * * `token` - Token contract, or `None` for hub points
+   */
+  get_accrued_fees: ({token}: {token: Option<string>}, options?: MethodOptions) => Promise<AssembledTransaction<i128>>
+
+  /**
+   * Construct and simulate a withdraw_fees transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Pay accrued fees out to the treasury. Admin only. Token fees are
+   * transferred; point fees are credited to the treasury's points balance.
+   * 
+   * # Arguments
+   * * `token` - Token contract, or `None` for hub points
+   * * `amount` - Amount to withdraw (must be positive)
+   */
+  withdraw_fees: ({token, amount}: {token: Option<string>, amount: i128}, options?: MethodOptions) => Promise<AssembledTransaction<Result<void>>>
+
+  /**
+   * Construct and simulate a add_points transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Credit points to a player's available balance. Admin only.
+   * 
+   * # Arguments
+   * * `player` - Player receiving the points
+   * * `amount` - Points to add (must be positive)
+   */
+  add_points: ({player, amount}: {player: string, amount: i128}, options?: MethodOptions) => Promise<AssembledTransaction<Result<void>>>
+
+  /**
+   * Construct and simulate a get_balance transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Get a player's available (unlocked) points balance
+   */
+  get_balance: ({player}: {player: string}, options?: MethodOptions) => Promise<AssembledTransaction<i128>>
+
+  /**
+   * Construct and simulate a get_locked transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Get the total points a player currently has locked in active sessions
+   */
+  get_locked: ({player}: {player: string}, options?: MethodOptions) => Promise<AssembledTransaction<i128>>
+
+  /**
+   * Construct and simulate a reserve_session transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Reserve the next session ID for a game. The ID must be passed to the
+   * game's `start_game` within `RESERVATION_TTL_LEDGERS`, and only a session
+   * of that game with `reserver` among its players can start with it.
+   * 
+   * **Auth:** Requires authorization from `reserver`.
+   * 
+   * # Arguments
+   * * `game_id` - Registered game contract the session will be played in
+   * * `reserver` - Player who will take part in the session
+   * 
+   * # Returns
+   * * `u32` - The reserved session ID
+   */
+  reserve_session: ({game_id, reserver}: {game_id: string, reserver: string}, options?: MethodOptions) => Promise<AssembledTransaction<Result<u32>>>
+
+  /**
+   * Construct and simulate a next_session_id transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Get the ID the next `reserve_session` call will hand out
+   */
+  next_session_id: (options?: MethodOptions) => Promise<AssembledTransaction<u32>>
+
+  /**
+   * Construct and simulate a get_reservation transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Get the reservation for a session ID, if it is still live
+   */
+  get_reservation: ({session_id}: {session_id: u32}, options?: MethodOptions) => Promise<AssembledTransaction<Option<Reservation>>>
+
+  /**
+   * Construct and simulate a get_session transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Get the hub's record of a session (active or ended)
+   */
+  get_session: ({session_id}: {session_id: u32}, options?: MethodOptions) => Promise<AssembledTransaction<Result<Session>>>
+
+  /**
+   * Construct and simulate a get_rating transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Get a player's Elo rating in a game (1500 if they have not played it)
+   */
+  get_rating: ({game_id, player}: {game_id: string, player: string}, options?: MethodOptions) => Promise<AssembledTransaction<Rating>>
+
+  /**
+   * Construct and simulate a create_season transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Define the next season. Admin only.
+   * Seasons are numbered from 1 and must start after the previous one ends.
+   * 
+   * # Arguments
+   * * `start_ledger` - First ledger of the season
+   * * `end_ledger` - Last ledger of the season
+   * 
+   * # Returns
+   * * `u32` - The new season's ID
+   */
+  create_season: ({start_ledger, end_ledger}: {start_ledger: u32, end_ledger: u32}, options?: MethodOptions) => Promise<AssembledTransaction<Result<u32>>>
+
+  /**
+   * Construct and simulate a close_season transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Freeze a season's stats and leaderboards and emit its summary. Admin only.
+   * Sessions settled afterwards no longer count towards it, even if its
+   * window has not ended yet.
+   */
+  close_season: ({season_id}: {season_id: u32}, options?: MethodOptions) => Promise<AssembledTransaction<Result<void>>>
+
+  /**
+   * Construct and simulate a get_season transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Get a season's definition and running totals
+   */
+  get_season: ({season_id}: {season_id: u32}, options?: MethodOptions) => Promise<AssembledTransaction<Result<Season>>>
+
+  /**
+   * Construct and simulate a get_current_season transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Get the ID of the season open at the current ledger, if any
+   */
+  get_current_season: (options?: MethodOptions) => Promise<AssembledTransaction<Option<u32>>>
+
+  /**
+   * Construct and simulate a get_season_stats transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Get a player's results in one game during one season
+   */
+  get_season_stats: ({season_id, game_id, player}: {season_id: u32, game_id: string, player: string}, options?: MethodOptions) => Promise<AssembledTransaction<SeasonStats>>
+
+  /**
+   * Construct and simulate a get_leaderboard transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Get the top players by net points in one game during one season
+   */
+  get_leaderboard: ({season_id, game_id}: {season_id: u32, game_id: string}, options?: MethodOptions) => Promise<AssembledTransaction<Array<LeaderboardEntry>>>
+
+  /**
+   * Construct and simulate a set_profile transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Set the cosmetic fields of the caller's profile. Lifetime stats and XP
+   * are kept by the hub and cannot be changed here.
+   * 
+   * **Auth:** Requires authorization from `player`.
+   * 
+   * # Arguments
+   * * `player` - Player whose profile is updated
+   * * `display_name_hash` - Hash of the display name, or `None` to clear it
+   * * `avatar_hash` - Content hash of the avatar, or `None` to clear it
+   */
+  set_profile: ({player, display_name_hash, avatar_hash}: {player: string, display_name_hash: Option<Buffer>, avatar_hash: Option<Buffer>}, options?: MethodOptions) => Promise<AssembledTransaction<null>>
+
+  /**
+   * Construct and simulate a get_profile transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Get a player's profile (empty if they have never played or set one)
+   */
+  get_profile: ({player}: {player: string}, options?: MethodOptions) => Promise<AssembledTransaction<PlayerProfile>>
+
+  /**
+   * Construct and simulate a get_game_wins transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Number of sessions a player has won outright in one game
+   */
+  get_game_wins: ({game_id, player}: {game_id: string, player: string}, options?: MethodOptions) => Promise<AssembledTransaction<u32>>
+
+  /**
+   * Construct and simulate a define_achievement transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Define a new achievement. Admin only. Players are checked against it
+   * the next time one of its counters changes.
+   * 
+   * # Returns
+   * * `u32` - ID of the new achievement (IDs start at 1)
+   */
+  define_achievement: ({achievement}: {achievement: Achievement}, options?: MethodOptions) => Promise<AssembledTransaction<Result<u32>>>
+
+  /**
+   * Construct and simulate a retire_achievement transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Stop awarding an achievement. Admin only. Badges already earned stay.
+   */
+  retire_achievement: ({achievement_id}: {achievement_id: u32}, options?: MethodOptions) => Promise<AssembledTransaction<Result<void>>>
+
+  /**
+   * Construct and simulate a get_achievement transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Get an achievement definition (active or retired)
+   */
+  get_achievement: ({achievement_id}: {achievement_id: u32}, options?: MethodOptions) => Promise<AssembledTransaction<Result<Achievement>>>
+
+  /**
+   * Construct and simulate a list_achievements transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * IDs of the achievements still being awarded
+   */
+  list_achievements: (options?: MethodOptions) => Promise<AssembledTransaction<Array<u32>>>
+
+  /**
+   * Construct and simulate a report_stat transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Add to a player's running total of a game-specific stat, and award any
+   * achievement on that stat the new total reaches.
+   * 
+   * **Auth:** Requires authorization from the game contract that started the session.
+   * 
+   * # Arguments
+   * * `session_id` - Session the stat was earned in (active or ended)
+   * * `player` - Participant the stat belongs to
+   * * `key` - Stat name, defined by the game
+   * * `value` - Amount to add (must be positive)
+   */
+  report_stat: ({session_id, player, key, value}: {session_id: u32, player: string, key: string, value: i128}, options?: MethodOptions) => Promise<AssembledTransaction<Result<void>>>
+
+  /**
+   * Construct and simulate a get_stat transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Get a player's running total of a game-reported stat
+   */
+  get_stat: ({game_id, player, key}: {game_id: string, player: string, key: string}, options?: MethodOptions) => Promise<AssembledTransaction<i128>>
+
+  /**
+   * Construct and simulate a get_badges transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Get every badge a player has earned, oldest first
+   */
+  get_badges: ({player}: {player: string}, options?: MethodOptions) => Promise<AssembledTransaction<Array<Badge>>>
+
+  /**
+   * Construct and simulate a has_badge transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Check whether a player has earned an achievement
+   */
+  has_badge: ({player, achievement_id}: {player: string, achievement_id: u32}, options?: MethodOptions) => Promise<AssembledTransaction<boolean>>
+
+  /**
+   * Construct and simulate a get_match transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Get the match record of an ended session
+   */
+  get_match: ({session_id}: {session_id: u32}, options?: MethodOptions) => Promise<AssembledTransaction<Result<MatchRecord>>>
+
+  /**
+   * Construct and simulate a count_sessions_by_player transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Number of ended sessions a player took part in
+   */
+  count_sessions_by_player: ({player}: {player: string}, options?: MethodOptions) => Promise<AssembledTransaction<u32>>
+
+  /**
+   * Construct and simulate a list_sessions_by_player transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Page through a player's ended sessions, oldest first.
+   * 
+   * # Arguments
+   * * `player` - Player whose history to read
+   * * `cursor` - Index of the first record to return (0 for the oldest)
+   * * `limit` - Maximum records to return (capped at 25)
+   */
+  list_sessions_by_player: ({player, cursor, limit}: {player: string, cursor: u32, limit: u32}, options?: MethodOptions) => Promise<AssembledTransaction<Array<MatchRecord>>>
+
+  /**
+   * Construct and simulate a count_sessions_by_game transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Number of ended sessions of a game
+   */
+  count_sessions_by_game: ({game_id}: {game_id: string}, options?: MethodOptions) => Promise<AssembledTransaction<u32>>
+
+  /**
+   * Construct and simulate a list_sessions_by_game transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Page through a game's ended sessions, oldest first.
+   * 
+   * # Arguments
+   * * `game_id` - Game whose history to read
+   * * `cursor` - Index of the first record to return (0 for the oldest)
+   * * `limit` - Maximum records to return (capped at 25)
+   */
+  list_sessions_by_game: ({game_id, cursor, limit}: {game_id: string, cursor: u32, limit: u32}, options?: MethodOptions) => Promise<AssembledTransaction<Array<MatchRecord>>>
+
+  /**
+   * Construct and simulate a get_admin transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Get the current admin address
+   */
+  get_admin: (options?: MethodOptions) => Promise<AssembledTransaction<string>>
+
+  /**
+   * Construct and simulate a get_pending_admin transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Get the admin proposed by `propose_admin`, if any
+   */
+  get_pending_admin: (options?: MethodOptions) => Promise<AssembledTransaction<Option<string>>>
+
+  /**
+   * Construct and simulate a propose_admin transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Propose a new admin. Control only moves once they call
+   * `accept_admin`; a later proposal replaces this one. Current admin only.
+   */
+  propose_admin: ({new_admin}: {new_admin: string}, options?: MethodOptions) => Promise<AssembledTransaction<null>>
+
+  /**
+   * Construct and simulate a accept_admin transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Accept a transfer started by `propose_admin`. Pending admin only.
+   */
+  accept_admin: (options?: MethodOptions) => Promise<AssembledTransaction<Result<void>>>
+
+  /**
+   * Construct and simulate a upgrade transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Update the contract WASM hash (upgrade contract). Admin only.
+   */
+  upgrade: ({new_wasm_hash}: {new_wasm_hash: Buffer}, options?: MethodOptions) => Promise<AssembledTransaction<null>>
+
+}
+export class Client extends ContractClient {
+  static async deploy<T = Client>(
+        /** Constructor/Initialization Args for the contract's `__constructor` method */
+        {admin}: {admin: string},
+    /** Options for initializing a Client as well as for calling a method, with extras specific to deploying. */
+    options: MethodOptions &
+      Omit<ContractClientOptions, "contractId"> & {
+        /** The hash of the Wasm blob, which must already be installed on-chain. */
+        wasmHash: Buffer | string;
+        /** Salt used to generate the contract's ID. Passed through to {@link Operation.createCustomContract}. Default: random. */
+        salt?: Buffer | Uint8Array;
+        /** The format used to decode `wasmHash`, if it's provided as a string. */
+        format?: "hex" | "base64";
+      }
+  ): Promise<AssembledTransaction<T>> {
+    return ContractClient.deploy({admin}, options)
+  }
+  constructor(public readonly options: ContractClientOptions) {
+    super(
+      new ContractSpec([ "AAAABAAAAAAAAAAAAAAABUVycm9yAAAAAAAAHQAAAEdQb2ludHMgYW1vdW50IGlzIG5lZ2F0aXZlIChvciB6ZXJvIHdoZXJlIGEgcG9zaXRpdmUgYW1vdW50IGlzIHJlcXVpcmVkKQAAAAANSW52YWxpZEFtb3VudAAAAAAAAAEAAAA/UGxheWVyIGRvZXMgbm90IGhhdmUgZW5vdWdoIGF2YWlsYWJsZSBwb2ludHMgdG8gY292ZXIgdGhlIHN0YWtlAAAAABNJbnN1ZmZpY2llbnRCYWxhbmNlAAAAAAIAAAAeTm8gc2Vzc2lvbiBleGlzdHMgd2l0aCB0aGlzIElEAAAAAAAPU2Vzc2lvbk5vdEZvdW5kAAAAAAMAAAAlQSBzZXNzaW9uIGFscmVhZHkgZXhpc3RzIHdpdGggdGhpcyBJRAAAAAAAABRTZXNzaW9uQWxyZWFkeUV4aXN0cwAAAAQAAAAgU2Vzc2lvbiBoYXMgYWxyZWFkeSBiZWVuIHNldHRsZWQAAAATU2Vzc2lvbkFscmVhZHlFbmRlZAAAAAAFAAAAK0dhbWUgY29udHJhY3QgaXMgbm90IG9uIHRoZSBodWIncyBhbGxvd2xpc3QAAAAAEUdhbWVOb3RSZWdpc3RlcmVkAAAAAAAABgAAACdBZGRyZXNzIGlzIG5vdCBhIHBsYXllciBpbiB0aGlzIHNlc3Npb24AAAAACU5vdFBsYXllcgAAAAAAAAcAAABCUGFydGljaXBhbnQgbGlzdCBpcyB0b28gc2hvcnQsIHRvbyBsb25nIG9yIGNvbnRhaW5zIGEgcGxheWVyIHR3aWNlAAAAAAATSW52YWxpZFBhcnRpY2lwYW50cwAAAAAIAAAARFBsYWNlbWVudHMgYXJlIG5vdCBhbiBvcmRlcmluZyBvZiBleGFjdGx5IHRoZSBzZXNzaW9uJ3MgcGFydGljaXBhbnRzAAAAEUludmFsaWRQbGFjZW1lbnRzAAAAAAAACQAAACtPdXRjb21lIG9ubHkgYXBwbGllcyB0byB0d28tcGxheWVyIHNlc3Npb25zAAAAAA5JbnZhbGlkT3V0Y29tZQAAAAAACgAAAB1ObyBzZWFzb24gZXhpc3RzIHdpdGggdGhpcyBJRAAAAAAAAA5TZWFzb25Ob3RGb3VuZAAAAAAACwAAADZTZWFzb24gd2luZG93IGlzIGVtcHR5IG9yIG92ZXJsYXBzIHRoZSBwcmV2aW91cyBzZWFzb24AAAAAAA1JbnZhbGlkU2Vhc29uAAAAAAAADAAAAB5TZWFzb24gaGFzIGFscmVhZHkgYmVlbiBjbG9zZWQAAAAAAAxTZWFzb25DbG9zZWQAAAANAAAAIEZlZSBpcyBhYm92ZSAxMF8wMDAgYmFzaXMgcG9pbnRzAAAACkludmFsaWRGZWUAAAAAAA4AAAAjU2Vzc2lvbiBkZWFkbGluZSBoYXMgbm90IHBhc3NlZCB5ZXQAAAAAEVNlc3Npb25Ob3RFeHBpcmVkAAAAAAAADwAAADhXaXRoZHJhd2FsIGlzIGxhcmdlciB0aGFuIHRoZSBmZWVzIGFjY3J1ZWQgaW4gdGhhdCBhc3NldAAAABBJbnN1ZmZpY2llbnRGZWVzAAAAEAAAAD9HYW1lIGlzIHJlZ2lzdGVyZWQgYnV0IGl0cyBjb25maWcgaGFzIG5ldyBzZXNzaW9ucyBzd2l0Y2hlZCBvZmYAAAAADEdhbWVEaXNhYmxlZAAAABEAAAAnQSBzdGFrZSBpcyBiZWxvdyB0aGUgZ2FtZSdzIGBtaW5fc3Rha2VgAAAAAAtTdGFrZVRvb0xvdwAAAAASAAAAJ0Egc3Rha2UgaXMgYWJvdmUgdGhlIGdhbWUncyBgbWF4X3N0YWtlYAAAAAAMU3Rha2VUb29IaWdoAAAAEwAAADNHYW1lIHJlcXVpcmVzIGV2ZXJ5IHBsYXllciB0byBzdGFrZSB0aGUgc2FtZSBhbW91bnQAAAAADVVuZXF1YWxTdGFrZXMAAAAAAAAUAAAAMkEgc3Rha2UgaXMgbmVnYXRpdmUgYW5kIHRoZSBnYW1lIGRvZXMgbm90IGFsbG93IGl0AAAAAAANTmVnYXRpdmVTdGFrZQAAAAAAABUAAABcQ29uZmlnIGhhcyBgbWluX3N0YWtlYCBhYm92ZSBgbWF4X3N0YWtlYCwgb3IgYSBuZWdhdGl2ZSBgbWluX3N0YWtlYAp3aXRob3V0IGBhbGxvd19uZWdhdGl2ZWAAAAANSW52YWxpZENvbmZpZwAAAAAAABYAAAArVGhlIGh1Yiwgb3IgdGhpcyBnYW1lIG9uIHRoZSBodWIsIGlzIHBhdXNlZAAAAAAOU2Vzc2lvbnNQYXVzZWQAAAAAABcAAABAU2Vzc2lvbidzIGdhbWUgaXMgbm90IHBhdXNlZCwgc28gaXQgY2Fubm90IGJlIGNhbmNlbGxlZCB0aGlzIHdheQAAAAlOb3RQYXVzZWQAAAAAAAAYAAAAW1Nlc3Npb24gSUQgd2FzIG5vdCByZXNlcnZlZCBmb3IgdGhpcyBnYW1lIGJ5IG9uZSBvZiBpdHMgcGxheWVycywgb3IgdGhlCnJlc2VydmF0aW9uIGV4cGlyZWQAAAAAElNlc3Npb25Ob3RSZXNlcnZlZAAAAAAAGQAAACJObyBhY2hpZXZlbWVudCBleGlzdHMgd2l0aCB0aGlzIElEAAAAAAATQWNoaWV2ZW1lbnROb3RGb3VuZAAAAAAaAAAAUEFjaGlldmVtZW50IHRocmVzaG9sZCBpcyBub3QgcG9zaXRpdmUsIG9yIGl0cyBtZXRyaWMgZG9lcyBub3QgZml0IGl0cwpnYW1lIHNjb3BlAAAAEkludmFsaWRBY2hpZXZlbWVudAAAAAAAGwAAADxUaGUgbWF4aW11bSBudW1iZXIgb2YgYWN0aXZlIGFjaGlldmVtZW50cyBpcyBhbHJlYWR5IGRlZmluZWQAAAATVG9vTWFueUFjaGlldmVtZW50cwAAAAAcAAAAI05vIGFkbWluIHRyYW5zZmVyIGhhcyBiZWVuIHByb3Bvc2VkAAAAAA5Ob1BlbmRpbmdBZG1pbgAAAAAAHQ==",
+        "AAAAAgAAAEBIb3cgYSBzZXNzaW9uIGVuZGVkLiBEZXRlcm1pbmVzIGhvdyB0aGUgZXNjcm93ZWQgcG90IGlzIHNldHRsZWQuAAAAAAAAAAtHYW1lT3V0Y29tZQAAAAAFAAAAAAAAABZQbGF5ZXIgMSB0YWtlcyB0aGUgcG90AAAAAAAKUGxheWVyMVdvbgAAAAAAAAAAABZQbGF5ZXIgMiB0YWtlcyB0aGUgcG90AAAAAAAKUGxheWVyMldvbgAAAAAAAAAAADZQbGF5ZWQgdG8gYSB0aWU6IGVhY2ggcGxheWVyIGdldHMgdGhlaXIgb3duIHN0YWtlIGJhY2sAAAAAAAREcmF3AAAAAAAAAEJDYWxsZWQgb2ZmIHdpdGhvdXQgYSByZXN1bHQ6IGVhY2ggcGxheWVyIGdldHMgdGhlaXIgb3duIHN0YWtlIGJhY2sAAAAAAAlDYW5jZWxsZWQAAAAAAAABAAAAOFRoZSBnaXZlbiBwbGF5ZXIgZm9yZmVpdGVkOiB0aGVpciBvcHBvbmVudCB0YWtlcyB0aGUgcG90AAAAB0ZvcmZlaXQAAAAAAQAAABM=",
+        "AAAAAgAAAAAAAAAAAAAADVNlc3Npb25TdGF0dXMAAAAAAAADAAAAAAAAAAAAAAAGQWN0aXZlAAAAAAABAAAAAAAAAAVFbmRlZAAAAAAAAAEAAAfQAAAAC0dhbWVPdXRjb21lAAAAAAEAAAAwU2V0dGxlZCBieSBmaW5pc2hpbmcgcG9zaXRpb24sIGZpcnN0IHBsYWNlIGZpcnN0AAAABlBsYWNlZAAAAAAAAQAAA+oAAAAT",
+        "AAAAAQAAAKVQZXItZ2FtZSBydWxlcyBgc3RhcnRfZ2FtZWAgYW5kIGBzdGFydF9tdWx0aXBsYXllcl9nYW1lYCBlbmZvcmNlIG9uIHN0YWtlcy4KR2FtZXMgd2l0aG91dCBhIGNvbmZpZyB1c2UgYEdhbWVDb25maWc6OmRlZmF1bHQoKWAsIHdoaWNoIGFjY2VwdHMgYW55Cm5vbi1uZWdhdGl2ZSBzdGFrZS4AAAAAAAAAAAAACkdhbWVDb25maWcAAAAAAAUAAABBTmVnYXRpdmUgc3Rha2VzIGFyZSBhY2NlcHRlZCBhcyBhIGZyZWUgZW50cnk6IG5vdGhpbmcgaXMgZXNjcm93ZWQAAAAAAAAOYWxsb3dfbmVnYXRpdmUAAAAAAAEAAAA6TmV3IHNlc3Npb25zIGNhbiBiZSBvcGVuZWQ7IHJ1bm5pbmcgc2Vzc2lvbnMgYWx3YXlzIHNldHRsZQAAAAAAB2VuYWJsZWQAAAAAAQAAACdFdmVyeSBwbGF5ZXIgbXVzdCBzdGFrZSB0aGUgc2FtZSBhbW91bnQAAAAADGVxdWFsX3N0YWtlcwAAAAEAAAAAAAAACW1heF9zdGFrZQAAAAAAAAsAAAAAAAAACW1pbl9zdGFrZQAAAAAAAAs=",
+        "AAAAAQAAACFBIHBsYXllcidzIEVsbyByYXRpbmcgaW4gb25lIGdhbWUAAAAAAAAAAAAABlJhdGluZwAAAAAAAgAAADlSYXRlZCBzZXNzaW9ucyBwbGF5ZWQ7IHBsYXllcnMgd2l0aCBmZXcgZ2FtZXMgbW92ZSBmYXN0ZXIAAAAAAAAMZ2FtZXNfcGxheWVkAAAABAAAAAAAAAAGcmF0aW5nAAAAAAAF",
+        "AAAAAQAAAHtBbiBhZG1pbi1kZWZpbmVkIGNvbXBldGl0aW9uIHdpbmRvdy4gU2Vzc2lvbnMgc2V0dGxlZCBiZXR3ZWVuCmBzdGFydF9sZWRnZXJgIGFuZCBgZW5kX2xlZGdlcmAgKGluY2x1c2l2ZSkgY291bnQgdG93YXJkcyBpdC4AAAAAAAAAAAZTZWFzb24AAAAAAAUAAAAAAAAABmNsb3NlZAAAAAAAAQAAAAAAAAAKZW5kX2xlZGdlcgAAAAAABAAAACVUb3RhbCBwb2ludHMgc3Rha2VkIGluIHRob3NlIHNlc3Npb25zAAAAAAAADnBvaW50c193YWdlcmVkAAAAAAALAAAANFNlc3Npb25zIHNldHRsZWQgZHVyaW5nIHRoZSBzZWFzb24sIGFjcm9zcyBhbGwgZ2FtZXMAAAAIc2Vzc2lvbnMAAAAEAAAAAAAAAAxzdGFydF9sZWRnZXIAAAAE",
+        "AAAAAQAAADBBIHBsYXllcidzIHJlc3VsdHMgaW4gb25lIGdhbWUgZHVyaW5nIG9uZSBzZWFzb24AAAAAAAAAC1NlYXNvblN0YXRzAAAAAAQAAAAAAAAABmxvc3NlcwAAAAAABAAAAB5Qb2ludHMgd29uIG1pbnVzIHBvaW50cyBzdGFrZWQAAAAAAApuZXRfcG9pbnRzAAAAAAALAAAAAAAAAAhzZXNzaW9ucwAAAAQAAAAAAAAABHdpbnMAAAAE",
+        "AAAAAQAAAAAAAAAAAAAAEExlYWRlcmJvYXJkRW50cnkAAAACAAAAAAAAAApuZXRfcG9pbnRzAAAAAAALAAAAAAAAAAZwbGF5ZXIAAAAAABM=",
+        "AAAAAQAAAERBIHBsYXllcidzIGlkZW50aXR5IGFuZCBsaWZldGltZSByZWNvcmQgYWNyb3NzIGV2ZXJ5IGdhbWUgb24gdGhlIGh1YgAAAAAAAAANUGxheWVyUHJvZmlsZQAAAAAAAAUAAAAgQ29udGVudCBoYXNoIG9mIHRoZSBhdmF0YXIgaW1hZ2UAAAALYXZhdGFyX2hhc2gAAAAD6AAAA+4AAAAgAAAAOUhhc2ggb2YgdGhlIGRpc3BsYXkgbmFtZTsgdGhlIG5hbWUgaXRzZWxmIGxpdmVzIG9mZi1jaGFpbgAAAAAAABFkaXNwbGF5X25hbWVfaGFzaAAAAAAAA+gAAAPuAAAAIAAAAEJTZXR0bGVkIHNlc3Npb25zIHBsYXllZCwgYWNyb3NzIGFsbCBnYW1lcyAoY2FuY2VsbGF0aW9ucyBleGNsdWRlZCkAAAAAAAhzZXNzaW9ucwAAAAQAAAAnU2Vzc2lvbnMgd29uIG91dHJpZ2h0LCBhY3Jvc3MgYWxsIGdhbWVzAAAAAAR3aW5zAAAABAAAAAAAAAACeHAAAAAAAAY=",
+        "AAAAAgAAACVDb3VudGVyIGFuIGFjaGlldmVtZW50IGlzIG1lYXN1cmVkIG9uAAAAAAAAAAAAABFBY2hpZXZlbWVudE1ldHJpYwAAAAAAAAQAAAAAAAAAPFNlc3Npb25zIHdvbjogaW4gdGhlIGFjaGlldmVtZW50J3MgZ2FtZSwgb3IgYWNyb3NzIGFsbCBnYW1lcwAAAARXaW5zAAAAAAAAACFQcm9maWxlIHNlc3Npb25zIGFjcm9zcyBhbGwgZ2FtZXMAAAAAAAAIU2Vzc2lvbnMAAAAAAAAAClByb2ZpbGUgWFAAAAAAAAJYcAAAAAAAAQAAAElSdW5uaW5nIHRvdGFsIG9mIGEgc3RhdCB0aGUgYWNoaWV2ZW1lbnQncyBnYW1lIHJlcG9ydHMgd2l0aCBgcmVwb3J0X3N0YXRgAAAAAAAABFN0YXQAAAABAAAAEQ==",
+        "AAAAAQAAALhBZG1pbi1kZWZpbmVkIG1pbGVzdG9uZS4gQSBwbGF5ZXIgZWFybnMgaXQgb25jZSBgbWV0cmljYCByZWFjaGVzCmB0aHJlc2hvbGRgLiBgZ2FtZV9pZGAgc2NvcGVzIGBXaW5zYCB0byBvbmUgZ2FtZSBhbmQgaXMgcmVxdWlyZWQgZm9yCmBTdGF0YDsgYFNlc3Npb25zYCBhbmQgYFhwYCBhcmUgYWx3YXlzIGNyb3NzLWdhbWUuAAAAAAAAAAtBY2hpZXZlbWVudAAAAAADAAAAAAAAAAdnYW1lX2lkAAAAA+gAAAATAAAAAAAAAAZtZXRyaWMAAAAAB9AAAAARQWNoaWV2ZW1lbnRNZXRyaWMAAAAAAAAAAAAACXRocmVzaG9sZAAAAAAAAAs=",
+        "AAAAAQAAADZBIHNvdWxib3VuZCByZWNvcmQgdGhhdCBhIHBsYXllciBlYXJuZWQgYW4gYWNoaWV2ZW1lbnQAAAAAAAAAAAAFQmFkZ2UAAAAAAAACAAAAAAAAAA5hY2hpZXZlbWVudF9pZAAAAAAABAAAAAAAAAANZWFybmVkX2xlZGdlcgAAAAAAAAQ=",
+        "AAAAAQAAAD1QZXJtYW5lbnQgc3VtbWFyeSBvZiBhbiBlbmRlZCBzZXNzaW9uLCBrZXB0IGZvciBtYXRjaCBoaXN0b3J5AAAAAAAAAAAAAAtNYXRjaFJlY29yZAAAAAAGAAAAAAAAAAplbmRfbGVkZ2VyAAAAAAAEAAAAAAAAAAdnYW1lX2lkAAAAABMAAAA3RXZlcnkgcGxheWVyIHdpdGggdGhlIHBvaW50cyB0aGV5IHN0YWtlZCwgaW4gc2VhdCBvcmRlcgAAAAAMcGFydGljaXBhbnRzAAAD6gAAA+0AAAACAAAAEwAAAAsAAAA8V2hhdCBlYWNoIHBsYXllciB3YXMgcGFpZCBvdXQsIGluIHNlYXQgb3JkZXIgKGFmdGVyIGFueSBmZWUpAAAAB3BheW91dHMAAAAD6gAAAAsAAAAAAAAABnJlc3VsdAAAAAAH0AAAAA1TZXNzaW9uU3RhdHVzAAAAAAAAAAAAAApzZXNzaW9uX2lkAAAAAAAE",
+        "AAAAAQAAAEVIdWItc2lkZSByZWNvcmQgb2YgYSBnYW1lIHNlc3Npb24uIFN0YWtlcyBzdGF5IGxvY2tlZCB3aGlsZSBgQWN0aXZlYC4AAAAAAAAAAAAAB1Nlc3Npb24AAAAACAAAAC5MYXN0IGxlZGdlciBiZWZvcmUgYGNsYWltX3RpbWVvdXRgIGNhbiBiZSB1c2VkAAAAAAAPZGVhZGxpbmVfbGVkZ2VyAAAAAAQAAAAAAAAACmVuZF9sZWRnZXIAAAAAAAQAAABDSG91c2UgZmVlIHRha2VuIGZyb20gd2lubmluZyBwYXlvdXRzLCBmaXhlZCB3aGVuIHRoZSBzZXNzaW9uIHN0YXJ0cwAAAAAHZmVlX2JwcwAAAAAEAAAAAAAAAAdnYW1lX2lkAAAAABMAAAA3RXZlcnkgcGxheWVyIHdpdGggdGhlIHBvaW50cyB0aGV5IHN0YWtlZCwgaW4gc2VhdCBvcmRlcgAAAAAMcGFydGljaXBhbnRzAAAD6gAAA+0AAAACAAAAEwAAAAsAAAAAAAAADHN0YXJ0X2xlZGdlcgAAAAQAAAAAAAAABnN0YXR1cwAAAAAH0AAAAA1TZXNzaW9uU3RhdHVzAAAAAAAAOVRva2VuIGVzY3Jvd2VkIGZvciB0aGlzIHNlc3Npb24sIG9yIGBOb25lYCBmb3IgaHViIHBvaW50cwAAAAAAAAV0b2tlbgAAAAAAA+gAAAAT",
+        "AAAAAQAAAEBBIHNlc3Npb24gSUQgaGFuZGVkIG91dCBieSBgcmVzZXJ2ZV9zZXNzaW9uYCBidXQgbm90IHlldCBzdGFydGVkAAAAAAAAAAtSZXNlcnZhdGlvbgAAAAACAAAAAAAAAAdnYW1lX2lkAAAAABMAAAA+UGxheWVyIHdobyByZXNlcnZlZCB0aGUgSUQ7IHRoZXkgbXVzdCBiZSBzZWF0ZWQgaW4gdGhlIHNlc3Npb24AAAAAAAhyZXNlcnZlcgAAABM=",
+        "AAAAAgAAAAAAAAAAAAAAB0RhdGFLZXkAAAAAIAAAAAAAAAAAAAAABUFkbWluAAAAAAAAAAAAAAAAAAAMUGVuZGluZ0FkbWluAAAAAQAAAAAAAAAHQmFsYW5jZQAAAAABAAAAEwAAAAEAAAAAAAAABkxvY2tlZAAAAAAAAQAAABMAAAABAAAAAAAAAAdTZXNzaW9uAAAAAAEAAAAEAAAAAAAAAAAAAAANTmV4dFNlc3Npb25JZAAAAAAAAAEAAAAAAAAAC1Jlc2VydmF0aW9uAAAAAAEAAAAEAAAAAQAAAAAAAAAOUmVnaXN0ZXJlZEdhbWUAAAAAAAEAAAATAAAAAQAAAAAAAAAKR2FtZUNvbmZpZwAAAAAAAQAAABMAAAAAAAAAAAAAAAZQYXVzZWQAAAAAAAEAAAAAAAAACkdhbWVQYXVzZWQAAAAAAAEAAAATAAAAAQAAAAAAAAAGUmF0aW5nAAAAAAACAAAAEwAAABMAAAABAAAAAAAAAAlHYW1lVG9rZW4AAAAAAAABAAAAEwAAAAEAAAAAAAAAB0dhbWVGZWUAAAAAAQAAABMAAAAAAAAAAAAAAAhUcmVhc3VyeQAAAAEAAAAAAAAAC0FjY3J1ZWRGZWVzAAAAAAEAAAPoAAAAEwAAAAAAAAAAAAAAC1NlYXNvbkNvdW50AAAAAAEAAAAAAAAABlNlYXNvbgAAAAAAAQAAAAQAAAABAAAAAAAAAAtTZWFzb25TdGF0cwAAAAADAAAABAAAABMAAAATAAAAAQAAAAAAAAALTGVhZGVyYm9hcmQAAAAAAgAAAAQAAAATAAAAAQAAAAAAAAAHUHJvZmlsZQAAAAABAAAAEwAAAAEAAAAAAAAACEdhbWVXaW5zAAAAAgAAABMAAAATAAAAAAAAAAAAAAAQQWNoaWV2ZW1lbnRDb3VudAAAAAEAAAAAAAAAC0FjaGlldmVtZW50AAAAAAEAAAAEAAAAAAAAAAAAAAASQWN0aXZlQWNoaWV2ZW1lbnRzAAAAAAABAAAAAAAAAAZCYWRnZXMAAAAAAAEAAAATAAAAAQAAAAAAAAAKUGxheWVyU3RhdAAAAAAAAwAAABMAAAATAAAAEQAAAAEAAAAAAAAAC01hdGNoUmVjb3JkAAAAAAEAAAAEAAAAAQAAAAAAAAAQUGxheWVyTWF0Y2hDb3VudAAAAAEAAAATAAAAAQAAAAAAAAALUGxheWVyTWF0Y2gAAAAAAgAAABMAAAAEAAAAAQAAAAAAAAAOR2FtZU1hdGNoQ291bnQAAAAAAAEAAAATAAAAAQAAAAAAAAAJR2FtZU1hdGNoAAAAAAAAAgAAABMAAAAE",
+        "AAAAAAAAAHJJbml0aWFsaXplIHRoZSBodWIgd2l0aCBpdHMgYWRtaW4KCiMgQXJndW1lbnRzCiogYGFkbWluYCAtIEFkbWluIGFkZHJlc3MgKGNhbiBhZGQgcG9pbnRzIGFuZCB1cGdyYWRlIHRoZSBjb250cmFjdCkAAAAAAA1fX2NvbnN0cnVjdG9yAAAAAAAAAQAAAAAAAAAFYWRtaW4AAAAAAAATAAAAAA==",
+        "AAAAAAAAAmtTdGFydCBhIGdhbWUgc2Vzc2lvbiBhbmQgbG9jayBib3RoIHBsYXllcnMnIHBvaW50cyBpbiBlc2Nyb3cuCkZhaWxzIHVubGVzcyBvbmUgb2YgdGhlIHBsYXllcnMgcmVzZXJ2ZWQgYHNlc3Npb25faWRgIGZvciB0aGlzIGdhbWUgd2l0aApgcmVzZXJ2ZV9zZXNzaW9uYCBhbmQgbm8gc2Vzc2lvbiB3aXRoIHRoaXMgSUQgd2FzIHN0YXJ0ZWQgeWV0LgoKKipBdXRoOioqIFJlcXVpcmVzIGF1dGhvcml6YXRpb24gZnJvbSBgZ2FtZV9pZGAsIHdoaWNoIG11c3QgYmUgcmVnaXN0ZXJlZC4KCiMgQXJndW1lbnRzCiogYGdhbWVfaWRgIC0gQWRkcmVzcyBvZiB0aGUgZ2FtZSBjb250cmFjdCBjYWxsaW5nIHRoaXMgbWV0aG9kCiogYHNlc3Npb25faWRgIC0gSUQgYSBwbGF5ZXIgcmVzZXJ2ZWQgZm9yIHRoaXMgZ2FtZSB3aXRoIGByZXNlcnZlX3Nlc3Npb25gCiogYHBsYXllcjFgIC0gQWRkcmVzcyBvZiBmaXJzdCBwbGF5ZXIKKiBgcGxheWVyMmAgLSBBZGRyZXNzIG9mIHNlY29uZCBwbGF5ZXIKKiBgcGxheWVyMV9wb2ludHNgIC0gUG9pbnRzIGxvY2tlZCBmcm9tIHBsYXllciAxJ3MgYmFsYW5jZQoqIGBwbGF5ZXIyX3BvaW50c2AgLSBQb2ludHMgbG9ja2VkIGZyb20gcGxheWVyIDIncyBiYWxhbmNlAAAAAApzdGFydF9nYW1lAAAAAAAGAAAAAAAAAAdnYW1lX2lkAAAAABMAAAAAAAAACnNlc3Npb25faWQAAAAAAAQAAAAAAAAAB3BsYXllcjEAAAAAEwAAAAAAAAAHcGxheWVyMgAAAAATAAAAAAAAAA5wbGF5ZXIxX3BvaW50cwAAAAAACwAAAAAAAAAOcGxheWVyMl9wb2ludHMAAAAAAAsAAAABAAAD6QAAAAIAAAAD",
+        "AAAAAAAAAahTdGFydCBhIHNlc3Npb24gd2l0aCBhbnkgbnVtYmVyIG9mIHBsYXllcnMgKDIgdG8gMTYpIHNoYXJpbmcgb25lIHBvdC4KRWFjaCBwYXJ0aWNpcGFudCdzIHN0YWtlIGlzIGxvY2tlZCBmcm9tIHRoZWlyIGJhbGFuY2UuCgoqKkF1dGg6KiogUmVxdWlyZXMgYXV0aG9yaXphdGlvbiBmcm9tIGBnYW1lX2lkYCwgd2hpY2ggbXVzdCBiZSByZWdpc3RlcmVkLgoKIyBBcmd1bWVudHMKKiBgZ2FtZV9pZGAgLSBBZGRyZXNzIG9mIHRoZSBnYW1lIGNvbnRyYWN0IGNhbGxpbmcgdGhpcyBtZXRob2QKKiBgc2Vzc2lvbl9pZGAgLSBJRCBhIHBsYXllciByZXNlcnZlZCBmb3IgdGhpcyBnYW1lIHdpdGggYHJlc2VydmVfc2Vzc2lvbmAKKiBgcGFydGljaXBhbnRzYCAtIEVhY2ggcGxheWVyIHdpdGggdGhlIHBvaW50cyBsb2NrZWQgZnJvbSB0aGVpciBiYWxhbmNlAAAAFnN0YXJ0X211bHRpcGxheWVyX2dhbWUAAAAAAAMAAAAAAAAAB2dhbWVfaWQAAAAAEwAAAAAAAAAKc2Vzc2lvbl9pZAAAAAAABAAAAAAAAAAMcGFydGljaXBhbnRzAAAD6gAAA+0AAAACAAAAEwAAAAsAAAABAAAD6QAAAAIAAAAD",
+        "AAAAAAAAAUBFbmQgYSBnYW1lIHNlc3Npb24gYW5kIHBheSB0aGUgcG90IHRvIHRoZSB3aW5uZXIuCkZhaWxzIGZvciB1bmtub3duIHNlc3Npb25zIGFuZCBmb3Igc2Vzc2lvbnMgdGhhdCB3ZXJlIGFscmVhZHkgZW5kZWQuCgoqKkF1dGg6KiogUmVxdWlyZXMgYXV0aG9yaXphdGlvbiBmcm9tIHRoZSBnYW1lIGNvbnRyYWN0IHRoYXQgc3RhcnRlZCB0aGUgc2Vzc2lvbi4KCiMgQXJndW1lbnRzCiogYHNlc3Npb25faWRgIC0gVGhlIGdhbWUgc2Vzc2lvbiBiZWluZyBlbmRlZAoqIGBwbGF5ZXIxX3dvbmAgLSBUcnVlIGlmIHBsYXllcjEgd29uLCBmYWxzZSBpZiBwbGF5ZXIyIHdvbgAAAAhlbmRfZ2FtZQAAAAIAAAAAAAAACnNlc3Npb25faWQAAAAAAAQAAAAAAAAAC3BsYXllcjFfd29uAAAAAAEAAAABAAAD6QAAAAIAAAAD",
+        "AAAAAAAAAcZFbmQgYSBnYW1lIHNlc3Npb24gd2l0aCBhbnkgb3V0Y29tZSBhbmQgc2V0dGxlIHRoZSBwb3QgYWNjb3JkaW5nbHkuCgpTZXR0bGVtZW50IHJ1bGVzOgotIGBQbGF5ZXIxV29uYCAvIGBQbGF5ZXIyV29uYDogdGhlIHdpbm5lciB0YWtlcyB0aGUgd2hvbGUgcG90Ci0gYERyYXdgIC8gYENhbmNlbGxlZGA6IGVhY2ggcGxheWVyIGdldHMgdGhlaXIgb3duIHN0YWtlIGJhY2sKLSBgRm9yZmVpdChwbGF5ZXIpYDogdGhlIG9wcG9uZW50IG9mIGBwbGF5ZXJgIHRha2VzIHRoZSB3aG9sZSBwb3QKCioqQXV0aDoqKiBSZXF1aXJlcyBhdXRob3JpemF0aW9uIGZyb20gdGhlIGdhbWUgY29udHJhY3QgdGhhdCBzdGFydGVkIHRoZSBzZXNzaW9uLgoKIyBBcmd1bWVudHMKKiBgc2Vzc2lvbl9pZGAgLSBUaGUgZ2FtZSBzZXNzaW9uIGJlaW5nIGVuZGVkCiogYG91dGNvbWVgIC0gSG93IHRoZSBzZXNzaW9uIGVuZGVkAAAAAAAVZW5kX2dhbWVfd2l0aF9vdXRjb21lAAAAAAAAAgAAAAAAAAAKc2Vzc2lvbl9pZAAAAAAABAAAAAAAAAAHb3V0Y29tZQAAAAfQAAAAC0dhbWVPdXRjb21lAAAAAAEAAAPpAAAAAgAAAAM=",
+        "AAAAAAAAAehFbmQgYSBzZXNzaW9uIGJ5IGZpbmlzaGluZyBwb3NpdGlvbiBhbmQgc3BsaXQgdGhlIHBvdCBieSBwbGFjZW1lbnQuCgpgcGxhY2VtZW50c2AgbGlzdHMgZXZlcnkgcGFydGljaXBhbnQgZXhhY3RseSBvbmNlLCBmaXJzdCBwbGFjZSBmaXJzdC4KVGhlIHBvdCBpcyBzcGxpdCB1c2luZyB0aGUgaHViJ3MgYFBBWU9VVF9TUExJVFNfQlBTYCB0YWJsZToKLSAyIHBsYXllcnM6IHdpbm5lciB0YWtlcyBhbGwKLSAzLTQgcGxheWVyczogNzAlIC8gMzAlCi0gNSsgcGxheWVyczogNTAlIC8gMzAlIC8gMjAlCgoqKkF1dGg6KiogUmVxdWlyZXMgYXV0aG9yaXphdGlvbiBmcm9tIHRoZSBnYW1lIGNvbnRyYWN0IHRoYXQgc3RhcnRlZCB0aGUgc2Vzc2lvbi4KCiMgQXJndW1lbnRzCiogYHNlc3Npb25faWRgIC0gVGhlIGdhbWUgc2Vzc2lvbiBiZWluZyBlbmRlZAoqIGBwbGFjZW1lbnRzYCAtIFBhcnRpY2lwYW50cyBvcmRlcmVkIGZyb20gZmlyc3QgdG8gbGFzdCBwbGFjZQAAABhlbmRfZ2FtZV93aXRoX3BsYWNlbWVudHMAAAACAAAAAAAAAApzZXNzaW9uX2lkAAAAAAAEAAAAAAAAAApwbGFjZW1lbnRzAAAAAAPqAAAAEwAAAAEAAAPpAAAAAgAAAAM=",
+        "AAAAAAAAAw5TZXR0bGUgYSBzZXNzaW9uIHdob3NlIGdhbWUgaGFzIHN0b3BwZWQgcHJvZ3Jlc3NpbmcuCgpPbmNlIHRoZSBzZXNzaW9uJ3MgYGRlYWRsaW5lX2xlZGdlcmAgaGFzIHBhc3NlZCwgYSBwbGF5ZXIgaW4gYQp0d28tcGxheWVyIHNlc3Npb24gY2FuIGNsYWltIGZvciBgVElNRU9VVF9HUkFDRV9MRURHRVJTYDogdGhlaXIgb3Bwb25lbnQKZm9yZmVpdHMsIHByb3ZpZGVkIHRoZSBnYW1lIGNvbnRyYWN0IGFjY2VwdHMgYG9uX3Nlc3Npb25fZm9yZmVpdGVkYCBmb3IKdGhhdCBvcHBvbmVudC4gUGxheWVycyBpbiBsYXJnZXIgc2Vzc2lvbnMgaGF2ZSBubyBzaW5nbGUgb3Bwb25lbnQgdG8KYmxhbWUgYW5kIG11c3Qgd2FpdCBvdXQgdGhlIGdyYWNlIHBlcmlvZC4gQWZ0ZXIgdGhlIGdyYWNlIHBlcmlvZCBhbnlvbmUKY2FuIGNsYWltLCBhbmQgdGhlIHNlc3Npb24gaXMgY2FuY2VsbGVkIHdpdGggZXZlcnkgc3Rha2UgcmVmdW5kZWQ7IHRoZQpnYW1lIGlzIHRvbGQgdGhyb3VnaCBgb25fc2Vzc2lvbl9jYW5jZWxsZWRgIG9uIGEgYmVzdC1lZmZvcnQgYmFzaXMgc28gaXQKY2Fubm90IGJsb2NrIHJlZnVuZHMuCgoqKkF1dGg6KiogUmVxdWlyZXMgYXV0aG9yaXphdGlvbiBmcm9tIGBjbGFpbWFudGAuCgojIEFyZ3VtZW50cwoqIGBzZXNzaW9uX2lkYCAtIFRoZSBzdGFsbGVkIGdhbWUgc2Vzc2lvbgoqIGBjbGFpbWFudGAgLSBBZGRyZXNzIGNsYWltaW5nIHRoZSB0aW1lb3V0CgojIFJldHVybnMKKiBgR2FtZU91dGNvbWVgIC0gYEZvcmZlaXQob3Bwb25lbnQpYCBvciBgQ2FuY2VsbGVkYAAAAAAADWNsYWltX3RpbWVvdXQAAAAAAAACAAAAAAAAAApzZXNzaW9uX2lkAAAAAAAEAAAAAAAAAAhjbGFpbWFudAAAABMAAAABAAAD6QAAB9AAAAALR2FtZU91dGNvbWUAAAAAAw==",
+        "AAAAAAAAADNBbGxvdyBhIGdhbWUgY29udHJhY3QgdG8gb3BlbiBzZXNzaW9ucy4gQWRtaW4gb25seS4AAAAACGFkZF9nYW1lAAAAAQAAAAAAAAAHZ2FtZV9pZAAAAAATAAAAAA==",
+        "AAAAAAAAAGtTdG9wIGEgZ2FtZSBjb250cmFjdCBmcm9tIG9wZW5pbmcgbmV3IHNlc3Npb25zLiBBZG1pbiBvbmx5LgpTZXNzaW9ucyBpdCBhbHJlYWR5IHN0YXJ0ZWQgY2FuIHN0aWxsIGJlIGVuZGVkLgAAAAALcmVtb3ZlX2dhbWUAAAAAAQAAAAAAAAAHZ2FtZV9pZAAAAAATAAAAAA==",
+        "AAAAAAAAADFDaGVjayB3aGV0aGVyIGEgZ2FtZSBjb250cmFjdCBpcyBvbiB0aGUgYWxsb3dsaXN0AAAAAAAAEmlzX2dhbWVfcmVnaXN0ZXJlZAAAAAAAAQAAAAAAAAAHZ2FtZV9pZAAAAAATAAAAAQAAAAE=",
+        "AAAAAAAAAL1TZXQgdGhlIHN0YWtlIHJ1bGVzIGZvciBhIHJlZ2lzdGVyZWQgZ2FtZS4gQWRtaW4gb25seS4KQXBwbGllcyB0byBzZXNzaW9ucyBvcGVuZWQgZnJvbSBub3cgb24uCgojIEFyZ3VtZW50cwoqIGBnYW1lX2lkYCAtIFJlZ2lzdGVyZWQgZ2FtZSBjb250cmFjdAoqIGBjb25maWdgIC0gU3Rha2UgbGltaXRzIGFuZCBlbmFibGVkIGZsYWcAAAAAAAAPc2V0X2dhbWVfY29uZmlnAAAAAAIAAAAAAAAAB2dhbWVfaWQAAAAAEwAAAAAAAAAGY29uZmlnAAAAAAfQAAAACkdhbWVDb25maWcAAAAAAAEAAAPpAAAAAgAAAAM=",
+        "AAAAAAAAAD1HZXQgYSBnYW1lJ3Mgc3Rha2UgcnVsZXMgKHRoZSBkZWZhdWx0IGNvbmZpZyBpZiBub25lIHdhcyBzZXQpAAAAAAAAD2dldF9nYW1lX2NvbmZpZwAAAAABAAAAAAAAAAdnYW1lX2lkAAAAABMAAAABAAAH0AAAAApHYW1lQ29uZmlnAAA=",
+        "AAAAAAAAAPVTdG9wIG5ldyBzZXNzaW9ucyBmcm9tIG9wZW5pbmcsIGZvciBvbmUgZ2FtZSBvciBmb3IgdGhlIHdob2xlIGh1Yi4KQWRtaW4gb25seS4gUnVubmluZyBzZXNzaW9ucyBjYW4gc3RpbGwgYmUgc2V0dGxlZCBieSB0aGVpciBnYW1lIG9yCmNhbmNlbGxlZCB3aXRoIGBjYW5jZWxfcGF1c2VkX3Nlc3Npb25gLgoKIyBBcmd1bWVudHMKKiBgZ2FtZV9pZGAgLSBHYW1lIHRvIHBhdXNlLCBvciBgTm9uZWAgdG8gcGF1c2UgZXZlcnkgZ2FtZQAAAAAAAAVwYXVzZQAAAAAAAAEAAAAAAAAAB2dhbWVfaWQAAAAD6AAAABMAAAAA",
+        "AAAAAAAAAKpMaWZ0IGEgcGF1c2Ugc2V0IHdpdGggYHBhdXNlYC4gQWRtaW4gb25seS4gVW5wYXVzaW5nIHRoZSBodWIgZG9lcyBub3QKbGlmdCBwZXItZ2FtZSBwYXVzZXMuCgojIEFyZ3VtZW50cwoqIGBnYW1lX2lkYCAtIEdhbWUgdG8gdW5wYXVzZSwgb3IgYE5vbmVgIHRvIGxpZnQgdGhlIGdsb2JhbCBwYXVzZQAAAAAAB3VucGF1c2UAAAAAAQAAAAAAAAAHZ2FtZV9pZAAAAAPoAAAAEwAAAAA=",
+        "AAAAAAAAAG5DaGVjayB3aGV0aGVyIHNlc3Npb25zIGFyZSBwYXVzZWQsIGZvciBvbmUgZ2FtZSAoY291bnRpbmcgdGhlIGdsb2JhbApwYXVzZSkgb3IsIHdpdGggYE5vbmVgLCBmb3IgdGhlIHdob2xlIGh1YgAAAAAACWlzX3BhdXNlZAAAAAAAAAEAAAAAAAAAB2dhbWVfaWQAAAAD6AAAABMAAAABAAAAAQ==",
+        "AAAAAAAAAVNDYW5jZWwgYSBydW5uaW5nIHNlc3Npb24gb2YgYSBwYXVzZWQgZ2FtZSwgcmVmdW5kaW5nIGV2ZXJ5IHN0YWtlLgpUaGUgZ2FtZSBpcyB0b2xkIHRocm91Z2ggYG9uX3Nlc3Npb25fY2FuY2VsbGVkYCBvbiBhIGJlc3QtZWZmb3J0IGJhc2lzLgoKKipBdXRoOioqIFJlcXVpcmVzIGF1dGhvcml6YXRpb24gZnJvbSBgY2FsbGVyYCwgd2hvIG11c3QgYmUgYSBwbGF5ZXIgaW4KdGhlIHNlc3Npb24gb3IgdGhlIGFkbWluLgoKIyBBcmd1bWVudHMKKiBgc2Vzc2lvbl9pZGAgLSBUaGUgc2Vzc2lvbiB0byBjYW5jZWwKKiBgY2FsbGVyYCAtIFBsYXllciBvciBhZG1pbiByZXF1ZXN0aW5nIHRoZSByZWZ1bmQAAAAAFWNhbmNlbF9wYXVzZWRfc2Vzc2lvbgAAAAAAAAIAAAAAAAAACnNlc3Npb25faWQAAAAAAAQAAAAAAAAABmNhbGxlcgAAAAAAEwAAAAEAAAPpAAAAAgAAAAM=",
+        "AAAAAAAAAQdTdGFrZSBhIGdhbWUncyBmdXR1cmUgc2Vzc2lvbnMgaW4gYHRva2VuYCBpbnN0ZWFkIG9mIGh1YiBwb2ludHMuIEFkbWluIG9ubHkuClNlc3Npb25zIGFscmVhZHkgcnVubmluZyBrZWVwIHRoZSBtb2RlIHRoZXkgc3RhcnRlZCB3aXRoLgoKIyBBcmd1bWVudHMKKiBgZ2FtZV9pZGAgLSBHYW1lIGNvbnRyYWN0IHdob3NlIHNlc3Npb25zIHVzZSB0aGUgdG9rZW4KKiBgdG9rZW5gIC0gVG9rZW4gY29udHJhY3QgKGUuZy4gYSBTdGVsbGFyIEFzc2V0IENvbnRyYWN0KQAAAAAOc2V0X2dhbWVfdG9rZW4AAAAAAAIAAAAAAAAAB2dhbWVfaWQAAAAAEwAAAAAAAAAFdG9rZW4AAAAAAAATAAAAAQAAA+kAAAACAAAAAw==",
+        "AAAAAAAAAD9Td2l0Y2ggYSBnYW1lJ3MgZnV0dXJlIHNlc3Npb25zIGJhY2sgdG8gaHViIHBvaW50cy4gQWRtaW4gb25seS4AAAAAEXJlbW92ZV9nYW1lX3Rva2VuAAAAAAAAAQAAAAAAAAAHZ2FtZV9pZAAAAAATAAAAAA==",
+        "AAAAAAAAADVHZXQgdGhlIHRva2VuIGEgZ2FtZSdzIHNlc3Npb25zIGFyZSBzdGFrZWQgaW4sIGlmIGFueQAAAAAAAA5nZXRfZ2FtZV90b2tlbgAAAAAAAQAAAAAAAAAHZ2FtZV9pZAAAAAATAAAAAQAAA+gAAAAT",
+        "AAAAAAAAAP9TZXQgdGhlIGhvdXNlIGZlZSB0YWtlbiBmcm9tIGEgZ2FtZSdzIHdpbm5pbmcgcGF5b3V0cy4gQWRtaW4gb25seS4KU2Vzc2lvbnMgYWxyZWFkeSBydW5uaW5nIGtlZXAgdGhlIGZlZSB0aGV5IHN0YXJ0ZWQgd2l0aC4KCiMgQXJndW1lbnRzCiogYGdhbWVfaWRgIC0gR2FtZSBjb250cmFjdCB0aGUgZmVlIGFwcGxpZXMgdG8KKiBgZmVlX2Jwc2AgLSBTaGFyZSBvZiBlYWNoIHdpbm5pbmcgcGF5b3V0IGtlcHQgYnkgdGhlIGh1YiAobWF4IDEwXzAwMCkAAAAADHNldF9nYW1lX2ZlZQAAAAIAAAAAAAAAB2dhbWVfaWQAAAAAEwAAAAAAAAAHZmVlX2JwcwAAAAAEAAAAAQAAA+kAAAACAAAAAw==",
+        "AAAAAAAAADpHZXQgYSBnYW1lJ3MgaG91c2UgZmVlIGluIGJhc2lzIHBvaW50cyAoMCBpZiBub25lIHdhcyBzZXQpAAAAAAAMZ2V0X2dhbWVfZmVlAAAAAQAAAAAAAAAHZ2FtZV9pZAAAAAATAAAAAQAAAAQ=",
+        "AAAAAAAAADhTZXQgdGhlIGFkZHJlc3MgZmVlIHdpdGhkcmF3YWxzIGFyZSBwYWlkIHRvLiBBZG1pbiBvbmx5LgAAAAxzZXRfdHJlYXN1cnkAAAABAAAAAAAAAAh0cmVhc3VyeQAAABMAAAAA",
+        "AAAAAAAAADVHZXQgdGhlIHRyZWFzdXJ5IGFkZHJlc3MgKHRoZSBhZG1pbiB1bnRpbCBvbmUgaXMgc2V0KQAAAAAAAAxnZXRfdHJlYXN1cnkAAAAAAAAAAQAAABM=",
+        "AAAAAAAAAHlHZXQgdGhlIGZlZXMgYWNjcnVlZCBhbmQgbm90IHlldCB3aXRoZHJhd24gaW4gb25lIGFzc2V0CgojIEFyZ3VtZW50cwoqIGB0b2tlbmAgLSBUb2tlbiBjb250cmFjdCwgb3IgYE5vbmVgIGZvciBodWIgcG9pbnRzAAAAAAAAEGdldF9hY2NydWVkX2ZlZXMAAAABAAAAAAAAAAV0b2tlbgAAAAAAA+gAAAATAAAAAQAAAAs=",
+        "AAAAAAAAAPxQYXkgYWNjcnVlZCBmZWVzIG91dCB0byB0aGUgdHJlYXN1cnkuIEFkbWluIG9ubHkuIFRva2VuIGZlZXMgYXJlCnRyYW5zZmVycmVkOyBwb2ludCBmZWVzIGFyZSBjcmVkaXRlZCB0byB0aGUgdHJlYXN1cnkncyBwb2ludHMgYmFsYW5jZS4KCiMgQXJndW1lbnRzCiogYHRva2VuYCAtIFRva2VuIGNvbnRyYWN0LCBvciBgTm9uZWAgZm9yIGh1YiBwb2ludHMKKiBgYW1vdW50YCAtIEFtb3VudCB0byB3aXRoZHJhdyAobXVzdCBiZSBwb3NpdGl2ZSkAAAANd2l0aGRyYXdfZmVlcwAAAAAAAAIAAAAAAAAABXRva2VuAAAAAAAD6AAAABMAAAAAAAAABmFtb3VudAAAAAAACwAAAAEAAAPpAAAAAgAAAAM=",
+        "AAAAAAAAAJ5DcmVkaXQgcG9pbnRzIHRvIGEgcGxheWVyJ3MgYXZhaWxhYmxlIGJhbGFuY2UuIEFkbWluIG9ubHkuCgojIEFyZ3VtZW50cwoqIGBwbGF5ZXJgIC0gUGxheWVyIHJlY2VpdmluZyB0aGUgcG9pbnRzCiogYGFtb3VudGAgLSBQb2ludHMgdG8gYWRkIChtdXN0IGJlIHBvc2l0aXZlKQAAAAAACmFkZF9wb2ludHMAAAAAAAIAAAAAAAAABnBsYXllcgAAAAAAEwAAAAAAAAAGYW1vdW50AAAAAAALAAAAAQAAA+kAAAACAAAAAw==",
+        "AAAAAAAAADJHZXQgYSBwbGF5ZXIncyBhdmFpbGFibGUgKHVubG9ja2VkKSBwb2ludHMgYmFsYW5jZQAAAAAAC2dldF9iYWxhbmNlAAAAAAEAAAAAAAAABnBsYXllcgAAAAAAEwAAAAEAAAAL",
+        "AAAAAAAAAEVHZXQgdGhlIHRvdGFsIHBvaW50cyBhIHBsYXllciBjdXJyZW50bHkgaGFzIGxvY2tlZCBpbiBhY3RpdmUgc2Vzc2lvbnMAAAAAAAAKZ2V0X2xvY2tlZAAAAAAAAQAAAAAAAAAGcGxheWVyAAAAAAATAAAAAQAAAAs=",
+        "AAAAAAAAAblSZXNlcnZlIHRoZSBuZXh0IHNlc3Npb24gSUQgZm9yIGEgZ2FtZS4gVGhlIElEIG11c3QgYmUgcGFzc2VkIHRvIHRoZQpnYW1lJ3MgYHN0YXJ0X2dhbWVgIHdpdGhpbiBgUkVTRVJWQVRJT05fVFRMX0xFREdFUlNgLCBhbmQgb25seSBhIHNlc3Npb24Kb2YgdGhhdCBnYW1lIHdpdGggYHJlc2VydmVyYCBhbW9uZyBpdHMgcGxheWVycyBjYW4gc3RhcnQgd2l0aCBpdC4KCioqQXV0aDoqKiBSZXF1aXJlcyBhdXRob3JpemF0aW9uIGZyb20gYHJlc2VydmVyYC4KCiMgQXJndW1lbnRzCiogYGdhbWVfaWRgIC0gUmVnaXN0ZXJlZCBnYW1lIGNvbnRyYWN0IHRoZSBzZXNzaW9uIHdpbGwgYmUgcGxheWVkIGluCiogYHJlc2VydmVyYCAtIFBsYXllciB3aG8gd2lsbCB0YWtlIHBhcnQgaW4gdGhlIHNlc3Npb24KCiMgUmV0dXJucwoqIGB1MzJgIC0gVGhlIHJlc2VydmVkIHNlc3Npb24gSUQAAAAAAAAPcmVzZXJ2ZV9zZXNzaW9uAAAAAAIAAAAAAAAAB2dhbWVfaWQAAAAAEwAAAAAAAAAIcmVzZXJ2ZXIAAAATAAAAAQAAA+kAAAAEAAAAAw==",
+        "AAAAAAAAADhHZXQgdGhlIElEIHRoZSBuZXh0IGByZXNlcnZlX3Nlc3Npb25gIGNhbGwgd2lsbCBoYW5kIG91dAAAAA9uZXh0X3Nlc3Npb25faWQAAAAAAAAAAAEAAAAE",
+        "AAAAAAAAADlHZXQgdGhlIHJlc2VydmF0aW9uIGZvciBhIHNlc3Npb24gSUQsIGlmIGl0IGlzIHN0aWxsIGxpdmUAAAAAAAAPZ2V0X3Jlc2VydmF0aW9uAAAAAAEAAAAAAAAACnNlc3Npb25faWQAAAAAAAQAAAABAAAD6AAAB9AAAAALUmVzZXJ2YXRpb24A",
+        "AAAAAAAAADNHZXQgdGhlIGh1YidzIHJlY29yZCBvZiBhIHNlc3Npb24gKGFjdGl2ZSBvciBlbmRlZCkAAAAAC2dldF9zZXNzaW9uAAAAAAEAAAAAAAAACnNlc3Npb25faWQAAAAAAAQAAAABAAAD6QAAB9AAAAAHU2Vzc2lvbgAAAAAD",
+        "AAAAAAAAAEVHZXQgYSBwbGF5ZXIncyBFbG8gcmF0aW5nIGluIGEgZ2FtZSAoMTUwMCBpZiB0aGV5IGhhdmUgbm90IHBsYXllZCBpdCkAAAAAAAAKZ2V0X3JhdGluZwAAAAAAAgAAAAAAAAAHZ2FtZV9pZAAAAAATAAAAAAAAAAZwbGF5ZXIAAAAAABMAAAABAAAH0AAAAAZSYXRpbmcAAA==",
+        "AAAAAAAAAPpEZWZpbmUgdGhlIG5leHQgc2Vhc29uLiBBZG1pbiBvbmx5LgpTZWFzb25zIGFyZSBudW1iZXJlZCBmcm9tIDEgYW5kIG11c3Qgc3RhcnQgYWZ0ZXIgdGhlIHByZXZpb3VzIG9uZSBlbmRzLgoKIyBBcmd1bWVudHMKKiBgc3RhcnRfbGVkZ2VyYCAtIEZpcnN0IGxlZGdlciBvZiB0aGUgc2Vhc29uCiogYGVuZF9sZWRnZXJgIC0gTGFzdCBsZWRnZXIgb2YgdGhlIHNlYXNvbgoKIyBSZXR1cm5zCiogYHUzMmAgLSBUaGUgbmV3IHNlYXNvbidzIElEAAAAAAANY3JlYXRlX3NlYXNvbgAAAAAAAAIAAAAAAAAADHN0YXJ0X2xlZGdlcgAAAAQAAAAAAAAACmVuZF9sZWRnZXIAAAAAAAQAAAABAAAD6QAAAAQAAAAD",
+        "AAAAAAAAAKhGcmVlemUgYSBzZWFzb24ncyBzdGF0cyBhbmQgbGVhZGVyYm9hcmRzIGFuZCBlbWl0IGl0cyBzdW1tYXJ5LiBBZG1pbiBvbmx5LgpTZXNzaW9ucyBzZXR0bGVkIGFmdGVyd2FyZHMgbm8gbG9uZ2VyIGNvdW50IHRvd2FyZHMgaXQsIGV2ZW4gaWYgaXRzCndpbmRvdyBoYXMgbm90IGVuZGVkIHlldC4AAAAMY2xvc2Vfc2Vhc29uAAAAAQAAAAAAAAAJc2Vhc29uX2lkAAAAAAAABAAAAAEAAAPpAAAAAgAAAAM=",
+        "AAAAAAAAACxHZXQgYSBzZWFzb24ncyBkZWZpbml0aW9uIGFuZCBydW5uaW5nIHRvdGFscwAAAApnZXRfc2Vhc29uAAAAAAABAAAAAAAAAAlzZWFzb25faWQAAAAAAAAEAAAAAQAAA+kAAAfQAAAABlNlYXNvbgAAAAAAAw==",
+        "AAAAAAAAADtHZXQgdGhlIElEIG9mIHRoZSBzZWFzb24gb3BlbiBhdCB0aGUgY3VycmVudCBsZWRnZXIsIGlmIGFueQAAAAASZ2V0X2N1cnJlbnRfc2Vhc29uAAAAAAAAAAAAAQAAA+gAAAAE",
+        "AAAAAAAAADRHZXQgYSBwbGF5ZXIncyByZXN1bHRzIGluIG9uZSBnYW1lIGR1cmluZyBvbmUgc2Vhc29uAAAAEGdldF9zZWFzb25fc3RhdHMAAAADAAAAAAAAAAlzZWFzb25faWQAAAAAAAAEAAAAAAAAAAdnYW1lX2lkAAAAABMAAAAAAAAABnBsYXllcgAAAAAAEwAAAAEAAAfQAAAAC1NlYXNvblN0YXRzAA==",
+        "AAAAAAAAAD9HZXQgdGhlIHRvcCBwbGF5ZXJzIGJ5IG5ldCBwb2ludHMgaW4gb25lIGdhbWUgZHVyaW5nIG9uZSBzZWFzb24AAAAAD2dldF9sZWFkZXJib2FyZAAAAAACAAAAAAAAAAlzZWFzb25faWQAAAAAAAAEAAAAAAAAAAdnYW1lX2lkAAAAABMAAAABAAAD6gAAB9AAAAAQTGVhZGVyYm9hcmRFbnRyeQ==",
+        "AAAAAAAAAW1TZXQgdGhlIGNvc21ldGljIGZpZWxkcyBvZiB0aGUgY2FsbGVyJ3MgcHJvZmlsZS4gTGlmZXRpbWUgc3RhdHMgYW5kIFhQCmFyZSBrZXB0IGJ5IHRoZSBodWIgYW5kIGNhbm5vdCBiZSBjaGFuZ2VkIGhlcmUuCgoqKkF1dGg6KiogUmVxdWlyZXMgYXV0aG9yaXphdGlvbiBmcm9tIGBwbGF5ZXJgLgoKIyBBcmd1bWVudHMKKiBgcGxheWVyYCAtIFBsYXllciB3aG9zZSBwcm9maWxlIGlzIHVwZGF0ZWQKKiBgZGlzcGxheV9uYW1lX2hhc2hgIC0gSGFzaCBvZiB0aGUgZGlzcGxheSBuYW1lLCBvciBgTm9uZWAgdG8gY2xlYXIgaXQKKiBgYXZhdGFyX2hhc2hgIC0gQ29udGVudCBoYXNoIG9mIHRoZSBhdmF0YXIsIG9yIGBOb25lYCB0byBjbGVhciBpdAAAAAAAAAtzZXRfcHJvZmlsZQAAAAADAAAAAAAAAAZwbGF5ZXIAAAAAABMAAAAAAAAAEWRpc3BsYXlfbmFtZV9oYXNoAAAAAAAD6AAAA+4AAAAgAAAAAAAAAAthdmF0YXJfaGFzaAAAAAPoAAAD7gAAACAAAAAA",
+        "AAAAAAAAAENHZXQgYSBwbGF5ZXIncyBwcm9maWxlIChlbXB0eSBpZiB0aGV5IGhhdmUgbmV2ZXIgcGxheWVkIG9yIHNldCBvbmUpAAAAAAtnZXRfcHJvZmlsZQAAAAABAAAAAAAAAAZwbGF5ZXIAAAAAABMAAAABAAAH0AAAAA1QbGF5ZXJQcm9maWxlAAAA",
+        "AAAAAAAAADhOdW1iZXIgb2Ygc2Vzc2lvbnMgYSBwbGF5ZXIgaGFzIHdvbiBvdXRyaWdodCBpbiBvbmUgZ2FtZQAAAA1nZXRfZ2FtZV93aW5zAAAAAAAAAgAAAAAAAAAHZ2FtZV9pZAAAAAATAAAAAAAAAAZwbGF5ZXIAAAAAABMAAAABAAAABA==",
+        "AAAAAAAAAK9EZWZpbmUgYSBuZXcgYWNoaWV2ZW1lbnQuIEFkbWluIG9ubHkuIFBsYXllcnMgYXJlIGNoZWNrZWQgYWdhaW5zdCBpdAp0aGUgbmV4dCB0aW1lIG9uZSBvZiBpdHMgY291bnRlcnMgY2hhbmdlcy4KCiMgUmV0dXJucwoqIGB1MzJgIC0gSUQgb2YgdGhlIG5ldyBhY2hpZXZlbWVudCAoSURzIHN0YXJ0IGF0IDEpAAAAABJkZWZpbmVfYWNoaWV2ZW1lbnQAAAAAAAEAAAAAAAAAC2FjaGlldmVtZW50AAAAB9AAAAALQWNoaWV2ZW1lbnQAAAAAAQAAA+kAAAAEAAAAAw==",
+        "AAAAAAAAAEVTdG9wIGF3YXJkaW5nIGFuIGFjaGlldmVtZW50LiBBZG1pbiBvbmx5LiBCYWRnZXMgYWxyZWFkeSBlYXJuZWQgc3RheS4AAAAAAAAScmV0aXJlX2FjaGlldmVtZW50AAAAAAABAAAAAAAAAA5hY2hpZXZlbWVudF9pZAAAAAAABAAAAAEAAAPpAAAAAgAAAAM=",
+        "AAAAAAAAADFHZXQgYW4gYWNoaWV2ZW1lbnQgZGVmaW5pdGlvbiAoYWN0aXZlIG9yIHJldGlyZWQpAAAAAAAAD2dldF9hY2hpZXZlbWVudAAAAAABAAAAAAAAAA5hY2hpZXZlbWVudF9pZAAAAAAABAAAAAEAAAPpAAAH0AAAAAtBY2hpZXZlbWVudAAAAAAD",
+        "AAAAAAAAACtJRHMgb2YgdGhlIGFjaGlldmVtZW50cyBzdGlsbCBiZWluZyBhd2FyZGVkAAAAABFsaXN0X2FjaGlldmVtZW50cwAAAAAAAAAAAAABAAAD6gAAAAQ=",
+        "AAAAAAAAAZtBZGQgdG8gYSBwbGF5ZXIncyBydW5uaW5nIHRvdGFsIG9mIGEgZ2FtZS1zcGVjaWZpYyBzdGF0LCBhbmQgYXdhcmQgYW55CmFjaGlldmVtZW50IG9uIHRoYXQgc3RhdCB0aGUgbmV3IHRvdGFsIHJlYWNoZXMuCgoqKkF1dGg6KiogUmVxdWlyZXMgYXV0aG9yaXphdGlvbiBmcm9tIHRoZSBnYW1lIGNvbnRyYWN0IHRoYXQgc3RhcnRlZCB0aGUgc2Vzc2lvbi4KCiMgQXJndW1lbnRzCiogYHNlc3Npb25faWRgIC0gU2Vzc2lvbiB0aGUgc3RhdCB3YXMgZWFybmVkIGluIChhY3RpdmUgb3IgZW5kZWQpCiogYHBsYXllcmAgLSBQYXJ0aWNpcGFudCB0aGUgc3RhdCBiZWxvbmdzIHRvCiogYGtleWAgLSBTdGF0IG5hbWUsIGRlZmluZWQgYnkgdGhlIGdhbWUKKiBgdmFsdWVgIC0gQW1vdW50IHRvIGFkZCAobXVzdCBiZSBwb3NpdGl2ZSkAAAAAC3JlcG9ydF9zdGF0AAAAAAQAAAAAAAAACnNlc3Npb25faWQAAAAAAAQAAAAAAAAABnBsYXllcgAAAAAAEwAAAAAAAAADa2V5AAAAABEAAAAAAAAABXZhbHVlAAAAAAAACwAAAAEAAAPpAAAAAgAAAAM=",
+        "AAAAAAAAADRHZXQgYSBwbGF5ZXIncyBydW5uaW5nIHRvdGFsIG9mIGEgZ2FtZS1yZXBvcnRlZCBzdGF0AAAACGdldF9zdGF0AAAAAwAAAAAAAAAHZ2FtZV9pZAAAAAATAAAAAAAAAAZwbGF5ZXIAAAAAABMAAAAAAAAAA2tleQAAAAARAAAAAQAAAAs=",
+        "AAAAAAAAADFHZXQgZXZlcnkgYmFkZ2UgYSBwbGF5ZXIgaGFzIGVhcm5lZCwgb2xkZXN0IGZpcnN0AAAAAAAACmdldF9iYWRnZXMAAAAAAAEAAAAAAAAABnBsYXllcgAAAAAAEwAAAAEAAAPqAAAH0AAAAAVCYWRnZQAAAA==",
+        "AAAAAAAAADBDaGVjayB3aGV0aGVyIGEgcGxheWVyIGhhcyBlYXJuZWQgYW4gYWNoaWV2ZW1lbnQAAAAJaGFzX2JhZGdlAAAAAAAAAgAAAAAAAAAGcGxheWVyAAAAAAATAAAAAAAAAA5hY2hpZXZlbWVudF9pZAAAAAAABAAAAAEAAAAB",
+        "AAAAAAAAAChHZXQgdGhlIG1hdGNoIHJlY29yZCBvZiBhbiBlbmRlZCBzZXNzaW9uAAAACWdldF9tYXRjaAAAAAAAAAEAAAAAAAAACnNlc3Npb25faWQAAAAAAAQAAAABAAAD6QAAB9AAAAALTWF0Y2hSZWNvcmQAAAAAAw==",
+        "AAAAAAAAAC5OdW1iZXIgb2YgZW5kZWQgc2Vzc2lvbnMgYSBwbGF5ZXIgdG9vayBwYXJ0IGluAAAAAAAYY291bnRfc2Vzc2lvbnNfYnlfcGxheWVyAAAAAQAAAAAAAAAGcGxheWVyAAAAAAATAAAAAQAAAAQ=",
+        "AAAAAAAAAOVQYWdlIHRocm91Z2ggYSBwbGF5ZXIncyBlbmRlZCBzZXNzaW9ucywgb2xkZXN0IGZpcnN0LgoKIyBBcmd1bWVudHMKKiBgcGxheWVyYCAtIFBsYXllciB3aG9zZSBoaXN0b3J5IHRvIHJlYWQKKiBgY3Vyc29yYCAtIEluZGV4IG9mIHRoZSBmaXJzdCByZWNvcmQgdG8gcmV0dXJuICgwIGZvciB0aGUgb2xkZXN0KQoqIGBsaW1pdGAgLSBNYXhpbXVtIHJlY29yZHMgdG8gcmV0dXJuIChjYXBwZWQgYXQgMjUpAAAAAAAAF2xpc3Rfc2Vzc2lvbnNfYnlfcGxheWVyAAAAAAMAAAAAAAAABnBsYXllcgAAAAAAEwAAAAAAAAAGY3Vyc29yAAAAAAAEAAAAAAAAAAVsaW1pdAAAAAAAAAQAAAABAAAD6gAAB9AAAAALTWF0Y2hSZWNvcmQA",
+        "AAAAAAAAACJOdW1iZXIgb2YgZW5kZWQgc2Vzc2lvbnMgb2YgYSBnYW1lAAAAAAAWY291bnRfc2Vzc2lvbnNfYnlfZ2FtZQAAAAAAAQAAAAAAAAAHZ2FtZV9pZAAAAAATAAAAAQAAAAQ=",
+        "AAAAAAAAAOJQYWdlIHRocm91Z2ggYSBnYW1lJ3MgZW5kZWQgc2Vzc2lvbnMsIG9sZGVzdCBmaXJzdC4KCiMgQXJndW1lbnRzCiogYGdhbWVfaWRgIC0gR2FtZSB3aG9zZSBoaXN0b3J5IHRvIHJlYWQKKiBgY3Vyc29yYCAtIEluZGV4IG9mIHRoZSBmaXJzdCByZWNvcmQgdG8gcmV0dXJuICgwIGZvciB0aGUgb2xkZXN0KQoqIGBsaW1pdGAgLSBNYXhpbXVtIHJlY29yZHMgdG8gcmV0dXJuIChjYXBwZWQgYXQgMjUpAAAAAAAVbGlzdF9zZXNzaW9uc19ieV9nYW1lAAAAAAAAAwAAAAAAAAAHZ2FtZV9pZAAAAAATAAAAAAAAAAZjdXJzb3IAAAAAAAQAAAAAAAAABWxpbWl0AAAAAAAABAAAAAEAAAPqAAAH0AAAAAtNYXRjaFJlY29yZAA=",
+        "AAAAAAAAAB1HZXQgdGhlIGN1cnJlbnQgYWRtaW4gYWRkcmVzcwAAAAAAAAlnZXRfYWRtaW4AAAAAAAAAAAAAAQAAABM=",
+        "AAAAAAAAADFHZXQgdGhlIGFkbWluIHByb3Bvc2VkIGJ5IGBwcm9wb3NlX2FkbWluYCwgaWYgYW55AAAAAAAAEWdldF9wZW5kaW5nX2FkbWluAAAAAAAAAAAAAAEAAAPoAAAAEw==",
+        "AAAAAAAAAH5Qcm9wb3NlIGEgbmV3IGFkbWluLiBDb250cm9sIG9ubHkgbW92ZXMgb25jZSB0aGV5IGNhbGwKYGFjY2VwdF9hZG1pbmA7IGEgbGF0ZXIgcHJvcG9zYWwgcmVwbGFjZXMgdGhpcyBvbmUuIEN1cnJlbnQgYWRtaW4gb25seS4AAAAAAA1wcm9wb3NlX2FkbWluAAAAAAAAAQAAAAAAAAAJbmV3X2FkbWluAAAAAAAAEwAAAAA=",
+        "AAAAAAAAAEFBY2NlcHQgYSB0cmFuc2ZlciBzdGFydGVkIGJ5IGBwcm9wb3NlX2FkbWluYC4gUGVuZGluZyBhZG1pbiBvbmx5LgAAAAAAAAxhY2NlcHRfYWRtaW4AAAAAAAAAAQAAA+kAAAACAAAAAw==",
+        "AAAAAAAAAD1VcGRhdGUgdGhlIGNvbnRyYWN0IFdBU00gaGFzaCAodXBncmFkZSBjb250cmFjdCkuIEFkbWluIG9ubHkuAAAAAAAAB3VwZ3JhZGUAAAAAAQAAAAAAAAANbmV3X3dhc21faGFzaAAAAAAAA+4AAAAgAAAAAA==" ]),
+      options
+    )
+  }
+  public readonly fromJSON = {
+    start_game: this.txFromJSON<Result<void>>,
+        start_multiplayer_game: this.txFromJSON<Result<void>>,
+        end_game: this.txFromJSON<Result<void>>,
+        end_game_with_outcome: this.txFromJSON<Result<void>>,
+        end_game_with_placements: this.txFromJSON<Result<void>>,
+        claim_timeout: this.txFromJSON<Result<GameOutcome>>,
+        add_game: this.txFromJSON<null>,
+        remove_game: this.txFromJSON<null>,
+        is_game_registered: this.txFromJSON<boolean>,
+        set_game_config: this.txFromJSON<Result<void>>,
+        get_game_config: this.txFromJSON<GameConfig>,
+        pause: this.txFromJSON<null>,
+        unpause: this.txFromJSON<null>,
+        is_paused: this.txFromJSON<boolean>,
+        cancel_paused_session: this.txFromJSON<Result<void>>,
+        set_game_token: this.txFromJSON<Result<void>>,
+        remove_game_token: this.txFromJSON<null>,
+        get_game_token: this.txFromJSON<Option<string>>,
+        set_game_fee: this.txFromJSON<Result<void>>,
+        get_game_fee: this.txFromJSON<u32>,
+        set_treasury: this.txFromJSON<null>,
+        get_treasury: this.txFromJSON<string>,
+        get_accrued_fees: this.txFromJSON<i128>,
+        withdraw_fees: this.txFromJSON<Result<void>>,
+        add_points: this.txFromJSON<Result<void>>,
+        get_balance: this.txFromJSON<i128>,
+        get_locked: this.txFromJSON<i128>,
+        reserve_session: this.txFromJSON<Result<u32>>,
+        next_session_id: this.txFromJSON<u32>,
+        get_reservation: this.txFromJSON<Option<Reservation>>,
+        get_session: this.txFromJSON<Result<Session>>,
+        get_rating: this.txFromJSON<Rating>,
+        create_season: this.txFromJSON<Result<u32>>,
+        close_season: this.txFromJSON<Result<void>>,
+        get_season: this.txFromJSON<Result<Season>>,
+        get_current_season: this.txFromJSON<Option<u32>>,
+        get_season_stats: this.txFromJSON<SeasonStats>,
+        get_leaderboard: this.txFromJSON<Array<LeaderboardEntry>>,
+        set_profile: this.txFromJSON<null>,
+        get_profile: this.txFromJSON<PlayerProfile>,
+        get_game_wins: this.txFromJSON<u32>,
+        define_achievement: this.txFromJSON<Result<u32>>,
+        retire_achievement: this.txFromJSON<Result<void>>,
+        get_achievement: this.txFromJSON<Result<Achievement>>,
+        list_achievements: this.txFromJSON<Array<u32>>,
+        report_stat: this.txFromJSON<Result<void>>,
+        get_stat: this.txFromJSON<i128>,
+        get_badges: this.txFromJSON<Array<Badge>>,
+        has_badge: this.txFromJSON<boolean>,
+        get_match: this.txFromJSON<Result<MatchRecord>>,
+        count_sessions_by_player: this.txFromJSON<u32>,
+        list_sessions_by_player: this.txFromJSON<Array<MatchRecord>>,
+        count_sessions_by_game: this.txFromJSON<u32>,
+        list_sessions_by_game: this.txFromJSON<Array<MatchRecord>>,
+        get_admin: this.txFromJSON<string>,
+        get_pending_admin: this.txFromJSON<Option<string>>,
+        propose_admin: this.txFromJSON<null>,
+        accept_admin: this.txFromJSON<Result<void>>,
+        upgrade: this.txFromJSON<null>
+  }
+}
\ No newline at end of file
@@ -0,0 +1,67 @@
+/**
+ * Commit-reveal utilities for sealed moves
+ *
+ * Commitments are hashed in the browser and the secrets behind them are kept
+ * in localStorage, so a secret only leaves this device when the player reveals it.
+ */
+
+import { Buffer } from 'buffer';
+import { keccak_256 } from '@noble/hashes/sha3';
+
+const SECRET_STORAGE_PREFIX = 'sgs-secret';
+
+/**
+ * Generate a random 32-byte secret (salt or seed)
+ */
+export function randomSecret(): Buffer {
+  return Buffer.from(crypto.getRandomValues(new Uint8Array(32)));
+}
+
+/**
+ * keccak256 of the concatenated parts, as the contracts compute it
+ */
+export function keccak256(...parts: Uint8Array[]): Buffer {
+  return Buffer.from(keccak_256(Buffer.concat(parts)));
+}
+
+/**
+ * Big-endian bytes of a u32 (Rust `u32::to_be_bytes`)
+ */
+export function u32Bytes(value: number): Buffer {
+  const bytes = Buffer.alloc(4);
+  bytes.writeUInt32BE(value);
+  return bytes;
+}
+
+/**
+ * Bytes of an address as contracts hash it (`address.to_string().to_bytes()`)
+ */
+export function addressBytes(address: string): Buffer {
+  return Buffer.from(address, 'utf8');
+}
+
+function secretStorageKey(contractId: string, sessionId: number, player: string): string {
+  return `${SECRET_STORAGE_PREFIX}:${contractId}:${sessionId}:${player}`;
+}
+
+/**
+ * Keep a player's secret for a session until they reveal it
+ */
+export function saveSecret<T>(contractId: string, sessionId: number, player: string, secret: T): void {
+  localStorage.setItem(secretStorageKey(contractId, sessionId, player), JSON.stringify(secret));
+}
+
+/**
+ * Load a secret saved with saveSecret, or null if this device has none
+ */
+export function loadSecret<T>(contractId: string, sessionId: number, player: string): T | null {
+  const stored = localStorage.getItem(secretStorageKey(contractId, sessionId, player));
+  return stored ? (JSON.parse(stored) as T) : null;
+}
+
+/**
+ * Forget a secret once it has been revealed
+ */
+export function clearSecret(contractId: string, sessionId: number, player: string): void {
+  localStorage.removeItem(secretStorageKey(contractId, sessionId, player));
+}
@@ -39,127 +39,280 @@ export const networks = {
 } as const
 
 
+export const Errors = {
+  1: {message:"GameNotFound"},
+  2: {message:"NotPlayer"},
+  3: {message:"AlreadyGuessed"},
+  4: {message:"BothPlayersNotGuessed"},
+  5: {message:"GameAlreadyEnded"},
+  6: {message:"PlayerNotStalling"},
+  7: {message:"ContractPaused"},
+  8: {message:"SessionAlreadyExists"},
+  9: {message:"BothPlayersNotCommitted"},
+  10: {message:"AlreadyRevealed"},
+  11: {message:"CommitmentMismatch"}
+}
+
+export type GameStatus = {tag: "Active", values: void} | {tag: "Ended", values: readonly [GameOutcome]};
+
 export interface Game {
   player1: string;
+  player1_commitment: Option<Buffer>;
+  /**
+   * Revealed guesses, set by `reveal_guess`
+   */
   player1_guess: Option<u32>;
   player1_points: i128;
+  /**
+   * Revealed salts, set by `reveal_guess` and mixed into the winning
+   * number's seed
+   */
+  player1_salt: Option<Buffer>;
   player2: string;
+  player2_commitment: Option<Buffer>;
   player2_guess: Option<u32>;
   player2_points: i128;
+  player2_salt: Option<Buffer>;
+  status: GameStatus;
   winner: Option<string>;
   winning_number: Option<u32>;
 }
 
-export const Errors = {
-  1: {message:"GameNotFound"},
-  2: {message:"NotPlayer"},
-  3: {message:"AlreadyGuessed"},
-  4: {message:"BothPlayersNotGuessed"},
-  5: {message:"GameAlreadyEnded"}
+export type DataKey = {tag: "Game", values: readonly [u32]};
+
+export const CommonError = {
+  100: {message:"NoPendingAdmin"}
 }
 
-export type DataKey = {tag: "Game", values: readonly [u32]} | {tag: "GameHubAddress", values: void} | {tag: "Admin", values: void};
+/**
+ * Session outcome reported to the Game Hub (mirrors the hub's `GameOutcome`)
+ */
+export type GameOutcome = {tag: "Player1Won", values: void} | {tag: "Player2Won", values: void} | {tag: "Draw", values: void} | {tag: "Cancelled", values: void} | {tag: "Forfeit", values: readonly [string]};
+
+/**
+ * How the hub settles a game's stakes
+ */
+export type StakeSemantics = {tag: "WinnerTakesAll", values: void} | {tag: "Placements", values: void};
+
+/**
+ * Static description of a game, for lobbies and hub tools
+ */
+export interface GameInfo {
+  max_players: u32;
+  min_players: u32;
+  name: string;
+  /**
+   * Bumped whenever a change to the rules could change an outcome
+   */
+  rules_version: u32;
+  stakes: StakeSemantics;
+}
+
+/**
+ * Game-independent view of a session's status
+ */
+export type SessionStatus = {tag: "Active", values: void} | {tag: "Ended", values: readonly [GameOutcome]};
+
+/**
+ * Roles the admin can grant. The admin implicitly holds every role.
+ */
+export type Role = {tag: "Operator", values: void};
 
 export interface Client {
   /**
-   * Construct and simulate a get_hub transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
-   * Get the current GameHub contract address
+   * Construct and simulate a start_game transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Start a new game between two players with points.
+   * This creates a session in the Game Hub and locks points before starting the game.
    * 
-   * # Returns
-   * * `Address` - The GameHub contract address
+   * **CRITICAL:** This method requires authorization from THIS contract (not players).
+   * The Game Hub will call `game_id.require_auth()` which checks this contract's address.
+   * 
+   * # Arguments
+   * * `session_id` - Session ID reserved on the Game Hub (`reserve_session`)
+   * * `player1` - Address of first player
+   * * `player2` - Address of second player
+   * * `player1_points` - Points amount committed by player 1
+   * * `player2_points` - Points amount committed by player 2
    */
-  get_hub: (options?: MethodOptions) => Promise<AssembledTransaction<string>>
+  start_game: ({session_id, player1, player2, player1_points, player2_points}: {session_id: u32, player1: string, player2: string, player1_points: i128, player2_points: i128}, options?: MethodOptions) => Promise<AssembledTransaction<Result<void>>>
 
   /**
-   * Construct and simulate a set_hub transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
-   * Set a new GameHub contract address
+   * Construct and simulate a commit_guess transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Commit a sealed guess for the current game.
+   * The guess stays hidden until both players have committed.
    * 
    * # Arguments
-   * * `new_hub` - The new GameHub contract address
+   * * `session_id` - The session ID of the game
+   * * `player` - Address of the player committing
+   * * `commitment` - `keccak256(guess || salt || player_address)`, with the
+   * guess as 4 big-endian bytes and a 32-byte secret salt
    */
-  set_hub: ({new_hub}: {new_hub: string}, options?: MethodOptions) => Promise<AssembledTransaction<null>>
+  commit_guess: ({session_id, player, commitment}: {session_id: u32, player: string, commitment: Buffer}, options?: MethodOptions) => Promise<AssembledTransaction<Result<void>>>
 
   /**
-   * Construct and simulate a upgrade transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
-   * Update the contract WASM hash (upgrade contract)
+   * Construct and simulate a reveal_guess transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Reveal a committed guess. Can only be called after both players have
+   * committed. Players can guess a number between 1 and 10.
    * 
    * # Arguments
-   * * `new_wasm_hash` - The hash of the new WASM binary
+   * * `session_id` - The session ID of the game
+   * * `player` - Address of the player revealing
+   * * `guess` - The guessed number (1-10)
+   * * `salt` - The salt used in the commitment
    */
-  upgrade: ({new_wasm_hash}: {new_wasm_hash: Buffer}, options?: MethodOptions) => Promise<AssembledTransaction<null>>
+  reveal_guess: ({session_id, player, guess, salt}: {session_id: u32, player: string, guess: u32, salt: Buffer}, options?: MethodOptions) => Promise<AssembledTransaction<Result<void>>>
 
   /**
-   * Construct and simulate a get_game transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
-   * Get game information.
+   * Construct and simulate a reveal_winner transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Reveal the winner of the game and submit outcome to GameHub.
+   * Can only be called after both players have revealed their guesses.
+   * This generates the winning number, determines the winner, and ends the session.
    * 
    * # Arguments
    * * `session_id` - The session ID of the game
    * 
    * # Returns
-   * * `Game` - The game state (includes winning number after game ends)
+   * * `Option<Address>` - Address of the winning player, or `None` for a draw
    */
-  get_game: ({session_id}: {session_id: u32}, options?: MethodOptions) => Promise<AssembledTransaction<Result<Game>>>
+  reveal_winner: ({session_id}: {session_id: u32}, options?: MethodOptions) => Promise<AssembledTransaction<Result<Option<string>>>>
 
   /**
-   * Construct and simulate a get_admin transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
-   * Get the current admin address
+   * Construct and simulate a get_game transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Get game information.
+   * 
+   * # Arguments
+   * * `session_id` - The session ID of the game
    * 
    * # Returns
-   * * `Address` - The admin address
+   * * `Game` - The game state (includes winning number after game ends)
    */
-  get_admin: (options?: MethodOptions) => Promise<AssembledTransaction<string>>
+  get_game: ({session_id}: {session_id: u32}, options?: MethodOptions) => Promise<AssembledTransaction<Result<Game>>>
 
   /**
-   * Construct and simulate a set_admin transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
-   * Set a new admin address
+   * Construct and simulate a on_session_forfeited transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Called by the Game Hub when a timeout is claimed against `player`.
+   * Only accepted if `player` has not committed while their opponent has,
+   * or has not revealed while their opponent has; the game then ends as a
+   * forfeit by `player`. The hub settles the points.
    * 
    * # Arguments
-   * * `new_admin` - The new admin address
+   * * `session_id` - The session ID of the game
+   * * `player` - Address of the player being forfeited
    */
-  set_admin: ({new_admin}: {new_admin: string}, options?: MethodOptions) => Promise<AssembledTransaction<null>>
+  on_session_forfeited: ({session_id, player}: {session_id: u32, player: string}, options?: MethodOptions) => Promise<AssembledTransaction<Result<void>>>
 
   /**
-   * Construct and simulate a make_guess transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
-   * Make a guess for the current game.
-   * Players can guess a number between 1 and 10.
+   * Construct and simulate a on_session_cancelled transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Called by the Game Hub when it cancels the session (timeout or pause).
+   * The game ends without a winner; the hub refunds both stakes.
    * 
    * # Arguments
    * * `session_id` - The session ID of the game
-   * * `player` - Address of the player making the guess
-   * * `guess` - The guessed number (1-10)
    */
-  make_guess: ({session_id, player, guess}: {session_id: u32, player: string, guess: u32}, options?: MethodOptions) => Promise<AssembledTransaction<Result<void>>>
+  on_session_cancelled: ({session_id}: {session_id: u32}, options?: MethodOptions) => Promise<AssembledTransaction<Result<void>>>
 
   /**
-   * Construct and simulate a start_game transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
-   * Start a new game between two players with points.
-   * This creates a session in the Game Hub and locks points before starting the game.
-   * 
-   * **CRITICAL:** This method requires authorization from THIS contract (not players).
-   * The Game Hub will call `game_id.require_auth()` which checks this contract's address.
-   * 
-   * # Arguments
-   * * `session_id` - Unique session identifier (u32)
-   * * `player1` - Address of first player
-   * * `player2` - Address of second player
-   * * `player1_points` - Points amount committed by player 1
-   * * `player2_points` - Points amount committed by player 2
+   * Construct and simulate a game_info transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Describe the game: two players, winner takes both stakes
    */
-  start_game: ({session_id, player1, player2, player1_points, player2_points}: {session_id: u32, player1: string, player2: string, player1_points: i128, player2_points: i128}, options?: MethodOptions) => Promise<AssembledTransaction<Result<void>>>
+  game_info: (options?: MethodOptions) => Promise<AssembledTransaction<GameInfo>>
 
   /**
-   * Construct and simulate a reveal_winner transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
-   * Reveal the winner of the game and submit outcome to GameHub.
-   * Can only be called after both players have made their guesses.
-   * This generates the winning number, determines the winner, and ends the session.
+   * Construct and simulate a get_session_status transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Get the status of a session, or `None` if it does not exist (or expired)
+   */
+  get_session_status: ({session_id}: {session_id: u32}, options?: MethodOptions) => Promise<AssembledTransaction<Option<SessionStatus>>>
+
+  /**
+   * Construct and simulate a forfeit transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Concede the game. The opponent wins and the Game Hub settles the points.
    * 
    * # Arguments
    * * `session_id` - The session ID of the game
-   * 
-   * # Returns
-   * * `Address` - Address of the winning player
+   * * `player` - Address of the player conceding
+   */
+  forfeit: ({session_id, player}: {session_id: u32, player: string}, options?: MethodOptions) => Promise<AssembledTransaction<Result<void>>>
+
+  /**
+   * Construct and simulate a get_admin transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Get the current admin address
+   */
+  get_admin: (options?: MethodOptions) => Promise<AssembledTransaction<string>>
+
+  /**
+   * Construct and simulate a get_pending_admin transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Get the admin proposed by `propose_admin`, if any
+   */
+  get_pending_admin: (options?: MethodOptions) => Promise<AssembledTransaction<Option<string>>>
+
+  /**
+   * Construct and simulate a propose_admin transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Propose a new admin. They take over once they call
+   * `accept_admin`.
+   */
+  propose_admin: ({new_admin}: {new_admin: string}, options?: MethodOptions) => Promise<AssembledTransaction<null>>
+
+  /**
+   * Construct and simulate a accept_admin transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Accept a pending admin transfer. Pending admin only.
+   */
+  accept_admin: (options?: MethodOptions) => Promise<AssembledTransaction<Result<void>>>
+
+  /**
+   * Construct and simulate a grant_role transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Grant a role to an account
+   */
+  grant_role: ({role, account}: {role: Role, account: string}, options?: MethodOptions) => Promise<AssembledTransaction<null>>
+
+  /**
+   * Construct and simulate a revoke_role transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Revoke a role from an account
+   */
+  revoke_role: ({role, account}: {role: Role, account: string}, options?: MethodOptions) => Promise<AssembledTransaction<null>>
+
+  /**
+   * Construct and simulate a has_role transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Check whether an account holds a role (the admin holds all)
+   */
+  has_role: ({role, account}: {role: Role, account: string}, options?: MethodOptions) => Promise<AssembledTransaction<boolean>>
+
+  /**
+   * Construct and simulate a get_hub transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Get the current GameHub contract address
+   */
+  get_hub: (options?: MethodOptions) => Promise<AssembledTransaction<string>>
+
+  /**
+   * Construct and simulate a set_hub transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Set a new GameHub contract address
+   */
+  set_hub: ({new_hub}: {new_hub: string}, options?: MethodOptions) => Promise<AssembledTransaction<null>>
+
+  /**
+   * Construct and simulate a pause transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Pause gameplay: new games and moves fail with `ContractPaused`
+   * until `unpause` is called. Hub callbacks still run, so sessions
+   * can be cancelled and refunded through the hub.
+   */
+  pause: (options?: MethodOptions) => Promise<AssembledTransaction<null>>
+
+  /**
+   * Construct and simulate a unpause transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Resume gameplay after `pause`
+   */
+  unpause: (options?: MethodOptions) => Promise<AssembledTransaction<null>>
+
+  /**
+   * Construct and simulate a is_paused transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Check whether the contract is paused
+   */
+  is_paused: (options?: MethodOptions) => Promise<AssembledTransaction<boolean>>
+
+  /**
+   * Construct and simulate a upgrade transaction. Returns an `AssembledTransaction` object which will have a `result` field containing the result of the simulation. If this transaction changes contract state, you will need to call `signAndSend()` on the returned object.
+   * Update the contract WASM hash (upgrade contract)
    */
-  reveal_winner: ({session_id}: {session_id: u32}, options?: MethodOptions) => Promise<AssembledTransaction<Result<string>>>
+  upgrade: ({new_wasm_hash}: {new_wasm_hash: Buffer}, options?: MethodOptions) => Promise<AssembledTransaction<null>>
 
 }
 export class Client extends ContractClient {
@@ -181,31 +334,66 @@ export class Client extends ContractClient {
   }
   constructor(public readonly options: ContractClientOptions) {
     super(
-      new ContractSpec([ "AAAAAQAAAAAAAAAAAAAABEdhbWUAAAAIAAAAAAAAAAdwbGF5ZXIxAAAAABMAAAAAAAAADXBsYXllcjFfZ3Vlc3MAAAAAAAPoAAAABAAAAAAAAAAOcGxheWVyMV9wb2ludHMAAAAAAAsAAAAAAAAAB3BsYXllcjIAAAAAEwAAAAAAAAANcGxheWVyMl9ndWVzcwAAAAAAA+gAAAAEAAAAAAAAAA5wbGF5ZXIyX3BvaW50cwAAAAAACwAAAAAAAAAGd2lubmVyAAAAAAPoAAAAEwAAAAAAAAAOd2lubmluZ19udW1iZXIAAAAAA+gAAAAE",
-        "AAAABAAAAAAAAAAAAAAABUVycm9yAAAAAAAABQAAAAAAAAAMR2FtZU5vdEZvdW5kAAAAAQAAAAAAAAAJTm90UGxheWVyAAAAAAAAAgAAAAAAAAAOQWxyZWFkeUd1ZXNzZWQAAAAAAAMAAAAAAAAAFUJvdGhQbGF5ZXJzTm90R3Vlc3NlZAAAAAAAAAQAAAAAAAAAEEdhbWVBbHJlYWR5RW5kZWQAAAAF",
-        "AAAAAgAAAAAAAAAAAAAAB0RhdGFLZXkAAAAAAwAAAAEAAAAAAAAABEdhbWUAAAABAAAABAAAAAAAAAAAAAAADkdhbWVIdWJBZGRyZXNzAAAAAAAAAAAAAAAAAAVBZG1pbgAAAA==",
-        "AAAAAAAAAF5HZXQgdGhlIGN1cnJlbnQgR2FtZUh1YiBjb250cmFjdCBhZGRyZXNzCgojIFJldHVybnMKKiBgQWRkcmVzc2AgLSBUaGUgR2FtZUh1YiBjb250cmFjdCBhZGRyZXNzAAAAAAAHZ2V0X2h1YgAAAAAAAAAAAQAAABM=",
-        "AAAAAAAAAF5TZXQgYSBuZXcgR2FtZUh1YiBjb250cmFjdCBhZGRyZXNzCgojIEFyZ3VtZW50cwoqIGBuZXdfaHViYCAtIFRoZSBuZXcgR2FtZUh1YiBjb250cmFjdCBhZGRyZXNzAAAAAAAHc2V0X2h1YgAAAAABAAAAAAAAAAduZXdfaHViAAAAABMAAAAA",
-        "AAAAAAAAAHFVcGRhdGUgdGhlIGNvbnRyYWN0IFdBU00gaGFzaCAodXBncmFkZSBjb250cmFjdCkKCiMgQXJndW1lbnRzCiogYG5ld193YXNtX2hhc2hgIC0gVGhlIGhhc2ggb2YgdGhlIG5ldyBXQVNNIGJpbmFyeQAAAAAAAAd1cGdyYWRlAAAAAAEAAAAAAAAADW5ld193YXNtX2hhc2gAAAAAAAPuAAAAIAAAAAA=",
-        "AAAAAAAAAJ1HZXQgZ2FtZSBpbmZvcm1hdGlvbi4KCiMgQXJndW1lbnRzCiogYHNlc3Npb25faWRgIC0gVGhlIHNlc3Npb24gSUQgb2YgdGhlIGdhbWUKCiMgUmV0dXJucwoqIGBHYW1lYCAtIFRoZSBnYW1lIHN0YXRlIChpbmNsdWRlcyB3aW5uaW5nIG51bWJlciBhZnRlciBnYW1lIGVuZHMpAAAAAAAACGdldF9nYW1lAAAAAQAAAAAAAAAKc2Vzc2lvbl9pZAAAAAAABAAAAAEAAAPpAAAH0AAAAARHYW1lAAAAAw==",
-        "AAAAAAAAAEhHZXQgdGhlIGN1cnJlbnQgYWRtaW4gYWRkcmVzcwoKIyBSZXR1cm5zCiogYEFkZHJlc3NgIC0gVGhlIGFkbWluIGFkZHJlc3MAAAAJZ2V0X2FkbWluAAAAAAAAAAAAAAEAAAAT",
-        "AAAAAAAAAEpTZXQgYSBuZXcgYWRtaW4gYWRkcmVzcwoKIyBBcmd1bWVudHMKKiBgbmV3X2FkbWluYCAtIFRoZSBuZXcgYWRtaW4gYWRkcmVzcwAAAAAACXNldF9hZG1pbgAAAAAAAAEAAAAAAAAACW5ld19hZG1pbgAAAAAAABMAAAAA",
-        "AAAAAAAAAOJNYWtlIGEgZ3Vlc3MgZm9yIHRoZSBjdXJyZW50IGdhbWUuClBsYXllcnMgY2FuIGd1ZXNzIGEgbnVtYmVyIGJldHdlZW4gMSBhbmQgMTAuCgojIEFyZ3VtZW50cwoqIGBzZXNzaW9uX2lkYCAtIFRoZSBzZXNzaW9uIElEIG9mIHRoZSBnYW1lCiogYHBsYXllcmAgLSBBZGRyZXNzIG9mIHRoZSBwbGF5ZXIgbWFraW5nIHRoZSBndWVzcwoqIGBndWVzc2AgLSBUaGUgZ3Vlc3NlZCBudW1iZXIgKDEtMTApAAAAAAAKbWFrZV9ndWVzcwAAAAAAAwAAAAAAAAAKc2Vzc2lvbl9pZAAAAAAABAAAAAAAAAAGcGxheWVyAAAAAAATAAAAAAAAAAVndWVzcwAAAAAAAAQAAAABAAAD6QAAA+0AAAAAAAAAAw==",
-        "AAAAAAAAAipTdGFydCBhIG5ldyBnYW1lIGJldHdlZW4gdHdvIHBsYXllcnMgd2l0aCBwb2ludHMuClRoaXMgY3JlYXRlcyBhIHNlc3Npb24gaW4gdGhlIEdhbWUgSHViIGFuZCBsb2NrcyBwb2ludHMgYmVmb3JlIHN0YXJ0aW5nIHRoZSBnYW1lLgoKKipDUklUSUNBTDoqKiBUaGlzIG1ldGhvZCByZXF1aXJlcyBhdXRob3JpemF0aW9uIGZyb20gVEhJUyBjb250cmFjdCAobm90IHBsYXllcnMpLgpUaGUgR2FtZSBIdWIgd2lsbCBjYWxsIGBnYW1lX2lkLnJlcXVpcmVfYXV0aCgpYCB3aGljaCBjaGVja3MgdGhpcyBjb250cmFjdCdzIGFkZHJlc3MuCgojIEFyZ3VtZW50cwoqIGBzZXNzaW9uX2lkYCAtIFVuaXF1ZSBzZXNzaW9uIGlkZW50aWZpZXIgKHUzMikKKiBgcGxheWVyMWAgLSBBZGRyZXNzIG9mIGZpcnN0IHBsYXllcgoqIGBwbGF5ZXIyYCAtIEFkZHJlc3Mgb2Ygc2Vjb25kIHBsYXllcgoqIGBwbGF5ZXIxX3BvaW50c2AgLSBQb2ludHMgYW1vdW50IGNvbW1pdHRlZCBieSBwbGF5ZXIgMQoqIGBwbGF5ZXIyX3BvaW50c2AgLSBQb2ludHMgYW1vdW50IGNvbW1pdHRlZCBieSBwbGF5ZXIgMgAAAAAACnN0YXJ0X2dhbWUAAAAAAAUAAAAAAAAACnNlc3Npb25faWQAAAAAAAQAAAAAAAAAB3BsYXllcjEAAAAAEwAAAAAAAAAHcGxheWVyMgAAAAATAAAAAAAAAA5wbGF5ZXIxX3BvaW50cwAAAAAACwAAAAAAAAAOcGxheWVyMl9wb2ludHMAAAAAAAsAAAABAAAD6QAAA+0AAAAAAAAAAw==",
+      new ContractSpec([ "AAAABAAAAAAAAAAAAAAABUVycm9yAAAAAAAACwAAAAAAAAAMR2FtZU5vdEZvdW5kAAAAAQAAAAAAAAAJTm90UGxheWVyAAAAAAAAAgAAAAAAAAAOQWxyZWFkeUd1ZXNzZWQAAAAAAAMAAAAAAAAAFUJvdGhQbGF5ZXJzTm90R3Vlc3NlZAAAAAAAAAQAAAAAAAAAEEdhbWVBbHJlYWR5RW5kZWQAAAAFAAAAAAAAABFQbGF5ZXJOb3RTdGFsbGluZwAAAAAAAAYAAAAAAAAADkNvbnRyYWN0UGF1c2VkAAAAAAAHAAAAAAAAABRTZXNzaW9uQWxyZWFkeUV4aXN0cwAAAAgAAAAAAAAAF0JvdGhQbGF5ZXJzTm90Q29tbWl0dGVkAAAAAAkAAAAAAAAAD0FscmVhZHlSZXZlYWxlZAAAAAAKAAAAAAAAABJDb21taXRtZW50TWlzbWF0Y2gAAAAAAAs=",
+        "AAAAAgAAAAAAAAAAAAAACkdhbWVTdGF0dXMAAAAAAAIAAAAAAAAAAAAAAAZBY3RpdmUAAAAAAAEAAAAAAAAABUVuZGVkAAAAAAAAAQAAB9AAAAALR2FtZU91dGNvbWUA",
+        "AAAAAQAAAAAAAAAAAAAABEdhbWUAAAANAAAAAAAAAAdwbGF5ZXIxAAAAABMAAAAAAAAAEnBsYXllcjFfY29tbWl0bWVudAAAAAAD6AAAA+4AAAAgAAAAJ1JldmVhbGVkIGd1ZXNzZXMsIHNldCBieSBgcmV2ZWFsX2d1ZXNzYAAAAAANcGxheWVyMV9ndWVzcwAAAAAAA+gAAAAEAAAAAAAAAA5wbGF5ZXIxX3BvaW50cwAAAAAACwAAAE5SZXZlYWxlZCBzYWx0cywgc2V0IGJ5IGByZXZlYWxfZ3Vlc3NgIGFuZCBtaXhlZCBpbnRvIHRoZSB3aW5uaW5nCm51bWJlcidzIHNlZWQAAAAAAAxwbGF5ZXIxX3NhbHQAAAPoAAAD7gAAACAAAAAAAAAAB3BsYXllcjIAAAAAEwAAAAAAAAAScGxheWVyMl9jb21taXRtZW50AAAAAAPoAAAD7gAAACAAAAAAAAAADXBsYXllcjJfZ3Vlc3MAAAAAAAPoAAAABAAAAAAAAAAOcGxheWVyMl9wb2ludHMAAAAAAAsAAAAAAAAADHBsYXllcjJfc2FsdAAAA+gAAAPuAAAAIAAAAAAAAAAGc3RhdHVzAAAAAAfQAAAACkdhbWVTdGF0dXMAAAAAAAAAAAAGd2lubmVyAAAAAAPoAAAAEwAAAAAAAAAOd2lubmluZ19udW1iZXIAAAAAA+gAAAAE",
+        "AAAAAgAAAAAAAAAAAAAAB0RhdGFLZXkAAAAAAQAAAAEAAAAAAAAABEdhbWUAAAABAAAABA==",
+        "AAAABAAAAHpFcnJvcnMgcmV0dXJuZWQgYnkgdGhlIHNoYXJlZCBhZG1pbiBlbnRyeXBvaW50cy4gQ29kZXMgc3RhcnQgYXQgMTAwIHNvCnRoZXkgbmV2ZXIgY29sbGlkZSB3aXRoIGEgZ2FtZSdzIG93biBgRXJyb3JgIGNvZGVzLgAAAAAAAAAAAAtDb21tb25FcnJvcgAAAAABAAAAI05vIGFkbWluIHRyYW5zZmVyIGhhcyBiZWVuIHByb3Bvc2VkAAAAAA5Ob1BlbmRpbmdBZG1pbgAAAAAAZA==",
+        "AAAAAgAAAEpTZXNzaW9uIG91dGNvbWUgcmVwb3J0ZWQgdG8gdGhlIEdhbWUgSHViIChtaXJyb3JzIHRoZSBodWIncyBgR2FtZU91dGNvbWVgKQAAAAAAAAAAAAtHYW1lT3V0Y29tZQAAAAAFAAAAAAAAAAAAAAAKUGxheWVyMVdvbgAAAAAAAAAAAAAAAAAKUGxheWVyMldvbgAAAAAAAAAAAAAAAAAERHJhdwAAAAAAAAAAAAAACUNhbmNlbGxlZAAAAAAAAAEAAAAAAAAAB0ZvcmZlaXQAAAAAAQAAABM=",
+        "AAAAAgAAACNIb3cgdGhlIGh1YiBzZXR0bGVzIGEgZ2FtZSdzIHN0YWtlcwAAAAAAAAAADlN0YWtlU2VtYW50aWNzAAAAAAACAAAAAAAAAFZFYWNoIHBsYXllciBzdGFrZXM7IHRoZSB3aW5uZXIgdGFrZXMgZXZlcnkgc3Rha2UuIERyYXdzIGFuZApjYW5jZWxsYXRpb25zIHJlZnVuZCB0aGVtLgAAAAAADldpbm5lclRha2VzQWxsAAAAAAAAAAAAQEVhY2ggcGxheWVyIHN0YWtlczsgdGhlIGh1YiBzcGxpdHMgdGhlIHBvdCBieSBmaW5pc2hpbmcgcG9zaXRpb24AAAAKUGxhY2VtZW50cwAA",
+        "AAAAAQAAADdTdGF0aWMgZGVzY3JpcHRpb24gb2YgYSBnYW1lLCBmb3IgbG9iYmllcyBhbmQgaHViIHRvb2xzAAAAAAAAAAAIR2FtZUluZm8AAAAFAAAAAAAAAAttYXhfcGxheWVycwAAAAAEAAAAAAAAAAttaW5fcGxheWVycwAAAAAEAAAAAAAAAARuYW1lAAAAEAAAAD1CdW1wZWQgd2hlbmV2ZXIgYSBjaGFuZ2UgdG8gdGhlIHJ1bGVzIGNvdWxkIGNoYW5nZSBhbiBvdXRjb21lAAAAAAAADXJ1bGVzX3ZlcnNpb24AAAAAAAAEAAAAAAAAAAZzdGFrZXMAAAAAB9AAAAAOU3Rha2VTZW1hbnRpY3MAAA==",
+        "AAAAAgAAACtHYW1lLWluZGVwZW5kZW50IHZpZXcgb2YgYSBzZXNzaW9uJ3Mgc3RhdHVzAAAAAAAAAAANU2Vzc2lvblN0YXR1cwAAAAAAAAIAAAAAAAAAAAAAAAZBY3RpdmUAAAAAAAEAAAAAAAAABUVuZGVkAAAAAAAAAQAAB9AAAAALR2FtZU91dGNvbWUA",
+        "AAAAAgAAAEFSb2xlcyB0aGUgYWRtaW4gY2FuIGdyYW50LiBUaGUgYWRtaW4gaW1wbGljaXRseSBob2xkcyBldmVyeSByb2xlLgAAAAAAAAAAAAAEUm9sZQAAAAEAAAAAAAAAfkRheS10by1kYXkgZ2FtZSBvcGVyYXRpb25zIChjcmVhdGluZyBjYXNlcyBhbmQgc2NlbmVzLCByZXNvbHZpbmcKZ2FtZXMpLiBDYW5ub3QgY2hhbmdlIHRoZSBhZG1pbiwgdGhlIGh1YiBhZGRyZXNzIG9yIHRoZSBXQVNNLgAAAAAACE9wZXJhdG9y",
         "AAAAAAAAAKNJbml0aWFsaXplIHRoZSBjb250cmFjdCB3aXRoIEdhbWVIdWIgYWRkcmVzcyBhbmQgYWRtaW4KCiMgQXJndW1lbnRzCiogYGFkbWluYCAtIEFkbWluIGFkZHJlc3MgKGNhbiB1cGdyYWRlIGNvbnRyYWN0KQoqIGBnYW1lX2h1YmAgLSBBZGRyZXNzIG9mIHRoZSBHYW1lSHViIGNvbnRyYWN0AAAAAA1fX2NvbnN0cnVjdG9yAAAAAAAAAgAAAAAAAAAFYWRtaW4AAAAAAAATAAAAAAAAAAhnYW1lX2h1YgAAABMAAAAA",
-        "AAAAAAAAATtSZXZlYWwgdGhlIHdpbm5lciBvZiB0aGUgZ2FtZSBhbmQgc3VibWl0IG91dGNvbWUgdG8gR2FtZUh1Yi4KQ2FuIG9ubHkgYmUgY2FsbGVkIGFmdGVyIGJvdGggcGxheWVycyBoYXZlIG1hZGUgdGhlaXIgZ3Vlc3Nlcy4KVGhpcyBnZW5lcmF0ZXMgdGhlIHdpbm5pbmcgbnVtYmVyLCBkZXRlcm1pbmVzIHRoZSB3aW5uZXIsIGFuZCBlbmRzIHRoZSBzZXNzaW9uLgoKIyBBcmd1bWVudHMKKiBgc2Vzc2lvbl9pZGAgLSBUaGUgc2Vzc2lvbiBJRCBvZiB0aGUgZ2FtZQoKIyBSZXR1cm5zCiogYEFkZHJlc3NgIC0gQWRkcmVzcyBvZiB0aGUgd2lubmluZyBwbGF5ZXIAAAAADXJldmVhbF93aW5uZXIAAAAAAAABAAAAAAAAAApzZXNzaW9uX2lkAAAAAAAEAAAAAQAAA+kAAAATAAAAAw==" ]),
+        "AAAAAAAAAkJTdGFydCBhIG5ldyBnYW1lIGJldHdlZW4gdHdvIHBsYXllcnMgd2l0aCBwb2ludHMuClRoaXMgY3JlYXRlcyBhIHNlc3Npb24gaW4gdGhlIEdhbWUgSHViIGFuZCBsb2NrcyBwb2ludHMgYmVmb3JlIHN0YXJ0aW5nIHRoZSBnYW1lLgoKKipDUklUSUNBTDoqKiBUaGlzIG1ldGhvZCByZXF1aXJlcyBhdXRob3JpemF0aW9uIGZyb20gVEhJUyBjb250cmFjdCAobm90IHBsYXllcnMpLgpUaGUgR2FtZSBIdWIgd2lsbCBjYWxsIGBnYW1lX2lkLnJlcXVpcmVfYXV0aCgpYCB3aGljaCBjaGVja3MgdGhpcyBjb250cmFjdCdzIGFkZHJlc3MuCgojIEFyZ3VtZW50cwoqIGBzZXNzaW9uX2lkYCAtIFNlc3Npb24gSUQgcmVzZXJ2ZWQgb24gdGhlIEdhbWUgSHViIChgcmVzZXJ2ZV9zZXNzaW9uYCkKKiBgcGxheWVyMWAgLSBBZGRyZXNzIG9mIGZpcnN0IHBsYXllcgoqIGBwbGF5ZXIyYCAtIEFkZHJlc3Mgb2Ygc2Vjb25kIHBsYXllcgoqIGBwbGF5ZXIxX3BvaW50c2AgLSBQb2ludHMgYW1vdW50IGNvbW1pdHRlZCBieSBwbGF5ZXIgMQoqIGBwbGF5ZXIyX3BvaW50c2AgLSBQb2ludHMgYW1vdW50IGNvbW1pdHRlZCBieSBwbGF5ZXIgMgAAAAAACnN0YXJ0X2dhbWUAAAAAAAUAAAAAAAAACnNlc3Npb25faWQAAAAAAAQAAAAAAAAAB3BsYXllcjEAAAAAEwAAAAAAAAAHcGxheWVyMgAAAAATAAAAAAAAAA5wbGF5ZXIxX3BvaW50cwAAAAAACwAAAAAAAAAOcGxheWVyMl9wb2ludHMAAAAAAAsAAAABAAAD6QAAAAIAAAAD",
+        "AAAAAAAAAUpDb21taXQgYSBzZWFsZWQgZ3Vlc3MgZm9yIHRoZSBjdXJyZW50IGdhbWUuClRoZSBndWVzcyBzdGF5cyBoaWRkZW4gdW50aWwgYm90aCBwbGF5ZXJzIGhhdmUgY29tbWl0dGVkLgoKIyBBcmd1bWVudHMKKiBgc2Vzc2lvbl9pZGAgLSBUaGUgc2Vzc2lvbiBJRCBvZiB0aGUgZ2FtZQoqIGBwbGF5ZXJgIC0gQWRkcmVzcyBvZiB0aGUgcGxheWVyIGNvbW1pdHRpbmcKKiBgY29tbWl0bWVudGAgLSBga2VjY2FrMjU2KGd1ZXNzIHx8IHNhbHQgfHwgcGxheWVyX2FkZHJlc3MpYCwgd2l0aCB0aGUKZ3Vlc3MgYXMgNCBiaWctZW5kaWFuIGJ5dGVzIGFuZCBhIDMyLWJ5dGUgc2VjcmV0IHNhbHQAAAAAAAxjb21taXRfZ3Vlc3MAAAADAAAAAAAAAApzZXNzaW9uX2lkAAAAAAAEAAAAAAAAAAZwbGF5ZXIAAAAAABMAAAAAAAAACmNvbW1pdG1lbnQAAAAAA+4AAAAgAAAAAQAAA+kAAAACAAAAAw==",
+        "AAAAAAAAATNSZXZlYWwgYSBjb21taXR0ZWQgZ3Vlc3MuIENhbiBvbmx5IGJlIGNhbGxlZCBhZnRlciBib3RoIHBsYXllcnMgaGF2ZQpjb21taXR0ZWQuIFBsYXllcnMgY2FuIGd1ZXNzIGEgbnVtYmVyIGJldHdlZW4gMSBhbmQgMTAuCgojIEFyZ3VtZW50cwoqIGBzZXNzaW9uX2lkYCAtIFRoZSBzZXNzaW9uIElEIG9mIHRoZSBnYW1lCiogYHBsYXllcmAgLSBBZGRyZXNzIG9mIHRoZSBwbGF5ZXIgcmV2ZWFsaW5nCiogYGd1ZXNzYCAtIFRoZSBndWVzc2VkIG51bWJlciAoMS0xMCkKKiBgc2FsdGAgLSBUaGUgc2FsdCB1c2VkIGluIHRoZSBjb21taXRtZW50AAAAAAxyZXZlYWxfZ3Vlc3MAAAAEAAAAAAAAAApzZXNzaW9uX2lkAAAAAAAEAAAAAAAAAAZwbGF5ZXIAAAAAABMAAAAAAAAABWd1ZXNzAAAAAAAABAAAAAAAAAAEc2FsdAAAA+4AAAAgAAAAAQAAA+kAAAACAAAAAw==",
+        "AAAAAAAAAV1SZXZlYWwgdGhlIHdpbm5lciBvZiB0aGUgZ2FtZSBhbmQgc3VibWl0IG91dGNvbWUgdG8gR2FtZUh1Yi4KQ2FuIG9ubHkgYmUgY2FsbGVkIGFmdGVyIGJvdGggcGxheWVycyBoYXZlIHJldmVhbGVkIHRoZWlyIGd1ZXNzZXMuClRoaXMgZ2VuZXJhdGVzIHRoZSB3aW5uaW5nIG51bWJlciwgZGV0ZXJtaW5lcyB0aGUgd2lubmVyLCBhbmQgZW5kcyB0aGUgc2Vzc2lvbi4KCiMgQXJndW1lbnRzCiogYHNlc3Npb25faWRgIC0gVGhlIHNlc3Npb24gSUQgb2YgdGhlIGdhbWUKCiMgUmV0dXJucwoqIGBPcHRpb248QWRkcmVzcz5gIC0gQWRkcmVzcyBvZiB0aGUgd2lubmluZyBwbGF5ZXIsIG9yIGBOb25lYCBmb3IgYSBkcmF3AAAAAAAADXJldmVhbF93aW5uZXIAAAAAAAABAAAAAAAAAApzZXNzaW9uX2lkAAAAAAAEAAAAAQAAA+kAAAPoAAAAEwAAAAM=",
+        "AAAAAAAAAJ1HZXQgZ2FtZSBpbmZvcm1hdGlvbi4KCiMgQXJndW1lbnRzCiogYHNlc3Npb25faWRgIC0gVGhlIHNlc3Npb24gSUQgb2YgdGhlIGdhbWUKCiMgUmV0dXJucwoqIGBHYW1lYCAtIFRoZSBnYW1lIHN0YXRlIChpbmNsdWRlcyB3aW5uaW5nIG51bWJlciBhZnRlciBnYW1lIGVuZHMpAAAAAAAACGdldF9nYW1lAAAAAQAAAAAAAAAKc2Vzc2lvbl9pZAAAAAAABAAAAAEAAAPpAAAH0AAAAARHYW1lAAAAAw==",
+        "AAAAAAAAAWtDYWxsZWQgYnkgdGhlIEdhbWUgSHViIHdoZW4gYSB0aW1lb3V0IGlzIGNsYWltZWQgYWdhaW5zdCBgcGxheWVyYC4KT25seSBhY2NlcHRlZCBpZiBgcGxheWVyYCBoYXMgbm90IGNvbW1pdHRlZCB3aGlsZSB0aGVpciBvcHBvbmVudCBoYXMsCm9yIGhhcyBub3QgcmV2ZWFsZWQgd2hpbGUgdGhlaXIgb3Bwb25lbnQgaGFzOyB0aGUgZ2FtZSB0aGVuIGVuZHMgYXMgYQpmb3JmZWl0IGJ5IGBwbGF5ZXJgLiBUaGUgaHViIHNldHRsZXMgdGhlIHBvaW50cy4KCiMgQXJndW1lbnRzCiogYHNlc3Npb25faWRgIC0gVGhlIHNlc3Npb24gSUQgb2YgdGhlIGdhbWUKKiBgcGxheWVyYCAtIEFkZHJlc3Mgb2YgdGhlIHBsYXllciBiZWluZyBmb3JmZWl0ZWQAAAAAFG9uX3Nlc3Npb25fZm9yZmVpdGVkAAAAAgAAAAAAAAAKc2Vzc2lvbl9pZAAAAAAABAAAAAAAAAAGcGxheWVyAAAAAAATAAAAAQAAA+kAAAACAAAAAw==",
+        "AAAAAAAAALxDYWxsZWQgYnkgdGhlIEdhbWUgSHViIHdoZW4gaXQgY2FuY2VscyB0aGUgc2Vzc2lvbiAodGltZW91dCBvciBwYXVzZSkuClRoZSBnYW1lIGVuZHMgd2l0aG91dCBhIHdpbm5lcjsgdGhlIGh1YiByZWZ1bmRzIGJvdGggc3Rha2VzLgoKIyBBcmd1bWVudHMKKiBgc2Vzc2lvbl9pZGAgLSBUaGUgc2Vzc2lvbiBJRCBvZiB0aGUgZ2FtZQAAABRvbl9zZXNzaW9uX2NhbmNlbGxlZAAAAAEAAAAAAAAACnNlc3Npb25faWQAAAAAAAQAAAABAAAD6QAAAAIAAAAD",
+        "AAAAAAAAADhEZXNjcmliZSB0aGUgZ2FtZTogdHdvIHBsYXllcnMsIHdpbm5lciB0YWtlcyBib3RoIHN0YWtlcwAAAAlnYW1lX2luZm8AAAAAAAAAAAAAAQAAB9AAAAAIR2FtZUluZm8=",
+        "AAAAAAAAAEhHZXQgdGhlIHN0YXR1cyBvZiBhIHNlc3Npb24sIG9yIGBOb25lYCBpZiBpdCBkb2VzIG5vdCBleGlzdCAob3IgZXhwaXJlZCkAAAASZ2V0X3Nlc3Npb25fc3RhdHVzAAAAAAABAAAAAAAAAApzZXNzaW9uX2lkAAAAAAAEAAAAAQAAA+gAAAfQAAAADVNlc3Npb25TdGF0dXMAAAA=",
+        "AAAAAAAAAK5Db25jZWRlIHRoZSBnYW1lLiBUaGUgb3Bwb25lbnQgd2lucyBhbmQgdGhlIEdhbWUgSHViIHNldHRsZXMgdGhlIHBvaW50cy4KCiMgQXJndW1lbnRzCiogYHNlc3Npb25faWRgIC0gVGhlIHNlc3Npb24gSUQgb2YgdGhlIGdhbWUKKiBgcGxheWVyYCAtIEFkZHJlc3Mgb2YgdGhlIHBsYXllciBjb25jZWRpbmcAAAAAAAdmb3JmZWl0AAAAAAIAAAAAAAAACnNlc3Npb25faWQAAAAAAAQAAAAAAAAABnBsYXllcgAAAAAAEwAAAAEAAAPpAAAAAgAAAAM=",
+        "AAAAAAAAAB1HZXQgdGhlIGN1cnJlbnQgYWRtaW4gYWRkcmVzcwAAAAAAAAlnZXRfYWRtaW4AAAAAAAAAAAAAAQAAABM=",
+        "AAAAAAAAADFHZXQgdGhlIGFkbWluIHByb3Bvc2VkIGJ5IGBwcm9wb3NlX2FkbWluYCwgaWYgYW55AAAAAAAAEWdldF9wZW5kaW5nX2FkbWluAAAAAAAAAAAAAAEAAAPoAAAAEw==",
+        "AAAAAAAAAEJQcm9wb3NlIGEgbmV3IGFkbWluLiBUaGV5IHRha2Ugb3ZlciBvbmNlIHRoZXkgY2FsbApgYWNjZXB0X2FkbWluYC4AAAAAAA1wcm9wb3NlX2FkbWluAAAAAAAAAQAAAAAAAAAJbmV3X2FkbWluAAAAAAAAEwAAAAA=",
+        "AAAAAAAAADRBY2NlcHQgYSBwZW5kaW5nIGFkbWluIHRyYW5zZmVyLiBQZW5kaW5nIGFkbWluIG9ubHkuAAAADGFjY2VwdF9hZG1pbgAAAAAAAAABAAAD6QAAAAIAAAfQAAAAC0NvbW1vbkVycm9yAA==",
+        "AAAAAAAAABpHcmFudCBhIHJvbGUgdG8gYW4gYWNjb3VudAAAAAAACmdyYW50X3JvbGUAAAAAAAIAAAAAAAAABHJvbGUAAAfQAAAABFJvbGUAAAAAAAAAB2FjY291bnQAAAAAEwAAAAA=",
+        "AAAAAAAAAB1SZXZva2UgYSByb2xlIGZyb20gYW4gYWNjb3VudAAAAAAAAAtyZXZva2Vfcm9sZQAAAAACAAAAAAAAAARyb2xlAAAH0AAAAARSb2xlAAAAAAAAAAdhY2NvdW50AAAAABMAAAAA",
+        "AAAAAAAAADtDaGVjayB3aGV0aGVyIGFuIGFjY291bnQgaG9sZHMgYSByb2xlICh0aGUgYWRtaW4gaG9sZHMgYWxsKQAAAAAIaGFzX3JvbGUAAAACAAAAAAAAAARyb2xlAAAH0AAAAARSb2xlAAAAAAAAAAdhY2NvdW50AAAAABMAAAABAAAAAQ==",
+        "AAAAAAAAAChHZXQgdGhlIGN1cnJlbnQgR2FtZUh1YiBjb250cmFjdCBhZGRyZXNzAAAAB2dldF9odWIAAAAAAAAAAAEAAAAT",
+        "AAAAAAAAACJTZXQgYSBuZXcgR2FtZUh1YiBjb250cmFjdCBhZGRyZXNzAAAAAAAHc2V0X2h1YgAAAAABAAAAAAAAAAduZXdfaHViAAAAABMAAAAA",
+        "AAAAAAAAAK1QYXVzZSBnYW1lcGxheTogbmV3IGdhbWVzIGFuZCBtb3ZlcyBmYWlsIHdpdGggYENvbnRyYWN0UGF1c2VkYAp1bnRpbCBgdW5wYXVzZWAgaXMgY2FsbGVkLiBIdWIgY2FsbGJhY2tzIHN0aWxsIHJ1biwgc28gc2Vzc2lvbnMKY2FuIGJlIGNhbmNlbGxlZCBhbmQgcmVmdW5kZWQgdGhyb3VnaCB0aGUgaHViLgAAAAAAAAVwYXVzZQAAAAAAAAAAAAAA",
+        "AAAAAAAAAB1SZXN1bWUgZ2FtZXBsYXkgYWZ0ZXIgYHBhdXNlYAAAAAAAAAd1bnBhdXNlAAAAAAAAAAAA",
+        "AAAAAAAAACRDaGVjayB3aGV0aGVyIHRoZSBjb250cmFjdCBpcyBwYXVzZWQAAAAJaXNfcGF1c2VkAAAAAAAAAAAAAAEAAAAB",
+        "AAAAAAAAADBVcGRhdGUgdGhlIGNvbnRyYWN0IFdBU00gaGFzaCAodXBncmFkZSBjb250cmFjdCkAAAAHdXBncmFkZQAAAAABAAAAAAAAAA1uZXdfd2FzbV9oYXNoAAAAAAAD7gAAACAAAAAA" ]),
       options
     )
   }
   public readonly fromJSON = {
-    get_hub: this.txFromJSON<string>,
-        set_hub: this.txFromJSON<null>,
-        upgrade: this.txFromJSON<null>,
+    start_game: this.txFromJSON<Result<void>>,
+        commit_guess: this.txFromJSON<Result<void>>,
+        reveal_guess: this.txFromJSON<Result<void>>,
+        reveal_winner: this.txFromJSON<Result<Option<string>>>,
         get_game: this.txFromJSON<Result<Game>>,
+        on_session_forfeited: this.txFromJSON<Result<void>>,
+        on_session_cancelled: this.txFromJSON<Result<void>>,
+        game_info: this.txFromJSON<GameInfo>,
+        get_session_status: this.txFromJSON<Option<SessionStatus>>,
+        forfeit: this.txFromJSON<Result<void>>,
         get_admin: this.txFromJSON<string>,
-        set_admin: this.txFromJSON<null>,
-        make_guess: this.txFromJSON<Result<void>>,
-        start_game: this.txFromJSON<Result<void>>,
-        reveal_winner: this.txFromJSON<Result<string>>
+        get_pending_admin: this.txFromJSON<Option<string>>,
+        propose_admin: this.txFromJSON<null>,
+        accept_admin: this.txFromJSON<Result<void>>,
+        grant_role: this.txFromJSON<null>,
+        revoke_role: this.txFromJSON<null>,
+        has_role: this.txFromJSON<boolean>,
+        get_hub: this.txFromJSON<string>,
+        set_hub: this.txFromJSON<null>,
+        pause: this.txFromJSON<null>,
+        unpause: this.txFromJSON<null>,
+        is_paused: this.txFromJSON<boolean>,
+        upgrade: this.txFromJSON<null>
   }
 }
\ No newline at end of file
@@ -5,9 +5,18 @@ import { Buffer } from 'buffer';
 import { signAndSendViaLaunchtube } from '@/utils/transactionHelper';
 import { calculateValidUntilLedger } from '@/utils/ledgerUtils';
 import { injectSignedAuthEntry } from '@/utils/authEntryUtils';
+import { randomSecret, keccak256, u32Bytes, addressBytes, saveSecret, loadSecret, clearSecret } from '@/utils/commitUtils';
 
 type ClientOptions = contract.ClientOptions;
 
+/**
+ * A sealed guess kept on this device until it is revealed
+ */
+interface SavedGuess {
+  guess: number;
+  salt: string; // hex
+}
+
 /**
  * Service for interacting with the NumberGuess game contract
  */
@@ -576,9 +585,11 @@ export class NumberGuessService {
   }
 
   /**
-   * Make a guess (1-10)
+   * Commit a sealed guess (1-10)
+   * Only keccak256(guess || salt || player) is sent; the guess and a fresh random
+   * salt are saved on this device for revealGuess.
    */
-  async makeGuess(
+  async commitGuess(
     sessionId: number,
     playerAddress: string,
     guess: number,
@@ -589,11 +600,19 @@ export class NumberGuessService {
       throw new Error('Guess must be between 1 and 10');
     }
 
+    const salt = randomSecret();
+    const commitment = keccak256(u32Bytes(guess), salt, addressBytes(playerAddress));
+    // Save before sending: without the salt the guess can never be revealed
+    saveSecret<SavedGuess>(this.contractId, sessionId, playerAddress, {
+      guess,
+      salt: salt.toString('hex'),
+    });
+
     const client = this.createSigningClient(playerAddress, signer);
-    const tx = await client.make_guess({
+    const tx = await client.commit_guess({
       session_id: sessionId,
       player: playerAddress,
-      guess,
+      commitment,
     }, DEFAULT_METHOD_OPTIONS);
     // NOTE: Contract methods automatically simulate - footprint is already prepared
 
@@ -619,7 +638,58 @@ export class NumberGuessService {
   }
 
   /**
-   * Reveal the winner after both players have guessed
+   * Guess committed from this device and not yet revealed, if any
+   */
+  getSavedGuess(sessionId: number, playerAddress: string): number | null {
+    return loadSecret<SavedGuess>(this.contractId, sessionId, playerAddress)?.guess ?? null;
+  }
+
+  /**
+   * Reveal the guess committed from this device (after both players have committed)
+   */
+  async revealGuess(
+    sessionId: number,
+    playerAddress: string,
+    signer: Pick<contract.ClientOptions, 'signTransaction' | 'signAuthEntry'>,
+    authTtlMinutes?: number
+  ) {
+    const saved = loadSecret<SavedGuess>(this.contractId, sessionId, playerAddress);
+    if (!saved) {
+      throw new Error('Your guess for this session was not committed from this device, so it cannot be revealed here');
+    }
+
+    const client = this.createSigningClient(playerAddress, signer);
+    const tx = await client.reveal_guess({
+      session_id: sessionId,
+      player: playerAddress,
+      guess: saved.guess,
+      salt: Buffer.from(saved.salt, 'hex'),
+    }, DEFAULT_METHOD_OPTIONS);
+
+    const validUntilLedgerSeq = authTtlMinutes
+      ? await calculateValidUntilLedger(RPC_URL, authTtlMinutes)
+      : await calculateValidUntilLedger(RPC_URL, DEFAULT_AUTH_TTL_MINUTES);
+
+    try {
+      const sentTx = await signAndSendViaLaunchtube(tx, DEFAULT_METHOD_OPTIONS.timeoutInSeconds, validUntilLedgerSeq);
+
+      if (sentTx.getTransactionResponse?.status === 'FAILED') {
+        const errorMessage = this.extractErrorFromDiagnostics(sentTx.getTransactionResponse);
+        throw new Error(`Transaction failed: ${errorMessage}`);
+      }
+
+      clearSecret(this.contractId, sessionId, playerAddress);
+      return sentTx.result;
+    } catch (err) {
+      if (err instanceof Error && err.message.includes('Transaction failed!')) {
+        throw new Error('Transaction failed - check if both players have committed and you haven\'t already revealed');
+      }
+      throw err;
+    }
+  }
+
+  /**
+   * Reveal the winner after both players have revealed their guesses
    */
   async revealWinner(
     sessionId: number,
@@ -655,7 +725,7 @@ export class NumberGuessService {
 
       if (err instanceof Error && err.message.includes('Transaction failed!')) {
         // This is the SDK error when trying to access .result on a failed transaction
-        throw new Error('Transaction failed - check if both players have guessed and the game is still active');
+        throw new Error('Transaction failed - check if both players have revealed and the game is still active');
       }
 
       throw err;
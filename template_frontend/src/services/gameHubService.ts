@@ -0,0 +1,57 @@
+import { Client as GameHubClient } from './gameHubBindings';
+import { NETWORK_PASSPHRASE, RPC_URL, DEFAULT_METHOD_OPTIONS, DEFAULT_AUTH_TTL_MINUTES } from '@/utils/constants';
+import { contract } from '@stellar/stellar-sdk';
+import { signAndSendViaLaunchtube } from '@/utils/transactionHelper';
+import { calculateValidUntilLedger } from '@/utils/ledgerUtils';
+
+/**
+ * Service for the Game Hub calls a game frontend makes directly
+ * (session IDs are issued by the hub, not chosen by the client)
+ */
+export class GameHubService {
+  private contractId: string;
+
+  constructor(contractId: string) {
+    this.contractId = contractId;
+  }
+
+  /**
+   * Reserve a session ID for a game.
+   * The reserver must be one of the players of the session started with it,
+   * and the game's start_game must be submitted before the reservation expires.
+   */
+  async reserveSession(
+    gameId: string,
+    reserver: string,
+    signer: Pick<contract.ClientOptions, 'signTransaction' | 'signAuthEntry'>,
+    authTtlMinutes?: number
+  ): Promise<number> {
+    const client = new GameHubClient({
+      contractId: this.contractId,
+      networkPassphrase: NETWORK_PASSPHRASE,
+      rpcUrl: RPC_URL,
+      publicKey: reserver,
+      ...signer,
+    });
+    const tx = await client.reserve_session({ game_id: gameId, reserver }, DEFAULT_METHOD_OPTIONS);
+
+    const validUntilLedgerSeq = await calculateValidUntilLedger(
+      RPC_URL,
+      authTtlMinutes ?? DEFAULT_AUTH_TTL_MINUTES
+    );
+    const sentTx = await signAndSendViaLaunchtube(
+      tx,
+      DEFAULT_METHOD_OPTIONS.timeoutInSeconds,
+      validUntilLedgerSeq
+    );
+
+    if (sentTx.getTransactionResponse?.status === 'FAILED') {
+      throw new Error('Failed to reserve a session ID on the Game Hub');
+    }
+    const result = sentTx.result;
+    if (result.isErr()) {
+      throw new Error(`Failed to reserve a session ID: ${result.unwrapErr().message}`);
+    }
+    return result.unwrap();
+  }
+}